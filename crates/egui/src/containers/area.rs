@@ -5,8 +5,9 @@
 use emath::GuiRounding as _;
 
 use crate::{
-    emath, pos2, Align2, Context, Id, InnerResponse, LayerId, Layout, NumExt, Order, Pos2, Rect,
-    Response, Sense, Ui, UiBuilder, UiKind, UiStackInfo, Vec2, WidgetRect, WidgetWithState,
+    emath, pos2, Align2, Context, Id, InnerResponse, Key, LayerId, Layout, Modifiers, NumExt,
+    Order, Pos2, Rect, Response, Sense, Ui, UiBuilder, UiKind, UiStackInfo, Vec2, Vec2b,
+    WidgetRect, WidgetWithState,
 };
 
 /// State of an [`Area`] that is persisted between frames.
@@ -39,6 +40,20 @@ pub struct AreaState {
     /// Used to fade in the area.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub last_became_visible_at: Option<f64>,
+
+    /// At what time did this area stop being shown?
+    ///
+    /// Used to fade out the area, see [`Area::fade_out`].
+    /// `None` while the area is being shown.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub last_became_invisible_at: Option<f64>,
+
+    /// Has the area become fully visible since it was last fully hidden?
+    ///
+    /// Used to call [`crate::Window::on_shown`] and [`crate::Window::on_hidden`]
+    /// once per transition.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub fully_visible: bool,
 }
 
 impl Default for AreaState {
@@ -49,6 +64,8 @@ impl Default for AreaState {
             size: None,
             interactable: true,
             last_became_visible_at: None,
+            last_became_invisible_at: None,
+            fully_visible: false,
         }
     }
 }
@@ -87,6 +104,50 @@ impl AreaState {
     }
 }
 
+/// Where an [`Area`] is placed this pass, before its contents are added.
+///
+/// See [`Area::show_with_placement`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AreaPlacement {
+    /// Where on screen the area is put.
+    ///
+    /// The size is the size of the contents last pass,
+    /// after any anchoring, pivoting and constraining of the position.
+    pub rect: Rect,
+
+    /// Which point of the area is at the pivot position, see [`Area::pivot`].
+    ///
+    /// For instance a popup that opened upwards has a bottom pivot.
+    pub pivot: Align2,
+
+    /// The area is kept within, and clipped to, this rect.
+    pub constrain_rect: Rect,
+
+    /// Is this the first, invisible, pass where the contents are measured?
+    ///
+    /// If so, the size in [`Self::rect`] is only a guess.
+    pub sizing_pass: bool,
+}
+
+/// Which points of an [`Area`] the pointer can interact with, see [`Area::hit_shape`].
+///
+/// The predicate gets positions relative to the top-left corner of the area.
+#[derive(Clone)]
+pub(crate) struct HitShape(pub(crate) std::sync::Arc<dyn Fn(Pos2) -> bool + Send + Sync>);
+
+impl HitShape {
+    /// Is `pos` (relative to the top-left corner of the area) inside the shape?
+    pub(crate) fn contains(&self, pos: Pos2) -> bool {
+        (self.0)(pos)
+    }
+}
+
+impl std::fmt::Debug for HitShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("HitShape")
+    }
+}
+
 /// An area on the screen that can be moved by dragging.
 ///
 /// This forms the base of the [`crate::Window`] container.
@@ -102,6 +163,9 @@ impl AreaState {
 /// ```
 ///
 /// The previous rectangle used by this area can be obtained through [`crate::Memory::area_rect()`].
+///
+/// An area is shown in the viewport of the [`Context`] pass it is shown in.
+/// Its state is kept per viewport, so the same [`Id`] can safely be used in several viewports.
 #[must_use = "You should call .show()"]
 #[derive(Clone, Debug)]
 pub struct Area {
@@ -109,17 +173,32 @@ pub struct Area {
     info: UiStackInfo,
     sense: Option<Sense>,
     movable: bool,
+    movable_axes: Vec2b,
     interactable: bool,
+    interactable_rects: Option<Vec<Rect>>,
+    hit_shape: Option<HitShape>,
     enabled: bool,
     constrain: bool,
     constrain_rect: Option<Rect>,
+    keep_on_screen_min: Option<Vec2>,
     order: Order,
     default_pos: Option<Pos2>,
     default_size: Vec2,
+    max_size: Option<Vec2>,
+    fit_to_screen: bool,
     pivot: Align2,
     anchor: Option<(Align2, Vec2)>,
+    movable_anchor: Option<(Align2, Vec2)>,
     new_pos: Option<Pos2>,
+    snap_to_grid: Option<(Vec2, Vec2)>,
+    snap_to_areas: Option<f32>,
+    snap_centers: bool,
+    snap_disable_modifiers: Modifiers,
+    drag_modifiers: Modifiers,
+    keyboard_modifiers: Option<Modifiers>,
+    keyboard_step: (f32, f32),
     fade_in: bool,
+    fade_out: f32,
     layout: Layout,
 }
 
@@ -135,17 +214,32 @@ impl Area {
             info: UiStackInfo::new(UiKind::GenericArea),
             sense: None,
             movable: true,
+            movable_axes: Vec2b::TRUE,
             interactable: true,
+            interactable_rects: None,
+            hit_shape: None,
             constrain: true,
             constrain_rect: None,
+            keep_on_screen_min: None,
             enabled: true,
             order: Order::Middle,
             default_pos: None,
             default_size: Vec2::NAN,
+            max_size: None,
+            fit_to_screen: false,
             new_pos: None,
             pivot: Align2::LEFT_TOP,
             anchor: None,
+            movable_anchor: None,
+            snap_to_grid: None,
+            snap_to_areas: None,
+            snap_centers: false,
+            snap_disable_modifiers: Modifiers::NONE,
+            drag_modifiers: Modifiers::NONE,
+            keyboard_modifiers: None,
+            keyboard_step: (8.0, 64.0),
             fade_in: true,
+            fade_out: 0.0,
             layout: Layout::default(),
         }
     }
@@ -199,6 +293,56 @@ impl Area {
         self
     }
 
+    /// Along which axes a [`Self::movable`] area can be moved, by dragging or with the keyboard.
+    ///
+    /// The other coordinate stays where it is, also when snapping.
+    /// For instance, `.movable_axes([true, false])` only lets the area be dragged horizontally.
+    ///
+    /// Default: `[true, true]`.
+    #[inline]
+    pub fn movable_axes(mut self, movable_axes: impl Into<Vec2b>) -> Self {
+        self.movable_axes = movable_axes.into();
+        self
+    }
+
+    /// Only move the area when these modifiers are held down while dragging it.
+    ///
+    /// Without them, the area doesn't sense drags at all, so they go to whatever is in it or behind it,
+    /// e.g. to let overlay windows on an interactive canvas be moved with `.drag_with_modifier(Modifiers::ALT)`.
+    /// A drag that starts with the modifiers held keeps moving the area if they are released.
+    ///
+    /// Default: [`Modifiers::NONE`], i.e. any drag moves a [`Self::movable`] area.
+    #[inline]
+    pub fn drag_with_modifier(mut self, modifiers: Modifiers) -> Self {
+        self.drag_modifiers = modifiers;
+        self
+    }
+
+    /// Let the user move the area with the arrow keys while holding down these modifiers.
+    ///
+    /// This only works while the keyboard focus is on a widget in the area
+    /// (or on the area itself), and only if the area is [`Self::movable`].
+    /// Holding down shift as well moves it by the larger step, see [`Self::keyboard_step`].
+    ///
+    /// For instance, `.keyboard_movable(Modifiers::ALT)` moves the area with alt+arrows.
+    ///
+    /// Default: off.
+    #[inline]
+    pub fn keyboard_movable(mut self, modifiers: Modifiers) -> Self {
+        self.keyboard_modifiers = Some(modifiers);
+        self
+    }
+
+    /// How many points each arrow key press moves a [`Self::keyboard_movable`] area,
+    /// and how many when shift is held down as well.
+    ///
+    /// Default: `8.0` and `64.0`.
+    #[inline]
+    pub fn keyboard_step(mut self, step: f32, large_step: f32) -> Self {
+        self.keyboard_step = (step, large_step);
+        self
+    }
+
     pub fn is_enabled(&self) -> bool {
         self.enabled
     }
@@ -219,6 +363,51 @@ impl Area {
         self
     }
 
+    /// Only let the pointer interact with the area inside these rectangles.
+    ///
+    /// Outside of them, clicks and hovers pass straight through to what is behind the area,
+    /// just like with [`Self::interactable`] set to `false`.
+    ///
+    /// This is useful for mostly-transparent overlays with a few interactive parts,
+    /// like a HUD with a toolbar in one corner.
+    ///
+    /// The rectangles are in the same coordinate space as the contents of the area.
+    ///
+    /// Default: the whole area is interactable.
+    #[inline]
+    pub fn interactable_rects(mut self, rects: impl IntoIterator<Item = Rect>) -> Self {
+        self.interactable_rects = Some(rects.into_iter().collect());
+        self
+    }
+
+    /// Only let the pointer interact with the area where `contains` returns `true`.
+    ///
+    /// This gives the area a non-rectangular hit area, like a circle.
+    /// Outside of the shape, clicks, hovers and drags pass straight through to what is behind the area,
+    /// just like outside of [`Self::interactable_rects`].
+    ///
+    /// `contains` gets positions relative to the top-left corner of the area,
+    /// so the shape follows the area when it is moved.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// let radius = 50.0;
+    /// egui::Area::new(egui::Id::new("round_hud"))
+    ///     .hit_shape(move |pos| pos.distance(egui::pos2(radius, radius)) <= radius)
+    ///     .show(ctx, |ui| {
+    ///         let (rect, _) = ui.allocate_exact_size(egui::Vec2::splat(2.0 * radius), egui::Sense::click());
+    ///         ui.painter().circle_filled(rect.center(), radius, egui::Color32::DARK_GRAY);
+    ///     });
+    /// # });
+    /// ```
+    ///
+    /// Default: the whole area is interactable.
+    #[inline]
+    pub fn hit_shape(mut self, contains: impl Fn(Pos2) -> bool + Send + Sync + 'static) -> Self {
+        self.hit_shape = Some(HitShape(std::sync::Arc::new(contains)));
+        self
+    }
+
     /// Explicitly set a sense.
     ///
     /// If not set, this will default to `Sense::drag()` if movable, `Sense::click()` if interactable, and `Sense::hover()` otherwise.
@@ -270,6 +459,30 @@ impl Area {
         self
     }
 
+    /// Never let the area grow larger than this.
+    ///
+    /// If the contents don't fit, they are clipped and can be scrolled
+    /// (with a [`crate::ScrollArea`] that is added automatically).
+    ///
+    /// Only applies to [`Self::show`].
+    #[inline]
+    pub fn max_size(mut self, max_size: impl Into<Vec2>) -> Self {
+        self.max_size = Some(max_size.into());
+        self
+    }
+
+    /// Never let the area grow larger than the screen (or [`Self::constrain_to`]).
+    ///
+    /// If the contents don't fit, they are clipped and can be scrolled,
+    /// just like with [`Self::max_size`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn fit_to_screen(mut self, fit_to_screen: bool) -> Self {
+        self.fit_to_screen = fit_to_screen;
+        self
+    }
+
     /// Positions the window and prevents it from being moved
     #[inline]
     pub fn fixed_pos(mut self, fixed_pos: impl Into<Pos2>) -> Self {
@@ -278,6 +491,59 @@ impl Area {
         self
     }
 
+    /// Snap the left-top corner of the area to a grid while the user drags it.
+    ///
+    /// The grid points are at `offset + n * spacing` for integer `n` (per axis).
+    /// The snapped position is what gets stored in the [`AreaState`].
+    ///
+    /// See also [`Self::snap_disable_modifiers`].
+    #[inline]
+    pub fn snap_to_grid(mut self, spacing: Vec2, offset: Vec2) -> Self {
+        self.snap_to_grid = Some((spacing, offset));
+        self
+    }
+
+    /// Magnetically snap the edges of the area to the edges of other areas while the user drags it.
+    ///
+    /// An edge snaps when it is within `distance` points of an edge of another visible area
+    /// of the same [`Order`], or of the region left free by the panels ([`Context::available_rect`]).
+    /// Edges snap both to the facing edge (putting the areas side by side)
+    /// and to the edge on the same side (lining them up).
+    /// A guide line is shown along the edge while the snap is engaged.
+    ///
+    /// A larger `distance` makes the snap stronger: it engages from further away,
+    /// and you have to drag further to pull the area loose again.
+    ///
+    /// See also [`Self::snap_centers`] and [`Self::snap_disable_modifiers`].
+    ///
+    /// Default: no snapping.
+    #[inline]
+    pub fn snap_to_areas(mut self, distance: f32) -> Self {
+        self.snap_to_areas = Some(distance.at_least(0.0));
+        self
+    }
+
+    /// With [`Self::snap_to_areas`], also snap the center of the area to the centers of other areas.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn snap_centers(mut self, snap_centers: bool) -> Self {
+        self.snap_centers = snap_centers;
+        self
+    }
+
+    /// Holding down these modifiers while dragging temporarily disables [`Self::snap_to_grid`]
+    /// and [`Self::snap_to_areas`].
+    ///
+    /// For instance, `.snap_disable_modifiers(Modifiers::ALT)`.
+    ///
+    /// Default: [`Modifiers::NONE`], i.e. snapping can't be disabled.
+    #[inline]
+    pub fn snap_disable_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.snap_disable_modifiers = modifiers;
+        self
+    }
+
     /// Constrains this area to [`Context::screen_rect`]?
     ///
     /// Default: `true`.
@@ -297,6 +563,19 @@ impl Area {
         self
     }
 
+    /// When not [constrained](Self::constrain), still keep at least this much
+    /// of the top of the area within the screen (or [`Self::constrain_to`]),
+    /// so it can't be dragged fully out of reach.
+    ///
+    /// The area is nudged back if it ends up further out, e.g. when the screen shrinks.
+    ///
+    /// Default: `None`.
+    #[inline]
+    pub fn keep_on_screen_min(mut self, min: impl Into<Vec2>) -> Self {
+        self.keep_on_screen_min = Some(min.into());
+        self
+    }
+
     /// Where the "root" of the area is.
     ///
     /// For instance, if you set this to [`Align2::RIGHT_TOP`]
@@ -334,6 +613,22 @@ impl Area {
         self.movable(false)
     }
 
+    /// Keep the area at the same distance from a corner (or edge) of the screen,
+    /// while still letting the user move it.
+    ///
+    /// The position is remembered as an offset from `align` of the screen,
+    /// starting out at `default_offset`, and updated as the user drags the area.
+    /// When the screen is resized, the area keeps its offset from that corner
+    /// instead of its distance from the left-top.
+    ///
+    /// For instance, `movable_anchor(Align2::RIGHT_BOTTOM, [-10.0, -10.0])` starts
+    /// the area in the bottom-right corner, and keeps it there as the screen grows or shrinks.
+    #[inline]
+    pub fn movable_anchor(mut self, align: Align2, default_offset: impl Into<Vec2>) -> Self {
+        self.movable_anchor = Some((align, default_offset.into()));
+        self
+    }
+
     pub(crate) fn get_pivot(&self) -> Align2 {
         if let Some((pivot, _)) = self.anchor {
             pivot
@@ -351,6 +646,20 @@ impl Area {
         self
     }
 
+    /// Keep painting the area for this many seconds after you stop showing it,
+    /// fading it out, instead of having it vanish instantly.
+    ///
+    /// The area is not interactive while fading out.
+    /// What is painted is the area as it looked the last time it was shown,
+    /// so this costs a copy of its shapes each frame.
+    ///
+    /// Default: `0.0` (no fade-out).
+    #[inline]
+    pub fn fade_out(mut self, duration: f32) -> Self {
+        self.fade_out = duration;
+        self
+    }
+
     /// Set the layout for the child Ui.
     #[inline]
     pub fn layout(mut self, layout: Layout) -> Self {
@@ -376,6 +685,7 @@ pub(crate) struct Prepared {
     sizing_pass: bool,
 
     fade_in: bool,
+    fade_out: f32,
     layout: Layout,
 }
 
@@ -385,30 +695,95 @@ impl Area {
         ctx: &Context,
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
+        self.show_with_placement(ctx, |ui, _placement| add_contents(ui))
+    }
+
+    /// Like [`Self::show`], but also tells the contents where the area is placed this pass.
+    ///
+    /// This lets the contents adapt to their placement on screen,
+    /// e.g. by flipping their layout when a popup had to open upwards.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// egui::Area::new(egui::Id::new("menu"))
+    ///     .fixed_pos(egui::pos2(32.0, 32.0))
+    ///     .pivot(egui::Align2::LEFT_BOTTOM)
+    ///     .show_with_placement(ctx, |ui, placement| {
+    ///         if placement.pivot.y() == egui::Align::Max {
+    ///             // Opened upwards: put the most important item at the bottom, nearest the button.
+    ///         }
+    ///         ui.label(format!("Placed at {:?}", placement.rect));
+    ///     });
+    /// # });
+    /// ```
+    pub fn show_with_placement<R>(
+        self,
+        ctx: &Context,
+        add_contents: impl FnOnce(&mut Ui, AreaPlacement) -> R,
+    ) -> InnerResponse<R> {
+        let max_size = self.capped_size(ctx);
         let mut prepared = self.begin(ctx);
+        let placement = prepared.placement();
         let mut content_ui = prepared.content_ui(ctx);
-        let inner = add_contents(&mut content_ui);
+        let inner = if let Some(max_size) = max_size {
+            crate::ScrollArea::both()
+                .max_width(max_size.x)
+                .max_height(max_size.y)
+                .show(&mut content_ui, |ui| add_contents(ui, placement))
+                .inner
+        } else {
+            add_contents(&mut content_ui, placement)
+        };
         let response = prepared.end(ctx, content_ui);
         InnerResponse { inner, response }
     }
 
+    /// The largest size the area may have, if it is capped by [`Self::max_size`] or [`Self::fit_to_screen`].
+    fn capped_size(&self, ctx: &Context) -> Option<Vec2> {
+        let screen_size = self.fit_to_screen.then(|| {
+            self.constrain_rect
+                .unwrap_or_else(|| ctx.screen_rect())
+                .size()
+        });
+        match (self.max_size, screen_size) {
+            (Some(max_size), Some(screen_size)) => Some(max_size.min(screen_size)),
+            (max_size, screen_size) => max_size.or(screen_size),
+        }
+    }
+
     pub(crate) fn begin(self, ctx: &Context) -> Prepared {
+        let capped_size = self.capped_size(ctx);
         let Self {
             id,
             info,
             sense,
             movable,
+            movable_axes,
             order,
             interactable,
+            interactable_rects,
+            hit_shape,
             enabled,
             default_pos,
             default_size,
+            max_size: _,
+            fit_to_screen: _,
             new_pos,
             pivot,
             anchor,
+            movable_anchor,
+            snap_to_grid,
+            snap_to_areas,
+            snap_centers,
+            snap_disable_modifiers,
+            drag_modifiers,
+            keyboard_modifiers,
+            keyboard_step,
             constrain,
             constrain_rect,
+            keep_on_screen_min,
             fade_in,
+            fade_out,
             layout,
         } = self;
 
@@ -416,6 +791,12 @@ impl Area {
 
         let layer_id = LayerId::new(order, id);
 
+        ctx.memory_mut(|m| {
+            m.areas_mut()
+                .set_interactable_rects(layer_id, interactable_rects);
+            m.areas_mut().set_hit_shape(layer_id, hit_shape);
+        });
+
         let state = AreaState::load(ctx, id);
         let mut sizing_pass = state.is_none();
         let mut state = state.unwrap_or(AreaState {
@@ -424,8 +805,11 @@ impl Area {
             size: None,
             interactable,
             last_became_visible_at: None,
+            last_became_invisible_at: None,
+            fully_visible: false,
         });
         state.pivot = pivot;
+        state.last_became_invisible_at = None;
         state.interactable = interactable;
         if let Some(new_pos) = new_pos {
             state.pivot_pos = Some(new_pos);
@@ -435,7 +819,7 @@ impl Area {
         });
         state.interactable = interactable;
 
-        let size = *state.size.get_or_insert_with(|| {
+        let mut size = *state.size.get_or_insert_with(|| {
             sizing_pass = true;
 
             // during the sizing pass we will use this as the max size
@@ -455,6 +839,11 @@ impl Area {
 
             size
         });
+        if let Some(capped_size) = capped_size {
+            // Place and constrain the area by the size it will be shown at:
+            size = size.at_most(capped_size);
+            state.size = Some(size);
+        }
 
         // TODO(emilk): if last frame was sizing pass, it should be considered invisible for smoother fade-in
         let visible_last_frame = ctx.memory(|mem| mem.areas().visible_last_frame(&layer_id));
@@ -472,9 +861,34 @@ impl Area {
             );
         }
 
+        // The offset from the anchor corner, and the screen rect it was measured against.
+        // Kept per viewport, like the `AreaState`:
+        let movable_anchor_id = id.with("movable_anchor").with(ctx.viewport_id());
+        if let Some((align, default_offset)) = movable_anchor {
+            let stored = ctx.data_mut(|d| d.get_persisted::<(Vec2, Rect)>(movable_anchor_id));
+            let (offset, anchored_to) = stored.unwrap_or((default_offset, Rect::NOTHING));
+            if anchored_to != constrain_rect {
+                // First frame, or the screen was resized:
+                state.set_left_top_pos(
+                    align
+                        .align_size_within_rect(size, constrain_rect)
+                        .left_top()
+                        + offset,
+                );
+            }
+        }
+
         // interact right away to prevent frame-delay
         let mut move_response = {
             let interact_id = layer_id.id.with("move");
+            let holds_modifiers = drag_modifiers.is_none()
+                || ctx.input(|i| i.modifiers.contains(drag_modifiers))
+                || ctx.is_being_dragged(interact_id);
+            ctx.memory_mut(|m| {
+                m.areas_mut()
+                    .set_passes_drags_through(layer_id, movable && !holds_modifiers);
+            });
+            let movable = movable && holds_modifiers;
             let sense = sense.unwrap_or_else(|| {
                 if movable {
                     Sense::drag()
@@ -497,9 +911,60 @@ impl Area {
                 true,
             );
 
+            // Keep the locked coordinates where they are:
+            let lock_axes = |pos: Pos2, current: Pos2| {
+                pos2(
+                    if movable_axes.x { pos.x } else { current.x },
+                    if movable_axes.y { pos.y } else { current.y },
+                )
+            };
+            let axes_mask = Vec2::new(
+                if movable_axes.x { 1.0 } else { 0.0 },
+                if movable_axes.y { 1.0 } else { 0.0 },
+            );
+
             if movable && move_response.dragged() {
-                if let Some(pivot_pos) = &mut state.pivot_pos {
-                    *pivot_pos += move_response.drag_delta();
+                if snap_to_grid.is_some() || snap_to_areas.is_some() {
+                    // Accumulate the unsnapped position, so that small drags add up:
+                    let unsnapped_id = interact_id.with("unsnapped");
+                    let drag_started = move_response.drag_started();
+                    let drag_delta = move_response.drag_delta() * axes_mask;
+                    let unsnapped = ctx.data_mut(|d| {
+                        if drag_started {
+                            d.insert_temp(unsnapped_id, state.left_top_pos());
+                        }
+                        let unsnapped =
+                            d.get_temp_mut_or_insert_with(unsnapped_id, || state.left_top_pos());
+                        *unsnapped += drag_delta;
+                        *unsnapped
+                    });
+
+                    let snap_disabled = !snap_disable_modifiers.is_none()
+                        && ctx.input(|i| i.modifiers.contains(snap_disable_modifiers));
+                    let mut pos = unsnapped;
+                    if !snap_disabled {
+                        if let Some((spacing, offset)) = snap_to_grid {
+                            pos = snap_pos_to_grid(pos, spacing, offset);
+                        }
+                        if let Some(distance) = snap_to_areas {
+                            let rect = Rect::from_min_size(pos, state.rect().size());
+                            let (delta, guides) =
+                                snap_rect_to_areas(ctx, layer_id, rect, distance, snap_centers);
+                            pos += delta;
+
+                            let painter = ctx.layer_painter(LayerId::new(
+                                Order::Foreground,
+                                interact_id.with("snap_guides"),
+                            ));
+                            let stroke = ctx.style().visuals.selection.stroke;
+                            for guide in guides {
+                                painter.line_segment(guide, stroke);
+                            }
+                        }
+                    }
+                    state.set_left_top_pos(lock_axes(pos, state.left_top_pos()));
+                } else if let Some(pivot_pos) = &mut state.pivot_pos {
+                    *pivot_pos += move_response.drag_delta() * axes_mask;
                 }
             }
 
@@ -511,6 +976,17 @@ impl Area {
                 ctx.request_repaint();
             }
 
+            if let Some(modifiers) = keyboard_modifiers.filter(|_| movable) {
+                if has_focus_within(ctx, layer_id) {
+                    let delta = consume_arrow_keys(ctx, modifiers, keyboard_step) * axes_mask;
+                    if delta != Vec2::ZERO {
+                        state.set_left_top_pos(state.left_top_pos() + delta);
+                        ctx.memory_mut(|m| m.areas_mut().move_to_top(layer_id));
+                        ctx.request_repaint();
+                    }
+                }
+            }
+
             move_response
         };
 
@@ -518,10 +994,21 @@ impl Area {
             state.set_left_top_pos(
                 Context::constrain_window_rect_to_area(state.rect(), constrain_rect).min,
             );
+        } else if let Some(min) = keep_on_screen_min {
+            state.set_left_top_pos(keep_top_on_screen(state.rect(), constrain_rect, min).min);
         }
 
         state.set_left_top_pos(state.left_top_pos());
 
+        // Not stored during the sizing pass, so we get placed again once we know our size:
+        if let Some((align, _)) = movable_anchor.filter(|_| !sizing_pass) {
+            let anchor_pos = align
+                .align_size_within_rect(size, constrain_rect)
+                .left_top();
+            let offset = state.left_top_pos() - anchor_pos;
+            ctx.data_mut(|d| d.insert_persisted(movable_anchor_id, (offset, constrain_rect)));
+        }
+
         // Update response with possibly moved/constrained rect:
         move_response.rect = state.rect();
         move_response.interact_rect = state.rect();
@@ -536,6 +1023,7 @@ impl Area {
             constrain_rect,
             sizing_pass,
             fade_in,
+            fade_out,
             layout,
         }
     }
@@ -550,6 +1038,10 @@ impl Prepared {
         &mut self.state
     }
 
+    pub(crate) fn sizing_pass(&self) -> bool {
+        self.sizing_pass
+    }
+
     pub(crate) fn constrain(&self) -> bool {
         self.constrain
     }
@@ -558,6 +1050,15 @@ impl Prepared {
         self.constrain_rect
     }
 
+    pub(crate) fn placement(&self) -> AreaPlacement {
+        AreaPlacement {
+            rect: self.state.rect(),
+            pivot: self.state.pivot,
+            constrain_rect: self.constrain_rect,
+            sizing_pass: self.sizing_pass,
+        }
+    }
+
     pub(crate) fn content_ui(&mut self, ctx: &Context) -> Ui {
         let max_rect = self.state.rect();
 
@@ -610,6 +1111,7 @@ impl Prepared {
             mut state,
             move_response: mut response,
             sizing_pass,
+            fade_out,
             ..
         } = self;
 
@@ -629,6 +1131,24 @@ impl Prepared {
 
         ctx.memory_mut(|m| m.areas_mut().set_state(layer_id, state));
 
+        if 0.0 < fade_out && !sizing_pass {
+            // Remember what we look like, so we can keep painting it after we're closed:
+            let shapes = ctx.graphics(|g| {
+                g.get(layer_id)
+                    .map(|list| list.all_entries().cloned().collect())
+                    .unwrap_or_default()
+            });
+            ctx.memory_mut(|m| m.areas_mut().set_fade_out(layer_id, fade_out, shapes));
+        } else if fade_out <= 0.0 {
+            ctx.memory_mut(|m| m.areas_mut().clear_fade_out(layer_id));
+        }
+
+        if ctx.is_pointer_over_layers(&[layer_id]) {
+            response
+                .flags
+                .set(crate::response::Flags::POINTER_OVER_AREA, true);
+        }
+
         if sizing_pass {
             // If we didn't know the size, we were likely drawing the area in the wrong place.
             ctx.request_repaint();
@@ -638,6 +1158,158 @@ impl Prepared {
     }
 }
 
+/// Round `pos` to the nearest point on the grid.
+fn snap_pos_to_grid(pos: Pos2, spacing: Vec2, offset: Vec2) -> Pos2 {
+    let snap = |value: f32, spacing: f32, offset: f32| {
+        if spacing > 0.0 {
+            offset + ((value - offset) / spacing).round() * spacing
+        } else {
+            value
+        }
+    };
+    pos2(
+        snap(pos.x, spacing.x, offset.x),
+        snap(pos.y, spacing.y, offset.y),
+    )
+}
+
+/// Move `rect` so that at least `min` of its top stays within `area`,
+/// and its top edge is never above the area.
+fn keep_top_on_screen(rect: Rect, area: Rect, min: Vec2) -> Rect {
+    let min = min.min(rect.size());
+    let mut pos = rect.min;
+    pos.x = pos.x.at_most(area.right() - min.x);
+    pos.x = pos.x.at_least(area.left() + min.x - rect.width());
+    pos.y = pos.y.at_most(area.bottom() - min.y);
+    pos.y = pos.y.at_least(area.top());
+    Rect::from_min_size(pos, rect.size()).round_ui()
+}
+
+/// Snap the edges (and optionally the center) of `rect` to those of the other areas and the panels.
+///
+/// Returns how far to move `rect`, and the guide lines to show for the snapped edges.
+fn snap_rect_to_areas(
+    ctx: &Context,
+    layer_id: LayerId,
+    rect: Rect,
+    distance: f32,
+    snap_centers: bool,
+) -> (Vec2, Vec<[Pos2; 2]>) {
+    let mut targets: Vec<Rect> = ctx.memory(|mem| {
+        let areas = mem.areas();
+        areas
+            .visible_layer_ids()
+            .into_iter()
+            .filter(|layer| layer.order == layer_id.order && *layer != layer_id)
+            .filter(|layer| !areas.is_sublayer(layer))
+            .filter_map(|layer| areas.get(layer.id).map(|state| state.rect()))
+            .collect()
+    });
+    targets.push(ctx.available_rect());
+
+    let lines = |rect: Rect, d: usize| {
+        let range = if d == 0 {
+            rect.x_range()
+        } else {
+            rect.y_range()
+        };
+        let mut lines = vec![range.min, range.max];
+        if snap_centers {
+            lines.push(range.center());
+        }
+        lines
+    };
+
+    // Per axis: the offset to snap by, and the rect snapped to.
+    let mut snaps: [Option<(f32, Rect)>; 2] = [None; 2];
+    for (d, snap) in snaps.iter_mut().enumerate() {
+        for target in &targets {
+            for theirs in lines(*target, d) {
+                for mine in lines(rect, d) {
+                    let offset = theirs - mine;
+                    let closer = snap.map_or(true, |(best, _)| offset.abs() < best.abs());
+                    if offset.abs() <= distance && closer {
+                        *snap = Some((offset, *target));
+                    }
+                }
+            }
+        }
+    }
+
+    let delta = Vec2::new(
+        snaps[0].map_or(0.0, |(offset, _)| offset),
+        snaps[1].map_or(0.0, |(offset, _)| offset),
+    );
+    let snapped = rect.translate(delta);
+
+    // Show a guide along each snapped line, spanning both rects:
+    let mut guides = vec![];
+    for (d, snap) in snaps.into_iter().enumerate() {
+        let Some((_, target)) = snap else {
+            continue;
+        };
+        let Some(coordinate) = lines(snapped, d).into_iter().find(|mine| {
+            lines(target, d)
+                .iter()
+                .any(|theirs| (theirs - mine).abs() < 0.5)
+        }) else {
+            continue;
+        };
+        let union = snapped.union(target);
+        guides.push(if d == 0 {
+            [
+                pos2(coordinate, union.top()),
+                pos2(coordinate, union.bottom()),
+            ]
+        } else {
+            [
+                pos2(union.left(), coordinate),
+                pos2(union.right(), coordinate),
+            ]
+        });
+    }
+
+    (delta, guides)
+}
+
+/// Is the keyboard focus on a widget in this layer?
+pub(crate) fn has_focus_within(ctx: &Context, layer_id: LayerId) -> bool {
+    let Some(focused) = ctx.memory(|m| m.focused()) else {
+        return false;
+    };
+    ctx.read_response(focused)
+        .is_some_and(|response| response.layer_id == layer_id)
+}
+
+/// Consume the arrow key presses made with `modifiers` held down,
+/// and return how far they move something, in points.
+///
+/// Each press moves by `step`, or by `large_step` if shift is held down.
+pub(crate) fn consume_arrow_keys(
+    ctx: &Context,
+    modifiers: Modifiers,
+    (step, large_step): (f32, f32),
+) -> Vec2 {
+    let delta = ctx.input_mut(|i| {
+        let mut delta = Vec2::ZERO;
+        for (key, dir) in [
+            (Key::ArrowLeft, Vec2::LEFT),
+            (Key::ArrowRight, Vec2::RIGHT),
+            (Key::ArrowUp, Vec2::UP),
+            (Key::ArrowDown, Vec2::DOWN),
+        ] {
+            delta += i.count_and_consume_key(modifiers, key) as f32 * dir;
+        }
+        let step = if i.modifiers.shift { large_step } else { step };
+        delta * step
+    });
+    if delta != Vec2::ZERO {
+        // The arrow keys were for us, not for moving the focus around:
+        ctx.memory_mut(|m| m.focus_mut().reset_focus());
+    }
+    delta
+}
+
 fn pointer_pressed_on_area(ctx: &Context, layer_id: LayerId) -> bool {
     if let Some(pointer_pos) = ctx.pointer_interact_pos() {
         let any_pressed = ctx.input(|i| i.pointer.any_pressed());
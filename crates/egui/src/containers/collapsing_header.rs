@@ -1,9 +1,9 @@
 use std::hash::Hash;
 
 use crate::{
-    emath, epaint, pos2, remap, remap_clamp, vec2, Context, Id, InnerResponse, NumExt, Rect,
-    Response, Sense, Stroke, TextStyle, TextWrapMode, Ui, UiBuilder, UiKind, UiStackInfo, Vec2,
-    WidgetInfo, WidgetText, WidgetType,
+    emath, epaint, pos2, remap, remap_clamp, vec2, Context, DragAndDrop, Id, InnerResponse, NumExt,
+    Rect, Response, Sense, Stroke, TextStyle, TextWrapMode, Ui, UiBuilder, UiKind, UiStackInfo,
+    Vec2, WidgetInfo, WidgetText, WidgetType,
 };
 use emath::GuiRounding as _;
 use epaint::{Shape, StrokeKind};
@@ -13,9 +13,81 @@ use epaint::{Shape, StrokeKind};
 pub(crate) struct InnerState {
     open: bool,
 
-    /// Height of the region when open. Used for animations
+    /// Height of the region when open. Used for animations.
+    ///
+    /// Measured the first time it is opened, and kept up to date while open.
     #[cfg_attr(feature = "serde", serde(default))]
     open_height: Option<f32>,
+
+    /// The last [`CollapseAllCommands`] command applied to this state.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_command: u64,
+}
+
+/// The [`crate::UiTags`] key of the [`CollapsingState::prefixes`] of the header a body [`Ui`] belongs to.
+const COLLAPSING_PREFIXES_TAG: &str = "egui_collapsing_prefixes";
+
+/// Pending "open all" / "close all" commands, set by [`Context::open_all_collapsing`]
+/// and [`Context::close_all_collapsing`].
+///
+/// They are applied lazily as the headers are shown, so that headers that are shown later
+/// (e.g. children of headers that were just opened) are affected too.
+#[derive(Clone, Default)]
+pub(crate) struct CollapseAllCommands {
+    last_command: u64,
+
+    /// `id_prefix` -> (open, command number).
+    ///
+    /// A prefix is the [`Id`] of a header, or the [`Ui::id`] of the [`Ui`] headers are shown in,
+    /// and covers everything nested in the bodies of those headers.
+    commands: crate::IdMap<(bool, u64)>,
+}
+
+impl CollapseAllCommands {
+    fn id() -> Id {
+        Id::new("__collapse_all_commands")
+    }
+
+    pub(crate) fn push(ctx: &Context, id_prefix: Id, open: bool) {
+        ctx.data_mut(|d| {
+            let commands = d.get_temp_mut_or_default::<Self>(Self::id());
+            commands.last_command += 1;
+            let command = commands.last_command;
+            commands.commands.insert(id_prefix, (open, command));
+        });
+        ctx.request_repaint();
+    }
+
+    /// Apply the latest command for the header, or any header it is nested in,
+    /// that hasn't been applied yet.
+    ///
+    /// `parent_id` is the [`Ui::id`] of the [`Ui`] the header is shown in.
+    fn apply(ui: &Ui, parent_id: Id, state: &mut CollapsingState) {
+        state.parent_id = parent_id;
+        let latest = ui.data_mut(|d| {
+            let commands = d.get_temp_mut_or_default::<Self>(Self::id());
+            if commands.commands.is_empty() {
+                return None;
+            }
+            let enclosing = ui.stack().iter().filter_map(|frame| {
+                frame
+                    .info
+                    .tags
+                    .get_downcast::<[Id; 2]>(COLLAPSING_PREFIXES_TAG)
+                    .copied()
+            });
+            std::iter::once(state.prefixes())
+                .chain(enclosing)
+                .flatten()
+                .filter_map(|id_prefix| commands.commands.get(&id_prefix).copied())
+                .filter(|&(_, command)| state.state.last_command < command)
+                .max_by_key(|&(_, command)| command)
+        });
+        if let Some((open, command)) = latest {
+            state.state.open = open;
+            state.state.last_command = command;
+        }
+    }
 }
 
 /// This is a a building block for building collapsing regions.
@@ -27,13 +99,19 @@ pub(crate) struct InnerState {
 pub struct CollapsingState {
     id: Id,
     state: InnerState,
+
+    /// The [`Ui::id`] of the [`Ui`] the header was shown in, if any.
+    parent_id: Id,
 }
 
 impl CollapsingState {
     pub fn load(ctx: &Context, id: Id) -> Option<Self> {
         ctx.data_mut(|d| {
-            d.get_persisted::<InnerState>(id)
-                .map(|state| Self { id, state })
+            d.get_persisted::<InnerState>(id).map(|state| Self {
+                id,
+                state,
+                parent_id: Id::NULL,
+            })
         })
     }
 
@@ -55,10 +133,17 @@ impl CollapsingState {
             state: InnerState {
                 open: default_open,
                 open_height: None,
+                last_command: 0,
             },
+            parent_id: Id::NULL,
         })
     }
 
+    /// The ids that "open all" / "close all" commands for this header and its body are keyed by.
+    fn prefixes(&self) -> [Id; 2] {
+        [self.id, self.parent_id]
+    }
+
     pub fn is_open(&self) -> bool {
         self.state.open
     }
@@ -157,6 +242,7 @@ impl CollapsingState {
         ui: &mut Ui,
         add_header: impl FnOnce(&mut Ui) -> HeaderRet,
     ) -> HeaderResponse<'_, HeaderRet> {
+        CollapseAllCommands::apply(ui, ui.id(), &mut self);
         let header_response = ui.horizontal(|ui| {
             let prev_item_spacing = ui.spacing_mut().item_spacing;
             ui.spacing_mut().item_spacing.x = 0.0; // the toggler button uses the full indent width
@@ -206,19 +292,29 @@ impl CollapsingState {
         let openness = self.openness(ui.ctx());
 
         let builder = UiBuilder::new()
-            .ui_stack_info(UiStackInfo::new(UiKind::Collapsible))
+            .ui_stack_info(
+                UiStackInfo::new(UiKind::Collapsible)
+                    .with_tag_value(COLLAPSING_PREFIXES_TAG, self.prefixes()),
+            )
             .closable();
 
         if openness <= 0.0 {
             self.store(ui.ctx()); // we store any earlier toggling as promised in the docstring
             None
         } else if openness < 1.0 {
+            // First frame of expansion, and we don't know the full height yet.
+            // The height is remembered after that, so this only happens the first time it is opened.
+            let measuring = self.state.open && self.state.open_height.is_none();
+            let builder = if measuring {
+                // Measure the body in an invisible sizing pass:
+                builder.sizing_pass().invisible()
+            } else {
+                builder
+            };
+
             Some(ui.scope_builder(builder, |child_ui| {
-                let max_height = if self.state.open && self.state.open_height.is_none() {
-                    // First frame of expansion.
-                    // We don't know full height yet, but we will next frame.
-                    // Just use a placeholder value that shows some movement:
-                    10.0
+                let max_height = if measuring {
+                    0.0
                 } else {
                     let full_height = self.state.open_height.unwrap_or_default();
                     remap_clamp(openness, 0.0..=1.0, 0.0..=full_height).round_ui()
@@ -236,6 +332,12 @@ impl CollapsingState {
                     self.state.open = false;
                 }
                 self.store(child_ui.ctx()); // remember the height
+                if measuring {
+                    // Now that we know the height, show the first frame of the animation properly:
+                    child_ui
+                        .ctx()
+                        .request_discard("CollapsingState: measure body on first open");
+                }
 
                 // Pretend children took up at most `max_height` space:
                 min_rect.max.y = min_rect.max.y.at_most(min_rect.top() + max_height);
@@ -398,7 +500,13 @@ pub struct CollapsingHeader {
     selectable: bool,
     selected: bool,
     show_background: bool,
+    full_width_header: bool,
+    indent_guides: Option<bool>,
+    recursive_toggle: bool,
+    reorder_index: Option<usize>,
+    reorder_list: Id,
     icon: Option<IconPainter>,
+    badge: Option<String>,
 }
 
 impl CollapsingHeader {
@@ -408,6 +516,9 @@ impl CollapsingHeader {
     /// If the label is unique and static this is fine,
     /// but if it changes or there are several [`CollapsingHeader`] with the same title
     /// you need to provide a unique id source with [`Self::id_salt`].
+    ///
+    /// Use [`Self::id`] to get the resulting [`Id`], e.g. to open or close the header from code
+    /// with [`Context::set_collapsing_open`].
     pub fn new(text: impl Into<WidgetText>) -> Self {
         let text = text.into();
         let id_salt = Id::new(text.text());
@@ -420,7 +531,13 @@ impl CollapsingHeader {
             selectable: false,
             selected: false,
             show_background: false,
+            full_width_header: false,
+            indent_guides: None,
+            recursive_toggle: false,
+            reorder_index: None,
+            reorder_list: Id::new("collapsing_reorder"),
             icon: None,
+            badge: None,
         }
     }
 
@@ -451,6 +568,15 @@ impl CollapsingHeader {
         self
     }
 
+    /// The [`Id`] this header will have when shown in the given [`Ui`].
+    ///
+    /// This is [`Ui::make_persistent_id`] of the [`Self::id_salt`], which defaults to the title text.
+    /// This is where the open state is stored,
+    /// so you can use it with [`Context::set_collapsing_open`] and [`Context::open_all_collapsing`].
+    pub fn id(&self, ui: &Ui) -> Id {
+        ui.make_persistent_id(self.id_salt)
+    }
+
     /// Explicitly set the source of the [`Id`] of this widget, instead of using title label.
     /// This is useful if the title label is dynamic or not unique.
     #[deprecated = "Renamed id_salt"]
@@ -469,6 +595,45 @@ impl CollapsingHeader {
         self
     }
 
+    /// Make the header a selectable tree node, e.g. for a file tree.
+    ///
+    /// Clicking the icon then opens and closes the header, while clicking the rest of the row
+    /// only makes [`Response::clicked`] of [`CollapsingResponse::header_response`] true,
+    /// which you can use to select the node. Show which node is selected with [`Self::selected`].
+    /// The row fills the full width, and is highlighted when hovered.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut selected_path = String::new();
+    /// let path = "src";
+    /// let response = egui::CollapsingHeader::new(path)
+    ///     .selectable(true)
+    ///     .selected(selected_path == path)
+    ///     .show(ui, |ui| {
+    ///         ui.label("main.rs");
+    ///     });
+    /// if response.header_response.clicked() {
+    ///     selected_path = path.to_owned();
+    /// }
+    /// # });
+    /// ```
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
+    /// Highlight the header as selected, like a [`crate::Button::selected`].
+    ///
+    /// See [`Self::selectable`]. Default: `false`.
+    #[inline]
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
     /// Should the [`CollapsingHeader`] show a background behind it? Default: `false`.
     ///
     /// To show it behind all [`CollapsingHeader`] you can just use:
@@ -483,6 +648,91 @@ impl CollapsingHeader {
         self
     }
 
+    /// Make the header span the full width of the [`Ui`], and highlight it on hover
+    /// like a [`crate::SelectableLabel`].
+    ///
+    /// The whole row can then be clicked to open or close the header,
+    /// which makes trees feel like lists. The icon and text stay on the left.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn full_width_header(mut self, full_width_header: bool) -> Self {
+        self.full_width_header = full_width_header;
+        self
+    }
+
+    /// Draw a faint vertical line to the left of the indented body, aligned with the icon.
+    ///
+    /// The line follows the body while it animates open or closed.
+    /// Nested [`CollapsingHeader`]s inherit this, so setting it on the root
+    /// of a tree draws a guide at each level of indentation.
+    ///
+    /// Default: [`crate::Visuals::indent_has_left_vline`].
+    #[inline]
+    pub fn indent_guides(mut self, indent_guides: bool) -> Self {
+        self.indent_guides = Some(indent_guides);
+        self
+    }
+
+    /// Let the user open or close all nested headers at once.
+    ///
+    /// With this, double-clicking the header or clicking it while holding down
+    /// [`crate::Modifiers::alt`] toggles it, and then opens or closes every
+    /// [`CollapsingHeader`] in its body to match, all the way down the tree.
+    ///
+    /// This uses [`Context::open_all_collapsing`] and [`Context::close_all_collapsing`],
+    /// which you can also call yourself, e.g. for "expand all" buttons.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn recursive_toggle(mut self, recursive_toggle: bool) -> Self {
+        self.recursive_toggle = recursive_toggle;
+        self
+    }
+
+    /// Make the header a drag handle for reordering a list of sections,
+    /// where this is the section at `index`.
+    ///
+    /// Dragging the header of one section and dropping it on another section in the same list
+    /// reports the move in [`CollapsingResponse::reordered`], which is up to you to apply.
+    /// All reorderable sections shown directly in the same [`Ui`] are one list,
+    /// unless you separate them with [`Self::reorder_list`].
+    /// Clicking the header still opens and closes it, and a drag only starts
+    /// once the pointer has moved a bit, so a slightly shaky click isn't mistaken for a drag.
+    /// The body is hidden while its header is being dragged.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut sections = vec!["First", "Second", "Third"];
+    /// let mut reordered = None;
+    /// for (index, section) in sections.iter().enumerate() {
+    ///     let response = egui::CollapsingHeader::new(*section)
+    ///         .reorderable(index)
+    ///         .show(ui, |ui| ui.label("Body"));
+    ///     reordered = reordered.or(response.reordered);
+    /// }
+    /// if let Some((from, to)) = reordered {
+    ///     let section = sections.remove(from);
+    ///     sections.insert(to, section);
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn reorderable(mut self, index: usize) -> Self {
+        self.reorder_index = Some(index);
+        self
+    }
+
+    /// Which list of [`Self::reorderable`] sections this is in,
+    /// if you have more than one list in the same [`Ui`].
+    ///
+    /// Sections can only be dropped on other sections with the same `id_salt` in the same [`Ui`].
+    #[inline]
+    pub fn reorder_list(mut self, id_salt: impl Hash) -> Self {
+        self.reorder_list = Id::new(id_salt);
+        self
+    }
+
     /// Use the provided function to render a different [`CollapsingHeader`] icon.
     /// Defaults to a triangle that animates as the [`CollapsingHeader`] opens and closes.
     ///
@@ -505,16 +755,52 @@ impl CollapsingHeader {
         self.icon = Some(Box::new(icon_fn));
         self
     }
+
+    /// Show a count (or other short text) in a pill right after the title, e.g. for "Errors (3)".
+    ///
+    /// The pill uses the selection colors of the theme.
+    /// Clicking it doesn't open or close the header;
+    /// use [`CollapsingResponse::badge_response`] to react to clicks on it.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let errors = ["File not found", "Out of memory"];
+    /// egui::CollapsingHeader::new("Errors")
+    ///     .badge(errors.len())
+    ///     .show(ui, |ui| {
+    ///         for error in errors {
+    ///             ui.label(error);
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn badge(mut self, badge: impl std::fmt::Display) -> Self {
+        self.badge = Some(badge.to_string());
+        self
+    }
+}
+
+/// What is dragged when reordering [`CollapsingHeader::reorderable`] sections.
+struct ReorderPayload {
+    /// The list the sections are in, see [`CollapsingHeader::reorder_list`].
+    list: Id,
+
+    /// The index of the dragged section.
+    index: usize,
 }
 
 struct Prepared {
     header_response: Response,
+    toggle_response: Response,
+    badge_response: Option<Response>,
     state: CollapsingState,
     openness: f32,
 }
 
 impl CollapsingHeader {
-    fn begin(self, ui: &mut Ui) -> Prepared {
+    /// `id` is [`Self::id`] and `parent_id` the [`Ui::id`] of the [`Ui`] it was computed in.
+    fn begin(self, ui: &mut Ui, id: Id, parent_id: Id) -> Prepared {
         assert!(
             ui.layout().main_dir().is_vertical(),
             "Horizontal collapsing is unimplemented"
@@ -524,16 +810,21 @@ impl CollapsingHeader {
             text,
             default_open,
             open,
-            id_salt,
+            id_salt: _,
             enabled: _,
             selectable,
             selected,
             show_background,
+            full_width_header,
+            indent_guides: _,
+            recursive_toggle,
+            reorder_index,
+            reorder_list: _,
+            badge,
         } = self;
 
         // TODO(emilk): horizontal layout, with icon and text as labels. Insert background behind using Frame.
 
-        let id = ui.make_persistent_id(id_salt);
         let button_padding = ui.spacing().button_padding;
 
         let available = ui.available_rect_before_wrap();
@@ -545,10 +836,28 @@ impl CollapsingHeader {
             wrap_width,
             TextStyle::Button,
         );
-        let text_max_x = text_pos.x + galley.size().x;
+        let mut text_max_x = text_pos.x + galley.size().x;
+
+        let badge_galley = badge.map(|badge| {
+            WidgetText::from(badge).into_galley(
+                ui,
+                Some(TextWrapMode::Extend),
+                f32::INFINITY,
+                TextStyle::Small,
+            )
+        });
+        let badge_size = badge_galley.as_ref().map(|galley| {
+            // A pill, which is a circle for a single digit:
+            let size = galley.size() + vec2(galley.size().y, 2.0);
+            vec2(size.x.at_least(size.y), size.y)
+        });
+        let badge_left = text_max_x + ui.spacing().item_spacing.x;
+        if let Some(badge_size) = badge_size {
+            text_max_x = badge_left + badge_size.x;
+        }
 
         let mut desired_width = text_max_x + button_padding.x - available.left();
-        if ui.visuals().collapsing_header_frame {
+        if ui.visuals().collapsing_header_frame || selectable || full_width_header {
             desired_width = desired_width.max(available.width()); // fill full width
         }
 
@@ -556,31 +865,97 @@ impl CollapsingHeader {
         desired_size = desired_size.at_least(ui.spacing().interact_size);
         let (_, rect) = ui.allocate_space(desired_size);
 
-        let mut header_response = ui.interact(rect, id, Sense::click());
+        let sense = if reorder_index.is_some() {
+            Sense::click_and_drag()
+        } else {
+            Sense::click()
+        };
+        let mut header_response = ui.interact(rect, id, sense);
         let text_pos = pos2(
             text_pos.x,
             header_response.rect.center().y - galley.size().y / 2.0,
         );
 
+        let (mut icon_rect, _) = ui.spacing().icon_rectangles(header_response.rect);
+        icon_rect.set_center(pos2(
+            header_response.rect.left() + ui.spacing().indent / 2.0,
+            header_response.rect.center().y,
+        ));
+
+        // On top of the header, so it can have its own tooltip or context menu:
+        let toggle_rect = Rect::from_center_size(
+            icon_rect.center(),
+            Vec2::splat(ui.spacing().indent).min(header_response.rect.size()),
+        );
+        let toggle_response = ui.interact(toggle_rect, id.with("toggle"), Sense::click());
+        // On top of the header, so that clicking it doesn't toggle the header:
+        let badge_response = badge_galley.as_ref().zip(badge_size).map(|(galley, size)| {
+            let badge_rect = Rect::from_min_size(
+                pos2(badge_left, header_response.rect.center().y - size.y / 2.0),
+                size,
+            );
+            let response = ui.interact(badge_rect, id.with("badge"), Sense::click());
+            response.widget_info(|| {
+                WidgetInfo::labeled(WidgetType::Label, ui.is_enabled(), galley.text())
+            });
+            response
+        });
+
+        // A selectable node is opened and closed with its icon only, so clicking the row can select it:
+        let toggle_clicked = toggle_response.clicked() || !selectable && header_response.clicked();
+        let toggle_double_clicked =
+            toggle_response.double_clicked() || !selectable && header_response.double_clicked();
+
         let mut state = CollapsingState::load_with_default_open(ui.ctx(), id, default_open);
+        CollapseAllCommands::apply(ui, parent_id, &mut state);
         if let Some(open) = open {
             if open != state.is_open() {
                 state.toggle(ui);
                 header_response.mark_changed();
             }
-        } else if header_response.clicked() {
+        } else if recursive_toggle && toggle_double_clicked {
+            // The first click of the double-click already toggled the header,
+            // so keep that and make the whole subtree follow:
+            CollapseAllCommands::push(ui.ctx(), id, state.is_open());
+            header_response.mark_changed();
+        } else if toggle_clicked {
             state.toggle(ui);
+            if recursive_toggle && ui.input(|i| i.modifiers.alt) {
+                CollapseAllCommands::push(ui.ctx(), id, state.is_open());
+            }
             header_response.mark_changed();
         }
 
         header_response.widget_info(|| {
-            WidgetInfo::labeled(WidgetType::CollapsingHeader, ui.is_enabled(), galley.text())
+            if selectable {
+                WidgetInfo::selected(
+                    WidgetType::CollapsingHeader,
+                    ui.is_enabled(),
+                    selected,
+                    galley.text(),
+                )
+            } else {
+                WidgetInfo::labeled(WidgetType::CollapsingHeader, ui.is_enabled(), galley.text())
+            }
         });
+        let label = if state.is_open() {
+            "Collapse"
+        } else {
+            "Expand"
+        };
+        toggle_response
+            .widget_info(|| WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), label));
 
         let openness = state.openness(ui.ctx());
 
         if ui.is_rect_visible(rect) {
-            let visuals = ui.style().interact_selectable(&header_response, selected);
+            // Hovering the toggle of a plain header is hovering the header:
+            let row_response = if selectable {
+                header_response.clone()
+            } else {
+                header_response.union(toggle_response.clone())
+            };
+            let visuals = ui.style().interact_selectable(&row_response, selected);
 
             if ui.visuals().collapsing_header_frame || show_background {
                 ui.painter().add(epaint::RectShape::new(
@@ -592,7 +967,9 @@ impl CollapsingHeader {
                 ));
             }
 
-            if selected || selectable && (header_response.hovered() || header_response.has_focus())
+            let highlight_on_hover = selectable || full_width_header;
+            if selected
+                || highlight_on_hover && (row_response.hovered() || row_response.has_focus())
             {
                 let rect = rect.expand(visuals.expansion);
 
@@ -606,12 +983,13 @@ impl CollapsingHeader {
             }
 
             {
-                let (mut icon_rect, _) = ui.spacing().icon_rectangles(header_response.rect);
-                icon_rect.set_center(pos2(
-                    header_response.rect.left() + ui.spacing().indent / 2.0,
-                    header_response.rect.center().y,
-                ));
-                let icon_response = header_response.clone().with_new_rect(icon_rect);
+                let icon_response = if selectable {
+                    &toggle_response
+                } else {
+                    &row_response
+                }
+                .clone()
+                .with_new_rect(icon_rect);
                 if let Some(icon) = icon {
                     icon(ui, openness, &icon_response);
                 } else {
@@ -620,10 +998,22 @@ impl CollapsingHeader {
             }
 
             ui.painter().galley(text_pos, galley, visuals.text_color());
+
+            if let (Some(badge_galley), Some(badge_response)) = (badge_galley, &badge_response) {
+                let selection = ui.visuals().selection;
+                let rect = badge_response.rect;
+                ui.painter()
+                    .rect_filled(rect, rect.height() / 2.0, selection.bg_fill);
+                let text_pos = rect.center() - badge_galley.size() / 2.0;
+                ui.painter()
+                    .galley(text_pos, badge_galley, selection.stroke.color);
+            }
         }
 
         Prepared {
             header_response,
+            toggle_response,
+            badge_response,
             state,
             openness,
         }
@@ -655,16 +1045,51 @@ impl CollapsingHeader {
     ) -> CollapsingResponse<R> {
         // Make sure body is bellow header,
         // and make sure it is one unit (necessary for putting a [`CollapsingHeader`] in a grid).
-        ui.vertical(|ui| {
+        let indent_guides = self.indent_guides;
+        let reorder_index = self.reorder_index;
+        let reorder_list = ui.id().with(self.reorder_list);
+        let parent_id = ui.id();
+        let id = self.id(ui);
+        let mut response = ui.vertical(|ui| {
             if !self.enabled {
                 ui.disable();
             }
 
             let Prepared {
                 header_response,
+                toggle_response,
+                badge_response,
                 mut state,
                 openness,
-            } = self.begin(ui); // show the header
+            } = self.begin(ui, id, parent_id); // show the header
+
+            if let Some(indent_guides) = indent_guides {
+                ui.visuals_mut().indent_has_left_vline = indent_guides;
+            }
+
+            if let Some(index) = reorder_index {
+                if header_response.drag_started() {
+                    DragAndDrop::set_payload(
+                        ui.ctx(),
+                        ReorderPayload {
+                            list: reorder_list,
+                            index,
+                        },
+                    );
+                }
+                if header_response.dragged() {
+                    // Keep the drag preview small:
+                    return CollapsingResponse {
+                        header_response,
+                        toggle_response,
+                        badge_response,
+                        body_response: None,
+                        body_returned: None,
+                        openness: 0.0,
+                        reordered: None,
+                    };
+                }
+            }
 
             let ret_response = if indented {
                 state.show_body_indented(&header_response, ui, add_body)
@@ -675,20 +1100,49 @@ impl CollapsingHeader {
             if let Some(ret_response) = ret_response {
                 CollapsingResponse {
                     header_response,
+                    toggle_response,
+                    badge_response,
                     body_response: Some(ret_response.response),
                     body_returned: Some(ret_response.inner),
                     openness,
+                    reordered: None,
                 }
             } else {
                 CollapsingResponse {
                     header_response,
+                    toggle_response,
+                    badge_response,
                     body_response: None,
                     body_returned: None,
                     openness,
+                    reordered: None,
                 }
             }
-        })
-        .inner
+        });
+
+        if let Some(to) = reorder_index {
+            let section = &response.response;
+            if let Some(payload) = section
+                .dnd_hover_payload::<ReorderPayload>()
+                .filter(|payload| payload.list == reorder_list && payload.index != to)
+            {
+                // Show where the dragged section will end up:
+                let y = if payload.index < to {
+                    section.rect.bottom()
+                } else {
+                    section.rect.top()
+                };
+                let stroke = ui.visuals().selection.stroke;
+                ui.painter().hline(section.rect.x_range(), y, stroke);
+
+                if ui.input(|i| i.pointer.any_released()) {
+                    DragAndDrop::clear_payload(ui.ctx());
+                    response.inner.reordered = Some((payload.index, to));
+                }
+            }
+        }
+
+        response.inner
     }
 }
 
@@ -697,6 +1151,14 @@ pub struct CollapsingResponse<R> {
     /// Response of the actual clickable header.
     pub header_response: Response,
 
+    /// Response of the open/close icon at the start of the header, e.g. for a tooltip of its own.
+    ///
+    /// Clicking it toggles the header, but doesn't click the [`Self::header_response`].
+    pub toggle_response: Response,
+
+    /// Response of the [`CollapsingHeader::badge`], if any.
+    pub badge_response: Option<Response>,
+
     /// None iff collapsed.
     pub body_response: Option<Response>,
 
@@ -705,6 +1167,12 @@ pub struct CollapsingResponse<R> {
 
     /// 0.0 if fully closed, 1.0 if fully open, and something in-between while animating.
     pub openness: f32,
+
+    /// With [`CollapsingHeader::reorderable`]: `Some((from, to))` on the frame the section at index `from`
+    /// was dropped on this section, at index `to`.
+    ///
+    /// Move the section with e.g. `sections.insert(to, sections.remove(from))`.
+    pub reordered: Option<(usize, usize)>,
 }
 
 impl<R> CollapsingResponse<R> {
@@ -1,9 +1,10 @@
 use epaint::Shape;
 
 use crate::{
-    epaint, style::StyleModifier, style::WidgetVisuals, vec2, Align2, Context, Id, InnerResponse,
-    NumExt, Painter, Popup, PopupCloseBehavior, Rect, Response, ScrollArea, Sense, Stroke,
-    TextStyle, TextWrapMode, Ui, UiBuilder, Vec2, WidgetInfo, WidgetText, WidgetType,
+    epaint, style::StyleModifier, style::WidgetVisuals, vec2, Align, Align2, Context, Frame, Grid,
+    Id, InnerResponse, NumExt, Painter, Popup, PopupCloseBehavior, PopupStatus, Rect, Response,
+    ScrollArea, Sense, Stroke, TextStyle, TextWrapMode, Ui, UiBuilder, Vec2, WidgetInfo,
+    WidgetText, WidgetType,
 };
 
 #[allow(unused_imports)] // Documentation
@@ -14,6 +15,9 @@ pub type IconPainter = Box<dyn FnOnce(&Ui, Rect, &WidgetVisuals, bool)>;
 
 /// A drop-down selection menu with a descriptive label.
 ///
+/// The menu is shown in its own [`crate::Order::Foreground`] layer, placed next to the button,
+/// so it is not clipped by e.g. a [`crate::ScrollArea`] or panel that the button is in.
+///
 /// ```
 /// # egui::__run_test_ui(|ui| {
 /// # #[derive(Debug, PartialEq)]
@@ -35,10 +39,15 @@ pub struct ComboBox {
     label: Option<WidgetText>,
     selected_text: WidgetText,
     width: Option<f32>,
+    items_width: Option<f32>,
     height: Option<f32>,
+    max_visible_items: Option<usize>,
     icon: Option<IconPainter>,
     wrap_mode: Option<TextWrapMode>,
     close_behavior: Option<PopupCloseBehavior>,
+    none_option: Option<WidgetText>,
+    empty_text: Option<WidgetText>,
+    columns: usize,
 }
 
 impl ComboBox {
@@ -49,10 +58,15 @@ impl ComboBox {
             label: Some(label.into()),
             selected_text: Default::default(),
             width: None,
+            items_width: None,
             height: None,
+            max_visible_items: None,
             icon: None,
             wrap_mode: None,
             close_behavior: None,
+            none_option: None,
+            empty_text: None,
+            columns: 1,
         }
     }
 
@@ -64,10 +78,15 @@ impl ComboBox {
             label: Some(label),
             selected_text: Default::default(),
             width: None,
+            items_width: None,
             height: None,
+            max_visible_items: None,
             icon: None,
             wrap_mode: None,
             close_behavior: None,
+            none_option: None,
+            empty_text: None,
+            columns: 1,
         }
     }
 
@@ -78,10 +97,15 @@ impl ComboBox {
             label: Default::default(),
             selected_text: Default::default(),
             width: None,
+            items_width: None,
             height: None,
+            max_visible_items: None,
             icon: None,
             wrap_mode: None,
             close_behavior: None,
+            none_option: None,
+            empty_text: None,
+            columns: 1,
         }
     }
 
@@ -100,15 +124,77 @@ impl ComboBox {
         self
     }
 
+    /// Make the button wide enough for the widest of these items,
+    /// so that it doesn't change size when the selection changes.
+    ///
+    /// The items are measured once and the result is cached by [`Id`],
+    /// so they are only measured again when the number of items (or the font) changes.
+    /// If the items can change without their number changing, e.g. when switching languages,
+    /// make that part of the id salt of the combo box.
+    ///
+    /// The width is at least [`Self::width`] (or [`Spacing::combo_width`]),
+    /// but never more than the available width.
+    /// Items too long to fit are truncated with an ellipsis
+    /// (unless you set a different [`Self::wrap_mode`]).
+    ///
+    /// Call this with the [`Ui`] you show the combo box in.
+    #[inline]
+    pub fn width_from_items(mut self, ui: &Ui, items: &[impl std::fmt::Display]) -> Self {
+        let id = ui.make_persistent_id(self.id_salt);
+        self.items_width = Some(widest_item_width(ui, id, items));
+        self
+    }
+
     /// Set the maximum outer height of the menu.
     ///
     /// Default is [`Spacing::combo_height`].
+    ///
+    /// The menu scrolls if its contents are taller than this.
+    /// It is also never taller than the space above or below the combo box,
+    /// so it always fits on the screen.
+    ///
+    /// See also [`Self::max_visible_items`].
     #[inline]
     pub fn height(mut self, height: f32) -> Self {
         self.height = Some(height);
         self
     }
 
+    /// Set the maximum height of the menu to fit this many rows of selectable labels.
+    ///
+    /// If [`Self::height`] is also set, the smaller of the two is used.
+    #[inline]
+    pub fn max_visible_items(mut self, max_visible_items: usize) -> Self {
+        self.max_visible_items = Some(max_visible_items);
+        self
+    }
+
+    /// Lay the items out in a grid with this many columns, instead of one item per row,
+    /// e.g. for a compact icon or emoji picker.
+    ///
+    /// The arrow keys move the keyboard focus between the items in all four directions.
+    ///
+    /// Only used by [`Self::show_indexed`] and [`Self::show_index`] (and its variants),
+    /// since those add the items themselves. With [`Self::show_indexed`], each row is laid out
+    /// from left to right, so give the items the same width to line up the columns.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let icons = ["🍎", "🍌", "🍒", "🍇", "🍉", "🍋", "🍑", "🍍"];
+    /// let mut selected = 0;
+    /// egui::ComboBox::from_label("Fruit")
+    ///     .columns(4)
+    ///     .show_index(ui, &mut selected, icons.len(), |i| icons[i]);
+    /// # });
+    /// ```
+    ///
+    /// Default: `1`.
+    #[inline]
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.at_least(1);
+        self
+    }
+
     /// What we show as the currently selected value
     #[inline]
     pub fn selected_text(mut self, selected_text: impl Into<WidgetText>) -> Self {
@@ -186,6 +272,30 @@ impl ComboBox {
         self
     }
 
+    /// Add an entry with this label at the top of the menu that clears the selection.
+    ///
+    /// While nothing is selected, the closed combo box shows the label as weak placeholder text.
+    ///
+    /// Only used by [`Self::show_ui_optional`].
+    #[inline]
+    pub fn show_none_option(mut self, label: impl Into<WidgetText>) -> Self {
+        self.none_option = Some(label.into());
+        self
+    }
+
+    /// Show this weak text in the menu when there are no items to show,
+    /// e.g. "No matches" when the items were filtered by a search query.
+    ///
+    /// Without it, the menu is empty.
+    ///
+    /// Only used by [`Self::show_indexed`] and [`Self::show_index`] (and its variants),
+    /// since those know how many items there are.
+    #[inline]
+    pub fn empty_text(mut self, empty_text: impl Into<WidgetText>) -> Self {
+        self.empty_text = Some(empty_text.into());
+        self
+    }
+
     /// Show the combo box, with the given ui code for the menu contents.
     ///
     /// Returns `InnerResponse { inner: None }` if the combo box is closed.
@@ -194,28 +304,129 @@ impl ComboBox {
         ui: &mut Ui,
         menu_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<Option<R>> {
-        self.show_ui_dyn(ui, Box::new(menu_contents))
+        self.show_ui_dyn(
+            ui,
+            Box::new(|scroll_area: ScrollArea, ui: &mut Ui| {
+                scroll_area.show(ui, menu_contents).inner
+            }),
+        )
     }
 
+    /// Show `len` items, only building the ones scrolled into view.
+    ///
+    /// `add_item` is called with the index of each visible item, and should add a single row,
+    /// usually a [`Ui::selectable_label`]. All rows are assumed to be as tall as one of those.
+    /// This makes it cheap to show a menu with a huge number of items.
+    ///
+    /// Returns the index of the item clicked this frame in `inner`,
+    /// in which case [`Response::changed`] is `true` too.
+    /// You need to show the current selection yourself with [`Self::selected_text`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut selected = 42;
+    /// let ir = egui::ComboBox::from_label("Number")
+    ///     .selected_text(selected.to_string())
+    ///     .show_indexed(ui, 100_000, |ui, i| {
+    ///         ui.selectable_label(i == selected, i.to_string())
+    ///     });
+    /// if let Some(clicked) = ir.inner {
+    ///     selected = clicked;
+    /// }
+    /// # });
+    /// ```
+    pub fn show_indexed(
+        mut self,
+        ui: &mut Ui,
+        len: usize,
+        mut add_item: impl FnMut(&mut Ui, usize) -> Response,
+    ) -> InnerResponse<Option<usize>> {
+        let row_height = item_row_height(ui);
+        let columns = self.columns;
+        let empty_text = self.empty_text.take().filter(|_| len == 0);
+        let mut ir = self.show_ui_dyn(
+            ui,
+            Box::new(|scroll_area: ScrollArea, ui: &mut Ui| {
+                if let Some(empty_text) = empty_text {
+                    ui.label(empty_text.weak());
+                    return None;
+                }
+                let num_rows = len.div_ceil(columns);
+                scroll_area
+                    .show_rows(ui, row_height, num_rows, |ui, row_range| {
+                        let mut clicked = None;
+                        for row in row_range {
+                            let items = row * columns..((row + 1) * columns).min(len);
+                            if columns == 1 {
+                                if add_item(ui, items.start).clicked() {
+                                    clicked = Some(items.start);
+                                }
+                                continue;
+                            }
+                            ui.horizontal(|ui| {
+                                for i in items {
+                                    if add_item(ui, i).clicked() {
+                                        clicked = Some(i);
+                                    }
+                                }
+                            });
+                        }
+                        clicked
+                    })
+                    .inner
+            }),
+        );
+
+        let clicked = ir.inner.flatten();
+        if clicked.is_some() {
+            ir.response.mark_changed();
+        }
+        InnerResponse {
+            inner: clicked,
+            response: ir.response,
+        }
+    }
+
+    /// Shows the combo box, with `menu_contents` showing the menu in the given [`ScrollArea`].
     fn show_ui_dyn<'c, R>(
         self,
         ui: &mut Ui,
-        menu_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+        menu_contents: Box<dyn FnOnce(ScrollArea, &mut Ui) -> R + 'c>,
     ) -> InnerResponse<Option<R>> {
         let Self {
             id_salt,
             label,
             selected_text,
-            width,
-            height,
+            mut width,
+            items_width,
+            mut height,
+            max_visible_items,
             icon,
-            wrap_mode,
+            mut wrap_mode,
             close_behavior,
+            none_option: _,
+            empty_text: _,
+            columns: _,
         } = self;
 
         let button_id = ui.make_persistent_id(id_salt);
 
+        if let Some(max_visible_items) = max_visible_items {
+            let items_height = items_height(ui, max_visible_items);
+            height = Some(height.map_or(items_height, |height| height.min(items_height)));
+        }
+
         ui.horizontal(|ui| {
+            if let Some(items_width) = items_width {
+                let spacing = ui.spacing();
+                let decoration_width =
+                    spacing.icon_spacing + spacing.icon_width + 2.0 * spacing.button_padding.x;
+                let items_width = items_width + decoration_width;
+                let min_width = width.unwrap_or(spacing.combo_width);
+                width = Some(items_width.max(min_width).at_most(ui.available_width()));
+                wrap_mode = wrap_mode.or(Some(TextWrapMode::Truncate));
+            }
+
             let mut ir = combo_box_dyn(
                 ui,
                 button_id,
@@ -240,8 +451,64 @@ impl ComboBox {
         .inner
     }
 
+    /// Show the combo box for an optional value.
+    ///
+    /// With [`Self::show_none_option`], the menu starts with an entry that sets `selected` to `None`,
+    /// and the closed combo box shows the placeholder text while `selected` is `None`.
+    /// [`Self::selected_text`] is shown while there is a selection.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut selected: Option<u32> = None;
+    /// egui::ComboBox::from_label("Priority")
+    ///     .selected_text(format!("{}", selected.unwrap_or_default()))
+    ///     .show_none_option("None")
+    ///     .show_ui_optional(ui, &mut selected, |ui, selected| {
+    ///         for priority in 1..=3 {
+    ///             ui.selectable_value(selected, Some(priority), priority.to_string());
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    pub fn show_ui_optional<T, R>(
+        mut self,
+        ui: &mut Ui,
+        selected: &mut Option<T>,
+        menu_contents: impl FnOnce(&mut Ui, &mut Option<T>) -> R,
+    ) -> InnerResponse<Option<R>> {
+        let none_option = self.none_option.take();
+        if selected.is_none() {
+            if let Some(none_option) = &none_option {
+                self.selected_text = none_option.clone().weak();
+            }
+        }
+
+        let mut cleared = false;
+        let mut ir = self.show_ui(ui, |ui| {
+            if let Some(none_option) = none_option {
+                if ui
+                    .selectable_label(selected.is_none(), none_option)
+                    .clicked()
+                    && selected.is_some()
+                {
+                    *selected = None;
+                    cleared = true;
+                }
+                ui.separator();
+            }
+            menu_contents(ui, selected)
+        });
+
+        if cleared {
+            ir.response.mark_changed();
+        }
+        ir
+    }
+
     /// Show a list of items with the given selected index.
     ///
+    /// While the combo box has keyboard focus, typing selects the first item
+    /// starting with the typed letters, like native dropdowns, even when the popup is closed.
     ///
     /// ```
     /// # #[derive(Debug, PartialEq)]
@@ -265,25 +532,146 @@ impl ComboBox {
         len: usize,
         get: impl Fn(usize) -> Text,
     ) -> Response {
-        let slf = self.selected_text(get(*selected));
+        self.show_index_with_pinned(ui, selected, &[], len, get)
+    }
 
-        let mut changed = false;
+    /// Like [`Self::show_index`], but with some items pinned to the top of the menu,
+    /// e.g. the most recently used ones.
+    ///
+    /// The `pinned` indices are shown first, in the given order, followed by a separator
+    /// and then the full list of all `len` items (including the pinned ones).
+    /// Both groups can be navigated with the keyboard as one list,
+    /// and the selected item is highlighted in both.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let apps = ["Calculator", "Calendar", "Clock", "Mail", "Maps"];
+    /// let recent = [3, 0];
+    /// let mut selected = 3;
+    /// egui::ComboBox::from_label("Open with")
+    ///     .show_index_with_pinned(ui, &mut selected, &recent, apps.len(), |i| apps[i]);
+    /// # });
+    /// ```
+    pub fn show_index_with_pinned<Text: Into<WidgetText>>(
+        self,
+        ui: &mut Ui,
+        selected: &mut usize,
+        pinned: &[usize],
+        len: usize,
+        get: impl Fn(usize) -> Text,
+    ) -> Response {
+        self.show_index_impl(ui, selected, pinned, len, get)
+            .response
+    }
 
-        let mut response = slf
-            .show_ui(ui, |ui| {
-                for i in 0..len {
-                    if ui.selectable_label(i == *selected, get(i)).clicked() {
-                        *selected = i;
-                        changed = true;
-                    }
+    /// Like [`Self::show_index`], but also reports which item the user is previewing.
+    ///
+    /// While the menu is open, the item last hovered (or focused with the keyboard)
+    /// is returned in `inner`, so you can apply it as a live preview, e.g. of a theme.
+    /// `inner` is `None` whenever nothing is being previewed, including when the menu
+    /// was closed without picking anything, so that is when you revert to `selected`.
+    ///
+    /// Clicking an item commits it: it is written to `selected`
+    /// and [`Response::changed`] is `true` for that frame.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let themes = ["Light", "Dark", "Solarized"];
+    /// let mut selected = 0;
+    /// let ir = egui::ComboBox::from_label("Theme")
+    ///     .show_index_with_preview(ui, &mut selected, themes.len(), |i| themes[i]);
+    /// let theme_to_show = ir.inner.unwrap_or(selected);
+    /// # });
+    /// ```
+    pub fn show_index_with_preview<Text: Into<WidgetText>>(
+        self,
+        ui: &mut Ui,
+        selected: &mut usize,
+        len: usize,
+        get: impl Fn(usize) -> Text,
+    ) -> InnerResponse<Option<usize>> {
+        self.show_index_impl(ui, selected, &[], len, get)
+    }
+
+    /// Shows the items, and returns the previewed item in `inner`.
+    fn show_index_impl<Text: Into<WidgetText>>(
+        mut self,
+        ui: &mut Ui,
+        selected: &mut usize,
+        pinned: &[usize],
+        len: usize,
+        get: impl Fn(usize) -> Text,
+    ) -> InnerResponse<Option<usize>> {
+        let button_id = ui.make_persistent_id(self.id_salt);
+        let mut changed = type_ahead(ui, button_id, selected, len, &get);
+        let was_open = Self::is_open(ui.ctx(), button_id);
+
+        let empty_text = self.empty_text.take().filter(|_| len == 0);
+        let slf = if len == 0 {
+            // There is nothing to get, so keep whatever `selected_text` was set to:
+            self
+        } else {
+            self.selected_text(get(*selected))
+        };
+
+        let pinned: Vec<usize> = pinned.iter().copied().filter(|&i| i < len).collect();
+        let columns = slf.columns;
+        let mut hovered = None;
+        let InnerResponse {
+            inner,
+            mut response,
+        } = slf.show_ui(ui, |ui| {
+            if let Some(empty_text) = empty_text {
+                ui.label(empty_text.weak());
+                return;
+            }
+
+            let mut scrolled_to_selected = false;
+            let mut item = |ui: &mut Ui, i: usize| {
+                let response = ui.selectable_label(i == *selected, get(i));
+                if i == *selected && !was_open && !scrolled_to_selected {
+                    // Just opened: make sure the selected item is in view.
+                    response.scroll_to_me(Some(Align::Center));
+                    scrolled_to_selected = true;
                 }
+                if response.hovered() || response.has_focus() {
+                    hovered = Some(i);
+                }
+                if response.clicked() {
+                    *selected = i;
+                    changed = true;
+                }
+            };
+
+            if !pinned.is_empty() {
+                item_grid(ui, button_id.with("pinned"), columns, pinned, &mut item);
+                ui.separator();
+            }
+            item_grid(ui, button_id.with("items"), columns, 0..len, &mut item);
+        });
+
+        // Keep previewing the last hovered item while the pointer is between items:
+        let preview_id = button_id.with("preview");
+        let previewed = if inner.is_some() && !changed {
+            ui.data_mut(|d| {
+                let previewed = d.get_temp_mut_or_default::<Option<usize>>(preview_id);
+                if hovered.is_some() {
+                    *previewed = hovered;
+                }
+                *previewed
             })
-            .response;
+        } else {
+            ui.data_mut(|d| d.remove::<Option<usize>>(preview_id));
+            None
+        };
 
         if changed {
             response.mark_changed();
         }
-        response
+        InnerResponse {
+            inner: previewed,
+            response,
+        }
     }
 
     /// Check if the [`ComboBox`] with the given id has its popup menu currently opened.
@@ -291,18 +679,145 @@ impl ComboBox {
         ctx.memory(|m| m.is_popup_open(Self::widget_to_popup_id(id)))
     }
 
+    /// Did the popup menu of the [`ComboBox`] with the given id just open or close this frame?
+    ///
+    /// Call this after showing the [`ComboBox`].
+    pub fn popup_status(ctx: &Context, id: Id) -> PopupStatus {
+        Popup::id_status(ctx, Self::widget_to_popup_id(id))
+    }
+
     /// Convert a [`ComboBox`] id to the id used to store it's popup state.
     fn widget_to_popup_id(widget_id: Id) -> Id {
         widget_id.with("popup")
     }
 }
 
+/// How long to wait after the last typed character before starting a new type-ahead search.
+const TYPE_AHEAD_TIMEOUT: f64 = 1.0;
+
+/// Select the first item starting with the text typed while the combo box has focus.
+///
+/// Returns `true` if the selection changed.
+fn type_ahead<Text: Into<WidgetText>>(
+    ui: &Ui,
+    button_id: Id,
+    selected: &mut usize,
+    len: usize,
+    get: &impl Fn(usize) -> Text,
+) -> bool {
+    if !ui.memory(|mem| mem.has_focus(button_id)) {
+        return false;
+    }
+
+    let (typed, time) = ui.input(|i| {
+        let typed: String = i
+            .events
+            .iter()
+            .filter_map(|event| match event {
+                crate::Event::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect();
+        (typed, i.time)
+    });
+    if typed.is_empty() {
+        return false;
+    }
+
+    let buffer_id = button_id.with("type_ahead");
+    let buffer = ui.data_mut(|d| {
+        let (buffer, last_time) = d.get_temp_mut_or_default::<(String, f64)>(buffer_id);
+        if time - *last_time > TYPE_AHEAD_TIMEOUT {
+            buffer.clear();
+        }
+        buffer.push_str(&typed.to_lowercase());
+        *last_time = time;
+        buffer.clone()
+    });
+
+    let found = (0..len).find(|&i| get(i).into().text().to_lowercase().starts_with(&buffer));
+    match found {
+        Some(i) if i != *selected => {
+            *selected = i;
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Add the items with the given indices, in a [`Grid`] if there is more than one column.
+fn item_grid(
+    ui: &mut Ui,
+    id: Id,
+    columns: usize,
+    indices: impl IntoIterator<Item = usize>,
+    item: &mut impl FnMut(&mut Ui, usize),
+) {
+    if columns <= 1 {
+        for i in indices {
+            item(ui, i);
+        }
+        return;
+    }
+
+    Grid::new(id).num_columns(columns).show(ui, |ui| {
+        for (n, i) in indices.into_iter().enumerate() {
+            item(ui, i);
+            if (n + 1) % columns == 0 {
+                ui.end_row();
+            }
+        }
+    });
+}
+
+/// The height of a selectable label in a [`ComboBox`] menu.
+fn item_row_height(ui: &Ui) -> f32 {
+    let spacing = ui.spacing();
+    (ui.text_style_height(&TextStyle::Button) + 2.0 * spacing.button_padding.y)
+        .at_least(spacing.interact_size.y)
+}
+
+/// The height of `n` rows of selectable labels in a [`ComboBox`] menu.
+fn items_height(ui: &Ui, n: usize) -> f32 {
+    let item_spacing = ui.spacing().item_spacing.y;
+    n as f32 * (item_row_height(ui) + item_spacing) - item_spacing
+}
+
+/// The width of the widest of `items` as the selected text of a [`ComboBox`].
+///
+/// Cached by `id`, and only measured again when the number of items or the font changes.
+fn widest_item_width(ui: &Ui, id: Id, items: &[impl std::fmt::Display]) -> f32 {
+    let font_id = TextStyle::Button.resolve(ui.style());
+    let key = Id::new((items.len(), &font_id, ui.ctx().pixels_per_point().to_bits()));
+    let cache_id = id.with("widest_item_width");
+
+    if let Some((cached_key, width)) = ui.data(|d| d.get_temp::<(Id, f32)>(cache_id)) {
+        if cached_key == key {
+            return width;
+        }
+    }
+
+    let width = ui.fonts(|fonts| {
+        items
+            .iter()
+            .map(|item| {
+                fonts
+                    .layout_no_wrap(item.to_string(), font_id.clone(), Default::default())
+                    .size()
+                    .x
+            })
+            .fold(0.0, f32::max)
+    });
+    ui.data_mut(|d| d.insert_temp(cache_id, (key, width)));
+    width
+}
+
 #[allow(clippy::too_many_arguments)]
 fn combo_box_dyn<'c, R>(
     ui: &mut Ui,
     button_id: Id,
     selected_text: WidgetText,
-    menu_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+    menu_contents: Box<dyn FnOnce(ScrollArea, &mut Ui) -> R + 'c>,
     icon: Option<IconPainter>,
     wrap_mode: Option<TextWrapMode>,
     close_behavior: Option<PopupCloseBehavior>,
@@ -372,6 +887,15 @@ fn combo_box_dyn<'c, R>(
 
     let height = height.unwrap_or_else(|| ui.spacing().combo_height);
 
+    // Never taller than the space on the roomier side of the button, so the popup
+    // can open upward near the bottom of the screen, or scroll if neither side has room for it all:
+    let screen_rect = ui.ctx().screen_rect();
+    let space = (button_response.rect.top() - screen_rect.top())
+        .max(screen_rect.bottom() - button_response.rect.bottom());
+    // The whole frame has to fit, including its stroke, or the popup is moved to the side:
+    let frame_margin = Frame::popup(ui.style()).total_margin().sum().y;
+    let height = height.at_most(space - frame_margin).at_least(0.0);
+
     let inner = Popup::menu(&button_response)
         .id(popup_id)
         .style(StyleModifier::default())
@@ -380,18 +904,13 @@ fn combo_box_dyn<'c, R>(
         .show(|ui| {
             ui.set_min_width(ui.available_width());
 
-            ScrollArea::vertical()
-                .max_height(height)
-                .show(ui, |ui| {
-                    // Often the button is very narrow, which means this popup
-                    // is also very narrow. Having wrapping on would therefore
-                    // result in labels that wrap very early.
-                    // Instead, we turn it off by default so that the labels
-                    // expand the width of the menu.
-                    ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
-                    menu_contents(ui)
-                })
-                .inner
+            // Often the button is very narrow, which means this popup
+            // is also very narrow. Having wrapping on would therefore
+            // result in labels that wrap very early.
+            // Instead, we turn it off by default so that the labels
+            // expand the width of the menu.
+            ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+            menu_contents(ScrollArea::vertical().max_height(height), ui)
         })
         .map(|r| r.inner);
 
@@ -0,0 +1,281 @@
+//! A searchable list of commands in a modal, as opened with Ctrl+P in many editors.
+//!
+//! ```
+//! # egui::__run_test_ctx(|ctx| {
+//! # let mut palette_open = true;
+//! let commands = ["Open file", "Save", "Save as…", "Quit"];
+//! if ctx.input_mut(|i| i.consume_key(egui::Modifiers::COMMAND, egui::Key::P)) {
+//!     palette_open = true;
+//! }
+//! if palette_open {
+//!     let response = egui::CommandPalette::new("command_palette").show(ctx, &commands);
+//!     if let Some(index) = response.selected {
+//!         println!("Running {}", commands[index]);
+//!     }
+//!     if response.selected.is_some() || response.closed {
+//!         palette_open = false;
+//!     }
+//! }
+//! # });
+//! ```
+
+use crate::{
+    text::LayoutJob, Context, Id, Key, Modal, Modifiers, Response, ScrollArea, Stroke, TextEdit,
+    TextFormat, TextStyle, Ui, WidgetText,
+};
+
+/// A modal with a search field and the commands matching it, see the [module docs](self).
+///
+/// The query matches a command if its characters appear in the command in the same order,
+/// ignoring case and spaces, so `"sva"` matches `"Save as…"`.
+/// The best matches are listed first, with the matched characters highlighted.
+///
+/// Use the arrow keys to pick a command and Enter to run it, or click it.
+/// Escape, or clicking outside the palette, closes it.
+#[must_use = "You should call .show()"]
+pub struct CommandPalette {
+    id: Id,
+    hint_text: WidgetText,
+    width: f32,
+}
+
+impl CommandPalette {
+    /// The query is remembered under this id while the palette is open.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            hint_text: "Type a command…".into(),
+            width: 400.0,
+        }
+    }
+
+    /// Shown in the search field while it is empty.
+    ///
+    /// Default: `"Type a command…"`.
+    #[inline]
+    pub fn hint_text(mut self, hint_text: impl Into<WidgetText>) -> Self {
+        self.hint_text = hint_text.into();
+        self
+    }
+
+    /// The width of the palette.
+    ///
+    /// Default: `400.0`.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Show the palette with the given commands.
+    ///
+    /// Call this every frame while the palette is open,
+    /// and stop once [`CommandPaletteResponse::selected`] or [`CommandPaletteResponse::closed`] is set.
+    pub fn show(self, ctx: &Context, commands: &[impl AsRef<str>]) -> CommandPaletteResponse {
+        let Self {
+            id,
+            hint_text,
+            width,
+        } = self;
+
+        let mut state = ctx.data_mut(|d| d.get_temp::<State>(id).unwrap_or_default());
+
+        let mut matches: Vec<(usize, i32, Vec<usize>)> = commands
+            .iter()
+            .enumerate()
+            .filter_map(|(index, command)| {
+                let (score, matched) = fuzzy_match(&state.query, command.as_ref())?;
+                Some((index, score, matched))
+            })
+            .collect();
+        matches.sort_by_key(|&(_, score, _)| -score); // stable, so equal scores keep their order
+
+        // Before the search field sees them:
+        let (down, up, enter) = ctx.input_mut(|i| {
+            (
+                i.consume_key(Modifiers::NONE, Key::ArrowDown),
+                i.consume_key(Modifiers::NONE, Key::ArrowUp),
+                i.consume_key(Modifiers::NONE, Key::Enter),
+            )
+        });
+        let last = matches.len().saturating_sub(1);
+        if down {
+            state.highlighted = (state.highlighted + 1).min(last);
+        }
+        if up {
+            state.highlighted = state.highlighted.saturating_sub(1);
+        }
+        state.highlighted = state.highlighted.min(last);
+        let mut selected = enter
+            .then(|| matches.get(state.highlighted).map(|(index, ..)| *index))
+            .flatten();
+
+        let modal = Modal::new(id).show(ctx, |ui| {
+            ui.set_width(width);
+
+            let query = ui.add(
+                TextEdit::singleline(&mut state.query)
+                    .hint_text(hint_text)
+                    .desired_width(f32::INFINITY),
+            );
+            query.request_focus();
+            if query.changed() {
+                state.highlighted = 0;
+            }
+
+            ui.separator();
+
+            ScrollArea::vertical()
+                .max_height(ui.spacing().combo_height)
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    ui.style_mut().wrap_mode = Some(crate::TextWrapMode::Extend);
+                    for (row, (index, _, matched)) in matches.iter().enumerate() {
+                        let highlighted = row == state.highlighted;
+                        let text = commands[*index].as_ref();
+                        let job = highlighted_text(ui, text, matched, highlighted);
+                        let response = ui.selectable_label(highlighted, job);
+                        if highlighted && (down || up) {
+                            response.scroll_to_me(None);
+                        }
+                        if response.clicked() {
+                            selected = Some(*index);
+                        }
+                    }
+                    if matches.is_empty() {
+                        ui.weak("No matching commands");
+                    }
+                });
+        });
+
+        let closed = selected.is_none() && modal.should_close();
+        if selected.is_some() || closed {
+            // Start afresh the next time the palette is opened:
+            ctx.data_mut(|d| d.remove::<State>(id));
+        } else {
+            ctx.data_mut(|d| d.insert_temp(id, state));
+        }
+
+        CommandPaletteResponse {
+            response: modal.response,
+            selected,
+            closed,
+        }
+    }
+}
+
+/// What happened in a [`CommandPalette`] this frame.
+pub struct CommandPaletteResponse {
+    /// The response of the palette.
+    pub response: Response,
+
+    /// The index, into the commands passed to [`CommandPalette::show`],
+    /// of the command the user picked this frame.
+    pub selected: Option<usize>,
+
+    /// Did the user dismiss the palette without picking a command,
+    /// with Escape or by clicking outside it?
+    pub closed: bool,
+}
+
+#[derive(Clone, Default)]
+struct State {
+    query: String,
+
+    /// Index into the matching commands.
+    highlighted: usize,
+}
+
+/// Match the characters of `query` in order against `text`, ignoring case and whitespace.
+///
+/// Returns a score, where higher is a better match, and the char indices of `text` that matched.
+fn fuzzy_match(query: &str, text: &str) -> Option<(i32, Vec<usize>)> {
+    let lowercase = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let mut query = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(lowercase)
+        .peekable();
+
+    let mut score = 0;
+    let mut matched = Vec::new();
+    let mut prev: Option<char> = None;
+    for (i, c) in text.chars().enumerate() {
+        let Some(&q) = query.peek() else {
+            break;
+        };
+        if lowercase(c) == q {
+            query.next();
+            score += 1;
+            if matched.last().is_some_and(|&last| last + 1 == i) {
+                score += 4; // consecutive
+            }
+            let word_start = !prev.is_some_and(char::is_alphanumeric)
+                || prev.is_some_and(|p| p.is_lowercase() && c.is_uppercase());
+            if word_start {
+                score += 3;
+            }
+            matched.push(i);
+        }
+        prev = Some(c);
+    }
+
+    query.peek().is_none().then_some((score, matched))
+}
+
+/// `text` with the chars at `matched` emphasized.
+fn highlighted_text(ui: &Ui, text: &str, matched: &[usize], highlighted: bool) -> LayoutJob {
+    let visuals = ui.visuals();
+    let font_id = TextStyle::Button.resolve(ui.style());
+    let color = if highlighted {
+        visuals.selection.stroke.color
+    } else {
+        visuals.text_color()
+    };
+    let normal = TextFormat::simple(font_id.clone(), color);
+    let strong_color = if highlighted {
+        color
+    } else {
+        visuals.strong_text_color()
+    };
+    let strong = TextFormat {
+        underline: Stroke::new(1.0, strong_color),
+        ..TextFormat::simple(font_id, strong_color)
+    };
+
+    let mut job = LayoutJob::default();
+    let mut matched = matched.iter().peekable();
+    for (i, (byte, c)) in text.char_indices().enumerate() {
+        let is_match = matched.next_if_eq(&&i).is_some();
+        let format = if is_match { &strong } else { &normal };
+        job.append(&text[byte..byte + c.len_utf8()], 0.0, format.clone());
+    }
+    job
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fuzzy_match;
+
+    #[test]
+    fn fuzzy_match_in_order_ignoring_case_and_spaces() {
+        assert_eq!(fuzzy_match("", "Save").map(|(_, m)| m), Some(vec![]));
+        assert_eq!(
+            fuzzy_match("sva", "Save as").map(|(_, m)| m),
+            Some(vec![0, 2, 5])
+        );
+        assert_eq!(
+            fuzzy_match("op fi", "Open file").map(|(_, m)| m),
+            Some(vec![0, 1, 5, 6])
+        );
+        assert!(fuzzy_match("as", "Quit").is_none());
+        assert!(fuzzy_match("evas", "Save").is_none(), "out of order");
+    }
+
+    #[test]
+    fn fuzzy_match_prefers_consecutive_and_word_starts() {
+        let score = |query, text| fuzzy_match(query, text).unwrap().0;
+        assert!(score("sa", "Save") > score("sa", "Close tabs"));
+        assert!(score("of", "Open file") > score("of", "Close buffer"));
+    }
+}
@@ -0,0 +1,659 @@
+//! A dock space: tabs that can be dragged around and docked into splits and tab groups,
+//! similar to what you find in many IDEs.
+//!
+//! See [`DockArea`].
+
+use crate::{
+    emath, vec2, Button, CursorIcon, DragAndDrop, Id, IdMap, LayerId, Order, Pos2, Rect, Response,
+    Sense, Stroke, Ui, UiBuilder, WidgetText,
+};
+
+/// How a [`DockNode::Split`] divides its rectangle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum DockSplit {
+    /// The children are placed left and right of each other.
+    Horizontal,
+
+    /// The children are placed above and below each other.
+    Vertical,
+}
+
+/// Where a dragged tab will be docked, relative to a tab group.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DockZone {
+    /// Split the tab group, putting the tab to the left.
+    Left,
+
+    /// Split the tab group, putting the tab to the right.
+    Right,
+
+    /// Split the tab group, putting the tab on top.
+    Top,
+
+    /// Split the tab group, putting the tab at the bottom.
+    Bottom,
+
+    /// Add the tab to the tab group.
+    Center,
+}
+
+impl DockZone {
+    /// Which zone of `rect` is `pos` in?
+    ///
+    /// The outer quarter of each side is an edge zone, the rest is [`Self::Center`].
+    pub fn from_pos(rect: Rect, pos: Pos2) -> Self {
+        let t = (pos - rect.min) / rect.size();
+        let distances = [
+            (Self::Left, t.x),
+            (Self::Right, 1.0 - t.x),
+            (Self::Top, t.y),
+            (Self::Bottom, 1.0 - t.y),
+        ];
+        let (zone, distance) = distances
+            .into_iter()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap_or((Self::Center, 1.0));
+        if distance < 0.25 {
+            zone
+        } else {
+            Self::Center
+        }
+    }
+
+    /// The part of `rect` that a tab docked in this zone would occupy.
+    pub fn preview_rect(self, rect: Rect) -> Rect {
+        let half = rect.size() / 2.0;
+        match self {
+            Self::Left => Rect::from_min_size(rect.min, vec2(half.x, rect.height())),
+            Self::Right => Rect::from_min_max(rect.center_top(), rect.max),
+            Self::Top => Rect::from_min_size(rect.min, vec2(rect.width(), half.y)),
+            Self::Bottom => Rect::from_min_max(rect.left_center(), rect.max),
+            Self::Center => rect,
+        }
+    }
+}
+
+/// A node in the layout tree of a [`DockArea`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum DockNode {
+    /// A group of tabs, of which one is shown at a time.
+    Tabs {
+        /// The ids of the tabs, in order.
+        tabs: Vec<Id>,
+
+        /// Index of the tab that is currently shown.
+        active: usize,
+    },
+
+    /// Two nodes next to each other.
+    Split {
+        split: DockSplit,
+
+        /// How much of the space goes to the first child, in the range `0..=1`.
+        fraction: f32,
+
+        children: Box<[DockNode; 2]>,
+    },
+}
+
+impl Default for DockNode {
+    fn default() -> Self {
+        Self::Tabs {
+            tabs: Vec::new(),
+            active: 0,
+        }
+    }
+}
+
+impl DockNode {
+    /// Does this node (or any of its children) contain the given tab?
+    pub fn contains(&self, tab: Id) -> bool {
+        match self {
+            Self::Tabs { tabs, .. } => tabs.contains(&tab),
+            Self::Split { children, .. } => children.iter().any(|child| child.contains(tab)),
+        }
+    }
+
+    /// Call the given function for each tab id, in order.
+    pub fn for_each_tab(&self, f: &mut impl FnMut(Id)) {
+        match self {
+            Self::Tabs { tabs, .. } => tabs.iter().copied().for_each(f),
+            Self::Split { children, .. } => {
+                for child in children.iter() {
+                    child.for_each_tab(f);
+                }
+            }
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        matches!(self, Self::Tabs { tabs, .. } if tabs.is_empty())
+    }
+
+    fn first_tabs_mut(&mut self) -> (&mut Vec<Id>, &mut usize) {
+        match self {
+            Self::Tabs { tabs, active } => (tabs, active),
+            Self::Split { children, .. } => children[0].first_tabs_mut(),
+        }
+    }
+
+    fn node_mut(&mut self, path: &[usize]) -> Option<&mut Self> {
+        match path.split_first() {
+            None => Some(self),
+            Some((&i, rest)) => match self {
+                Self::Split { children, .. } => children.get_mut(i)?.node_mut(rest),
+                Self::Tabs { .. } => None,
+            },
+        }
+    }
+
+    /// Remove the tab from wherever it is, leaving empty groups in place.
+    fn remove_tab(&mut self, tab: Id) {
+        match self {
+            Self::Tabs { tabs, active } => {
+                if let Some(index) = tabs.iter().position(|&t| t == tab) {
+                    tabs.remove(index);
+                    if index < *active || *active >= tabs.len() {
+                        *active = active.saturating_sub(1);
+                    }
+                }
+            }
+            Self::Split { children, .. } => {
+                for child in children.iter_mut() {
+                    child.remove_tab(tab);
+                }
+            }
+        }
+    }
+
+    /// Remove tabs that don't pass the predicate, leaving empty groups in place.
+    fn retain_tabs(&mut self, keep: &impl Fn(Id) -> bool) {
+        match self {
+            Self::Tabs { tabs, active } => {
+                tabs.retain(|&tab| keep(tab));
+                *active = (*active).min(tabs.len().saturating_sub(1));
+            }
+            Self::Split { children, .. } => {
+                for child in children.iter_mut() {
+                    child.retain_tabs(keep);
+                }
+            }
+        }
+    }
+
+    /// Collapse any split where one side is empty.
+    fn simplify(&mut self) {
+        if let Self::Split { children, .. } = self {
+            children[0].simplify();
+            children[1].simplify();
+            if children[0].is_empty() {
+                *self = std::mem::take(&mut children[1]);
+            } else if children[1].is_empty() {
+                *self = std::mem::take(&mut children[0]);
+            }
+        }
+    }
+
+    /// Dock the tab into this node in the given zone.
+    fn dock(&mut self, tab: Id, zone: DockZone) {
+        let new_tabs = Self::Tabs {
+            tabs: vec![tab],
+            active: 0,
+        };
+        let (split, new_first) = match zone {
+            DockZone::Center => {
+                let (tabs, active) = self.first_tabs_mut();
+                tabs.push(tab);
+                *active = tabs.len() - 1;
+                return;
+            }
+            DockZone::Left => (DockSplit::Horizontal, true),
+            DockZone::Right => (DockSplit::Horizontal, false),
+            DockZone::Top => (DockSplit::Vertical, true),
+            DockZone::Bottom => (DockSplit::Vertical, false),
+        };
+        let old = std::mem::take(self);
+        let children = if new_first {
+            [new_tabs, old]
+        } else {
+            [old, new_tabs]
+        };
+        *self = Self::Split {
+            split,
+            fraction: 0.5,
+            children: Box::new(children),
+        };
+    }
+}
+
+/// The persisted state of a [`DockArea`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct DockState {
+    /// The layout tree.
+    pub root: DockNode,
+}
+
+impl DockState {
+    pub fn load(ctx: &crate::Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_persisted(id))
+    }
+
+    pub fn store(self, ctx: &crate::Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+}
+
+/// What is dragged when dragging a tab of a [`DockArea`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct DockTabPayload {
+    dock_id: Id,
+    tab: Id,
+}
+
+/// A dock space: a container of tabs that the user can drag around
+/// to put them into tab groups, or split the space left/right/top/bottom.
+///
+/// The layout is a tree of splits and tab groups ([`DockNode`]),
+/// which is persisted by the [`Id`] of the dock area.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::DockArea::new("my_dock").show(ui, |dock| {
+///     dock.tab("Files", |ui| {
+///         ui.label("A list of files");
+///     });
+///     dock.tab("Editor", |ui| {
+///         ui.label("Some code");
+///     });
+/// });
+/// # });
+/// ```
+///
+/// Tabs that are new since last frame are added to the first tab group.
+/// Tabs that are no longer added are removed from the layout.
+#[must_use = "You should call .show()"]
+#[derive(Clone, Copy, Debug)]
+pub struct DockArea {
+    id_salt: Id,
+    tab_bar_height: Option<f32>,
+}
+
+impl DockArea {
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            tab_bar_height: None,
+        }
+    }
+
+    /// The height of the tab bar of each tab group.
+    ///
+    /// Default: [`crate::Spacing::interact_size`]`.y`.
+    #[inline]
+    pub fn tab_bar_height(mut self, tab_bar_height: f32) -> Self {
+        self.tab_bar_height = Some(tab_bar_height);
+        self
+    }
+
+    /// Fill the available space with the dock area.
+    ///
+    /// Add tabs to it in the given closure, using [`DockUi::tab`].
+    pub fn show<'a>(self, ui: &mut Ui, add_tabs: impl FnOnce(&mut DockUi<'a>)) -> Response {
+        let Self {
+            id_salt,
+            tab_bar_height,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt);
+        let tab_bar_height = tab_bar_height.unwrap_or_else(|| ui.spacing().interact_size.y);
+
+        let mut dock_ui = DockUi {
+            tabs: Vec::new(),
+            contents: Default::default(),
+        };
+        add_tabs(&mut dock_ui);
+        let DockUi {
+            tabs: tab_order,
+            mut contents,
+        } = dock_ui;
+
+        let mut state = DockState::load(ui.ctx(), id).unwrap_or_default();
+
+        // Reconcile the layout with the tabs that were actually added:
+        state.root.retain_tabs(&|tab| contents.contains_key(&tab));
+        for &tab in &tab_order {
+            if !state.root.contains(tab) {
+                state.root.first_tabs_mut().0.push(tab);
+            }
+        }
+        state.root.simplify();
+
+        let rect = ui.available_rect_before_wrap();
+        let response = ui.allocate_rect(rect, Sense::hover());
+
+        let mut dock = DockPass {
+            id,
+            tab_bar_height,
+            contents: &mut contents,
+            drop: None,
+        };
+        let mut path = Vec::new();
+        dock.node_ui(ui, &mut state.root, rect, &mut path);
+
+        if let Some((path, zone, payload)) = dock.drop.take() {
+            let is_noop = zone == DockZone::Center
+                && matches!(state.root.node_mut(&path), Some(DockNode::Tabs { tabs, .. }) if tabs.contains(&payload.tab));
+            if !is_noop {
+                state.root.remove_tab(payload.tab);
+                if let Some(target) = state.root.node_mut(&path) {
+                    target.dock(payload.tab, zone);
+                }
+                state.root.simplify();
+            }
+        }
+
+        state.store(ui.ctx(), id);
+
+        response
+    }
+}
+
+/// The title and contents of a tab.
+type TabContents<'a> = (WidgetText, Box<dyn FnOnce(&mut Ui) + 'a>);
+
+/// Add tabs to a [`DockArea`].
+pub struct DockUi<'a> {
+    tabs: Vec<Id>,
+    contents: IdMap<TabContents<'a>>,
+}
+
+impl<'a> DockUi<'a> {
+    /// Add a tab.
+    ///
+    /// The title is used as the [`Id`] of the tab, so it must be unique within the dock area.
+    /// If you need a changing title, use [`Self::tab_with_id`].
+    pub fn tab(&mut self, title: impl Into<WidgetText>, add_contents: impl FnOnce(&mut Ui) + 'a) {
+        let title = title.into();
+        let id = Id::new(title.text());
+        self.tab_with_id(id, title, add_contents);
+    }
+
+    /// Add a tab with an explicit [`Id`].
+    pub fn tab_with_id(
+        &mut self,
+        id: Id,
+        title: impl Into<WidgetText>,
+        add_contents: impl FnOnce(&mut Ui) + 'a,
+    ) {
+        if self
+            .contents
+            .insert(id, (title.into(), Box::new(add_contents)))
+            .is_none()
+        {
+            self.tabs.push(id);
+        }
+    }
+}
+
+/// Temporary state while showing a [`DockArea`].
+struct DockPass<'c, 'a> {
+    id: Id,
+    tab_bar_height: f32,
+    contents: &'c mut IdMap<TabContents<'a>>,
+
+    /// A tab was dropped onto the node at this path, in this zone.
+    drop: Option<(Vec<usize>, DockZone, DockTabPayload)>,
+}
+
+impl DockPass<'_, '_> {
+    fn node_ui(&mut self, ui: &mut Ui, node: &mut DockNode, rect: Rect, path: &mut Vec<usize>) {
+        match node {
+            DockNode::Tabs { tabs, active } => {
+                self.tabs_ui(ui, tabs, active, rect, path);
+            }
+            DockNode::Split {
+                split,
+                fraction,
+                children,
+            } => {
+                let [first, second] = self.split_ui(ui, *split, fraction, rect, path);
+                for (i, (child, rect)) in children.iter_mut().zip([first, second]).enumerate() {
+                    path.push(i);
+                    self.node_ui(ui, child, rect, path);
+                    path.pop();
+                }
+            }
+        }
+    }
+
+    /// Show the separator between the two children of a split, and return their rectangles.
+    fn split_ui(
+        &self,
+        ui: &Ui,
+        split: DockSplit,
+        fraction: &mut f32,
+        rect: Rect,
+        path: &[usize],
+    ) -> [Rect; 2] {
+        let id = self.id.with(("split", path));
+        let grab_radius = ui.style().interaction.resize_grab_radius_side;
+
+        let (separator_rect, cursor) = match split {
+            DockSplit::Horizontal => {
+                let x = emath::lerp(rect.x_range(), *fraction);
+                (
+                    Rect::from_x_y_ranges(x..=x, rect.y_range()).expand2(vec2(grab_radius, 0.0)),
+                    CursorIcon::ResizeHorizontal,
+                )
+            }
+            DockSplit::Vertical => {
+                let y = emath::lerp(rect.y_range(), *fraction);
+                (
+                    Rect::from_x_y_ranges(rect.x_range(), y..=y).expand2(vec2(0.0, grab_radius)),
+                    CursorIcon::ResizeVertical,
+                )
+            }
+        };
+
+        let response = ui.interact(separator_rect, id, Sense::drag());
+        if response.hovered() || response.dragged() {
+            ui.ctx().set_cursor_icon(cursor);
+        }
+        if let Some(pointer) = response
+            .interact_pointer_pos()
+            .filter(|_| response.dragged())
+        {
+            let t = (pointer - rect.min) / rect.size();
+            let t = match split {
+                DockSplit::Horizontal => t.x,
+                DockSplit::Vertical => t.y,
+            };
+            *fraction = t.clamp(0.1, 0.9);
+        }
+
+        let stroke = if response.dragged() {
+            ui.visuals().widgets.active.bg_stroke
+        } else if response.hovered() {
+            ui.visuals().widgets.hovered.bg_stroke
+        } else {
+            ui.visuals().widgets.noninteractive.bg_stroke
+        };
+
+        match split {
+            DockSplit::Horizontal => {
+                let x = emath::lerp(rect.x_range(), *fraction).round();
+                ui.painter().vline(x, rect.y_range(), stroke);
+                [
+                    Rect::from_min_max(rect.min, Pos2::new(x, rect.max.y)),
+                    Rect::from_min_max(Pos2::new(x, rect.min.y), rect.max),
+                ]
+            }
+            DockSplit::Vertical => {
+                let y = emath::lerp(rect.y_range(), *fraction).round();
+                ui.painter().hline(rect.x_range(), y, stroke);
+                [
+                    Rect::from_min_max(rect.min, Pos2::new(rect.max.x, y)),
+                    Rect::from_min_max(Pos2::new(rect.min.x, y), rect.max),
+                ]
+            }
+        }
+    }
+
+    fn tabs_ui(
+        &mut self,
+        ui: &mut Ui,
+        tabs: &[Id],
+        active: &mut usize,
+        rect: Rect,
+        path: &[usize],
+    ) {
+        let tab_bar_rect = Rect::from_min_size(rect.min, vec2(rect.width(), self.tab_bar_height));
+        let content_rect = Rect::from_min_max(tab_bar_rect.left_bottom(), rect.max);
+
+        ui.painter()
+            .rect_filled(tab_bar_rect, 0.0, ui.visuals().faint_bg_color);
+
+        // Tab bar:
+        let mut tab_bar_ui = ui.new_child(
+            UiBuilder::new()
+                .id_salt(("tab_bar", path))
+                .max_rect(tab_bar_rect)
+                .layout(crate::Layout::left_to_right(crate::Align::Center)),
+        );
+        tab_bar_ui.set_clip_rect(tab_bar_rect.intersect(ui.clip_rect()));
+        for (i, &tab) in tabs.iter().enumerate() {
+            let Some((title, _)) = self.contents.get(&tab) else {
+                continue;
+            };
+            let response = tab_bar_ui.add(
+                Button::new(title.clone())
+                    .selected(i == *active)
+                    .frame(i == *active)
+                    .sense(Sense::click_and_drag()),
+            );
+            if response.clicked() || response.drag_started() {
+                *active = i;
+            }
+            response.dnd_set_drag_payload(DockTabPayload {
+                dock_id: self.id,
+                tab,
+            });
+        }
+
+        // Content:
+        if let Some(&tab) = tabs.get(*active) {
+            if let Some((_, add_contents)) = self.contents.remove(&tab) {
+                let mut content_ui = ui.new_child(
+                    UiBuilder::new()
+                        .id_salt(tab)
+                        .max_rect(content_rect.shrink(ui.spacing().item_spacing.x)),
+                );
+                content_ui.set_clip_rect(content_rect.intersect(ui.clip_rect()));
+                add_contents(&mut content_ui);
+            }
+        }
+
+        // Drag-to-dock:
+        let Some(payload) = DragAndDrop::payload::<DockTabPayload>(ui.ctx()) else {
+            return;
+        };
+        if payload.dock_id != self.id {
+            return;
+        }
+        let Some(pointer) = ui.ctx().pointer_hover_pos().filter(|p| rect.contains(*p)) else {
+            return;
+        };
+
+        let zone = if tab_bar_rect.contains(pointer) {
+            DockZone::Center
+        } else {
+            DockZone::from_pos(content_rect, pointer)
+        };
+        let preview_rect = zone.preview_rect(if zone == DockZone::Center {
+            rect
+        } else {
+            content_rect
+        });
+
+        let highlight = ui.visuals().selection.bg_fill;
+        ui.ctx()
+            .layer_painter(LayerId::new(
+                Order::Foreground,
+                self.id.with("dock_preview"),
+            ))
+            .rect(
+                preview_rect.shrink(2.0),
+                ui.visuals().widgets.noninteractive.corner_radius,
+                highlight.gamma_multiply(0.25),
+                Stroke::new(1.0, highlight),
+                crate::StrokeKind::Inside,
+            );
+
+        if ui.input(|i| i.pointer.any_released()) {
+            self.drop = Some((path.to_vec(), zone, *payload));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vec2;
+
+    fn tabs(ids: &[&str]) -> DockNode {
+        DockNode::Tabs {
+            tabs: ids.iter().map(Id::new).collect(),
+            active: 0,
+        }
+    }
+
+    #[test]
+    fn dock_and_simplify() {
+        let mut root = tabs(&["a", "b"]);
+
+        root.remove_tab(Id::new("b"));
+        root.dock(Id::new("b"), DockZone::Right);
+        assert_eq!(
+            root,
+            DockNode::Split {
+                split: DockSplit::Horizontal,
+                fraction: 0.5,
+                children: Box::new([tabs(&["a"]), tabs(&["b"])]),
+            }
+        );
+
+        // Moving the last tab of a group back collapses the split:
+        root.remove_tab(Id::new("b"));
+        root.node_mut(&[0])
+            .unwrap()
+            .dock(Id::new("b"), DockZone::Center);
+        root.simplify();
+        assert_eq!(
+            root,
+            DockNode::Tabs {
+                tabs: vec![Id::new("a"), Id::new("b")],
+                active: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn zone_from_pos() {
+        let rect = Rect::from_min_size(Pos2::ZERO, Vec2::splat(100.0));
+        assert_eq!(
+            DockZone::from_pos(rect, Pos2::new(5.0, 50.0)),
+            DockZone::Left
+        );
+        assert_eq!(
+            DockZone::from_pos(rect, Pos2::new(50.0, 95.0)),
+            DockZone::Bottom
+        );
+        assert_eq!(
+            DockZone::from_pos(rect, Pos2::new(50.0, 50.0)),
+            DockZone::Center
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{Align, Grid, Id, InnerResponse, Label, Layout, Ui, WidgetText};
+
+/// A two-column form: labels in the first column, inputs in the second.
+///
+/// The label column is as wide as the widest label, and labels are right-aligned by default.
+/// The input column stretches to fill the rest of the available width,
+/// and so do the inputs in it, see [`FormUi::row`].
+///
+/// This is a thin layer on top of [`Grid`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut name = String::new();
+/// # let mut age = 42;
+/// egui::FormLayout::new("person").show(ui, |form| {
+///     form.row("Name:", |ui| ui.text_edit_singleline(&mut name));
+///     form.row("Age:", |ui| ui.add(egui::DragValue::new(&mut age)));
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct FormLayout {
+    id_salt: Id,
+    label_align: Align,
+    max_label_width: Option<f32>,
+    row_spacing: Option<f32>,
+    striped: bool,
+}
+
+impl FormLayout {
+    /// Create a new [`FormLayout`] with a locally unique identifier.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            label_align: Align::Max,
+            max_label_width: None,
+            row_spacing: None,
+            striped: false,
+        }
+    }
+
+    /// How to align the labels within the label column.
+    ///
+    /// Default: [`Align::Max`], i.e. right-aligned next to their inputs.
+    #[inline]
+    pub fn label_align(mut self, label_align: Align) -> Self {
+        self.label_align = label_align;
+        self
+    }
+
+    /// Wrap labels that are wider than this, instead of widening the label column.
+    ///
+    /// Default: no limit.
+    #[inline]
+    pub fn max_label_width(mut self, max_label_width: f32) -> Self {
+        self.max_label_width = Some(max_label_width);
+        self
+    }
+
+    /// Vertical space between the rows.
+    ///
+    /// Default: [`crate::Spacing::item_spacing`].
+    #[inline]
+    pub fn row_spacing(mut self, row_spacing: f32) -> Self {
+        self.row_spacing = Some(row_spacing);
+        self
+    }
+
+    /// If `true`, add a subtle background color to every other row.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn striped(mut self, striped: bool) -> Self {
+        self.striped = striped;
+        self
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_rows: impl FnOnce(&mut FormUi<'_>) -> R,
+    ) -> InnerResponse<R> {
+        let Self {
+            id_salt,
+            label_align,
+            max_label_width,
+            row_spacing,
+            striped,
+        } = self;
+
+        let mut spacing = ui.spacing().item_spacing;
+        if let Some(row_spacing) = row_spacing {
+            spacing.y = row_spacing;
+        }
+
+        Grid::new(id_salt)
+            .num_columns(2)
+            .spacing(spacing)
+            .striped(striped)
+            .show(ui, |ui| {
+                add_rows(&mut FormUi {
+                    ui,
+                    label_align,
+                    max_label_width,
+                })
+            })
+    }
+}
+
+/// Adds rows to a [`FormLayout`].
+pub struct FormUi<'a> {
+    ui: &'a mut Ui,
+    label_align: Align,
+    max_label_width: Option<f32>,
+}
+
+impl FormUi<'_> {
+    /// Add a row with the given label and input(s).
+    ///
+    /// The inputs are laid out top-down and stretched to the full width of the input column,
+    /// so e.g. a [`crate::TextEdit`] fills the rest of the form.
+    /// Use [`Ui::horizontal`] to put several inputs next to each other at their natural size.
+    pub fn row<R>(
+        &mut self,
+        label: impl Into<WidgetText>,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let Self {
+            ui,
+            label_align,
+            max_label_width,
+        } = self;
+
+        ui.with_layout(Layout::top_down(*label_align), |ui| {
+            if let Some(max_label_width) = *max_label_width {
+                ui.set_max_width(max_label_width);
+            }
+            ui.add(Label::new(label).wrap());
+        });
+        let response = ui.with_layout(Layout::top_down_justified(Align::Min), add_contents);
+        ui.end_row();
+        response
+    }
+
+    /// The [`Ui`] of the underlying [`Grid`], e.g. for adding custom rows.
+    ///
+    /// Remember to call [`Ui::end_row`] after each row.
+    pub fn ui(&mut self) -> &mut Ui {
+        self.ui
+    }
+}
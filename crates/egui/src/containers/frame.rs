@@ -1,10 +1,103 @@
 //! Frame container
 
 use crate::{
-    epaint, layers::ShapeIdx, InnerResponse, Response, Sense, Style, Ui, UiBuilder, UiKind,
-    UiStackInfo,
+    emath, emath::lerp, epaint, layers::ShapeIdx, pos2, vec2, Context, FrameRole, Id,
+    InnerResponse, Response, Sense, Style, Ui, UiBuilder, UiKind, UiStackInfo,
 };
-use epaint::{Color32, CornerRadius, Margin, MarginF32, Rect, Shadow, Shape, Stroke};
+use epaint::{
+    tessellator::path::add_circle_quadrant, Color32, CornerRadius, Margin, MarginF32, PathShape,
+    Pos2, Rect, Shadow, Shape, Stroke, Vec2,
+};
+
+/// What [`Frame::paint_press_feedback`] paints when the frame is pressed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum PressFeedback {
+    /// A circle growing from where the frame was pressed until it covers the frame.
+    #[default]
+    Ripple,
+
+    /// The whole frame lights up.
+    Flash,
+}
+
+/// How long, in seconds, the animation of [`Frame::paint_press_feedback`] takes to fade out.
+const PRESS_FEEDBACK_DURATION: f32 = 0.4;
+
+/// Which sides of a [`Frame`] to paint the [`Frame::stroke`] on.
+///
+/// See [`Frame::stroke_sides`].
+///
+/// ```
+/// # use egui::StrokeSides;
+/// assert_eq!(StrokeSides::TOP | StrokeSides::BOTTOM, StrokeSides::HORIZONTAL);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct StrokeSides(u8);
+
+bitflags::bitflags! {
+    impl StrokeSides: u8 {
+        const LEFT = 1<<0;
+        const RIGHT = 1<<1;
+        const TOP = 1<<2;
+        const BOTTOM = 1<<3;
+
+        /// Top and bottom, e.g. for the rows of a table.
+        const HORIZONTAL = Self::TOP.bits() | Self::BOTTOM.bits();
+
+        /// Left and right, e.g. for the columns of a table.
+        const VERTICAL = Self::LEFT.bits() | Self::RIGHT.bits();
+    }
+}
+
+impl std::fmt::Debug for StrokeSides {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "StrokeSides {{")?;
+        for (name, _) in self.iter_names() {
+            write!(f, " {}", name.to_lowercase())?;
+        }
+        write!(f, " }}")
+    }
+}
+
+impl StrokeSides {
+    /// All four sides. This is the default.
+    pub const ALL: Self = Self::all();
+
+    /// No sides.
+    pub const NONE: Self = Self::empty();
+
+    /// The sides in the order they are traced around the frame:
+    /// bottom (right-to-left), left (upwards), top (left-to-right), right (downwards).
+    fn clockwise(self) -> [bool; 4] {
+        [Self::BOTTOM, Self::LEFT, Self::TOP, Self::RIGHT].map(|side| self.contains(side))
+    }
+
+    /// Remove the rounding of every corner next to a side without stroke.
+    fn clamp_corner_radius(self, mut corner_radius: CornerRadius) -> CornerRadius {
+        if !self.contains(Self::LEFT | Self::TOP) {
+            corner_radius.nw = 0;
+        }
+        if !self.contains(Self::RIGHT | Self::TOP) {
+            corner_radius.ne = 0;
+        }
+        if !self.contains(Self::LEFT | Self::BOTTOM) {
+            corner_radius.sw = 0;
+        }
+        if !self.contains(Self::RIGHT | Self::BOTTOM) {
+            corner_radius.se = 0;
+        }
+        corner_radius
+    }
+}
+
+impl Default for StrokeSides {
+    #[inline]
+    fn default() -> Self {
+        Self::ALL
+    }
+}
 
 /// A frame around some content, including margin, colors, etc.
 ///
@@ -115,6 +208,12 @@ pub struct Frame {
     #[doc(alias = "border")]
     pub stroke: Stroke,
 
+    /// Which sides to paint the [`Self::stroke`] on.
+    ///
+    /// See [`Self::stroke_sides`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub stroke_sides: StrokeSides,
+
     /// The rounding of the _outer_ corner of the [`Self::stroke`]
     /// (or, if there is no stroke, the outer corner of [`Self::fill`]).
     ///
@@ -138,12 +237,34 @@ pub struct Frame {
 
     /// Optional drop-shadow behind the frame.
     pub shadow: Shadow,
+
+    /// Blur radius of the frosted glass effect behind the frame, in points.
+    ///
+    /// See [`Self::backdrop_blur`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub backdrop_blur: u8,
+
+    /// Clip the contents to the rounded shape of the frame?
+    ///
+    /// See [`Self::clip_content`].
+    pub clip_content: bool,
+
+    /// Make the whole frame interactive with this [`Sense`].
+    ///
+    /// See [`Self::sense`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub sense: Option<Sense>,
 }
 
 #[test]
 fn frame_size() {
+    // 32 bytes of margins, colors, stroke, corner radius and shadow,
+    // plus `stroke_sides`, `clip_content` and `backdrop_blur` (one byte each) and `sense` (two bytes),
+    // rounded up to the alignment of 4.
+    // Getting back to 36 would mean hiding `clip_content` in the bits of another public field,
+    // which isn't worth the more awkward API.
     assert_eq!(
-        std::mem::size_of::<Frame>(), 32,
+        std::mem::size_of::<Frame>(), 40,
         "Frame changed size! If it shrank - good! Update this test. If it grew - bad! Try to find a way to avoid it."
     );
     assert!(
@@ -152,6 +273,163 @@ fn frame_size() {
     );
 }
 
+#[test]
+fn frame_role_from_style() {
+    let mut style = Style::default();
+    assert_eq!(
+        Frame::role(&style, &FrameRole::Dialog),
+        Frame::window(&style)
+    );
+
+    let card = Frame::new().inner_margin(20).fill(Color32::RED);
+    style.frame_roles.insert(FrameRole::Card, card);
+    assert_eq!(Frame::role(&style, &FrameRole::Card), card);
+    assert_ne!(Frame::role(&style, &FrameRole::Inline), card);
+}
+
+#[test]
+fn frame_clamp_to_rounded_rect() {
+    let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 50.0));
+    let corner_radius = CornerRadius::same(10);
+
+    let inside = pos2(30.0, 20.0);
+    assert_eq!(clamp_to_rounded_rect(inside, rect, corner_radius), inside);
+    assert_eq!(
+        clamp_to_rounded_rect(pos2(50.0, -20.0), rect, corner_radius),
+        pos2(50.0, 0.0)
+    );
+
+    let clamped = clamp_to_rounded_rect(pos2(-20.0, -20.0), rect, corner_radius);
+    assert!(
+        (clamped.distance(pos2(10.0, 10.0)) - 10.0).abs() < 1e-4,
+        "On the rounded corner, not the square one: {clamped:?}"
+    );
+    assert!(clamped.x > 0.0 && clamped.y > 0.0);
+}
+
+#[test]
+fn frame_backdrop_blur_fallback() {
+    let fill = Color32::from_rgba_unmultiplied(40, 80, 120, 64);
+    let fallback = backdrop_blur_fallback_fill(fill);
+    assert_eq!(fallback.a(), BACKDROP_BLUR_FALLBACK_ALPHA);
+    assert_eq!(
+        fallback.to_srgba_unmultiplied()[..3],
+        fill.to_srgba_unmultiplied()[..3],
+        "Same color, just more opaque"
+    );
+
+    assert_eq!(backdrop_blur_fallback_fill(Color32::RED), Color32::RED);
+
+    let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 50.0));
+    let Shape::Rect(shape) = Frame::new().fill(fill).backdrop_blur(8).paint(rect) else {
+        panic!("A frame without a shadow is painted as a single rect");
+    };
+    assert_eq!(shape.fill, fallback);
+}
+
+#[test]
+fn frame_stroke_sides() {
+    let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 20.0));
+
+    let paths = stroke_side_paths(rect, CornerRadius::ZERO, 2.0, StrokeSides::HORIZONTAL);
+    assert_eq!(
+        paths,
+        vec![
+            vec![pos2(100.0, 19.0), pos2(0.0, 19.0)],
+            vec![pos2(0.0, 1.0), pos2(100.0, 1.0)],
+        ],
+        "One full-width line along the bottom and one along the top"
+    );
+
+    let paths = stroke_side_paths(
+        rect,
+        CornerRadius::same(6),
+        2.0,
+        StrokeSides::LEFT | StrokeSides::TOP,
+    );
+    assert_eq!(
+        paths.len(),
+        1,
+        "Adjacent sides are one path around the corner"
+    );
+    assert_eq!(paths[0].first(), Some(&pos2(1.0, 20.0)));
+    assert_eq!(paths[0].last(), Some(&pos2(100.0, 1.0)));
+
+    let frame = Frame::new()
+        .corner_radius(6)
+        .stroke_sides(StrokeSides::LEFT | StrokeSides::TOP);
+    assert_eq!(
+        frame.widget_corner_radius(),
+        CornerRadius {
+            nw: 6,
+            ne: 0,
+            sw: 0,
+            se: 0
+        }
+    );
+    assert_eq!(
+        Frame::new().corner_radius(6).widget_corner_radius(),
+        CornerRadius::same(6)
+    );
+}
+
+#[test]
+fn frame_clip_content() {
+    let ctx = crate::Context::default();
+    let mut frame_rect = Rect::NOTHING;
+    let output = ctx.run(Default::default(), |ctx| {
+        crate::CentralPanel::default().show(ctx, |ui| {
+            let response = Frame::new()
+                .inner_margin(10)
+                .corner_radius(8)
+                .clip_content(true)
+                .show(ui, |ui| {
+                    ui.set_min_size(vec2(100.0, 50.0));
+                    let overhanging = ui.max_rect().expand(20.0);
+                    ui.painter().rect_filled(overhanging, 0, Color32::RED);
+                    ui.painter().text(
+                        overhanging.left_top(),
+                        crate::Align2::LEFT_TOP,
+                        "Overhanging text",
+                        crate::FontId::default(),
+                        Color32::BLUE,
+                    );
+                })
+                .response;
+            frame_rect = response.rect;
+        });
+    });
+
+    let red = output
+        .shapes
+        .iter()
+        .find(|clipped| matches!(&clipped.shape, Shape::Rect(r) if r.fill == Color32::RED))
+        .expect("the red rect should be painted");
+    assert!(
+        frame_rect.contains_rect(red.clip_rect),
+        "clipped to the frame"
+    );
+    let Shape::Rect(red_rect) = &red.shape else {
+        unreachable!()
+    };
+    assert_eq!(red_rect.rect, frame_rect, "cropped to the frame");
+    assert_eq!(
+        red_rect.corner_radius,
+        CornerRadius::same(8),
+        "corners rounded"
+    );
+
+    let text = output
+        .shapes
+        .iter()
+        .find(|clipped| matches!(clipped.shape, Shape::Text(_)))
+        .expect("the text should be painted");
+    assert!(
+        frame_rect.contains_rect(text.clip_rect),
+        "clipped to the frame"
+    );
+}
+
 /// ## Constructors
 impl Frame {
     /// No colors, no margins, no border.
@@ -160,10 +438,14 @@ impl Frame {
     pub const NONE: Self = Self {
         inner_margin: Margin::ZERO,
         stroke: Stroke::NONE,
+        stroke_sides: StrokeSides::ALL,
         fill: Color32::TRANSPARENT,
         corner_radius: CornerRadius::ZERO,
         outer_margin: Margin::ZERO,
         shadow: Shadow::NONE,
+        backdrop_blur: 0,
+        clip_content: false,
+        sense: None,
     };
 
     /// No colors, no margins, no border.
@@ -239,6 +521,37 @@ impl Frame {
     pub fn dark_canvas(style: &Style) -> Self {
         Self::canvas(style).fill(Color32::from_black_alpha(250))
     }
+
+    /// The frame for the given [`FrameRole`], as set by the theme in [`Style::frame_roles`].
+    ///
+    /// Roles missing from there get a frame derived from the rest of the style,
+    /// with a custom [`FrameRole::Name`] falling back to [`Self::group`].
+    /// Use the builder methods to override parts of it for a single frame:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::Frame::role(ui.style(), &egui::FrameRole::Card)
+    ///     .outer_margin(4)
+    ///     .show(ui, |ui| {
+    ///         ui.label("Inside a card");
+    ///     });
+    /// # });
+    /// ```
+    pub fn role(style: &Style, role: &FrameRole) -> Self {
+        if let Some(frame) = style.frame_roles.get(role) {
+            return *frame;
+        }
+        match role {
+            FrameRole::Card => Self::group(style).fill(style.visuals.faint_bg_color),
+            FrameRole::Inline => Self::new()
+                .inner_margin(Margin::from(style.spacing.button_padding))
+                .corner_radius(style.visuals.widgets.noninteractive.corner_radius)
+                .fill(style.visuals.faint_bg_color)
+                .stroke(style.visuals.widgets.noninteractive.bg_stroke),
+            FrameRole::Dialog => Self::window(style),
+            FrameRole::Name(_) => Self::group(style),
+        }
+    }
 }
 
 /// ## Builders
@@ -272,6 +585,27 @@ impl Frame {
         self
     }
 
+    /// Only paint the [`Self::stroke`] on some sides, e.g. for table cells and dividers.
+    ///
+    /// The frame takes up the same space regardless, so frames with different
+    /// sides still line up. Corners next to a side without stroke are not rounded.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::Frame::new()
+    ///     .stroke(ui.visuals().widgets.noninteractive.bg_stroke)
+    ///     .stroke_sides(egui::StrokeSides::HORIZONTAL)
+    ///     .show(ui, |ui| ui.label("A table row"));
+    /// # });
+    /// ```
+    ///
+    /// Default: [`StrokeSides::ALL`].
+    #[inline]
+    pub fn stroke_sides(mut self, stroke_sides: StrokeSides) -> Self {
+        self.stroke_sides = stroke_sides;
+        self
+    }
+
     /// The rounding of the _outer_ corner of the [`Self::stroke`]
     /// (or, if there is no stroke, the outer corner of [`Self::fill`]).
     ///
@@ -318,6 +652,71 @@ impl Frame {
         self
     }
 
+    /// Use a blurred copy of what is behind the frame as its background, like frosted glass.
+    ///
+    /// This needs support from the integration to sample what has already been painted,
+    /// which no backend has yet.
+    /// Until it does, the frame falls back to a semi-opaque [`Self::fill`] (at least 88% opaque),
+    /// so what is behind the frame is still obscured, like it would be when blurred.
+    ///
+    /// Default: `0` (no blur).
+    #[inline]
+    pub fn backdrop_blur(mut self, radius: u8) -> Self {
+        self.backdrop_blur = radius;
+        self
+    }
+
+    /// Clip the contents to the rounded shape of the frame.
+    ///
+    /// This is useful for e.g. putting an image inside a rounded frame
+    /// without its corners spilling outside the frame.
+    ///
+    /// egui can only clip to rectangles, so the contents are clipped to the `fill_rect`,
+    /// and any rectangle (like an image) covering a corner of the frame
+    /// gets that corner rounded to match the inner corner radius of the frame.
+    ///
+    /// Only rectangles are rounded off like that: other shapes (e.g. text, lines or custom meshes)
+    /// that reach into the corners are only clipped to the `fill_rect`,
+    /// so they still show in the rounded-off corners.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn clip_content(mut self, clip_content: bool) -> Self {
+        self.clip_content = clip_content;
+        self
+    }
+
+    /// Make the whole frame sense clicks, drags, etc, e.g. to give a card a context menu.
+    ///
+    /// The [`Response`] returned by [`Self::show`] then covers the full frame
+    /// (including the outer margin), and is registered below the widgets in the frame,
+    /// so those still get their own clicks.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::Frame::group(ui.style())
+    ///     .sense(egui::Sense::click())
+    ///     .show(ui, |ui| {
+    ///         ui.label("Right-click anywhere on this card");
+    ///     })
+    ///     .response
+    ///     .context_menu(|ui| {
+    ///         if ui.button("Delete").clicked() {
+    ///             // …
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    ///
+    /// Only used by [`Self::show`], not by [`Self::begin`].
+    ///
+    /// Default: `None`, i.e. the response only senses hover.
+    #[inline]
+    pub fn sense(mut self, sense: Sense) -> Self {
+        self.sense = Some(sense);
+        self
+    }
+
     /// Opacity multiplier in gamma space.
     ///
     /// For instance, multiplying with `0.5`
@@ -329,6 +728,59 @@ impl Frame {
         self.shadow.color = self.shadow.color.gamma_multiply(opacity);
         self
     }
+
+    /// Lift the frame and grow its shadow towards `hovered_shadow` while `hovered`, animated.
+    ///
+    /// `lift` is how many points the frame moves up when fully hovered.
+    /// The space it takes up in the layout stays the same.
+    ///
+    /// The animation is keyed on `id`. Since the frame's own [`Response`] is only known
+    /// after showing it, you usually pass in whether it was hovered last frame:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let id = ui.id().with("card");
+    /// let hovered = ui.data(|d| d.get_temp(id)).unwrap_or(false);
+    /// let response = egui::Frame::group(ui.style())
+    ///     .hoverable_elevation(ui.ctx(), id, hovered, ui.visuals().popup_shadow, 2.0)
+    ///     .show(ui, |ui| ui.label("Card"))
+    ///     .response;
+    /// ui.data_mut(|d| d.insert_temp(id, response.contains_pointer()));
+    /// # });
+    /// ```
+    pub fn hoverable_elevation(
+        mut self,
+        ctx: &Context,
+        id: Id,
+        hovered: bool,
+        hovered_shadow: Shadow,
+        lift: f32,
+    ) -> Self {
+        let t = ctx.animate_bool_responsive(id, hovered);
+        if t == 0.0 {
+            return self;
+        }
+
+        let base = self.shadow;
+        let lerp_i8 = |a: i8, b: i8| lerp(a as f32..=b as f32, t).round() as i8;
+        let lerp_u8 = |a: u8, b: u8| lerp(a as f32..=b as f32, t).round() as u8;
+        self.shadow = Shadow {
+            offset: [
+                lerp_i8(base.offset[0], hovered_shadow.offset[0]),
+                lerp_i8(base.offset[1], hovered_shadow.offset[1]),
+            ],
+            blur: lerp_u8(base.blur, hovered_shadow.blur),
+            spread: lerp_u8(base.spread, hovered_shadow.spread),
+            color: base.color.lerp_to_gamma(hovered_shadow.color, t),
+        };
+
+        // Move the frame up, without changing the total size it allocates:
+        let lift = (t * lift).round() as i8;
+        self.outer_margin.top = self.outer_margin.top.saturating_sub(lift);
+        self.outer_margin.bottom = self.outer_margin.bottom.saturating_add(lift);
+
+        self
+    }
 }
 
 /// ## Inspectors
@@ -363,6 +815,21 @@ impl Frame {
     pub fn outer_rect(&self, content_rect: Rect) -> Rect {
         content_rect + self.inner_margin + MarginF32::from(self.stroke.width) + self.outer_margin
     }
+
+    /// The corner radius of the `fill_rect`, i.e. the inner corner of the [`Self::stroke`].
+    pub fn fill_corner_radius(&self) -> CornerRadius {
+        self.widget_corner_radius() - self.stroke.width.round().clamp(0.0, 255.0) as u8
+    }
+
+    /// The corner radius of the `widget_rect`,
+    /// i.e. [`Self::corner_radius`] without the corners left out by [`Self::stroke_sides`].
+    pub fn widget_corner_radius(&self) -> CornerRadius {
+        if self.stroke_sides.is_all() {
+            self.corner_radius
+        } else {
+            self.stroke_sides.clamp_corner_radius(self.corner_radius)
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -398,12 +865,18 @@ impl Frame {
         max_content_rect.max.x = max_content_rect.max.x.max(max_content_rect.min.x);
         max_content_rect.max.y = max_content_rect.max.y.max(max_content_rect.min.y);
 
-        let content_ui = ui.new_child(
+        let mut content_ui = ui.new_child(
             UiBuilder::new()
                 .ui_stack_info(UiStackInfo::new(UiKind::Frame).with_frame(self))
                 .max_rect(max_content_rect),
         );
 
+        if self.clip_content {
+            // We don't know the final size yet, so clip to the largest possible fill rect for now.
+            // The clip rect is tightened in `Prepared::paint`, once the content size is known.
+            content_ui.shrink_clip_rect(self.fill_rect(max_content_rect));
+        }
+
         Prepared {
             frame: self,
             where_to_put_background,
@@ -422,32 +895,108 @@ impl Frame {
         ui: &mut Ui,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
     ) -> InnerResponse<R> {
+        if let Some(sense) = self.sense {
+            // Sense in a surrounding ui, so that it ends up behind the contents:
+            return ui.scope_builder(UiBuilder::new().sense(sense), |ui| {
+                let mut prepared = self.begin(ui);
+                let ret = add_contents(&mut prepared.content_ui);
+                prepared.end(ui);
+                ret
+            });
+        }
+
         let mut prepared = self.begin(ui);
         let ret = add_contents(&mut prepared.content_ui);
         let response = prepared.end(ui);
         InnerResponse::new(ret, response)
     }
 
+    /// Measure how large this frame would be with the given contents, without showing it.
+    ///
+    /// The contents are laid out in an invisible sizing pass (see [`Ui::is_sizing_pass`]),
+    /// so nothing is painted, nothing can be interacted with, and no space is allocated in `ui`.
+    /// Returns the outer size, including all margins, as [`Self::show`] would allocate it
+    /// given the space available in `ui`.
+    ///
+    /// This lets you plan a layout before showing it, e.g. picking the number of columns from
+    /// the size of a card, or measuring a group with `Frame::group(ui.style()).measure(…)`.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let card = egui::Frame::group(ui.style());
+    /// let size = card.measure(ui, |ui| {
+    ///     ui.label("A card");
+    /// });
+    /// let columns = (ui.available_width() / size.x).floor().max(1.0) as usize;
+    /// # });
+    /// ```
+    pub fn measure<R>(self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> Vec2 {
+        // Each measurement gets its own id, so measuring several frames in one `Ui` doesn't clash:
+        let id = ui.next_auto_id();
+        ui.skip_ahead_auto_ids(1);
+        let mut measure_ui = Ui::new(
+            ui.ctx().clone(),
+            id,
+            UiBuilder::new()
+                .layer_id(ui.layer_id())
+                .max_rect(ui.available_rect_before_wrap())
+                .layout(*ui.layout())
+                .style(ui.style().clone())
+                .sizing_pass()
+                .invisible(),
+        );
+        self.show(&mut measure_ui, add_contents)
+            .response
+            .rect
+            .size()
+    }
+
     /// Paint this frame as a shape.
     pub fn paint(&self, content_rect: Rect) -> Shape {
         let Self {
             inner_margin: _,
             fill,
             stroke,
-            corner_radius,
+            stroke_sides,
+            corner_radius: _,
             outer_margin: _,
             shadow,
+            backdrop_blur,
+            clip_content: _,
+            sense: _,
         } = *self;
 
         let widget_rect = self.widget_rect(content_rect);
+        let corner_radius = self.widget_corner_radius();
+        let fill = if 0 < backdrop_blur {
+            // TODO(emilk): blur the backdrop in `egui_wgpu`. It paints all of egui in one render pass,
+            // which would have to be split to capture what is behind the frame, blur and composite it.
+            backdrop_blur_fallback_fill(fill)
+        } else {
+            fill
+        };
 
-        let frame_shape = Shape::Rect(epaint::RectShape::new(
-            widget_rect,
-            corner_radius,
-            fill,
-            stroke,
-            epaint::StrokeKind::Inside,
-        ));
+        let frame_shape = if stroke_sides.is_all() {
+            Shape::Rect(epaint::RectShape::new(
+                widget_rect,
+                corner_radius,
+                fill,
+                stroke,
+                epaint::StrokeKind::Inside,
+            ))
+        } else {
+            let fill_shape =
+                Shape::Rect(epaint::RectShape::filled(widget_rect, corner_radius, fill));
+            let mut shapes = vec![fill_shape];
+            if !stroke.is_empty() {
+                shapes.extend(
+                    stroke_side_paths(widget_rect, corner_radius, stroke.width, stroke_sides)
+                        .into_iter()
+                        .map(|points| Shape::Path(PathShape::line(points, stroke))),
+                );
+            }
+            Shape::Vec(shapes)
+        };
 
         if shadow == Default::default() {
             frame_shape
@@ -458,6 +1007,81 @@ impl Frame {
     }
 }
 
+impl Frame {
+    /// Animate a ripple or flash when the frame is pressed, given its [`Response`].
+    ///
+    /// The feedback starts where the pointer was pressed, is clipped to the rounded frame,
+    /// and fades out over a fraction of a second. It is painted on top of the contents,
+    /// tinted with the [`crate::Visuals::selection`] color, so call this after showing the frame.
+    /// The frame needs to sense clicks, see [`Self::sense`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let frame = egui::Frame::group(ui.style()).sense(egui::Sense::click());
+    /// let response = frame.show(ui, |ui| ui.label("Card")).response;
+    /// frame.paint_press_feedback(ui, &response, egui::PressFeedback::Ripple);
+    /// # });
+    /// ```
+    pub fn paint_press_feedback(&self, ui: &Ui, response: &Response, feedback: PressFeedback) {
+        let id = response.id.with("press_feedback");
+        let now = ui.input(|i| i.time);
+
+        if response.is_pointer_button_down_on() && ui.input(|i| i.pointer.any_pressed()) {
+            let origin = ui
+                .input(|i| i.pointer.press_origin())
+                .unwrap_or_else(|| response.rect.center());
+            ui.data_mut(|d| d.insert_temp(id, (origin, now)));
+        }
+
+        let Some((origin, start_time)) = ui.data(|d| d.get_temp::<(Pos2, f64)>(id)) else {
+            return;
+        };
+        let t = ((now - start_time) as f32 / PRESS_FEEDBACK_DURATION).clamp(0.0, 1.0);
+        if t >= 1.0 {
+            ui.data_mut(|d| d.remove::<(Pos2, f64)>(id));
+            return;
+        }
+        ui.ctx().request_repaint();
+
+        let widget_rect = response.rect - self.outer_margin;
+        let corner_radius = self.widget_corner_radius();
+        let color = ui
+            .visuals()
+            .selection
+            .bg_fill
+            .gamma_multiply(0.4 * (1.0 - t));
+
+        let shape = match feedback {
+            PressFeedback::Ripple => {
+                let max_radius = [
+                    widget_rect.left_top(),
+                    widget_rect.right_top(),
+                    widget_rect.left_bottom(),
+                    widget_rect.right_bottom(),
+                ]
+                .into_iter()
+                .map(|corner| corner.distance(origin))
+                .fold(0.0, f32::max);
+                let radius = max_radius * emath::easing::cubic_out(t);
+
+                const NUM_POINTS: usize = 64;
+                let points = (0..NUM_POINTS)
+                    .map(|i| {
+                        let angle = std::f32::consts::TAU * i as f32 / NUM_POINTS as f32;
+                        let point = origin + radius * Vec2::angled(angle);
+                        clamp_to_rounded_rect(point, widget_rect, corner_radius)
+                    })
+                    .collect();
+                Shape::convex_polygon(points, color, Stroke::NONE)
+            }
+            PressFeedback::Flash => {
+                Shape::Rect(epaint::RectShape::filled(widget_rect, corner_radius, color))
+            }
+        };
+        ui.painter().add(shape);
+    }
+}
+
 impl Prepared {
     fn outer_rect(&self) -> Rect {
         let content_rect = self.content_ui.min_rect();
@@ -487,6 +1111,24 @@ impl Prepared {
             let shape = self.frame.paint(content_rect);
             ui.painter().set(self.where_to_put_background, shape);
         }
+
+        if self.frame.clip_content {
+            self.clip_content_shapes(ui, self.frame.fill_rect(content_rect));
+        }
+    }
+
+    /// Clip everything painted by [`Self::content_ui`] so far to the rounded `fill_rect`.
+    fn clip_content_shapes(&self, ui: &Ui, fill_rect: Rect) {
+        let corner_radius = self.frame.fill_corner_radius();
+        let start = ShapeIdx(self.where_to_put_background.0 + 1);
+        ui.ctx().graphics_mut(|graphics| {
+            let list = graphics.entry(self.content_ui.layer_id());
+            let end = list.next_idx();
+            list.mutate_range(start, end, |clipped_shape| {
+                clipped_shape.clip_rect = clipped_shape.clip_rect.intersect(fill_rect);
+                round_rect_corners_within(&mut clipped_shape.shape, fill_rect, corner_radius);
+            });
+        });
     }
 
     /// Convenience for calling [`Self::allocate_space`] and [`Self::paint`].
@@ -497,3 +1139,169 @@ impl Prepared {
         self.allocate_space(ui)
     }
 }
+
+/// The point in the rounded `rect` closest to `pos`.
+fn clamp_to_rounded_rect(pos: Pos2, rect: Rect, corner_radius: CornerRadius) -> Pos2 {
+    let pos = rect.clamp(pos);
+    let max_radius = rect.width().min(rect.height()) / 2.0;
+    let corners = [
+        (rect.left_top(), vec2(1.0, 1.0), corner_radius.nw),
+        (rect.right_top(), vec2(-1.0, 1.0), corner_radius.ne),
+        (rect.left_bottom(), vec2(1.0, -1.0), corner_radius.sw),
+        (rect.right_bottom(), vec2(-1.0, -1.0), corner_radius.se),
+    ];
+    for (corner, inwards, radius) in corners {
+        let radius = f32::from(radius).min(max_radius);
+        let center = corner + radius * inwards;
+        let delta = pos - center;
+        let in_corner = delta.x * inwards.x < 0.0 && delta.y * inwards.y < 0.0;
+        if in_corner && radius < delta.length() {
+            return center + radius * delta.normalized();
+        }
+    }
+    pos
+}
+
+/// The least opacity of the fill of a frame with [`Frame::backdrop_blur`],
+/// while backends can't blur what is behind it.
+const BACKDROP_BLUR_FALLBACK_ALPHA: u8 = 0xE0;
+
+/// The fill to use instead of blurring the backdrop: the same color, but mostly opaque.
+fn backdrop_blur_fallback_fill(fill: Color32) -> Color32 {
+    let [r, g, b, a] = fill.to_srgba_unmultiplied();
+    if a < BACKDROP_BLUR_FALLBACK_ALPHA {
+        Color32::from_rgba_unmultiplied(r, g, b, BACKDROP_BLUR_FALLBACK_ALPHA)
+    } else {
+        fill
+    }
+}
+
+/// The center lines of the stroke on the given `sides` of `rect`,
+/// as one open path per run of adjacent sides.
+///
+/// The stroke is inside `rect`, like [`epaint::StrokeKind::Inside`].
+fn stroke_side_paths(
+    rect: Rect,
+    corner_radius: CornerRadius,
+    stroke_width: f32,
+    sides: StrokeSides,
+) -> Vec<Vec<Pos2>> {
+    let half_width = stroke_width / 2.0;
+    let line_rect = rect.shrink(half_width);
+    let max_radius = line_rect.width().min(line_rect.height()) / 2.0;
+    let radius = |r: u8| (f32::from(r) - half_width).clamp(0.0, max_radius.max(0.0));
+    let (min, max) = (line_rect.min, line_rect.max);
+
+    // The corner at the end of each side, in the order of `StrokeSides::clockwise`,
+    // with the direction towards the center of its rounding, its radius
+    // and its quadrant (see `add_circle_quadrant`):
+    let corners = [
+        (
+            pos2(min.x, max.y),
+            vec2(1.0, -1.0),
+            radius(corner_radius.sw),
+            1.0,
+        ),
+        (
+            pos2(min.x, min.y),
+            vec2(1.0, 1.0),
+            radius(corner_radius.nw),
+            2.0,
+        ),
+        (
+            pos2(max.x, min.y),
+            vec2(-1.0, 1.0),
+            radius(corner_radius.ne),
+            3.0,
+        ),
+        (
+            pos2(max.x, max.y),
+            vec2(-1.0, -1.0),
+            radius(corner_radius.se),
+            0.0,
+        ),
+    ];
+    // The direction we trace each side in:
+    let directions = [
+        vec2(-1.0, 0.0),
+        vec2(0.0, -1.0),
+        vec2(1.0, 0.0),
+        vec2(0.0, 1.0),
+    ];
+
+    let present = sides.clockwise();
+    let mut paths = vec![];
+    for start in 0..4 {
+        // Start a path at every side that has no preceding side:
+        if !present[start] || present[(start + 3) % 4] {
+            continue;
+        }
+        let mut points = vec![corners[(start + 3) % 4].0 - half_width * directions[start]];
+        let mut side = start;
+        while present[(side + 1) % 4] {
+            let (corner, inwards, radius, quadrant) = corners[side];
+            add_circle_quadrant(&mut points, corner + radius * inwards, radius, quadrant);
+            side = (side + 1) % 4;
+        }
+        points.push(corners[side].0 + half_width * directions[side]);
+        paths.push(points);
+    }
+    paths
+}
+
+/// Round the corners of any [`Shape::Rect`] in `shape` that covers a corner of `rect`,
+/// so that it fits within `rect` rounded by `corner_radius`.
+///
+/// Rectangles extending beyond `rect` are cropped to it first (adjusting the texture coordinates, if any).
+/// Other shapes, like text, paths and meshes, are left as they are:
+/// they are only clipped to `rect` by the clip rect, so they can still show in the rounded-off corners.
+fn round_rect_corners_within(shape: &mut Shape, rect: Rect, corner_radius: CornerRadius) {
+    match shape {
+        Shape::Vec(shapes) => {
+            for shape in shapes {
+                round_rect_corners_within(shape, rect, corner_radius);
+            }
+        }
+        Shape::Rect(rect_shape) => {
+            let original = rect_shape.rect;
+            if !original.is_positive() || !original.intersects(rect) {
+                return;
+            }
+
+            if !rect.contains_rect(original) {
+                let cropped = original.intersect(rect);
+                if let Some(brush) = &mut rect_shape.brush {
+                    let brush = std::sync::Arc::make_mut(brush);
+                    let uv = brush.uv;
+                    let to_uv = |pos: epaint::Pos2| {
+                        uv.min + (pos - original.min) / original.size() * uv.size()
+                    };
+                    brush.uv = Rect::from_min_max(to_uv(cropped.min), to_uv(cropped.max));
+                }
+                rect_shape.rect = cropped;
+            }
+
+            const EPSILON: f32 = 0.5;
+            let r = rect_shape.rect;
+            let left = r.left() <= rect.left() + EPSILON;
+            let right = rect.right() - EPSILON <= r.right();
+            let top = r.top() <= rect.top() + EPSILON;
+            let bottom = rect.bottom() - EPSILON <= r.bottom();
+
+            let cr = &mut rect_shape.corner_radius;
+            if left && top {
+                cr.nw = cr.nw.max(corner_radius.nw);
+            }
+            if right && top {
+                cr.ne = cr.ne.max(corner_radius.ne);
+            }
+            if left && bottom {
+                cr.sw = cr.sw.max(corner_radius.sw);
+            }
+            if right && bottom {
+                cr.se = cr.se.max(corner_radius.se);
+            }
+        }
+        _ => {}
+    }
+}
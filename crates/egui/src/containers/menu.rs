@@ -1,10 +1,11 @@
 use crate::style::StyleModifier;
 use crate::{
-    Button, Color32, Context, Frame, Id, InnerResponse, Layout, Popup, PopupCloseBehavior,
-    Response, Style, Ui, UiBuilder, UiKind, UiStack, UiStackInfo, Widget, WidgetText,
+    Button, Color32, Context, Frame, Id, Image, InnerResponse, Layout, Popup, PopupCloseBehavior,
+    Rect, Response, ScrollArea, Sense, Style, TextStyle, Ui, UiBuilder, UiKind, UiStack,
+    UiStackInfo, Widget, WidgetInfo, WidgetText, WidgetType,
 };
-use emath::{vec2, Align, RectAlign, Vec2};
-use epaint::Stroke;
+use emath::{pos2, vec2, Align, NumExt as _, RectAlign, Vec2};
+use epaint::{Shape, Stroke};
 
 /// Apply a menu style to the [`Style`].
 ///
@@ -58,18 +59,31 @@ pub struct MenuConfig {
     /// If the user clicks, should we close the menu?
     pub close_behavior: PopupCloseBehavior,
 
+    /// Should toggling a [`Ui::menu_item_checked`] close the menu?
+    ///
+    /// Default is `false`, so several items can be toggled in one go.
+    pub close_on_check: bool,
+
     /// Override the menu style.
     ///
     /// Default is [`menu_style`].
     pub style: StyleModifier,
+
+    /// The maximum height of the menu before its contents start scrolling.
+    ///
+    /// The menu is never taller than the screen, regardless of this setting.
+    /// Default is `None`, so the menu grows to fit its contents.
+    pub max_height: Option<f32>,
 }
 
 impl Default for MenuConfig {
     fn default() -> Self {
         Self {
             close_behavior: PopupCloseBehavior::default(),
+            close_on_check: false,
             bar: false,
             style: menu_style.into(),
+            max_height: None,
         }
     }
 }
@@ -89,6 +103,15 @@ impl MenuConfig {
         self
     }
 
+    /// Should toggling a [`Ui::menu_item_checked`] close the menu?
+    ///
+    /// Default is `false`, so several items can be toggled in one go.
+    #[inline]
+    pub fn close_on_check(mut self, close_on_check: bool) -> Self {
+        self.close_on_check = close_on_check;
+        self
+    }
+
     /// Override the menu style.
     ///
     /// Default is [`menu_style`].
@@ -98,6 +121,49 @@ impl MenuConfig {
         self
     }
 
+    /// The maximum height of the menu before its contents start scrolling.
+    ///
+    /// Use [`Ui::sticky_section`] to keep category headers visible while scrolling.
+    /// An item that gains keyboard focus is scrolled into view.
+    #[inline]
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
+    /// Show the menu contents, in a [`ScrollArea`] if [`Self::max_height`] is set.
+    fn show_contents<R>(&self, ui: &mut Ui, content: impl FnOnce(&mut Ui) -> R) -> R {
+        let Some(max_height) = self.max_height else {
+            return content(ui);
+        };
+        let frame_height = Frame::menu(ui.style()).total_margin().sum().y;
+        let max_height = max_height.at_most(ui.ctx().screen_rect().height() - frame_height);
+        ScrollArea::vertical()
+            .max_height(max_height)
+            .show(ui, |ui| {
+                let inner = content(ui);
+
+                // Scroll the item that was just focused with the keyboard into view,
+                // unless a sticky section already did so.
+                let focused_rect = ui
+                    .memory(|mem| mem.focused().filter(|&id| mem.gained_focus(id)))
+                    .and_then(|id| ui.ctx().read_response(id))
+                    .filter(|response| response.layer_id == ui.layer_id())
+                    .map(|response| response.rect);
+                let has_scroll_target = ui
+                    .ctx()
+                    .pass_state(|state| state.scroll_target[1].is_some());
+                if let Some(rect) = focused_rect {
+                    if !has_scroll_target {
+                        ui.scroll_to_rect(rect, None);
+                    }
+                }
+
+                inner
+            })
+            .inner
+    }
+
     fn from_stack(stack: &UiStack) -> Self {
         stack
             .info
@@ -158,6 +224,95 @@ impl MenuState {
     }
 }
 
+/// Keep all menus open despite the click in this pass, e.g. because it toggled a checked item.
+pub(crate) fn keep_open_this_pass(ctx: &Context) {
+    let pass_nr = ctx.cumulative_pass_nr();
+    ctx.data_mut(|d| d.insert_temp(Id::new("menu_keep_open"), pass_nr));
+}
+
+/// Was [`keep_open_this_pass`] called in this pass?
+pub(crate) fn is_kept_open_this_pass(ctx: &Context) -> bool {
+    let pass_nr = ctx.cumulative_pass_nr();
+    ctx.data(|d| d.get_temp::<u64>(Id::new("menu_keep_open"))) == Some(pass_nr)
+}
+
+/// What to show in the gutter of a menu item.
+pub(crate) enum Gutter<'a> {
+    Check(bool),
+    Icon(Image<'a>),
+}
+
+/// A menu item with a gutter on the left, so that check marks and icons line up.
+///
+/// See [`Ui::menu_item_checked`] and [`Ui::menu_item_with_icon`].
+pub(crate) fn gutter_item(ui: &mut Ui, gutter: Gutter<'_>, text: WidgetText) -> Response {
+    let spacing = ui.spacing();
+    let button_padding = spacing.button_padding;
+    let gutter_width = spacing.icon_width;
+    let icon_spacing = spacing.icon_spacing;
+    let interact_height = spacing.interact_size.y;
+
+    let text_wrap_width =
+        ui.available_width() - 2.0 * button_padding.x - gutter_width - icon_spacing;
+    let galley = text.into_galley(ui, None, text_wrap_width, TextStyle::Button);
+
+    let desired_size = vec2(
+        gutter_width + icon_spacing + galley.size().x,
+        galley.size().y.max(gutter_width),
+    ) + 2.0 * button_padding;
+    let desired_size = vec2(desired_size.x, desired_size.y.at_least(interact_height));
+    let (rect, response) = ui.allocate_at_least(desired_size, Sense::click());
+    response.widget_info(|| match &gutter {
+        Gutter::Check(checked) => WidgetInfo::selected(
+            WidgetType::Checkbox,
+            ui.is_enabled(),
+            *checked,
+            galley.text(),
+        ),
+        Gutter::Icon(_) => WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), galley.text()),
+    });
+
+    if ui.is_rect_visible(rect) {
+        let visuals = ui.style().interact(&response);
+        ui.painter().rect(
+            rect.expand(visuals.expansion),
+            visuals.corner_radius,
+            visuals.weak_bg_fill,
+            visuals.bg_stroke,
+            epaint::StrokeKind::Inside,
+        );
+
+        let inner = rect.shrink2(button_padding);
+        let gutter_rect = Rect::from_min_size(
+            pos2(inner.left(), inner.center().y - 0.5 * gutter_width),
+            Vec2::splat(gutter_width),
+        );
+        match gutter {
+            Gutter::Check(true) => {
+                let check_rect = gutter_rect.shrink(gutter_width / 4.0);
+                ui.painter().add(Shape::line(
+                    vec![
+                        pos2(check_rect.left(), check_rect.center().y),
+                        pos2(check_rect.center().x, check_rect.bottom()),
+                        pos2(check_rect.right(), check_rect.top()),
+                    ],
+                    visuals.fg_stroke,
+                ));
+            }
+            Gutter::Check(false) => {}
+            Gutter::Icon(image) => image.paint_at(ui, gutter_rect),
+        }
+
+        let text_pos = pos2(
+            gutter_rect.right() + icon_spacing,
+            inner.center().y - 0.5 * galley.size().y,
+        );
+        ui.painter().galley(text_pos, galley, visuals.text_color());
+    }
+
+    response
+}
+
 /// Horizontal menu bar where you can add [`MenuButton`]s.
 
 /// The menu bar goes well in a [`crate::TopBottomPanel::top`],
@@ -240,6 +395,7 @@ impl Bar {
 pub struct MenuButton<'a> {
     pub button: Button<'a>,
     pub config: Option<MenuConfig>,
+    pub enabled: bool,
 }
 
 impl<'a> MenuButton<'a> {
@@ -260,15 +416,28 @@ impl<'a> MenuButton<'a> {
         Self {
             button,
             config: None,
+            enabled: true,
         }
     }
 
+    /// If `false`, the button is shown greyed out and the menu can't be opened.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
     /// Show the menu button.
     pub fn ui<R>(
         self,
         ui: &mut Ui,
         content: impl FnOnce(&mut Ui) -> R,
     ) -> (Response, Option<InnerResponse<R>>) {
+        if !self.enabled {
+            return (ui.add_enabled(false, self.button), None);
+        }
         let response = self.button.ui(ui);
         let mut config = self.config.unwrap_or_else(|| MenuConfig::find(ui));
         config.bar = false;
@@ -276,9 +445,10 @@ impl<'a> MenuButton<'a> {
             .close_behavior(config.close_behavior)
             .style(config.style.clone())
             .info(
-                UiStackInfo::new(UiKind::Menu).with_tag_value(MenuConfig::MENU_CONFIG_TAG, config),
+                UiStackInfo::new(UiKind::Menu)
+                    .with_tag_value(MenuConfig::MENU_CONFIG_TAG, config.clone()),
             )
-            .show(content);
+            .show(|ui| config.show_contents(ui, content));
         (response, inner)
     }
 }
@@ -287,6 +457,7 @@ impl<'a> MenuButton<'a> {
 pub struct SubMenuButton<'a> {
     pub button: Button<'a>,
     pub sub_menu: SubMenu,
+    pub enabled: bool,
 }
 
 impl<'a> SubMenuButton<'a> {
@@ -305,9 +476,19 @@ impl<'a> SubMenuButton<'a> {
         Self {
             button,
             sub_menu: SubMenu::default(),
+            enabled: true,
         }
     }
 
+    /// If `false`, the button is shown greyed out and the submenu is never opened.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
     /// Set the config for the submenu.
     ///
     /// The close behavior will not affect the current button, but the buttons in the submenu.
@@ -323,6 +504,9 @@ impl<'a> SubMenuButton<'a> {
         ui: &mut Ui,
         content: impl FnOnce(&mut Ui) -> R,
     ) -> (Response, Option<InnerResponse<R>>) {
+        if !self.enabled {
+            return (ui.add_enabled(false, self.button), None);
+        }
         let my_id = ui.next_auto_id();
         let open = MenuState::from_ui(ui, |state, _| {
             state.open_item == Some(SubMenu::id_from_widget_id(my_id))
@@ -448,7 +632,7 @@ impl SubMenu {
                 if button_response.clicked() || button_response.is_pointer_button_down_on() {
                     ui.ctx().move_to_top(ui.layer_id());
                 }
-                content(ui)
+                menu_config.show_contents(ui, content)
             });
 
         if let Some(popup_response) = &popup_response {
@@ -472,6 +656,7 @@ impl SubMenu {
 
             let clicked_inside = is_deepest_submenu
                 && !submenu_button_clicked
+                && !is_kept_open_this_pass(ui.ctx())
                 && response.ctx.input(|i| i.pointer.any_click())
                 && hover_pos.is_some_and(|pos| popup_response.response.interact_rect.contains(pos));
 
@@ -6,6 +6,9 @@ pub(crate) mod area;
 pub mod close_tag;
 pub mod collapsing_header;
 mod combo_box;
+pub mod command_palette;
+pub mod dock;
+mod form_layout;
 pub mod frame;
 pub mod menu;
 pub mod modal;
@@ -16,22 +19,27 @@ pub(crate) mod resize;
 mod scene;
 pub mod scroll_area;
 mod sides;
+pub mod toasts;
 mod tooltip;
 pub(crate) mod window;
 
 pub use {
-    area::{Area, AreaState},
+    area::{Area, AreaPlacement, AreaState},
     collapsing_header::{CollapsingHeader, CollapsingResponse},
     combo_box::*,
-    frame::Frame,
+    command_palette::{CommandPalette, CommandPaletteResponse},
+    dock::{DockArea, DockNode, DockSplit, DockState, DockUi, DockZone},
+    form_layout::{FormLayout, FormUi},
+    frame::{Frame, PressFeedback, StrokeSides},
     modal::{Modal, ModalResponse},
     old_popup::*,
-    panel::{CentralPanel, SidePanel, TopBottomPanel},
+    panel::{CentralPanel, PanelBackground, SidePanel, TopBottomPanel},
     popup::*,
-    resize::Resize,
+    resize::{Resize, ResizeOutput},
     scene::Scene,
     scroll_area::ScrollArea,
     sides::Sides,
+    toasts::{Toast, Toasts},
     tooltip::*,
-    window::Window,
+    window::{TitleBarSide, Window, WindowInfo, WindowState, WindowTabGroup, WindowTabGroups},
 };
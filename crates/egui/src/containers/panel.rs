@@ -17,15 +17,140 @@
 
 use emath::GuiRounding as _;
 
+use epaint::{CornerRadius, Mesh, RectShape, Shape, TextureId};
+
 use crate::{
-    lerp, vec2, Align, Context, CursorIcon, Frame, Id, InnerResponse, LayerId, Layout, NumExt,
-    Rangef, Rect, Sense, Stroke, Ui, UiBuilder, UiKind, UiStackInfo, Vec2,
+    lerp, pos2, vec2, Align, Color32, Context, CursorIcon, Direction, Frame, Id, InnerResponse,
+    LayerId, Layout, NumExt, Order, Rangef, Rect, Sense, Stroke, Ui, UiBuilder, UiKind,
+    UiStackInfo, Vec2,
 };
 
 fn animate_expansion(ctx: &Context, id: Id, is_expanded: bool) -> f32 {
     ctx.animate_bool_responsive(id, is_expanded)
 }
 
+/// A background for a panel that is more than the flat [`Frame::fill`],
+/// e.g. a themed app background behind the [`CentralPanel`].
+///
+/// The background is painted over the fill of the panel [`Frame`], within its stroke,
+/// and behind all the contents of the panel.
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// use egui::{Color32, Direction, PanelBackground};
+/// egui::CentralPanel::default()
+///     .background(PanelBackground::linear_gradient(
+///         Direction::TopDown,
+///         Color32::from_rgb(20, 30, 60),
+///         Color32::from_rgb(60, 20, 40),
+///     ))
+///     .show(ctx, |ui| {
+///         ui.label("Hello World!");
+///     });
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PanelBackground {
+    /// Blend linearly from one color to another.
+    LinearGradient {
+        /// The direction of the gradient, e.g. [`Direction::TopDown`] goes from `from` at the top to `to` at the bottom.
+        direction: Direction,
+        from: Color32,
+        to: Color32,
+    },
+
+    /// Stretch an image over the whole panel.
+    Image {
+        texture_id: TextureId,
+
+        /// The part of the texture to show, in normalized texture coordinates.
+        uv: Rect,
+
+        /// Multiplied with the image, e.g. to darken it. Use [`Color32::WHITE`] for no tint.
+        tint: Color32,
+    },
+}
+
+impl PanelBackground {
+    /// A linear gradient from `from` to `to` in the given direction.
+    pub fn linear_gradient(direction: Direction, from: Color32, to: Color32) -> Self {
+        Self::LinearGradient {
+            direction,
+            from,
+            to,
+        }
+    }
+
+    /// The whole texture, stretched over the panel.
+    ///
+    /// Load the texture with e.g. [`Context::load_texture`].
+    pub fn image(texture_id: impl Into<TextureId>) -> Self {
+        Self::Image {
+            texture_id: texture_id.into(),
+            uv: Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
+            tint: Color32::WHITE,
+        }
+    }
+
+    /// Paint the background covering `rect`.
+    ///
+    /// The corners of an image are rounded with `corner_radius`. A gradient is always rectangular.
+    pub fn paint(&self, rect: Rect, corner_radius: CornerRadius) -> Shape {
+        match *self {
+            Self::LinearGradient {
+                direction,
+                from,
+                to,
+            } => {
+                // Colors of the top-left, top-right, bottom-left and bottom-right corners:
+                let [left_top, right_top, left_bottom, right_bottom] = match direction {
+                    Direction::LeftToRight => [from, to, from, to],
+                    Direction::RightToLeft => [to, from, to, from],
+                    Direction::TopDown => [from, from, to, to],
+                    Direction::BottomUp => [to, to, from, from],
+                };
+                let mut mesh = Mesh::default();
+                mesh.colored_vertex(rect.left_top(), left_top);
+                mesh.colored_vertex(rect.right_top(), right_top);
+                mesh.colored_vertex(rect.left_bottom(), left_bottom);
+                mesh.colored_vertex(rect.right_bottom(), right_bottom);
+                mesh.add_triangle(0, 1, 2);
+                mesh.add_triangle(2, 1, 3);
+                Shape::mesh(mesh)
+            }
+            Self::Image {
+                texture_id,
+                uv,
+                tint,
+            } => RectShape::filled(rect, corner_radius, tint)
+                .with_texture(texture_id, uv)
+                .into(),
+        }
+    }
+}
+
+/// Add the contents of a panel, with its `background` (if any) behind them.
+///
+/// Must be called from within the `frame` of the panel.
+fn add_panel_contents<R>(
+    ui: &mut Ui,
+    frame: &Frame,
+    background: Option<PanelBackground>,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> R {
+    let Some(background) = background else {
+        return add_contents(ui);
+    };
+    let where_to_put_background = ui.painter().add(Shape::Noop);
+    let inner = add_contents(ui);
+    let fill_rect = frame.fill_rect(ui.min_rect());
+    ui.painter().set(
+        where_to_put_background,
+        background.paint(fill_rect, frame.fill_corner_radius()),
+    );
+    inner
+}
+
 /// State regarding panels.
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -87,6 +212,43 @@ impl Side {
     }
 }
 
+/// Which edge of a [`SidePanel`] its resize handle is on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HandleEdge {
+    /// The edge facing the rest of the [`Ui`], e.g. the right edge of a left panel.
+    #[default]
+    Inner,
+
+    /// The edge against the side of the [`Ui`], e.g. the left edge of a left panel.
+    ///
+    /// Dragging it still moves the inner edge, since the outer edge is pinned to the side of the [`Ui`].
+    Outer,
+}
+
+/// How the resize handle of a [`SidePanel`] looks, and where it is.
+///
+/// See [`SidePanel::resize_handle`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct HandleStyle {
+    /// Which edge of the panel can be dragged.
+    pub edge: HandleEdge,
+
+    /// Width of the area around the edge that can be grabbed, independent of the drawn line.
+    ///
+    /// If `None`, twice [`crate::style::Interaction::resize_grab_radius_side`] is used.
+    pub grab_width: Option<f32>,
+
+    /// Width of the drawn divider line.
+    ///
+    /// If `None`, the stroke widths of [`crate::style::Widgets`] are used.
+    pub line_width: Option<f32>,
+
+    /// Color of the divider line while hovered or dragged.
+    ///
+    /// If `None`, the `fg_stroke` colors of [`crate::style::Widgets`] are used.
+    pub hover_color: Option<Color32>,
+}
+
 /// A panel that covers the entire left or right side of a [`Ui`] or screen.
 ///
 /// The order in which you add panels matter!
@@ -110,10 +272,16 @@ pub struct SidePanel {
     side: Side,
     id: Id,
     frame: Option<Frame>,
+    background: Option<PanelBackground>,
     resizable: bool,
     show_separator_line: bool,
+    resize_handle: HandleStyle,
     default_width: f32,
     width_range: Rangef,
+    animated: bool,
+
+    /// How far the panel is expanded, while it is animating in or out.
+    animation_progress: Option<f32>,
 }
 
 impl SidePanel {
@@ -133,10 +301,14 @@ impl SidePanel {
             side,
             id: id.into(),
             frame: None,
+            background: None,
             resizable: true,
             show_separator_line: true,
+            resize_handle: HandleStyle::default(),
             default_width: 200.0,
             width_range: Rangef::new(96.0, f32::INFINITY),
+            animated: false,
+            animation_progress: None,
         }
     }
 
@@ -166,6 +338,47 @@ impl SidePanel {
         self
     }
 
+    /// Paint the contents while [`Self::show_animated`] animates the panel in and out?
+    ///
+    /// If `true`, the panel slides in from its side of the screen, clipped to its
+    /// animated width, while the rest of the [`Ui`] smoothly reflows around it.
+    /// If `false`, the panel is empty until it is fully expanded.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
+    /// Customize the resize handle: which edge it is on, how wide the grab zone is,
+    /// and how the divider line looks.
+    ///
+    /// For an invisible grab zone, combine this with [`Self::show_separator_line`] set to `false`
+    /// and a `line_width` of zero.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// use egui::panel::{HandleEdge, HandleStyle};
+    ///
+    /// egui::SidePanel::right("inspector")
+    ///     .resize_handle(HandleStyle {
+    ///         edge: HandleEdge::Inner,
+    ///         grab_width: Some(16.0),
+    ///         line_width: Some(2.0),
+    ///         hover_color: Some(egui::Color32::LIGHT_BLUE),
+    ///     })
+    ///     .show(ctx, |ui| {
+    ///         ui.label("Hello World!");
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn resize_handle(mut self, resize_handle: HandleStyle) -> Self {
+        self.resize_handle = resize_handle;
+        self
+    }
+
     /// The initial wrapping width of the [`SidePanel`], including margins.
     #[inline]
     pub fn default_width(mut self, default_width: f32) -> Self {
@@ -214,6 +427,15 @@ impl SidePanel {
         self.frame = Some(frame);
         self
     }
+
+    /// Paint a gradient or image behind the contents, covering the whole panel.
+    ///
+    /// See [`PanelBackground`].
+    #[inline]
+    pub fn background(mut self, background: PanelBackground) -> Self {
+        self.background = Some(background);
+        self
+    }
 }
 
 impl SidePanel {
@@ -236,11 +458,21 @@ impl SidePanel {
             side,
             id,
             frame,
+            background,
             resizable,
             show_separator_line,
+            resize_handle,
             default_width,
             width_range,
+            animated: _,
+            animation_progress,
         } = self;
+        let resizable = resizable && animation_progress.is_none();
+
+        let handle_side = match resize_handle.edge {
+            HandleEdge::Inner => side.opposite(),
+            HandleEdge::Outer => side,
+        };
 
         let available_rect = ui.available_rect_before_wrap();
         let mut panel_rect = available_rect;
@@ -265,16 +497,41 @@ impl SidePanel {
 
                 if is_resizing {
                     if let Some(pointer) = resize_response.interact_pointer_pos() {
-                        width = (pointer.x - side.side_x(panel_rect)).abs();
+                        width = match resize_handle.edge {
+                            HandleEdge::Inner => (pointer.x - side.side_x(panel_rect)).abs(),
+                            HandleEdge::Outer => {
+                                // The outer edge can't move, so grow by as much as it is dragged outwards:
+                                let drag_start_width_id = resize_id.with("drag_start_width");
+                                let start_width = ui.data_mut(|d| {
+                                    *d.get_temp_mut_or_insert_with(drag_start_width_id, || width)
+                                });
+                                let press_origin =
+                                    ui.input(|i| i.pointer.press_origin()).unwrap_or(pointer);
+                                start_width + (pointer.x - press_origin.x) * side.sign()
+                            }
+                        };
                         width = clamp_to_range(width, width_range).at_most(available_rect.width());
                         side.set_rect_width(&mut panel_rect, width);
                     }
+                } else {
+                    ui.data_mut(|d| d.remove::<f32>(resize_id.with("drag_start_width")));
                 }
             }
         }
 
         panel_rect = panel_rect.round_ui();
 
+        // While animating, lay out the panel at its full width, but slid partially out of view:
+        let visible_rect = animation_progress.map(|how_expanded| {
+            let mut visible_rect = panel_rect;
+            side.set_rect_width(&mut visible_rect, (how_expanded * width).round_ui());
+            panel_rect = panel_rect.translate(vec2(
+                side.sign() * (panel_rect.width() - visible_rect.width()),
+                0.0,
+            ));
+            visible_rect
+        });
+
         let mut panel_ui = ui.new_child(
             UiBuilder::new()
                 .id_salt(id)
@@ -286,15 +543,23 @@ impl SidePanel {
                 .layout(Layout::top_down(Align::Min)),
         );
         panel_ui.expand_to_include_rect(panel_rect);
-        panel_ui.set_clip_rect(panel_rect); // If we overflow, don't do so visibly (#4475)
+        // If we overflow, don't do so visibly (#4475):
+        panel_ui.set_clip_rect(visible_rect.unwrap_or(panel_rect));
 
         let frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
         let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_height(ui.max_rect().height()); // Make sure the frame fills the full height
-            ui.set_min_width((width_range.min - frame.inner_margin.sum().x).at_least(0.0));
-            add_contents(ui)
+
+            // Not wider than the panel was clamped to, e.g. on a screen narrower than the minimum:
+            let min_width = width_range.min.at_most(width);
+            ui.set_min_width((min_width - frame.inner_margin.sum().x).at_least(0.0));
+            add_panel_contents(ui, &frame, background, add_contents)
         });
 
+        let mut inner_response = inner_response;
+        if let Some(visible_rect) = visible_rect {
+            inner_response.response.rect = visible_rect;
+        }
         let rect = inner_response.response.rect;
 
         {
@@ -315,9 +580,13 @@ impl SidePanel {
             // Now we do the actual resize interaction, on top of all the contents.
             // Otherwise its input could be eaten by the contents, e.g. a
             // `ScrollArea` on either side of the panel boundary.
-            let resize_x = side.opposite().side_x(panel_rect);
+            let resize_x = handle_side.side_x(panel_rect);
+            let grab_radius = resize_handle.grab_width.map_or(
+                ui.style().interaction.resize_grab_radius_side,
+                |grab_width| 0.5 * grab_width,
+            );
             let resize_rect = Rect::from_x_y_ranges(resize_x..=resize_x, panel_rect.y_range())
-                .expand2(vec2(ui.style().interaction.resize_grab_radius_side, 0.0));
+                .expand2(vec2(grab_radius, 0.0));
             let resize_response = ui.interact(resize_rect, resize_id, Sense::drag());
             resize_hover = resize_response.hovered();
             is_resizing = resize_response.dragged();
@@ -340,10 +609,12 @@ impl SidePanel {
             ui.ctx().set_cursor_icon(cursor_icon);
         }
 
-        PanelState { rect }.store(ui.ctx(), id);
+        if animation_progress.is_none() {
+            PanelState { rect }.store(ui.ctx(), id);
+        }
 
         {
-            let stroke = if is_resizing {
+            let mut stroke = if is_resizing {
                 ui.style().visuals.widgets.active.fg_stroke // highly visible
             } else if resize_hover {
                 ui.style().visuals.widgets.hovered.fg_stroke // highly visible
@@ -353,11 +624,19 @@ impl SidePanel {
             } else {
                 Stroke::NONE
             };
+            if let Some(line_width) = resize_handle.line_width {
+                stroke.width = line_width;
+            }
+            if let Some(hover_color) = resize_handle.hover_color {
+                if is_resizing || resize_hover {
+                    stroke.color = hover_color;
+                }
+            }
             // TODO(emilk): draw line on top of all panels in this ui when https://github.com/emilk/egui/issues/1516 is done
-            let resize_x = side.opposite().side_x(rect);
+            let resize_x = handle_side.side_x(rect);
 
             // Make sure the line is on the inside of the panel:
-            let resize_x = resize_x + 0.5 * side.sign() * stroke.width;
+            let resize_x = resize_x - 0.5 * handle_side.sign() * stroke.width;
             ui.painter().vline(resize_x, panel_rect.y_range(), stroke);
         }
 
@@ -406,6 +685,9 @@ impl SidePanel {
 
     /// Show the panel if `is_expanded` is `true`,
     /// otherwise don't show it, but with a nice animation between collapsed and expanded.
+    ///
+    /// Returns `None` while the panel is hidden or its contents are not shown.
+    /// With [`Self::animated`], the contents are also shown while animating.
     pub fn show_animated<R>(
         self,
         ctx: &Context,
@@ -416,10 +698,17 @@ impl SidePanel {
 
         if 0.0 == how_expanded {
             None
+        } else if how_expanded < 1.0 && self.animated {
+            // Show the real panel, sliding in or out:
+            Some(
+                Self {
+                    animation_progress: Some(how_expanded),
+                    ..self
+                }
+                .show(ctx, add_contents),
+            )
         } else if how_expanded < 1.0 {
             // Show a fake panel in this in-between animation state:
-            // TODO(emilk): move the panel out-of-screen instead of changing its width.
-            // Then we can actually paint it as it animates.
             let expanded_width = PanelState::load(ctx, self.id)
                 .map_or(self.default_width, |state| state.rect.width());
             let fake_width = how_expanded * expanded_width;
@@ -439,6 +728,9 @@ impl SidePanel {
 
     /// Show the panel if `is_expanded` is `true`,
     /// otherwise don't show it, but with a nice animation between collapsed and expanded.
+    ///
+    /// Returns `None` while the panel is hidden or its contents are not shown.
+    /// With [`Self::animated`], the contents are also shown while animating.
     pub fn show_animated_inside<R>(
         self,
         ui: &mut Ui,
@@ -449,10 +741,17 @@ impl SidePanel {
 
         if 0.0 == how_expanded {
             None
+        } else if how_expanded < 1.0 && self.animated {
+            // Show the real panel, sliding in or out:
+            Some(
+                Self {
+                    animation_progress: Some(how_expanded),
+                    ..self
+                }
+                .show_inside(ui, add_contents),
+            )
         } else if how_expanded < 1.0 {
             // Show a fake panel in this in-between animation state:
-            // TODO(emilk): move the panel out-of-screen instead of changing its width.
-            // Then we can actually paint it as it animates.
             let expanded_width = PanelState::load(ui.ctx(), self.id)
                 .map_or(self.default_width, |state| state.rect.width());
             let fake_width = how_expanded * expanded_width;
@@ -597,10 +896,16 @@ pub struct TopBottomPanel {
     side: TopBottomSide,
     id: Id,
     frame: Option<Frame>,
+    background: Option<PanelBackground>,
     resizable: bool,
     show_separator_line: bool,
     default_height: Option<f32>,
     height_range: Rangef,
+    floating: bool,
+    animated: bool,
+
+    /// How far the panel is expanded, while it is animating in or out.
+    animation_progress: Option<f32>,
 }
 
 impl TopBottomPanel {
@@ -620,10 +925,14 @@ impl TopBottomPanel {
             side,
             id: id.into(),
             frame: None,
+            background: None,
             resizable: false,
             show_separator_line: true,
             default_height: None,
             height_range: Rangef::new(20.0, f32::INFINITY),
+            floating: false,
+            animated: false,
+            animation_progress: None,
         }
     }
 
@@ -704,6 +1013,43 @@ impl TopBottomPanel {
         self.frame = Some(frame);
         self
     }
+
+    /// Paint a gradient or image behind the contents, covering the whole panel.
+    ///
+    /// See [`PanelBackground`].
+    #[inline]
+    pub fn background(mut self, background: PanelBackground) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    /// Draw the panel on top of the other contents instead of reserving space for it.
+    ///
+    /// A floating panel is painted in a [`Order::Foreground`] layer at the top/bottom edge,
+    /// and does not shrink the space available to e.g. the [`CentralPanel`].
+    /// This is useful for transient toolbars and media controls.
+    ///
+    /// Use [`Self::frame`] with a [`Frame::shadow`] to cast a shadow over the contents below.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn floating(mut self, floating: bool) -> Self {
+        self.floating = floating;
+        self
+    }
+
+    /// Paint the contents while [`Self::show_animated`] animates the panel in and out?
+    ///
+    /// If `true`, the panel slides in from its side of the screen, clipped to its
+    /// animated height, while the rest of the [`Ui`] smoothly reflows around it.
+    /// If `false`, the panel is empty until it is fully expanded.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
 }
 
 impl TopBottomPanel {
@@ -726,11 +1072,16 @@ impl TopBottomPanel {
             side,
             id,
             frame,
+            background,
             resizable,
             show_separator_line,
             default_height,
             height_range,
+            floating,
+            animated: _,
+            animation_progress,
         } = self;
+        let resizable = resizable && animation_progress.is_none();
 
         let frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
 
@@ -772,28 +1123,54 @@ impl TopBottomPanel {
 
         panel_rect = panel_rect.round_ui();
 
-        let mut panel_ui = ui.new_child(
-            UiBuilder::new()
-                .id_salt(id)
-                .ui_stack_info(UiStackInfo::new(match side {
-                    TopBottomSide::Top => UiKind::TopPanel,
-                    TopBottomSide::Bottom => UiKind::BottomPanel,
-                }))
-                .max_rect(panel_rect)
-                .layout(Layout::top_down(Align::Min)),
-        );
+        // While animating, lay out the panel at its full height, but slid partially out of view:
+        let visible_rect = animation_progress.map(|how_expanded| {
+            let mut visible_rect = panel_rect;
+            side.set_rect_height(&mut visible_rect, (how_expanded * height).round_ui());
+            panel_rect = panel_rect.translate(vec2(
+                0.0,
+                side.sign() * (panel_rect.height() - visible_rect.height()),
+            ));
+            visible_rect
+        });
+
+        let mut panel_builder = UiBuilder::new()
+            .id_salt(id)
+            .ui_stack_info(UiStackInfo::new(match side {
+                TopBottomSide::Top => UiKind::TopPanel,
+                TopBottomSide::Bottom => UiKind::BottomPanel,
+            }))
+            .max_rect(panel_rect)
+            .layout(Layout::top_down(Align::Min));
+        if floating {
+            panel_builder = panel_builder.layer_id(LayerId::new(Order::Foreground, id));
+        }
+        let mut panel_ui = ui.new_child(panel_builder);
         panel_ui.expand_to_include_rect(panel_rect);
-        panel_ui.set_clip_rect(panel_rect); // If we overflow, don't do so visibly (#4475)
+        if let Some(visible_rect) = visible_rect {
+            panel_ui.set_clip_rect(visible_rect.intersect(ui.clip_rect()));
+        } else if floating {
+            // Leave room for the shadow, and block the pointer from reaching what is behind us:
+            panel_ui.set_clip_rect((panel_rect + frame.shadow.margin()).intersect(ui.clip_rect()));
+            panel_ui.interact(panel_rect, id.with("__floating"), Sense::hover());
+        } else {
+            panel_ui.set_clip_rect(panel_rect); // If we overflow, don't do so visibly (#4475)
+        }
 
         let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_width(ui.max_rect().width()); // Make the frame fill full width
-            ui.set_min_height((height_range.min - frame.inner_margin.sum().y).at_least(0.0));
-            add_contents(ui)
+            let min_height = height_range.min.at_most(height);
+            ui.set_min_height((min_height - frame.inner_margin.sum().y).at_least(0.0));
+            add_panel_contents(ui, &frame, background, add_contents)
         });
 
+        let mut inner_response = inner_response;
+        if let Some(visible_rect) = visible_rect {
+            inner_response.response.rect = visible_rect;
+        }
         let rect = inner_response.response.rect;
 
-        {
+        if !floating {
             let mut cursor = ui.cursor();
             match side {
                 TopBottomSide::Top => {
@@ -804,8 +1181,16 @@ impl TopBottomPanel {
                 }
             }
             ui.set_cursor(cursor);
+            ui.expand_to_include_rect(rect);
         }
-        ui.expand_to_include_rect(rect);
+
+        // A floating panel does its interaction and painting in its own layer:
+        let ui: &Ui = if floating {
+            panel_ui.set_clip_rect(ui.clip_rect()); // Don't clip the resize handle
+            &panel_ui
+        } else {
+            ui
+        };
 
         if resizable {
             // Now we do the actual resize interaction, on top of all the contents.
@@ -837,7 +1222,9 @@ impl TopBottomPanel {
             ui.ctx().set_cursor_icon(cursor_icon);
         }
 
-        PanelState { rect }.store(ui.ctx(), id);
+        if animation_progress.is_none() {
+            PanelState { rect }.store(ui.ctx(), id);
+        }
 
         {
             let stroke = if is_resizing {
@@ -878,6 +1265,7 @@ impl TopBottomPanel {
     ) -> InnerResponse<R> {
         let available_rect = ctx.available_rect();
         let side = self.side;
+        let floating = self.floating;
 
         let mut panel_ui = Ui::new(
             ctx.clone(),
@@ -891,6 +1279,11 @@ impl TopBottomPanel {
         let inner_response = self.show_inside_dyn(&mut panel_ui, add_contents);
         let rect = inner_response.response.rect;
 
+        if floating {
+            // Leave the space to the other panels.
+            return inner_response;
+        }
+
         match side {
             TopBottomSide::Top => {
                 ctx.pass_state_mut(|state| {
@@ -909,6 +1302,9 @@ impl TopBottomPanel {
 
     /// Show the panel if `is_expanded` is `true`,
     /// otherwise don't show it, but with a nice animation between collapsed and expanded.
+    ///
+    /// Returns `None` while the panel is hidden or its contents are not shown.
+    /// With [`Self::animated`], the contents are also shown while animating.
     pub fn show_animated<R>(
         self,
         ctx: &Context,
@@ -919,10 +1315,17 @@ impl TopBottomPanel {
 
         if 0.0 == how_expanded {
             None
+        } else if how_expanded < 1.0 && self.animated {
+            // Show the real panel, sliding in or out:
+            Some(
+                Self {
+                    animation_progress: Some(how_expanded),
+                    ..self
+                }
+                .show(ctx, add_contents),
+            )
         } else if how_expanded < 1.0 {
             // Show a fake panel in this in-between animation state:
-            // TODO(emilk): move the panel out-of-screen instead of changing its height.
-            // Then we can actually paint it as it animates.
             let expanded_height = PanelState::load(ctx, self.id)
                 .map(|state| state.rect.height())
                 .or(self.default_height)
@@ -944,6 +1347,9 @@ impl TopBottomPanel {
 
     /// Show the panel if `is_expanded` is `true`,
     /// otherwise don't show it, but with a nice animation between collapsed and expanded.
+    ///
+    /// Returns `None` while the panel is hidden or its contents are not shown.
+    /// With [`Self::animated`], the contents are also shown while animating.
     pub fn show_animated_inside<R>(
         self,
         ui: &mut Ui,
@@ -954,10 +1360,17 @@ impl TopBottomPanel {
 
         if 0.0 == how_expanded {
             None
+        } else if how_expanded < 1.0 && self.animated {
+            // Show the real panel, sliding in or out:
+            Some(
+                Self {
+                    animation_progress: Some(how_expanded),
+                    ..self
+                }
+                .show_inside(ui, add_contents),
+            )
         } else if how_expanded < 1.0 {
             // Show a fake panel in this in-between animation state:
-            // TODO(emilk): move the panel out-of-screen instead of changing its height.
-            // Then we can actually paint it as it animates.
             let expanded_height = PanelState::load(ui.ctx(), self.id)
                 .map(|state| state.rect.height())
                 .or(self.default_height)
@@ -1082,6 +1495,7 @@ impl TopBottomPanel {
 #[derive(Default)]
 pub struct CentralPanel {
     frame: Option<Frame>,
+    background: Option<PanelBackground>,
 }
 
 impl CentralPanel {
@@ -1091,6 +1505,15 @@ impl CentralPanel {
         self.frame = Some(frame);
         self
     }
+
+    /// Paint a gradient or image behind the contents, covering the whole panel.
+    ///
+    /// See [`PanelBackground`].
+    #[inline]
+    pub fn background(mut self, background: PanelBackground) -> Self {
+        self.background = Some(background);
+        self
+    }
 }
 
 impl CentralPanel {
@@ -1109,7 +1532,7 @@ impl CentralPanel {
         ui: &mut Ui,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
     ) -> InnerResponse<R> {
-        let Self { frame } = self;
+        let Self { frame, background } = self;
 
         let panel_rect = ui.available_rect_before_wrap();
         let mut panel_ui = ui.new_child(
@@ -1123,7 +1546,7 @@ impl CentralPanel {
         let frame = frame.unwrap_or_else(|| Frame::central_panel(ui.style()));
         frame.show(&mut panel_ui, |ui| {
             ui.expand_to_include_rect(ui.max_rect()); // Expand frame to include it all
-            add_contents(ui)
+            add_panel_contents(ui, &frame, background, add_contents)
         })
     }
 
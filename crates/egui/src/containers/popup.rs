@@ -1,4 +1,4 @@
-use crate::containers::menu::{menu_style, MenuConfig, MenuState};
+use crate::containers::menu::{is_kept_open_this_pass, menu_style, MenuConfig, MenuState};
 use crate::style::StyleModifier;
 use crate::{
     Area, AreaState, Context, Frame, Id, InnerResponse, Key, LayerId, Layout, Order, Response,
@@ -104,6 +104,60 @@ impl From<bool> for SetOpenCommand {
     }
 }
 
+/// Is a popup open, and did it just open or close?
+///
+/// Compares whether the popup is open this frame with whether it was open last frame,
+/// so you can e.g. focus a text field exactly when a popup opens, or save when it closes.
+///
+/// Returned by [`Popup::show_with_status`]. For popups shown by other widgets,
+/// query it with [`Popup::id_status`] or [`crate::ComboBox::popup_status`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PopupStatus {
+    /// The popup is closed, and was closed last frame too.
+    #[default]
+    Closed,
+
+    /// The popup was opened this frame.
+    JustOpened,
+
+    /// The popup is open, and was open last frame too.
+    Open,
+
+    /// The popup was closed this frame.
+    JustClosed,
+}
+
+impl PopupStatus {
+    fn new(was_open: bool, is_open: bool) -> Self {
+        match (was_open, is_open) {
+            (false, false) => Self::Closed,
+            (false, true) => Self::JustOpened,
+            (true, true) => Self::Open,
+            (true, false) => Self::JustClosed,
+        }
+    }
+
+    /// Is the popup open after this frame?
+    pub fn is_open(self) -> bool {
+        matches!(self, Self::JustOpened | Self::Open)
+    }
+
+    /// Was the popup opened this frame?
+    pub fn just_opened(self) -> bool {
+        self == Self::JustOpened
+    }
+
+    /// Was the popup closed this frame?
+    pub fn just_closed(self) -> bool {
+        self == Self::JustClosed
+    }
+}
+
+/// Where a popup stores its [`PopupStatus`] of the last time it was shown.
+fn status_id(popup_id: Id) -> Id {
+    popup_id.with("status")
+}
+
 /// How do we determine if the popup should be open or closed
 enum OpenKind<'a> {
     /// Always open
@@ -117,6 +171,10 @@ enum OpenKind<'a> {
 
     /// Store the open state via [`crate::Memory`]
     Memory { set: Option<SetOpenCommand> },
+
+    /// Open while the trigger widget or the popup is hovered,
+    /// and for `close_delay` seconds after.
+    Hover { close_delay: f32 },
 }
 
 impl OpenKind<'_> {
@@ -127,10 +185,19 @@ impl OpenKind<'_> {
             OpenKind::Closed => false,
             OpenKind::Bool(open) => **open,
             OpenKind::Memory { .. } => ctx.memory(|mem| mem.is_popup_open(id)),
+            OpenKind::Hover { .. } => {
+                let closes_at = ctx.data(|d| d.get_temp::<f64>(hover_close_time_id(id)));
+                closes_at.is_some_and(|closes_at| ctx.input(|i| i.time) <= closes_at)
+            }
         }
     }
 }
 
+/// Where a hover popup stores the time at which it closes, unless hovered again.
+fn hover_close_time_id(popup_id: Id) -> Id {
+    popup_id.with("hover_closes_at")
+}
+
 /// Is the popup a popup, tooltip or menu?
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum PopupKind {
@@ -168,15 +235,25 @@ pub struct Popup<'a> {
     layer_id: LayerId,
     open_kind: OpenKind<'a>,
     close_behavior: PopupCloseBehavior,
+    modal: bool,
     info: Option<UiStackInfo>,
     kind: PopupKind,
 
     /// Gap between the anchor and the popup
     gap: f32,
 
+    /// The widget we're shown for, if any. A popup stored in [`crate::Memory`]
+    /// is closed once this widget is no longer shown.
+    anchor_widget: Option<Id>,
+
     /// Used later depending on close behavior
     widget_clicked_elsewhere: bool,
 
+    /// Was the widget we're shown for hovered or clicked? Used by [`Self::open_on_hover`]
+    /// and [`Self::toggle_on_click`].
+    widget_hovered: bool,
+    widget_clicked: bool,
+
     /// Default width passed to the Area
     width: Option<f32>,
     sense: Sense,
@@ -194,15 +271,21 @@ impl<'a> Popup<'a> {
             anchor: anchor.into(),
             open_kind: OpenKind::Open,
             close_behavior: PopupCloseBehavior::default(),
+            modal: false,
             info: None,
             kind: PopupKind::Popup,
             layer_id,
             rect_align: RectAlign::BOTTOM_START,
             alternative_aligns: None,
             gap: 0.0,
+            anchor_widget: None,
             widget_clicked_elsewhere: false,
+            widget_hovered: false,
+            widget_clicked: false,
             width: None,
-            sense: Sense::click(),
+            // Clicks on the background shouldn't fall through to what's behind the popup,
+            // but the background shouldn't take keyboard focus from the widgets inside it either:
+            sense: Sense::CLICK,
             layout: Layout::default(),
             frame: None,
             style: StyleModifier::default(),
@@ -210,6 +293,9 @@ impl<'a> Popup<'a> {
     }
 
     /// Set the kind of the popup. Used for [`Area::kind`] and [`Area::order`].
+    ///
+    /// If the popup is opened from a layer of a higher [`Order`], e.g. a pinned [`crate::Window`],
+    /// it uses that order instead and is kept directly above that layer.
     #[inline]
     pub fn kind(mut self, kind: PopupKind) -> Self {
         self.kind = kind;
@@ -244,15 +330,21 @@ impl<'a> Popup<'a> {
     /// Show a popup relative to some widget.
     /// The popup will be always open.
     ///
+    /// If the open state is stored in [`crate::Memory`] (e.g. with [`Self::open_memory`]),
+    /// the popup is closed automatically once the widget is no longer shown.
+    ///
     /// See [`Self::menu`] and [`Self::context_menu`] for common use cases.
     pub fn from_response(response: &Response) -> Self {
         let mut popup = Self::new(
-            response.id.with("popup"),
+            Self::default_response_id(response),
             response.ctx.clone(),
             response,
             response.layer_id,
         );
+        popup.anchor_widget = Some(response.id);
         popup.widget_clicked_elsewhere = response.clicked_elsewhere();
+        popup.widget_hovered = response.hovered();
+        popup.widget_clicked = response.clicked();
         popup
     }
 
@@ -308,6 +400,31 @@ impl<'a> Popup<'a> {
         self
     }
 
+    /// Open the popup while the widget (see [`Self::from_response`]) or the popup itself is hovered.
+    ///
+    /// After the pointer leaves both, the popup stays open for a grace period of `close_delay` seconds,
+    /// so it doesn't flicker closed when the pointer briefly leaves the widget,
+    /// e.g. on the way to the popup.
+    ///
+    /// The open state is stored in [`crate::Memory::data`], not in the popup state of [`crate::Memory`],
+    /// so this doesn't close other popups.
+    #[inline]
+    pub fn open_on_hover(mut self, close_delay: f32) -> Self {
+        self.open_kind = OpenKind::Hover { close_delay };
+        self
+    }
+
+    /// Open the popup when the widget (see [`Self::from_response`]) is clicked,
+    /// and keep it open until the widget is clicked again or there is a click outside the popup.
+    ///
+    /// This stores the open state via [`crate::Memory`], like [`Self::menu`].
+    #[inline]
+    pub fn toggle_on_click(self) -> Self {
+        let clicked = self.widget_clicked;
+        self.open_memory(clicked.then_some(SetOpenCommand::Toggle))
+            .close_behavior(PopupCloseBehavior::CloseOnClickOutside)
+    }
+
     /// Set the close behavior of the popup.
     ///
     /// This will do nothing if [`Popup::open`] was called.
@@ -317,6 +434,20 @@ impl<'a> Popup<'a> {
         self
     }
 
+    /// Make the popup modal while it is open, like a [`crate::Modal`] but without the backdrop.
+    ///
+    /// Widgets behind the popup can't be interacted with,
+    /// and the keyboard focus is trapped inside the popup:
+    /// Tab and Shift+Tab wrap around among the widgets in it (and its sub-menus),
+    /// and a widget behind the popup loses the focus when it opens.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn modal(mut self, modal: bool) -> Self {
+        self.modal = modal;
+        self
+    }
+
     /// Show the popup relative to the pointer.
     #[inline]
     pub fn at_pointer(mut self) -> Self {
@@ -360,7 +491,10 @@ impl<'a> Popup<'a> {
         self
     }
 
-    /// Set the sense of the popup.
+    /// Set the sense of the background of the popup.
+    ///
+    /// Default: [`Sense::CLICK`], without [`Sense::FOCUSABLE`], so that pressing Tab
+    /// moves the keyboard focus to the widgets in the popup, rather than to the popup itself.
     #[inline]
     pub fn sense(mut self, sense: Sense) -> Self {
         self.sense = sense;
@@ -437,12 +571,7 @@ impl<'a> Popup<'a> {
 
     /// Is the popup open?
     pub fn is_open(&self) -> bool {
-        match &self.open_kind {
-            OpenKind::Open => true,
-            OpenKind::Closed => false,
-            OpenKind::Bool(open) => **open,
-            OpenKind::Memory { .. } => self.ctx.memory(|mem| mem.is_popup_open(self.id)),
-        }
+        self.open_kind.is_open(self.id, &self.ctx)
     }
 
     /// Get the expected size of the popup.
@@ -485,6 +614,49 @@ impl<'a> Popup<'a> {
     /// Returns `None` if the popup is not open or anchor is `PopupAnchor::Pointer` and there is
     /// no pointer.
     pub fn show<R>(self, content: impl FnOnce(&mut Ui) -> R) -> Option<InnerResponse<R>> {
+        self.show_with_status(content).0
+    }
+
+    /// Like [`Self::show`], but also returns whether the popup just opened or closed this frame.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let response = ui.button("Search…");
+    /// let (inner, status) = egui::Popup::menu(&response).show_with_status(|ui| {
+    ///     ui.text_edit_singleline(&mut String::new())
+    /// });
+    /// if let Some(inner) = inner {
+    ///     if status.just_opened() {
+    ///         inner.inner.request_focus();
+    ///     }
+    /// }
+    /// # });
+    /// ```
+    pub fn show_with_status<R>(
+        self,
+        content: impl FnOnce(&mut Ui) -> R,
+    ) -> (Option<InnerResponse<R>>, PopupStatus) {
+        let id = self.id;
+        let ctx = self.ctx.clone();
+
+        let response = self.show_dyn(Box::new(content));
+
+        let is_open = response
+            .as_ref()
+            .is_some_and(|response| !response.response.should_close());
+        let status = ctx.data_mut(|d| {
+            let last = d.get_temp_mut_or_default::<PopupStatus>(status_id(id));
+            *last = PopupStatus::new(last.is_open(), is_open);
+            *last
+        });
+
+        (response, status)
+    }
+
+    fn show_dyn<'c, R>(
+        self,
+        content: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+    ) -> Option<InnerResponse<R>> {
         let best_align = self.get_best_align();
 
         let Popup {
@@ -493,13 +665,17 @@ impl<'a> Popup<'a> {
             anchor,
             open_kind,
             close_behavior,
+            modal,
             kind,
             info,
             layer_id,
             rect_align: _,
             alternative_aligns: _,
             gap,
+            anchor_widget,
             widget_clicked_elsewhere,
+            widget_hovered,
+            widget_clicked: _,
             width,
             sense,
             layout,
@@ -527,6 +703,29 @@ impl<'a> Popup<'a> {
                 }
                 None => {}
             });
+            if let Some(anchor_widget) = anchor_widget {
+                ctx.memory_mut(|mem| mem.anchor_popup(id, anchor_widget));
+            }
+        }
+
+        if let OpenKind::Hover { close_delay } = open_kind {
+            // The popup area is from last frame, but that's good enough:
+            let popup_hovered = hover_pos.is_some_and(|pos| {
+                ctx.memory(|mem| mem.area_rect(id))
+                    .is_some_and(|rect| rect.contains(pos))
+            });
+            let now = ctx.input(|i| i.time);
+            let close_time_id = hover_close_time_id(id);
+            if widget_hovered || popup_hovered {
+                ctx.data_mut(|d| d.insert_temp(close_time_id, now + close_delay as f64));
+            }
+            if let Some(closes_at) = ctx.data(|d| d.get_temp::<f64>(close_time_id)) {
+                if now <= closes_at {
+                    ctx.request_repaint_after_secs((closes_at - now) as f32);
+                } else {
+                    ctx.data_mut(|d| d.remove::<f64>(close_time_id));
+                }
+            }
         }
 
         if !open_kind.is_open(id, &ctx) {
@@ -547,8 +746,16 @@ impl<'a> Popup<'a> {
 
         let (pivot, anchor) = best_align.pivot_pos(&anchor_rect, gap);
 
+        // Popups stay above the layer they were opened from, even if that is e.g. a pinned window
+        // in the same order as the popup. Then we become a sublayer of it (or of its parent),
+        // so no other window of that order can come between us and our parent.
+        let parent_layer = ctx
+            .memory(|mem| mem.areas().parent_layer(layer_id))
+            .unwrap_or(layer_id);
+        let order = kind.order().max(parent_layer.order);
+
         let mut area = Area::new(id)
-            .order(kind.order())
+            .order(order)
             .pivot(pivot)
             .fixed_pos(anchor)
             .sense(sense)
@@ -564,6 +771,14 @@ impl<'a> Popup<'a> {
             area = area.default_width(width);
         }
 
+        if order == parent_layer.order && parent_layer != area.layer() {
+            ctx.set_sublayer(parent_layer, area.layer());
+        }
+
+        if modal {
+            ctx.memory_mut(|mem| mem.set_modal_layer(area.layer()));
+        }
+
         let frame = frame.unwrap_or_else(|| Frame::popup(&ctx.style()));
 
         let mut response = area.show(&ctx, |ui| {
@@ -571,6 +786,7 @@ impl<'a> Popup<'a> {
             frame.show(ui, content).inner
         });
 
+        let widget_clicked_elsewhere = widget_clicked_elsewhere && !is_kept_open_this_pass(&ctx);
         let closed_by_click = match close_behavior {
             PopupCloseBehavior::CloseOnClick => widget_clicked_elsewhere,
             PopupCloseBehavior::CloseOnClickOutside => {
@@ -602,8 +818,71 @@ impl<'a> Popup<'a> {
                     ctx.memory_mut(|mem| mem.close_popup());
                 }
             }
+            OpenKind::Hover { .. } => {
+                if should_close {
+                    ctx.data_mut(|d| d.remove::<f64>(hover_close_time_id(id)));
+                }
+            }
         }
 
         Some(response)
     }
+
+    /// The default id of a popup shown for a widget, e.g. with [`Self::menu`] or [`Self::from_response`].
+    ///
+    /// Use this with [`Self::is_id_open`], [`Self::close_id`] etc. to control such a popup from code.
+    pub fn default_response_id(response: &Response) -> Id {
+        response.id.with("popup")
+    }
+
+    /// Is the popup with the given id open?
+    ///
+    /// This only works for popups that store their state in [`crate::Memory`],
+    /// e.g. [`Self::menu`], [`Self::context_menu`] and [`Self::open_memory`].
+    pub fn is_id_open(ctx: &Context, popup_id: Id) -> bool {
+        ctx.memory(|mem| mem.is_popup_open(popup_id))
+    }
+
+    /// Did the popup with the given id just open or close?
+    ///
+    /// This is the [`PopupStatus`] of the last time the popup was shown,
+    /// so call this after showing it to get the status of this frame,
+    /// e.g. with [`Self::default_response_id`] after showing a [`Self::menu`].
+    pub fn id_status(ctx: &Context, popup_id: Id) -> PopupStatus {
+        ctx.data(|d| d.get_temp(status_id(popup_id)))
+            .unwrap_or_default()
+    }
+
+    /// Is any popup open?
+    ///
+    /// Like [`Self::is_id_open`], this only knows about popups that store their state in [`crate::Memory`].
+    pub fn is_any_open(ctx: &Context) -> bool {
+        ctx.memory(|mem| mem.any_popup_open())
+    }
+
+    /// Open the popup with the given id, and close any other.
+    pub fn open_id(ctx: &Context, popup_id: Id) {
+        ctx.memory_mut(|mem| mem.open_popup(popup_id));
+    }
+
+    /// Toggle the popup with the given id between open and closed.
+    pub fn toggle_id(ctx: &Context, popup_id: Id) {
+        ctx.memory_mut(|mem| mem.toggle_popup(popup_id));
+    }
+
+    /// Close whatever popup is open, if any.
+    pub fn close_all(ctx: &Context) {
+        ctx.memory_mut(|mem| mem.close_popup());
+    }
+
+    /// Close the popup with the given id, if it is open.
+    ///
+    /// Other popups are left alone.
+    pub fn close_id(ctx: &Context, popup_id: Id) {
+        ctx.memory_mut(|mem| {
+            if mem.is_popup_open(popup_id) {
+                mem.close_popup();
+            }
+        });
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{
-    pos2, vec2, Align2, Color32, Context, CursorIcon, Id, NumExt, Rect, Response, Sense, Shape, Ui,
-    UiBuilder, UiKind, UiStackInfo, Vec2, Vec2b,
+    panel::Side, pos2, vec2, Align2, Color32, Context, CursorIcon, Id, Modifiers, NumExt, Pos2,
+    Rect, Response, Sense, Shape, Ui, UiBuilder, UiKind, UiStackInfo, Vec2, Vec2b,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -13,10 +13,26 @@ pub(crate) struct State {
     pub(crate) desired_size: Vec2,
 
     /// Actual size of content last frame
-    last_content_size: Vec2,
+    pub(crate) last_content_size: Vec2,
 
     /// Externally requested size (e.g. by Window) for the next frame
     pub(crate) requested_size: Option<Vec2>,
+
+    /// Has the user ever resized this?
+    ///
+    /// Until then, [`Resize::auto_sized_initially`] sizes it to fit the contents.
+    #[cfg_attr(feature = "serde", serde(default))]
+    user_resized: bool,
+
+    /// Has the region been stowed away to its edge?
+    ///
+    /// See [`Resize::collapsible_to_edge`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    collapsed: bool,
+
+    /// The size the region had when it was last open, restored when it is reopened.
+    #[cfg_attr(feature = "serde", serde(default))]
+    last_open_size: Option<Vec2>,
 }
 
 impl State {
@@ -27,6 +43,49 @@ impl State {
     pub fn store(self, ctx: &Context, id: Id) {
         ctx.data_mut(|d| d.insert_persisted(id, self));
     }
+
+    /// Resize the region with the given id the next time it is shown,
+    /// as if the user had dragged it to that size.
+    pub(crate) fn request_size(ctx: &Context, id: Id, size: Vec2) {
+        let mut state = Self::load(ctx, id).unwrap_or(Self {
+            desired_size: size,
+            last_content_size: Vec2::ZERO,
+            requested_size: None,
+            user_resized: false,
+            collapsed: false,
+            last_open_size: None,
+        });
+        state.requested_size = Some(size);
+        state.store(ctx, id);
+    }
+}
+
+/// What [`Resize::show_with_output`] returns.
+pub struct ResizeOutput<R> {
+    /// What the user closure returned.
+    pub inner: R,
+
+    /// [`Id`] of the [`Resize`].
+    pub id: Id,
+
+    /// The size picked by the user, excluding the resize corner.
+    ///
+    /// This is the size that will be used next frame (unless the contents need more space).
+    pub size: Vec2,
+
+    /// Is the user currently dragging the resize corner?
+    pub is_resizing: bool,
+
+    /// Did the user just release the resize corner this frame?
+    ///
+    /// This is `true` once at the end of each resize gesture,
+    /// and is a good time to persist [`Self::size`] or re-layout expensive contents.
+    pub resize_stopped: bool,
+
+    /// Is the region collapsed to its edge?
+    ///
+    /// Only ever `true` when using [`Resize::collapsible_to_edge`].
+    pub is_collapsed: bool,
 }
 
 /// A region that can be resized by dragging the bottom right corner.
@@ -44,7 +103,19 @@ pub struct Resize {
 
     default_size: Vec2,
 
+    size_step: Option<Vec2>,
+    size_step_disable_modifiers: Modifiers,
+
+    auto_sized_initially: bool,
+
+    allow_shrink_below_content: bool,
+
     with_stroke: bool,
+
+    collapse_to_edge: Option<Side>,
+    collapse_threshold: f32,
+    collapse_handle_width: f32,
+    collapse_handle_fill: Option<Color32>,
 }
 
 impl Default for Resize {
@@ -56,7 +127,15 @@ impl Default for Resize {
             min_size: Vec2::splat(16.0),
             max_size: Vec2::splat(f32::INFINITY),
             default_size: vec2(320.0, 128.0), // TODO(emilk): preferred size of [`Resize`] area.
+            size_step: None,
+            size_step_disable_modifiers: Modifiers::NONE,
+            auto_sized_initially: false,
+            allow_shrink_below_content: false,
             with_stroke: true,
+            collapse_to_edge: None,
+            collapse_threshold: 24.0,
+            collapse_handle_width: 8.0,
+            collapse_handle_fill: None,
         }
     }
 }
@@ -114,7 +193,10 @@ impl Resize {
         self
     }
 
-    /// Won't shrink to smaller than this
+    /// Won't shrink to smaller than this.
+    ///
+    /// Unless [`Self::allow_shrink_below_content`] is set,
+    /// the region also won't shrink to smaller than its contents.
     #[inline]
     pub fn min_size(mut self, min_size: impl Into<Vec2>) -> Self {
         self.min_size = min_size.into();
@@ -172,6 +254,31 @@ impl Resize {
         self.resizable
     }
 
+    /// Round the size picked by the user to the nearest multiple of `step` while they drag.
+    ///
+    /// For instance `.size_step([8.0, 20.0])` for a grid of 8 points wide and 20 points tall cells.
+    /// Use zero for an axis that should not snap.
+    /// The snapped size is then clamped to [`Self::min_size`] and [`Self::max_size`],
+    /// so it may not be a multiple of the step at the limits.
+    ///
+    /// See also [`Self::size_step_disable_modifiers`].
+    #[inline]
+    pub fn size_step(mut self, step: impl Into<Vec2>) -> Self {
+        self.size_step = Some(step.into());
+        self
+    }
+
+    /// Holding down these modifiers while resizing temporarily disables [`Self::size_step`].
+    ///
+    /// For instance, `.size_step_disable_modifiers(Modifiers::ALT)`.
+    ///
+    /// Default: [`Modifiers::NONE`], i.e. snapping can't be disabled.
+    #[inline]
+    pub fn size_step_disable_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.size_step_disable_modifiers = modifiers;
+        self
+    }
+
     /// Not manually resizable, just takes the size of its contents.
     /// Text will not wrap, but will instead make your window width expand.
     pub fn auto_sized(self) -> Self {
@@ -180,6 +287,35 @@ impl Resize {
             .resizable(false)
     }
 
+    /// Size the region to fit its contents, until the user resizes it.
+    ///
+    /// The first frame, the contents are measured within [`Self::max_size`] (instead of [`Self::default_size`]),
+    /// and the region then follows the size of its contents.
+    /// As soon as the user drags the resize corner, the size they pick is used instead,
+    /// and remembered by [`Id`] from then on.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn auto_sized_initially(mut self, auto_sized_initially: bool) -> Self {
+        self.auto_sized_initially = auto_sized_initially;
+        self
+    }
+
+    /// Let the user resize the region to smaller than its contents.
+    ///
+    /// Normally the region grows to fit its contents, no matter how small the user drags it.
+    /// With this set, the size the user picks is kept,
+    /// and the contents are put in a [`crate::ScrollArea`] so the overflow can be scrolled to.
+    ///
+    /// The size is still kept within [`Self::min_size`] and [`Self::max_size`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn allow_shrink_below_content(mut self, allow_shrink_below_content: bool) -> Self {
+        self.allow_shrink_below_content = allow_shrink_below_content;
+        self
+    }
+
     #[inline]
     pub fn fixed_size(mut self, size: impl Into<Vec2>) -> Self {
         let size = size.into();
@@ -195,28 +331,121 @@ impl Resize {
         self.with_stroke = with_stroke;
         self
     }
+
+    /// Let the user stow the region away, like a drawer.
+    ///
+    /// Releasing the resize corner with the region narrower than [`Self::collapse_threshold`]
+    /// collapses it to a thin handle at the given edge.
+    /// Clicking the handle, or dragging it out past the threshold,
+    /// reopens the region to the size it had before it was collapsed.
+    ///
+    /// With [`Side::Right`] the region is placed against the right edge of the available space,
+    /// and the resize corner is in the bottom left.
+    ///
+    /// Both the collapsed state and the last open size are remembered by [`Id`].
+    #[inline]
+    pub fn collapsible_to_edge(mut self, side: Side) -> Self {
+        self.collapse_to_edge = Some(side);
+        self
+    }
+
+    /// How narrow the user needs to drag the region for it to snap closed.
+    ///
+    /// This is measured from the edge the region collapses to, to the pointer,
+    /// so it can be smaller than [`Self::min_width`].
+    ///
+    /// Only used with [`Self::collapsible_to_edge`]. Default: `24.0`.
+    #[inline]
+    pub fn collapse_threshold(mut self, collapse_threshold: f32) -> Self {
+        self.collapse_threshold = collapse_threshold;
+        self
+    }
+
+    /// Width of the handle shown while the region is collapsed.
+    ///
+    /// Only used with [`Self::collapsible_to_edge`]. Default: `8.0`.
+    #[inline]
+    pub fn collapse_handle_width(mut self, collapse_handle_width: f32) -> Self {
+        self.collapse_handle_width = collapse_handle_width;
+        self
+    }
+
+    /// Fill color of the handle shown while the region is collapsed.
+    ///
+    /// Only used with [`Self::collapsible_to_edge`].
+    /// Default: the background of an interactive widget.
+    #[inline]
+    pub fn collapse_handle_fill(mut self, collapse_handle_fill: impl Into<Color32>) -> Self {
+        self.collapse_handle_fill = Some(collapse_handle_fill.into());
+        self
+    }
 }
 
 struct Prepared {
     id: Id,
     corner_id: Option<Id>,
+
+    /// The handle to show instead of the contents, if collapsed.
+    handle: Option<(Id, Rect)>,
     state: State,
     content_ui: Ui,
 }
 
 impl Resize {
+    /// Apply [`Self::size_step`] to a size picked by the user, and clamp it to the min/max size.
+    pub(crate) fn snap_size(&self, ctx: &Context, size: Vec2) -> Vec2 {
+        let Some(step) = self.size_step else {
+            return size;
+        };
+        let disable_modifiers = self.size_step_disable_modifiers;
+        if !disable_modifiers.is_none() && ctx.input(|i| i.modifiers.contains(disable_modifiers)) {
+            return size;
+        }
+
+        let snap = |size: f32, step: f32| {
+            if step > 0.0 {
+                (size / step).round() * step
+            } else {
+                size
+            }
+        };
+        vec2(snap(size.x, step.x), snap(size.y, step.y))
+            .at_least(self.min_size)
+            .at_most(self.max_size)
+    }
+
+    /// How far from the edge the region is anchored to the user is dragging the corner or handle.
+    fn size_from_pointer(&self, available: Rect, pointer_pos: Pos2, handle_size: Vec2) -> Vec2 {
+        let width = if self.collapse_to_edge == Some(Side::Right) {
+            available.right() - pointer_pos.x
+        } else {
+            pointer_pos.x - available.left()
+        };
+        vec2(width, pointer_pos.y - available.top()) + 0.5 * handle_size
+    }
+
     fn begin(&self, ui: &mut Ui) -> Prepared {
-        let position = ui.available_rect_before_wrap().min;
+        let available = ui.available_rect_before_wrap();
         let id = self.id.unwrap_or_else(|| {
             let id_salt = self.id_salt.unwrap_or_else(|| Id::new("resize"));
             ui.make_persistent_id(id_salt)
         });
 
+        let auto_size = self.auto_sized_initially;
         let mut state = State::load(ui.ctx(), id).unwrap_or_else(|| {
-            ui.ctx().request_repaint(); // counter frame delay
+            if auto_size {
+                // Don't show the first frame, where we only measure the contents:
+                ui.ctx().request_discard("Resize::auto_sized_initially");
+            } else {
+                ui.ctx().request_repaint(); // counter frame delay
+            }
 
-            let default_size = self
-                .default_size
+            let default_size = if auto_size {
+                self.max_size
+            } else {
+                self.default_size
+            };
+            let default_size = default_size
                 .at_least(self.min_size)
                 .at_most(self.max_size)
                 .at_most(
@@ -228,8 +457,12 @@ impl Resize {
                 desired_size: default_size,
                 last_content_size: vec2(0.0, 0.0),
                 requested_size: None,
+                user_resized: false,
+                collapsed: false,
+                last_open_size: None,
             }
         });
+        state.collapsed &= self.collapse_to_edge.is_some();
 
         state.desired_size = state
             .desired_size
@@ -239,21 +472,55 @@ impl Resize {
 
         let mut user_requested_size = state.requested_size.take();
 
-        let corner_id = self.resizable.any().then(|| id.with("__resize_corner"));
+        let handle_id = id.with("__collapse_handle");
+        if state.collapsed {
+            if let Some(handle_response) = ui.ctx().read_response(handle_id) {
+                let dragged_out = handle_response.dragged()
+                    && handle_response
+                        .interact_pointer_pos()
+                        .is_some_and(|pointer_pos| {
+                            let size = self.size_from_pointer(available, pointer_pos, Vec2::ZERO);
+                            size.x > self.collapse_threshold
+                        });
+                if handle_response.clicked() || dragged_out {
+                    state.collapsed = false;
+                }
+            }
+        }
+
+        let corner_id =
+            (self.resizable.any() && !state.collapsed).then(|| id.with("__resize_corner"));
 
         if let Some(corner_id) = corner_id {
             if let Some(corner_response) = ui.ctx().read_response(corner_id) {
                 if let Some(pointer_pos) = corner_response.interact_pointer_pos() {
                     // Respond to the interaction early to avoid frame delay.
-                    user_requested_size =
-                        Some(pointer_pos - position + 0.5 * corner_response.rect.size());
+                    let size =
+                        self.size_from_pointer(available, pointer_pos, corner_response.rect.size());
+                    if self.collapse_to_edge.is_some()
+                        && corner_response.drag_stopped()
+                        && size.x < self.collapse_threshold
+                    {
+                        // Snap closed, remembering the size from before the drag:
+                        state.collapsed = true;
+                    } else {
+                        user_requested_size = Some(self.snap_size(ui.ctx(), size));
+                    }
                 }
             }
         }
 
+        if state.collapsed {
+            if let Some(last_open_size) = state.last_open_size {
+                state.desired_size = last_open_size;
+            }
+            user_requested_size = None;
+        }
+
         if let Some(user_requested_size) = user_requested_size {
             state.desired_size = user_requested_size;
-        } else {
+            state.user_resized = true;
+        } else if !self.allow_shrink_below_content {
             // We are not being actively resized, so auto-expand to include size of last frame.
             // This prevents auto-shrinking if the contents contain width-filling widgets (separators etc)
             // but it makes a lot of interactions with [`Window`]s nicer.
@@ -267,8 +534,25 @@ impl Resize {
 
         // ------------------------------
 
+        let mut position = available.min;
+        if self.collapse_to_edge == Some(Side::Right) {
+            position.x = available.right() - state.desired_size.x;
+        }
         let inner_rect = Rect::from_min_size(position, state.desired_size);
 
+        let handle = state.collapsed.then(|| {
+            let handle_size = vec2(self.collapse_handle_width, state.desired_size.y);
+            let handle_rect = if self.collapse_to_edge == Some(Side::Right) {
+                Rect::from_min_size(
+                    pos2(available.right() - handle_size.x, available.top()),
+                    handle_size,
+                )
+            } else {
+                Rect::from_min_size(available.min, handle_size)
+            };
+            (handle_id, handle_rect)
+        });
+
         let mut content_clip_rect = inner_rect.expand(ui.visuals().clip_rect_margin);
 
         // If we pull the resize handle to shrink, we want to TRY to shrink it.
@@ -276,9 +560,13 @@ impl Resize {
         // In those cases we don't want the clip_rect to be smaller, because
         // then we will clip the contents of the region even thought the result gets larger. This is simply ugly!
         // So we use the memory of last_content_size to make the clip rect large enough.
-        content_clip_rect.max = content_clip_rect.max.max(
-            inner_rect.min + state.last_content_size + Vec2::splat(ui.visuals().clip_rect_margin),
-        );
+        if !self.allow_shrink_below_content {
+            content_clip_rect.max = content_clip_rect.max.max(
+                inner_rect.min
+                    + state.last_content_size
+                    + Vec2::splat(ui.visuals().clip_rect_margin),
+            );
+        }
 
         content_clip_rect = content_clip_rect.intersect(ui.clip_rect()); // Respect parent region
 
@@ -288,37 +576,89 @@ impl Resize {
                 .max_rect(inner_rect),
         );
         content_ui.set_clip_rect(content_clip_rect);
+        if handle.is_some() {
+            // The contents are stowed away, but we still need to run them:
+            content_ui.set_invisible();
+        }
 
         Prepared {
             id,
             corner_id,
+            handle,
             state,
             content_ui,
         }
     }
 
     pub fn show<R>(self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> R {
+        self.show_with_output(ui, add_contents).inner
+    }
+
+    /// Like [`Self::show`], but also tells you the size the user picked,
+    /// and whether they just finished resizing.
+    pub fn show_with_output<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> ResizeOutput<R> {
         let mut prepared = self.begin(ui);
-        let ret = add_contents(&mut prepared.content_ui);
-        self.end(ui, prepared);
-        ret
+        let id = prepared.id;
+        let is_collapsed = prepared.handle.is_some();
+        let inner = if self.allow_shrink_below_content {
+            crate::ScrollArea::both()
+                .id_salt(id.with("__scroll"))
+                .auto_shrink(false)
+                .show(&mut prepared.content_ui, add_contents)
+                .inner
+        } else {
+            add_contents(&mut prepared.content_ui)
+        };
+        let (size, corner_response) = self.end(ui, prepared);
+        ResizeOutput {
+            inner,
+            id,
+            size,
+            is_resizing: corner_response.as_ref().is_some_and(|r| r.dragged()),
+            resize_stopped: corner_response.is_some_and(|r| r.drag_stopped()),
+            is_collapsed,
+        }
     }
 
-    fn end(self, ui: &mut Ui, prepared: Prepared) {
+    /// Returns the desired size and the response of the resize corner (if any).
+    fn end(self, ui: &mut Ui, prepared: Prepared) -> (Vec2, Option<Response>) {
         let Prepared {
             id,
             corner_id,
+            handle,
             mut state,
             content_ui,
         } = prepared;
 
+        if let Some((handle_id, handle_rect)) = handle {
+            ui.advance_cursor_after_rect(handle_rect);
+            let handle_response = ui.interact(handle_rect, handle_id, Sense::click_and_drag());
+            self.paint_collapse_handle(ui, &handle_response);
+            if handle_response.hovered() || handle_response.dragged() {
+                ui.ctx().set_cursor_icon(CursorIcon::ResizeHorizontal);
+            }
+            state.store(ui.ctx(), id);
+            return (state.desired_size, None);
+        }
+
         state.last_content_size = content_ui.min_size();
+        if self.auto_sized_initially && !state.user_resized {
+            // Follow the contents until the user resizes us:
+            state.desired_size = state.last_content_size;
+        }
 
         // ------------------------------
 
         let mut size = state.last_content_size;
         for d in 0..2 {
-            if self.with_stroke || self.resizable[d] {
+            if self.allow_shrink_below_content {
+                // The contents scroll to fit whatever size we picked:
+                size[d] = state.desired_size[d];
+            } else if self.with_stroke || self.resizable[d] {
                 // We show how large we are,
                 // so we must follow the contents:
 
@@ -338,10 +678,13 @@ impl Resize {
         let corner_response = if let Some(corner_id) = corner_id {
             // We do the corner interaction last to place it on top of the content:
             let corner_size = Vec2::splat(ui.visuals().resize_corner_size);
-            let corner_rect = Rect::from_min_size(
+            let mut corner_rect = Rect::from_min_size(
                 content_ui.min_rect().left_top() + size - corner_size,
                 corner_size,
             );
+            if self.collapse_to_edge == Some(Side::Right) {
+                corner_rect = corner_rect.translate(vec2(corner_size.x - size.x, 0.0));
+            }
             Some(ui.interact(corner_rect, corner_id, Sense::drag()))
         } else {
             None
@@ -360,14 +703,30 @@ impl Resize {
             ));
         }
 
-        if let Some(corner_response) = corner_response {
-            paint_resize_corner(ui, &corner_response);
-
-            if corner_response.hovered() || corner_response.dragged() {
-                ui.ctx().set_cursor_icon(CursorIcon::ResizeNwSe);
+        if let Some(corner_response) = &corner_response {
+            if self.collapse_to_edge == Some(Side::Right) {
+                let stroke = ui.style().interact(corner_response).fg_stroke;
+                paint_resize_corner_with_style(
+                    ui,
+                    &corner_response.rect,
+                    stroke.color,
+                    Align2::LEFT_BOTTOM,
+                );
+                if corner_response.hovered() || corner_response.dragged() {
+                    ui.ctx().set_cursor_icon(CursorIcon::ResizeNeSw);
+                }
+            } else {
+                paint_resize_corner(ui, corner_response);
+                if corner_response.hovered() || corner_response.dragged() {
+                    ui.ctx().set_cursor_icon(CursorIcon::ResizeNwSe);
+                }
             }
         }
 
+        if !corner_response.as_ref().is_some_and(|r| r.dragged()) {
+            state.last_open_size = Some(state.desired_size);
+        }
+
         state.store(ui.ctx(), id);
 
         #[cfg(debug_assertions)]
@@ -383,6 +742,23 @@ impl Resize {
                 "last_content_size",
             );
         }
+
+        (state.desired_size, corner_response)
+    }
+
+    fn paint_collapse_handle(&self, ui: &Ui, response: &Response) {
+        let visuals = ui.style().interact(response);
+        let fill = self.collapse_handle_fill.unwrap_or(visuals.bg_fill);
+        let rect = response.rect;
+        ui.painter().rect_filled(rect, visuals.corner_radius, fill);
+
+        // A short grip line in the middle:
+        let grip_height = (0.25 * rect.height()).at_most(24.0);
+        ui.painter().vline(
+            rect.center().x,
+            rect.center().y - 0.5 * grip_height..=rect.center().y + 0.5 * grip_height,
+            visuals.fg_stroke,
+        );
     }
 }
 
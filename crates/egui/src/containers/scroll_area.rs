@@ -1,15 +1,76 @@
 #![allow(clippy::needless_range_loop)]
 
 use crate::{
-    emath, epaint, lerp, pass_state, pos2, remap, remap_clamp, Context, Id, NumExt, Pos2, Rangef,
-    Rect, Sense, Ui, UiBuilder, UiKind, UiStackInfo, Vec2, Vec2b,
+    emath, epaint, lerp, panel::Side, pass_state, pos2, remap, remap_clamp, style, Context, Id,
+    NumExt, Pos2, Rangef, Rect, Sense, Ui, UiBuilder, UiKind, UiStackInfo, Vec2, Vec2b,
 };
 
+/// Time constant, in seconds, of the smoothing of [`ScrollAreaOutput::velocity`].
+const VELOCITY_SMOOTHING_TIME: f32 = 0.1;
+
+/// How far, in points, the content must be pulled down past the top to request a refresh,
+/// see [`ScrollArea::pull_to_refresh`].
+const PULL_TO_REFRESH_DISTANCE: f32 = 64.0;
+
+/// The [`crate::UiTags`] key of the [`Id`] of the [`ScrollArea`] a [`Ui`] is in.
+const SCROLL_AREA_ID_TAG: &str = "egui_scroll_area_id";
+
+/// The [`crate::UiTags`] key of the zoom factor of a [`ScrollArea::zoomable`] a [`Ui`] is in.
+const SCROLL_AREA_ZOOM_TAG: &str = "egui_scroll_area_zoom";
+
+/// The [`crate::UiTags`] key of the scroll offset of the [`ScrollArea`] a [`Ui`] is in.
+const SCROLL_AREA_OFFSET_TAG: &str = "egui_scroll_area_offset";
+
+/// Where a [`ScrollArea`] keeps the rows seen by [`ScrollArea::row_first_seen`].
+fn seen_rows_id(scroll_area_id: Id) -> Id {
+    scroll_area_id.with("seen_rows")
+}
+
 #[derive(Clone, Copy, Debug)]
-#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 struct ScrollingToTarget {
     animation_time_span: (f64, f64),
+    start_offset: f32,
     target_offset: f32,
+    easing: fn(f32) -> f32,
+}
+
+impl ScrollingToTarget {
+    /// Animate from `start_offset` to `target_offset`, starting now.
+    ///
+    /// The further we scroll, the more time we take.
+    fn new(
+        ui: &Ui,
+        animation: style::ScrollAnimation,
+        start_offset: f32,
+        target_offset: f32,
+    ) -> Self {
+        let now = ui.input(|i| i.time);
+        let animation_duration = ((target_offset - start_offset).abs()
+            / animation.points_per_second)
+            .clamp(animation.duration.min, animation.duration.max);
+        Self {
+            animation_time_span: (now, now + animation_duration as f64),
+            start_offset,
+            target_offset,
+            easing: animation.easing,
+        }
+    }
+
+    /// The offset at time `now`, or `None` once the animation is done.
+    fn offset_at(&self, now: f64) -> Option<f32> {
+        let (start_time, end_time) = self.animation_time_span;
+        let t = if start_time < end_time {
+            ((now - start_time) / (end_time - start_time)) as f32
+        } else {
+            1.0
+        };
+        (t < 1.0).then(|| {
+            emath::lerp(
+                self.start_offset..=self.target_offset,
+                (self.easing)(t.at_least(0.0)),
+            )
+        })
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -17,9 +78,14 @@ struct ScrollingToTarget {
 #[cfg_attr(feature = "serde", serde(default))]
 pub struct State {
     /// Positive offset means scrolling down/right
+    ///
+    /// After clamping to the content, as of the end of the frame.
+    /// Feed this back into [`ScrollArea::scroll_offset`] to restore the scroll position,
+    /// e.g. when restoring a session.
     pub offset: Vec2,
 
     /// If set, quickly but smoothly scroll to this target offset.
+    #[cfg_attr(feature = "serde", serde(skip))]
     offset_target: [Option<ScrollingToTarget>; 2],
 
     /// Were the scroll bars visible last frame?
@@ -35,9 +101,53 @@ pub struct State {
     #[cfg_attr(feature = "serde", serde(skip))]
     vel: Vec2,
 
+    /// How much the offset changed this frame, for any reason.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    scroll_delta: Vec2,
+
+    /// Smoothed rate of change of the offset, in points per second.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    scroll_velocity: Vec2,
+
     /// Mouse offset relative to the top of the handle when started moving the handle.
     scroll_start_offset_from_top_left: [Option<f32>; 2],
 
+    /// Is the current press on the scroll bar track paging, rather than moving the handle?
+    #[cfg_attr(feature = "serde", serde(skip))]
+    scroll_bar_paging: Vec2b,
+
+    /// The offset before it was clamped to the content.
+    ///
+    /// If the offset had to be clamped because the content shrank
+    /// (or because the scroll bar of the other axis appeared), we return to this
+    /// offset once there is room again.
+    /// Scrolling an axis (from user input or code) forgets this, so scrolling past the end
+    /// never comes back to haunt you when the content grows.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    unclamped_offset: Vec2,
+
+    /// Was the last pointer press inside the scroll area?
+    /// Used by [`ScrollArea::scroll_requires_focus`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    has_scroll_focus: bool,
+
+    /// Has the offset moved since [`ScrollArea::snap_to_children`] last snapped?
+    #[cfg_attr(feature = "serde", serde(skip))]
+    snap_pending: bool,
+
+    /// The content size when [`ScrollAreaOutput::reached_end`] was last reported,
+    /// or `None` while the end isn't near.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    reached_end_at: Option<Vec2>,
+
+    /// How far the content is pulled down past the top, see [`ScrollArea::pull_to_refresh`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pull: f32,
+
+    /// Did the current drag start at the top, so it may pull to refresh?
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pull_armed: bool,
+
     /// Is the scroll sticky. This is true while scroll handle is in the end position
     /// and remains that way until the user moves the `scroll_handle`. Once unstuck (false)
     /// it remains false until the scroll touches the end position, which reenables stickiness.
@@ -45,6 +155,14 @@ pub struct State {
 
     /// Area that can be dragged. This is the size of the content from the last frame.
     interact_rect: Option<Rect>,
+
+    /// Where the pointer was pressed to drag the contents,
+    /// until it has moved past [`ScrollArea::drag_start_threshold`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    drag_press_pos: Option<Pos2>,
+
+    /// The zoom factor picked by the user, see [`ScrollArea::zoomable`].
+    zoom: f32,
 }
 
 impl Default for State {
@@ -56,11 +174,65 @@ impl Default for State {
             content_is_too_large: Vec2b::FALSE,
             scroll_bar_interaction: Vec2b::FALSE,
             vel: Vec2::ZERO,
+            scroll_delta: Vec2::ZERO,
+            scroll_velocity: Vec2::ZERO,
             scroll_start_offset_from_top_left: [None; 2],
+            scroll_bar_paging: Vec2b::FALSE,
+            unclamped_offset: Vec2::ZERO,
+            has_scroll_focus: false,
+            snap_pending: false,
+            reached_end_at: None,
+            pull: 0.0,
+            pull_armed: false,
             scroll_stuck_to_end: Vec2b::TRUE,
             interact_rect: None,
+            drag_press_pos: None,
+            zoom: 1.0,
+        }
+    }
+}
+
+/// Did the current press start on an interactive widget (button, slider, …) on top of `area_id`?
+///
+/// Text labels don't count, so that you can still drag-scroll a list of labels on touch screens.
+fn press_started_on_child(ctx: &Context, area_id: Id) -> bool {
+    let Some(press_origin) = ctx.input(|i| i.pointer.press_origin()) else {
+        return false;
+    };
+    ctx.viewport(|viewport| {
+        let widgets = &viewport.prev_pass.widgets;
+        let Some((layer_id, area_index)) = widgets.order(area_id) else {
+            return false;
+        };
+        widgets
+            .get_layer(layer_id)
+            .skip(area_index + 1)
+            .any(|widget| {
+                widget.enabled
+                    && widget.sense.interactive()
+                    && widget.sense.is_focusable()
+                    && widget.interact_rect.contains(press_origin)
+            })
+    })
+}
+
+/// Along which axes `content_size` doesn't fit in `outer_size`,
+/// counting the room taken by the scroll bars (`bar_width` wide) that this makes necessary.
+fn content_is_too_large_for(
+    outer_size: Vec2,
+    content_size: Vec2,
+    scroll_enabled: Vec2b,
+    bar_width: f32,
+) -> Vec2b {
+    let mut too_large = Vec2b::FALSE;
+    // Twice, since the bar of one axis takes room from the other:
+    for _ in 0..2 {
+        for d in 0..2 {
+            let room = outer_size[d] - if too_large[1 - d] { bar_width } else { 0.0 };
+            too_large[d] = scroll_enabled[d] && room < content_size[d];
         }
     }
+    too_large
 }
 
 impl State {
@@ -94,6 +266,102 @@ pub struct ScrollAreaOutput<R> {
 
     /// Where on the screen the content is (excludes scroll bars).
     pub inner_rect: Rect,
+
+    /// The visible part of the content, in content coordinates,
+    /// i.e. `min` is the [`State::offset`] of [`Self::state`]
+    /// and the size is that of [`Self::inner_rect`].
+    ///
+    /// Together with [`Self::content_size`] this is what a minimap needs:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut jump_to: Option<egui::Vec2> = None;
+    /// let mut scroll_area = egui::ScrollArea::vertical();
+    /// if let Some(fraction) = jump_to.take() {
+    ///     scroll_area = scroll_area.scroll_to_fraction(fraction);
+    /// }
+    /// let output = scroll_area.show(ui, |ui| {
+    ///     // Lots of content…
+    /// });
+    ///
+    /// // The whole content, scaled down to 100 points high:
+    /// let scale = 100.0 / output.content_size.y.max(1.0);
+    /// let (minimap, response) = ui.allocate_exact_size(
+    ///     output.content_size * scale,
+    ///     egui::Sense::click_and_drag(),
+    /// );
+    /// let viewport = output.viewport_rect;
+    /// let indicator = egui::Rect::from_min_size(
+    ///     minimap.min + viewport.min.to_vec2() * scale,
+    ///     viewport.size() * scale,
+    /// );
+    /// let stroke = ui.visuals().selection.stroke;
+    /// ui.painter().rect_stroke(indicator, 0.0, stroke, egui::StrokeKind::Inside);
+    ///
+    /// if let Some(pos) = response.interact_pointer_pos() {
+    ///     // Center the viewport on the clicked part of the content:
+    ///     let center = (pos - minimap.min) / scale;
+    ///     let max_offset = output.content_size - viewport.size();
+    ///     let offset = center - viewport.size() / 2.0;
+    ///     jump_to = Some(offset / max_offset.max(egui::Vec2::splat(1.0)));
+    /// }
+    /// # });
+    /// ```
+    pub viewport_rect: Rect,
+
+    /// How much the [`State::offset`] of [`Self::state`] changed since last frame,
+    /// whether from the user scrolling, kinetic scrolling, animations or code.
+    pub delta: Vec2,
+
+    /// The smoothed velocity of the [`State::offset`] of [`Self::state`], in points per second.
+    ///
+    /// Useful for motion-reactive content like parallax effects.
+    /// Settles to exactly zero shortly after the scrolling stops.
+    pub velocity: Vec2,
+
+    /// With [`ScrollArea::snap_to_children`]: the index of the child closest to the middle of the viewport.
+    ///
+    /// `None` if snapping is off or there are no children.
+    pub centered_child: Option<usize>,
+
+    /// With [`ScrollArea::reach_end_threshold`]: did the end of the content come within the threshold this frame?
+    ///
+    /// This is only `true` for one frame per load cycle, see [`ScrollArea::reach_end_threshold`].
+    pub reached_end: bool,
+
+    /// With [`ScrollArea::pull_to_refresh`]: did the user release the content
+    /// after pulling it down far enough past the top this frame?
+    pub refresh_requested: bool,
+
+    /// The zoom factor, see [`ScrollArea::zoomable`].
+    ///
+    /// Always `1.0` unless the scroll area is zoomable.
+    pub zoom: f32,
+}
+
+impl<R> ScrollAreaOutput<R> {
+    /// Where the whole content is on the screen, including the parts scrolled out of view.
+    pub fn content_rect(&self) -> Rect {
+        Rect::from_min_size(self.inner_rect.min - self.state.offset, self.content_size)
+    }
+
+    /// How far the scroll area is scrolled, as a fraction of how far it can scroll, per axis.
+    ///
+    /// `0.0` is the top/left and `1.0` is the bottom/right.
+    /// An axis that doesn't need scrolling is `0.0`.
+    ///
+    /// Feed this back into [`ScrollArea::scroll_to_fraction`], e.g. for a minimap.
+    pub fn scroll_fraction(&self) -> Vec2 {
+        let max_offset = self.content_size - self.inner_rect.size();
+        let fraction = |d: usize| {
+            if max_offset[d] > 0.0 {
+                (self.state.offset[d] / max_offset[d]).clamp(0.0, 1.0)
+            } else {
+                0.0
+            }
+        };
+        Vec2::new(fraction(0), fraction(1))
+    }
 }
 
 /// Indicate whether the horizontal and vertical scroll bars must be always visible, hidden or visible when needed.
@@ -125,12 +393,86 @@ impl Default for ScrollBarVisibility {
     }
 }
 
+impl From<ScrollBarVisibility> for [ScrollBarVisibility; 2] {
+    #[inline]
+    fn from(visibility: ScrollBarVisibility) -> Self {
+        [visibility; 2]
+    }
+}
+
 impl ScrollBarVisibility {
     pub const ALL: [Self; 3] = [
         Self::AlwaysHidden,
         Self::VisibleWhenNeeded,
         Self::AlwaysVisible,
     ];
+
+    /// Should the scroll bar be shown, given whether it is needed and enabled?
+    fn show(self, needed: bool, enabled: bool) -> bool {
+        match self {
+            Self::AlwaysHidden => false,
+            Self::VisibleWhenNeeded => needed,
+            Self::AlwaysVisible => enabled,
+        }
+    }
+}
+
+/// Which user inputs can scroll a [`ScrollArea`], see [`ScrollArea::scroll_source`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ScrollSource {
+    /// Dragging or clicking the scroll bars.
+    pub scroll_bar: bool,
+
+    /// Dragging the contents, mostly useful on touch screens.
+    pub drag: bool,
+
+    /// The mouse wheel and touchpad scrolling.
+    pub mouse_wheel: bool,
+}
+
+impl Default for ScrollSource {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl ScrollSource {
+    pub const NONE: Self = Self {
+        scroll_bar: false,
+        drag: false,
+        mouse_wheel: false,
+    };
+    pub const ALL: Self = Self {
+        scroll_bar: true,
+        drag: true,
+        mouse_wheel: true,
+    };
+    pub const SCROLL_BAR: Self = Self {
+        scroll_bar: true,
+        ..Self::NONE
+    };
+    pub const DRAG: Self = Self {
+        drag: true,
+        ..Self::NONE
+    };
+    pub const MOUSE_WHEEL: Self = Self {
+        mouse_wheel: true,
+        ..Self::NONE
+    };
+}
+
+impl std::ops::BitOr for ScrollSource {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self {
+            scroll_bar: self.scroll_bar || rhs.scroll_bar,
+            drag: self.drag || rhs.drag,
+            mouse_wheel: self.mouse_wheel || rhs.mouse_wheel,
+        }
+    }
 }
 
 /// Add vertical and/or horizontal scrolling to a contained [`Ui`].
@@ -144,6 +486,9 @@ impl ScrollBarVisibility {
 /// to the contents. Floating scroll bars float on top of the contents, covering it.
 /// You can change the scroll style by changing the [`crate::style::Spacing::scroll`].
 ///
+/// Clicking the track of a scroll bar scrolls one page toward the pointer,
+/// while shift-clicking it jumps straight to that position.
+///
 /// ### Coordinate system
 /// * content: size of contents (generally large; that's why we want scroll bars)
 /// * outer: size of scroll area including scroll bar(s)
@@ -162,8 +507,16 @@ impl ScrollBarVisibility {
 ///
 /// You can scroll to an element using [`crate::Response::scroll_to_me`], [`Ui::scroll_to_cursor`] and [`Ui::scroll_to_rect`].
 ///
+/// ## Id and persistence
+/// The scroll offset is stored under the [`Id`] of the scroll area.
+/// By default that is the [`Id`] of the parent [`Ui`] combined with [`Self::id_salt`],
+/// so it never depends on the contents: showing different contents in the same place keeps the offset.
+/// It does change if the parent [`Ui`] changes, e.g. when each tab of a tab bar is shown in a `Ui` of its own.
+/// Use [`Self::id`] to keep the same offset no matter where the scroll area is shown.
+///
 /// ## See also
-/// If you want to allow zooming, use [`crate::Scene`].
+/// If you want to allow zooming of a document-like view, see [`ScrollArea::zoomable`].
+/// For free panning and zooming of a canvas, use [`crate::Scene`].
 #[derive(Clone, Debug)]
 #[must_use = "You should call .show()"]
 pub struct ScrollArea {
@@ -173,15 +526,21 @@ pub struct ScrollArea {
     auto_shrink: Vec2b,
     max_size: Vec2,
     min_scrolled_size: Vec2,
-    scroll_bar_visibility: ScrollBarVisibility,
+    scroll_bar_visibility: [ScrollBarVisibility; 2],
     scroll_bar_rect: Option<Rect>,
+    scroll_bar_position: Side,
+
+    /// Always leave room for the scroll bars, even when they are hidden.
+    reserve_scrollbar_space: bool,
+
+    id: Option<Id>,
     id_salt: Option<Id>,
-    offset_x: Option<f32>,
-    offset_y: Option<f32>,
+    offset: [Option<f32>; 2],
+    offset_fraction: [Option<f32>; 2],
 
     /// If false, we ignore scroll events.
     scrolling_enabled: bool,
-    drag_to_scroll: bool,
+    scroll_source: ScrollSource,
 
     /// If true for vertical or horizontal the scroll wheel will stick to the
     /// end position until user manually changes position. It will become true
@@ -190,6 +549,43 @@ pub struct ScrollArea {
 
     /// If false, `scroll_to_*` functions will not be animated
     animated: bool,
+
+    /// Paint gradients at the edges where there is more content to scroll to.
+    edge_shadows: bool,
+
+    /// Fade out the content over this many points at the edges, see [`ScrollArea::fade_edges`].
+    fade_edges: f32,
+
+    /// Animate towards the target offset of scroll wheel steps.
+    smooth_scroll: bool,
+
+    /// Scales how far the mouse wheel scrolls.
+    wheel_scroll_multiplier: f32,
+
+    /// Only respond to the mouse wheel after the user clicked inside the scroll area.
+    scroll_requires_focus: bool,
+
+    /// Expected content size, used on the first frame before the real one is known.
+    content_size_hint: Option<Vec2>,
+
+    /// Snap to center the nearest child once scrolling stops.
+    snap_to_children: bool,
+
+    /// How close to the end of the content [`ScrollAreaOutput::reached_end`] fires.
+    reach_end_threshold: Option<f32>,
+
+    /// Let the user pull the content down past the top to request a refresh.
+    pull_to_refresh: bool,
+
+    /// Let the user zoom the contents with ctrl+wheel or pinch.
+    zoomable: bool,
+    zoom_range: Rangef,
+
+    /// How far past the end of the content the user may scroll.
+    scroll_beyond_content: f32,
+
+    /// How far the pointer must move before dragging the contents scrolls.
+    drag_start_threshold: f32,
 }
 
 impl ScrollArea {
@@ -228,13 +624,29 @@ impl ScrollArea {
             min_scrolled_size: Vec2::splat(64.0),
             scroll_bar_visibility: Default::default(),
             scroll_bar_rect: None,
+            scroll_bar_position: Side::Right,
+            reserve_scrollbar_space: false,
+            id: None,
             id_salt: None,
-            offset_x: None,
-            offset_y: None,
+            offset: [None; 2],
+            offset_fraction: [None; 2],
             scrolling_enabled: true,
-            drag_to_scroll: true,
+            scroll_source: ScrollSource::ALL,
             stick_to_end: Vec2b::FALSE,
             animated: true,
+            edge_shadows: false,
+            fade_edges: 0.0,
+            smooth_scroll: false,
+            wheel_scroll_multiplier: 1.0,
+            scroll_requires_focus: false,
+            snap_to_children: false,
+            reach_end_threshold: None,
+            pull_to_refresh: false,
+            content_size_hint: None,
+            zoomable: false,
+            zoom_range: Rangef::new(0.1, 10.0),
+            scroll_beyond_content: 0.0,
+            drag_start_threshold: 0.0,
         }
     }
 
@@ -284,12 +696,18 @@ impl ScrollArea {
         self
     }
 
-    /// Set the visibility of both horizontal and vertical scroll bars.
+    /// Set the visibility of the horizontal and vertical scroll bars.
+    ///
+    /// Pass a single [`ScrollBarVisibility`] for both bars, or `[horizontal, vertical]`
+    /// to control each axis independently.
     ///
     /// With `ScrollBarVisibility::VisibleWhenNeeded` (default), the scroll bar will be visible only when needed.
     #[inline]
-    pub fn scroll_bar_visibility(mut self, scroll_bar_visibility: ScrollBarVisibility) -> Self {
-        self.scroll_bar_visibility = scroll_bar_visibility;
+    pub fn scroll_bar_visibility(
+        mut self,
+        scroll_bar_visibility: impl Into<[ScrollBarVisibility; 2]>,
+    ) -> Self {
+        self.scroll_bar_visibility = scroll_bar_visibility.into();
         self
     }
 
@@ -303,6 +721,36 @@ impl ScrollArea {
         self
     }
 
+    /// Always leave room for the scroll bars, even when they are hidden.
+    ///
+    /// With [`ScrollBarVisibility::VisibleWhenNeeded`] the content normally gets narrower
+    /// when the vertical scroll bar appears, which re-wraps text and makes the layout jump.
+    /// With this set, the content always has the width it would have with the scroll bar shown,
+    /// and the bar is only painted when needed.
+    ///
+    /// This has no effect on scroll bars with [`ScrollBarVisibility::AlwaysHidden`],
+    /// or on an axis that can't be scrolled.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn reserve_scrollbar_space(mut self, reserve_scrollbar_space: bool) -> Self {
+        self.reserve_scrollbar_space = reserve_scrollbar_space;
+        self
+    }
+
+    /// On which side of the content to show the scroll bars.
+    ///
+    /// With [`Side::Left`] the vertical scroll bar is shown on the left edge,
+    /// and the horizontal scroll bar on the top edge.
+    /// This is a good fit for content laid out with [`crate::Layout::right_to_left`].
+    ///
+    /// Default: [`Side::Right`], i.e. the vertical bar on the right and the horizontal bar at the bottom.
+    #[inline]
+    pub fn scroll_bar_position(mut self, side: Side) -> Self {
+        self.scroll_bar_position = side;
+        self
+    }
+
     /// A source for the unique [`Id`], e.g. `.id_source("second_scroll_area")` or `.id_source(loop_index)`.
     #[inline]
     #[deprecated = "Renamed id_salt"]
@@ -311,23 +759,55 @@ impl ScrollArea {
     }
 
     /// A source for the unique [`Id`], e.g. `.id_salt("second_scroll_area")` or `.id_salt(loop_index)`.
+    ///
+    /// This is combined with the [`Id`] of the parent [`Ui`].
     #[inline]
     pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
         self.id_salt = Some(Id::new(id_salt));
         self
     }
 
+    /// Assign an explicit and globally unique id.
+    ///
+    /// Unlike [`Self::id_salt`], this does not depend on the parent [`Ui`],
+    /// so scroll areas shown in different places with the same id share their scroll offset.
+    /// This is useful for tabs that show the same kind of list:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let tab = 0;
+    /// ui.push_id(tab, |ui| {
+    ///     egui::ScrollArea::vertical()
+    ///         .id(egui::Id::new("log_list"))
+    ///         .show(ui, |ui| {
+    ///             ui.label(format!("Log of tab {tab}"));
+    ///         });
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// Only one scroll area with this id may be shown at a time.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Set the horizontal and vertical scroll offset position.
     ///
     /// Positive offset means scrolling down/right.
     ///
+    /// The offset is clamped to the content size.
+    /// It takes precedence over any user scrolling during the frame it is set,
+    /// so only set it when you want to move the scroll area (e.g. when restoring a session),
+    /// not every frame.
+    ///
     /// See also: [`Self::vertical_scroll_offset`], [`Self::horizontal_scroll_offset`],
     /// [`Ui::scroll_to_cursor`](crate::ui::Ui::scroll_to_cursor) and
     /// [`Response::scroll_to_me`](crate::Response::scroll_to_me)
     #[inline]
     pub fn scroll_offset(mut self, offset: Vec2) -> Self {
-        self.offset_x = Some(offset.x);
-        self.offset_y = Some(offset.y);
+        self.offset = [Some(offset.x), Some(offset.y)];
         self
     }
 
@@ -335,11 +815,13 @@ impl ScrollArea {
     ///
     /// Positive offset means scrolling down.
     ///
+    /// Like [`Self::scroll_offset`], but only for the vertical axis.
+    ///
     /// See also: [`Self::scroll_offset`], [`Ui::scroll_to_cursor`](crate::ui::Ui::scroll_to_cursor) and
     /// [`Response::scroll_to_me`](crate::Response::scroll_to_me)
     #[inline]
     pub fn vertical_scroll_offset(mut self, offset: f32) -> Self {
-        self.offset_y = Some(offset);
+        self.offset[1] = Some(offset);
         self
     }
 
@@ -347,11 +829,31 @@ impl ScrollArea {
     ///
     /// Positive offset means scrolling right.
     ///
+    /// Like [`Self::scroll_offset`], but only for the horizontal axis.
+    ///
     /// See also: [`Self::scroll_offset`], [`Ui::scroll_to_cursor`](crate::ui::Ui::scroll_to_cursor) and
     /// [`Response::scroll_to_me`](crate::Response::scroll_to_me)
     #[inline]
     pub fn horizontal_scroll_offset(mut self, offset: f32) -> Self {
-        self.offset_x = Some(offset);
+        self.offset[0] = Some(offset);
+        self
+    }
+
+    /// Set the scroll position as a fraction of how far it can scroll, per axis.
+    ///
+    /// `0.0` is the top/left and `1.0` is the bottom/right, so `vec2(0.0, 0.75)`
+    /// means "75% of the way down". The fraction is clamped to `0..=1`.
+    ///
+    /// Like [`Self::scroll_offset`], this takes precedence over any user scrolling during the
+    /// frame it is set, so only set it when you want to jump (e.g. from a minimap).
+    /// Since the content size is only known after the content has been added,
+    /// the new position is shown starting next frame.
+    ///
+    /// Only axes with scrolling enabled are affected.
+    /// See [`ScrollAreaOutput::scroll_fraction`] for reading back the current position.
+    #[inline]
+    pub fn scroll_to_fraction(mut self, fraction: Vec2) -> Self {
+        self.offset_fraction = [Some(fraction.x), Some(fraction.y)];
         self
     }
 
@@ -407,10 +909,41 @@ impl ScrollArea {
     ///
     /// If `true`, the [`ScrollArea`] will sense drags.
     ///
+    /// This is the same as [`ScrollSource::drag`] in [`Self::scroll_source`].
+    ///
     /// Default: `true`.
     #[inline]
     pub fn drag_to_scroll(mut self, drag_to_scroll: bool) -> Self {
-        self.drag_to_scroll = drag_to_scroll;
+        self.scroll_source.drag = drag_to_scroll;
+        self
+    }
+
+    /// Only start scrolling by dragging the contents (see [`Self::drag_to_scroll`])
+    /// once the pointer has moved this many points away from where it was pressed.
+    ///
+    /// This stops small, accidental pointer movements from scrolling,
+    /// which is useful for touch screens.
+    /// While the pointer stays within this distance, the press can still be a click on the contents.
+    ///
+    /// Default: `0.0`.
+    #[inline]
+    pub fn drag_start_threshold(mut self, drag_start_threshold: f32) -> Self {
+        self.drag_start_threshold = drag_start_threshold.at_least(0.0);
+        self
+    }
+
+    /// Which user inputs can scroll the area: the scroll bars, dragging the contents, and/or the mouse wheel.
+    ///
+    /// For instance, a zoomable canvas inside a [`ScrollArea`] can use
+    /// `ScrollSource::SCROLL_BAR | ScrollSource::DRAG` to keep the mouse wheel for itself:
+    /// the scroll area then leaves the wheel input for its contents (or any parent scroll area).
+    ///
+    /// Scrolling from code, e.g. with [`Ui::scroll_to_cursor`], is not affected.
+    ///
+    /// Default: [`ScrollSource::ALL`].
+    #[inline]
+    pub fn scroll_source(mut self, scroll_source: ScrollSource) -> Self {
+        self.scroll_source = scroll_source;
         self
     }
 
@@ -419,19 +952,296 @@ impl ScrollArea {
     /// * If `true`, egui will add blank space outside the scroll area.
     /// * If `false`, egui will add blank space inside the scroll area.
     ///
-    /// Default: `true`.
-    #[inline]
-    pub fn auto_shrink(mut self, auto_shrink: impl Into<Vec2b>) -> Self {
-        self.auto_shrink = auto_shrink.into();
-        self
+    /// Default: `true`.
+    #[inline]
+    pub fn auto_shrink(mut self, auto_shrink: impl Into<Vec2b>) -> Self {
+        self.auto_shrink = auto_shrink.into();
+        self
+    }
+
+    /// Should the scroll area animate `scroll_to_*` functions?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
+    /// Paint a subtle shadow at each edge where there is more content beyond the visible area.
+    ///
+    /// The shadow fades out as you scroll towards the end of the content in that direction.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn edge_shadows(mut self, edge_shadows: bool) -> Self {
+        self.edge_shadows = edge_shadows;
+        self
+    }
+
+    /// Fade the content out towards each edge where there is more content beyond the visible area,
+    /// over `width` points, e.g. for a horizontal gallery.
+    ///
+    /// egui can only clip to rectangles, so this paints a gradient of the background color
+    /// over the content: the fill of the closest [`crate::Frame`] around the scroll area,
+    /// or [`crate::Visuals::panel_fill`] if there is none.
+    /// Like [`Self::edge_shadows`], the fade disappears as you scroll to the end of the content.
+    ///
+    /// Default: `0.0` (no fade).
+    #[inline]
+    pub fn fade_edges(mut self, width: f32) -> Self {
+        self.fade_edges = width;
+        self
+    }
+
+    /// Smoothly animate scrolling with a notched mouse wheel.
+    ///
+    /// Each wheel step is added to a target offset, which the scroll area eases towards
+    /// over [`crate::style::ScrollAnimation`] (see [`crate::Style::scroll_animation`]).
+    /// Small deltas, like the ones from a trackpad, are applied directly as usual.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn smooth_scroll(mut self, smooth_scroll: bool) -> Self {
+        self.smooth_scroll = smooth_scroll;
+        self
+    }
+
+    /// Scale how far each mouse wheel step (and trackpad swipe) scrolls this area.
+    ///
+    /// This is applied on top of the global [`crate::Options::line_scroll_speed`],
+    /// so e.g. a canvas can scroll slower, and a long list faster, than everything else.
+    /// To scroll a text editor by one row per wheel notch, use the row height divided by
+    /// [`crate::Options::line_scroll_speed`].
+    /// The scaled steps are what gets animated with [`Self::smooth_scroll`].
+    ///
+    /// Default: `1.0`.
+    #[inline]
+    pub fn wheel_scroll_multiplier(mut self, multiplier: f32) -> Self {
+        self.wheel_scroll_multiplier = multiplier;
+        self
+    }
+
+    /// Only scroll with the mouse wheel when the scroll area is both hovered and focused.
+    ///
+    /// The scroll area gets focus when the user presses the pointer anywhere inside it
+    /// (including on its contents), and loses it again on a press somewhere else.
+    /// This prevents accidentally scrolling background panels in dense layouts.
+    ///
+    /// Wheel input is only consumed while the area has focus, so an unfocused scroll area
+    /// lets it through to the enclosing scroll area (if any), like [`Self::scroll_source`] does.
+    /// A press inside a nested scroll area also focuses the scroll areas around it.
+    ///
+    /// The scroll bars and drag-to-scroll are not affected.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn scroll_requires_focus(mut self, scroll_requires_focus: bool) -> Self {
+        self.scroll_requires_focus = scroll_requires_focus;
+        self
+    }
+
+    /// Once the user stops scrolling, animate to center the child closest to the middle of the viewport,
+    /// e.g. for an image carousel or paged views.
+    ///
+    /// The children are the widgets placed directly in the scroll area, in order.
+    /// Put the widgets of one item in a [`Ui::scope`] (or a [`crate::Frame`]) to make them one child.
+    /// [`ScrollAreaOutput::centered_child`] tells you which child is currently centered.
+    ///
+    /// The first and last children can't be centered if that would scroll past the content,
+    /// so the scroll area stops at the content edge for those.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn snap_to_children(mut self, snap_to_children: bool) -> Self {
+        self.snap_to_children = snap_to_children;
+        self
+    }
+
+    /// Report in [`ScrollAreaOutput::reached_end`] when the end of the content comes within
+    /// this many points of the viewport, on any scrollable axis, e.g. to load more rows of an infinite list.
+    ///
+    /// It is reported once, when the end comes near. It is reported again once the content
+    /// has grown (i.e. the next page was loaded) while the end is still near,
+    /// or after the user scrolled away from the end and back.
+    /// Content that doesn't fill the viewport counts as being near the end,
+    /// so you keep loading until there is something to scroll.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut rows: Vec<String> = vec![];
+    /// let output = egui::ScrollArea::vertical()
+    ///     .reach_end_threshold(100.0)
+    ///     .show(ui, |ui| {
+    ///         for row in &rows {
+    ///             ui.label(row);
+    ///         }
+    ///     });
+    /// if output.reached_end {
+    ///     // Start loading the next page:
+    ///     rows.extend((rows.len()..rows.len() + 20).map(|i| format!("Row {i}")));
+    /// }
+    /// # });
+    /// ```
+    ///
+    /// Default: `None`, i.e. [`ScrollAreaOutput::reached_end`] is always `false`.
+    #[inline]
+    pub fn reach_end_threshold(mut self, threshold: f32) -> Self {
+        self.reach_end_threshold = Some(threshold);
+        self
+    }
+
+    /// Let the user drag the content down past the top to request a refresh,
+    /// like the lists of many mobile apps.
+    ///
+    /// While pulling, the content follows the pointer with some resistance,
+    /// and a spinner is shown in the space revealed above it.
+    /// Releasing the content after pulling it far enough sets [`ScrollAreaOutput::refresh_requested`],
+    /// after which the content springs back to the top.
+    ///
+    /// This only starts when a drag starts while the area is scrolled to the top,
+    /// and needs dragging the contents to be enabled (see [`Self::drag_to_scroll`]).
+    /// It works even if the content fits without scrolling.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # fn reload_messages() {}
+    /// let output = egui::ScrollArea::vertical()
+    ///     .pull_to_refresh(true)
+    ///     .show(ui, |ui| {
+    ///         ui.label("Messages");
+    ///     });
+    /// if output.refresh_requested {
+    ///     reload_messages();
+    /// }
+    /// # });
+    /// ```
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn pull_to_refresh(mut self, pull_to_refresh: bool) -> Self {
+        self.pull_to_refresh = pull_to_refresh;
+        self
+    }
+
+    /// The size you expect the contents to have.
+    ///
+    /// Which scroll bars are shown depends on the content size measured the previous frame.
+    /// On the very first frame, there is no such measurement, so the bars would only appear
+    /// on the second frame, shifting the layout. With a hint they are shown right away.
+    /// After the first frame, the real content size is used.
+    ///
+    /// Useful when a scroll area is rebuilt often, e.g. with an [`Self::id_salt`] per selection.
+    #[inline]
+    pub fn content_size_hint(mut self, content_size_hint: impl Into<Vec2>) -> Self {
+        self.content_size_hint = Some(content_size_hint.into());
+        self
+    }
+
+    /// Let the user zoom the contents with ctrl+wheel (cmd+wheel on Mac) or a pinch gesture,
+    /// about the point under the mouse cursor.
+    ///
+    /// The scroll area doesn't scale the contents for you, since only you know how to lay them out.
+    /// Instead, read the zoom factor with [`Self::zoom_factor`] from inside the scroll area,
+    /// and make the contents that many times larger.
+    /// The scroll offset is adjusted so the point of the contents under the cursor stays put.
+    ///
+    /// The zoom factor is remembered with the rest of the [`State`],
+    /// and is also in [`ScrollAreaOutput::zoom`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::ScrollArea::both().zoomable(true).show(ui, |ui| {
+    ///     let zoom = egui::ScrollArea::zoom_factor(ui);
+    ///     ui.allocate_exact_size(zoom * egui::vec2(800.0, 1000.0), egui::Sense::hover());
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn zoomable(mut self, zoomable: bool) -> Self {
+        self.zoomable = zoomable;
+        self
+    }
+
+    /// The range the zoom factor of a [`Self::zoomable`] scroll area is clamped to.
+    ///
+    /// Default: `0.1..=10.0`.
+    #[inline]
+    pub fn zoom_range(mut self, zoom_range: impl Into<Rangef>) -> Self {
+        self.zoom_range = zoom_range.into();
+        self
+    }
+
+    /// The zoom factor of the innermost [`Self::zoomable`] scroll area this [`Ui`] is in,
+    /// or `1.0` if there is none.
+    pub fn zoom_factor(ui: &Ui) -> f32 {
+        ui.stack()
+            .iter()
+            .find_map(|stack| stack.info.tags.get_downcast::<f32>(SCROLL_AREA_ZOOM_TAG))
+            .copied()
+            .unwrap_or(1.0)
+    }
+
+    /// Paint a parallax layer in the innermost [`ScrollArea`] this [`Ui`] is in.
+    ///
+    /// The layer moves by `factor` times the scroll offset: `0.0` keeps it fixed in the viewport,
+    /// `1.0` scrolls it with the content, and anything in between makes it lag behind.
+    /// `paint` is given a painter clipped to the viewport, and the rect of the layer
+    /// (the size of the viewport, at the translated position).
+    ///
+    /// The layer is only painted, so it never captures clicks or drags meant for the content.
+    /// Call this at the start of the contents to have it painted behind them.
+    ///
+    /// Outside of a [`ScrollArea`] the layer is just painted in [`Ui::max_rect`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::ScrollArea::vertical().show(ui, |ui| {
+    ///     egui::ScrollArea::paint_parallax_layer(ui, 0.5, |painter, rect| {
+    ///         painter.circle_filled(rect.center(), 40.0, egui::Color32::DARK_BLUE);
+    ///     });
+    ///     for i in 0..100 {
+    ///         ui.label(format!("Row {i}"));
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn paint_parallax_layer<R>(
+        ui: &Ui,
+        factor: f32,
+        paint: impl FnOnce(&crate::Painter, Rect) -> R,
+    ) -> R {
+        let rect = ui
+            .stack()
+            .iter()
+            .find_map(|stack| {
+                let offset = *stack
+                    .info
+                    .tags
+                    .get_downcast::<Vec2>(SCROLL_AREA_OFFSET_TAG)?;
+                // The content starts `offset` above the viewport:
+                let viewport_min = stack.max_rect.min + offset;
+                Some(Rect::from_min_size(
+                    viewport_min - factor * offset,
+                    stack.max_rect.size(),
+                ))
+            })
+            .unwrap_or_else(|| ui.max_rect());
+        paint(ui.painter(), rect)
     }
 
-    /// Should the scroll area animate `scroll_to_*` functions?
+    /// Let the user scroll this many points past the end of the content, on each scrollable axis.
     ///
-    /// Default: `true`.
+    /// Unlike an overscroll effect, the offset stays where the user left it, and doesn't spring back.
+    /// This is mostly useful for debugging, e.g. to inspect the padding at the bottom of the content,
+    /// or contents that are clipped.
+    ///
+    /// Default: `0.0`.
     #[inline]
-    pub fn animated(mut self, animated: bool) -> Self {
-        self.animated = animated;
+    pub fn allow_scroll_beyond_content(mut self, extra: f32) -> Self {
+        self.scroll_beyond_content = extra.at_least(0.0);
         self
     }
 
@@ -488,8 +1298,9 @@ struct Prepared {
     /// and vice versa.
     current_bar_use: Vec2,
 
-    scroll_bar_visibility: ScrollBarVisibility,
+    scroll_bar_visibility: [ScrollBarVisibility; 2],
     scroll_bar_rect: Option<Rect>,
+    scroll_bar_position: Side,
 
     /// Where on the screen the content is (excludes scroll bars).
     inner_rect: Rect,
@@ -501,13 +1312,36 @@ struct Prepared {
     viewport: Rect,
 
     scrolling_enabled: bool,
+    scroll_source: ScrollSource,
     stick_to_end: Vec2b,
 
+    /// The offset at the end of last frame.
+    offset_last_frame: Vec2,
+
     /// If there was a scroll target before the [`ScrollArea`] was added this frame, it's
     /// not for us to handle so we save it and restore it after this [`ScrollArea`] is done.
     saved_scroll_target: [Option<pass_state::ScrollTarget>; 2],
 
+    /// Offset set with [`ScrollArea::scroll_offset`] this frame.
+    ///
+    /// This wins over any user scrolling this frame.
+    offset_override: [Option<f32>; 2],
+
+    /// Fraction set with [`ScrollArea::scroll_to_fraction`] this frame.
+    offset_fraction: [Option<f32>; 2],
+
     animated: bool,
+    edge_shadows: bool,
+    fade_edges: f32,
+    smooth_scroll: bool,
+    wheel_scroll_multiplier: f32,
+    scroll_requires_focus: bool,
+    snap_to_children: bool,
+    reach_end_threshold: Option<f32>,
+    scroll_beyond_content: f32,
+
+    /// See [`ScrollAreaOutput::refresh_requested`].
+    refresh_requested: bool,
 }
 
 impl ScrollArea {
@@ -519,42 +1353,93 @@ impl ScrollArea {
             min_scrolled_size,
             scroll_bar_visibility,
             scroll_bar_rect,
+            scroll_bar_position,
+            reserve_scrollbar_space,
+            id,
             id_salt,
-            offset_x,
-            offset_y,
+            offset: offset_override,
+            offset_fraction,
             scrolling_enabled,
-            drag_to_scroll,
+            scroll_source,
             stick_to_end,
             animated,
+            edge_shadows,
+            fade_edges,
+            smooth_scroll,
+            wheel_scroll_multiplier,
+            scroll_requires_focus,
+            content_size_hint,
+            snap_to_children,
+            reach_end_threshold,
+            pull_to_refresh,
+            zoomable,
+            zoom_range,
+            scroll_beyond_content,
+            drag_start_threshold,
         } = self;
 
         let ctx = ui.ctx().clone();
         let scrolling_enabled = scrolling_enabled && ui.is_enabled();
 
-        let id_salt = id_salt.unwrap_or_else(|| Id::new("scroll_area"));
-        let id = ui.make_persistent_id(id_salt);
+        let id = id.unwrap_or_else(|| {
+            let id_salt = id_salt.unwrap_or_else(|| Id::new("scroll_area"));
+            ui.make_persistent_id(id_salt)
+        });
         ctx.check_for_id_clash(
             id,
             Rect::from_min_size(ui.available_rect_before_wrap().min, Vec2::ZERO),
             "ScrollArea",
         );
-        let mut state = State::load(&ctx, id).unwrap_or_default();
+        let mut state = State::load(&ctx, id).unwrap_or_else(|| {
+            let mut state = State::default();
+            if let Some(content_size) = content_size_hint {
+                // Nothing measured yet, so predict which bars will be needed from the hint:
+                let too_large = content_is_too_large_for(
+                    ui.available_rect_before_wrap().size().at_most(max_size),
+                    content_size,
+                    scroll_enabled,
+                    ui.spacing().scroll.allocated_width(),
+                );
+                state.content_is_too_large = too_large;
+                state.show_scroll = too_large;
+            }
+            state
+        });
+        let offset_last_frame = state.offset;
 
-        state.offset.x = offset_x.unwrap_or(state.offset.x);
-        state.offset.y = offset_y.unwrap_or(state.offset.y);
+        for d in 0..2 {
+            if let Some(offset) = offset_override[d] {
+                state.offset[d] = offset.max(0.0);
+            }
+            if offset_override[d].is_some() || offset_fraction[d].is_some() {
+                state.offset_target[d] = None;
+                state.vel[d] = 0.0;
+                state.scroll_stuck_to_end[d] = false;
+            }
+        }
 
-        let show_bars: Vec2b = match scroll_bar_visibility {
-            ScrollBarVisibility::AlwaysHidden => Vec2b::FALSE,
-            ScrollBarVisibility::VisibleWhenNeeded => state.show_scroll,
-            ScrollBarVisibility::AlwaysVisible => scroll_enabled,
-        };
+        let show_bars = Vec2b::new(
+            scroll_bar_visibility[0].show(state.show_scroll[0], scroll_enabled[0]),
+            scroll_bar_visibility[1].show(state.show_scroll[1], scroll_enabled[1]),
+        );
 
         let show_bars_factor = Vec2::new(
             ctx.animate_bool_responsive(id.with("h"), show_bars[0]),
             ctx.animate_bool_responsive(id.with("v"), show_bars[1]),
         );
 
-        let current_bar_use = show_bars_factor.yx() * ui.spacing().scroll.allocated_width();
+        let bar_width = ui.spacing().scroll.allocated_width();
+        let mut current_bar_use = show_bars_factor.yx() * bar_width;
+        if reserve_scrollbar_space {
+            for d in 0..2 {
+                if scroll_enabled[d]
+                    && scroll_bar_visibility[d] != ScrollBarVisibility::AlwaysHidden
+                {
+                    // The bar of one axis takes room from the other:
+                    current_bar_use[1 - d] = bar_width;
+                }
+            }
+        }
 
         let available_outer = ui.available_rect_before_wrap();
 
@@ -575,7 +1460,41 @@ impl ScrollArea {
             inner_size
         };
 
-        let inner_rect = Rect::from_min_size(available_outer.min, inner_size);
+        // Bars on the left/top push the content away from the outer edge:
+        let bar_offset = if scroll_bar_position == Side::Left {
+            current_bar_use
+        } else {
+            Vec2::ZERO
+        };
+        let inner_rect = Rect::from_min_size(available_outer.min + bar_offset, inner_size);
+
+        if zoomable {
+            let old_zoom = state.zoom;
+            let pointer_pos = ui
+                .input(|i| i.pointer.hover_pos())
+                .filter(|_| ui.rect_contains_pointer(inner_rect));
+            state.zoom = zoom_range.clamp(state.zoom);
+            if scrolling_enabled && pointer_pos.is_some() {
+                state.zoom = zoom_range.clamp(state.zoom * ui.input(|i| i.zoom_delta()));
+            }
+
+            if state.zoom != old_zoom {
+                // Keep the point of the contents under the cursor where it is:
+                let factor = state.zoom / old_zoom;
+                let anchor = pointer_pos.unwrap_or(inner_rect.min) - inner_rect.min;
+                for d in 0..2 {
+                    if scroll_enabled[d] {
+                        state.offset[d] =
+                            ((state.offset[d] + anchor[d]) * factor - anchor[d]).at_least(0.0);
+                        state.offset_target[d] = None;
+                        state.vel[d] = 0.0;
+                        state.scroll_stuck_to_end[d] = false;
+                    }
+                }
+            }
+        } else {
+            state.zoom = 1.0;
+        }
 
         let mut content_max_size = inner_size;
 
@@ -591,12 +1510,29 @@ impl ScrollArea {
             }
         }
 
-        let content_max_rect = Rect::from_min_size(inner_rect.min - state.offset, content_max_size);
+        if !pull_to_refresh {
+            state.pull = 0.0;
+        }
+        // The content is pushed down while pulled past the top:
+        let pull = state.pull;
+        let content_max_rect = Rect::from_min_size(
+            inner_rect.min - state.offset + Vec2::new(0.0, pull),
+            content_max_size,
+        );
+        let mut stack_info = UiStackInfo::new(UiKind::ScrollArea)
+            .with_tag_value(SCROLL_AREA_ID_TAG, id)
+            .with_tag_value(SCROLL_AREA_OFFSET_TAG, state.offset);
+        if zoomable {
+            stack_info = stack_info.with_tag_value(SCROLL_AREA_ZOOM_TAG, state.zoom);
+        }
         let mut content_ui = ui.new_child(
             UiBuilder::new()
-                .ui_stack_info(UiStackInfo::new(UiKind::ScrollArea))
+                .ui_stack_info(stack_info)
                 .max_rect(content_max_rect),
         );
+        if snap_to_children {
+            content_ui.start_recording_child_rects();
+        }
 
         {
             // Clip the content, but only when we really need to:
@@ -606,6 +1542,9 @@ impl ScrollArea {
                 if scroll_enabled[d] {
                     content_clip_rect.min[d] = inner_rect.min[d] - clip_rect_margin;
                     content_clip_rect.max[d] = inner_rect.max[d] + clip_rect_margin;
+                } else if scroll_bar_position == Side::Left {
+                    // Nice handling of forced resizing beyond the possible:
+                    content_clip_rect.min[d] = ui.clip_rect().min[d] + current_bar_use[d];
                 } else {
                     // Nice handling of forced resizing beyond the possible:
                     content_clip_rect.max[d] = ui.clip_rect().max[d] - current_bar_use[d];
@@ -619,8 +1558,12 @@ impl ScrollArea {
         let viewport = Rect::from_min_size(Pos2::ZERO + state.offset, inner_size);
         let dt = ui.input(|i| i.stable_dt).at_most(0.1);
 
-        if (scrolling_enabled && drag_to_scroll)
-            && (state.content_is_too_large[0] || state.content_is_too_large[1])
+        let mut is_pulling = false;
+        let mut refresh_requested = false;
+        if (scrolling_enabled && scroll_source.drag)
+            && (state.content_is_too_large[0]
+                || state.content_is_too_large[1]
+                || pull_to_refresh && scroll_enabled[1])
         {
             // Drag contents to scroll (for touch screens mostly).
             // We must do this BEFORE adding content to the `ScrollArea`,
@@ -629,12 +1572,39 @@ impl ScrollArea {
                 .interact_rect
                 .map(|rect| ui.interact(rect, id.with("area"), Sense::drag()));
 
+            let is_dragging = content_response_option.as_ref().is_some_and(|response| {
+                response.dragged() && !press_started_on_child(&ctx, response.id)
+            });
             if content_response_option
                 .as_ref()
-                .is_some_and(|response| response.dragged())
+                .is_some_and(|response| response.drag_started())
             {
+                state.drag_press_pos = ui.input(|i| i.pointer.press_origin());
+                state.pull_armed = state.offset.y <= 0.0;
+            }
+            let below_threshold = state.drag_press_pos.is_some_and(|press_pos| {
+                let pointer_pos = ui.input(|i| i.pointer.interact_pos()).unwrap_or(press_pos);
+                press_pos.distance(pointer_pos) <= drag_start_threshold
+            });
+            if !is_dragging || !below_threshold {
+                // Once past the threshold, keep scrolling even if the pointer comes back.
+                state.drag_press_pos = None;
+            }
+
+            if is_dragging && below_threshold {
+                // Not scrolling (yet), so the press can still be a click on the contents.
+            } else if is_dragging {
+                let pull_delta = ui.input(|i| i.pointer.delta().y);
+                is_pulling = pull_to_refresh
+                    && state.pull_armed
+                    && scroll_enabled[1]
+                    && (state.pull > 0.0 || state.offset.y <= 0.0 && pull_delta > 0.0);
+                if is_pulling {
+                    // Rubber band: the content follows the pointer at half the speed.
+                    state.pull = (state.pull + 0.5 * pull_delta).at_least(0.0);
+                }
                 for d in 0..2 {
-                    if scroll_enabled[d] {
+                    if scroll_enabled[d] && !(d == 1 && is_pulling) {
                         ui.input(|input| {
                             state.offset[d] -= input.pointer.delta()[d];
                         });
@@ -644,12 +1614,19 @@ impl ScrollArea {
                 }
             } else {
                 // Apply the cursor velocity to the scroll area when the user releases the drag.
-                if content_response_option
-                    .as_ref()
-                    .is_some_and(|response| response.drag_stopped())
+                if !below_threshold
+                    && content_response_option
+                        .as_ref()
+                        .is_some_and(|response| response.drag_stopped())
                 {
                     state.vel =
                         scroll_enabled.to_vec2() * ui.input(|input| input.pointer.velocity());
+                    if state.pull > 0.0 {
+                        state.vel.y = 0.0;
+                        refresh_requested =
+                            state.pull_armed && PULL_TO_REFRESH_DISTANCE <= state.pull;
+                    }
+                    state.pull_armed = false;
                 }
                 for d in 0..2 {
                     // Kinetic scrolling
@@ -670,33 +1647,41 @@ impl ScrollArea {
             }
         }
 
+        if !is_pulling && state.pull > 0.0 {
+            // Spring back to the top:
+            state.pull *= (-dt / 0.08).exp();
+            if state.pull < 0.5 {
+                state.pull = 0.0;
+            }
+            ctx.request_repaint();
+        }
+        if pull > 0.0 {
+            // Show that letting go will refresh in the space revealed above the content:
+            let size = pull.at_most(ui.spacing().interact_size.y);
+            let center = pos2(inner_rect.center().x, inner_rect.top() + pull / 2.0);
+            crate::Spinner::new().paint_at(ui, Rect::from_center_size(center, Vec2::splat(size)));
+        }
+
         // Scroll with an animation if we have a target offset (that hasn't been cleared by the code
         // above).
         for d in 0..2 {
             if let Some(scroll_target) = state.offset_target[d] {
                 state.vel[d] = 0.0;
 
-                if (state.offset[d] - scroll_target.target_offset).abs() < 1.0 {
+                let offset =
+                    if (scroll_target.start_offset - scroll_target.target_offset).abs() < 1.0 {
+                        None
+                    } else {
+                        scroll_target.offset_at(ui.input(|i| i.time))
+                    };
+                if let Some(offset) = offset {
+                    // Move towards target
+                    state.offset[d] = offset;
+                    ctx.request_repaint();
+                } else {
                     // Arrived
                     state.offset[d] = scroll_target.target_offset;
                     state.offset_target[d] = None;
-                } else {
-                    // Move towards target
-                    let t = emath::interpolation_factor(
-                        scroll_target.animation_time_span,
-                        ui.input(|i| i.time),
-                        dt,
-                        emath::ease_in_ease_out,
-                    );
-                    if t < 1.0 {
-                        state.offset[d] =
-                            emath::lerp(state.offset[d]..=scroll_target.target_offset, t);
-                        ctx.request_repaint();
-                    } else {
-                        // Arrived
-                        state.offset[d] = scroll_target.target_offset;
-                        state.offset_target[d] = None;
-                    }
                 }
             }
         }
@@ -714,13 +1699,27 @@ impl ScrollArea {
             current_bar_use,
             scroll_bar_visibility,
             scroll_bar_rect,
+            scroll_bar_position,
             inner_rect,
             content_ui,
             viewport,
             scrolling_enabled,
+            scroll_source,
             stick_to_end,
+            offset_last_frame,
             saved_scroll_target,
+            offset_override,
+            offset_fraction,
             animated,
+            edge_shadows,
+            fade_edges,
+            smooth_scroll,
+            wheel_scroll_multiplier,
+            scroll_requires_focus,
+            snap_to_children,
+            reach_end_threshold,
+            scroll_beyond_content,
+            refresh_requested,
         }
     }
 
@@ -732,11 +1731,13 @@ impl ScrollArea {
         ui: &mut Ui,
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> ScrollAreaOutput<R> {
-        self.show_viewport_dyn(ui, Box::new(|ui, _viewport| add_contents(ui)))
+        self.show_viewport_dyn(ui, Box::new(|ui, _viewport, _inner_rect| add_contents(ui)))
     }
 
     /// Efficiently show only the visible part of a large number of rows.
     ///
+    /// All rows must have the same height. For rows of different heights, see [`Ui::visible_scope`].
+    ///
     /// ```
     /// # egui::__run_test_ui(|ui| {
     /// let text_style = egui::TextStyle::Body;
@@ -751,6 +1752,11 @@ impl ScrollArea {
     /// });
     /// # });
     /// ```
+    ///
+    /// Scroll offsets are `f32`, so once the rows are more than about 16 million points tall
+    /// (2^24), the offset only has whole point precision, and coarser steps the further down you go.
+    /// The visible rows are still placed precisely relative to each other and the scroll area,
+    /// so a list with millions of rows doesn't jitter, but scrolling far down it moves in those steps.
     pub fn show_rows<R>(
         self,
         ui: &mut Ui,
@@ -760,28 +1766,40 @@ impl ScrollArea {
     ) -> ScrollAreaOutput<R> {
         let spacing = ui.spacing().item_spacing;
         let row_height_with_spacing = row_height_sans_spacing + spacing.y;
-        self.show_viewport(ui, |ui, viewport| {
-            ui.set_height((row_height_with_spacing * total_rows as f32 - spacing.y).at_least(0.0));
-
-            let mut min_row = (viewport.min.y / row_height_with_spacing).floor() as usize;
-            let mut max_row = (viewport.max.y / row_height_with_spacing).ceil() as usize + 1;
-            if max_row > total_rows {
-                let diff = max_row.saturating_sub(min_row);
-                max_row = total_rows;
-                min_row = total_rows.saturating_sub(diff);
-            }
+        self.show_viewport_dyn(
+            ui,
+            Box::new(|ui, viewport, inner_rect| {
+                ui.set_height(
+                    (row_height_with_spacing * total_rows as f32 - spacing.y).at_least(0.0),
+                );
 
-            let y_min = ui.max_rect().top() + min_row as f32 * row_height_with_spacing;
-            let y_max = ui.max_rect().top() + max_row as f32 * row_height_with_spacing;
+                // Very tall contents puts the top of the content (and `viewport.min.y`) so far
+                // away that an `f32` can't hold it to a fraction of a point.
+                // So we work out the visible rows in `f64`, and place them relative to the inner
+                // rect instead of adding the huge offset to the (equally huge) top of the contents,
+                // which would make the rows jitter as we scroll.
+                let row_height = f64::from(row_height_with_spacing);
+                let offset = f64::from(viewport.min.y);
+                let mut min_row = (offset / row_height).floor() as usize;
+                let mut max_row = (f64::from(viewport.max.y) / row_height).ceil() as usize + 1;
+                if max_row > total_rows {
+                    let diff = max_row.saturating_sub(min_row);
+                    max_row = total_rows;
+                    min_row = total_rows.saturating_sub(diff);
+                }
 
-            let rect = Rect::from_x_y_ranges(ui.max_rect().x_range(), y_min..=y_max);
+                let y_min = inner_rect.top() + (min_row as f64 * row_height - offset) as f32;
+                let y_max = y_min + ((max_row - min_row) as f64 * row_height) as f32;
 
-            ui.scope_builder(UiBuilder::new().max_rect(rect), |viewport_ui| {
-                viewport_ui.skip_ahead_auto_ids(min_row); // Make sure we get consistent IDs.
-                add_contents(viewport_ui, min_row..max_row)
-            })
-            .inner
-        })
+                let rect = Rect::from_x_y_ranges(ui.max_rect().x_range(), y_min..=y_max);
+
+                ui.scope_builder(UiBuilder::new().max_rect(rect), |viewport_ui| {
+                    viewport_ui.skip_ahead_auto_ids(min_row); // Make sure we get consistent IDs.
+                    add_contents(viewport_ui, min_row..max_row)
+                })
+                .inner
+            }),
+        )
     }
 
     /// This can be used to only paint the visible part of the contents.
@@ -793,32 +1811,226 @@ impl ScrollArea {
         ui: &mut Ui,
         add_contents: impl FnOnce(&mut Ui, Rect) -> R,
     ) -> ScrollAreaOutput<R> {
-        self.show_viewport_dyn(ui, Box::new(add_contents))
+        self.show_viewport_dyn(
+            ui,
+            Box::new(|ui, viewport, _inner_rect| add_contents(ui, viewport)),
+        )
+    }
+
+    /// Show the [`ScrollArea`] with a frozen header above it, e.g. the column titles of a table.
+    ///
+    /// The header scrolls horizontally together with the body, and is clipped to the same width,
+    /// but stays put when the body is scrolled vertically.
+    /// Lay out the header to be as wide as the body, so that the columns line up.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::ScrollArea::both().show_with_header(
+    ///     ui,
+    ///     |ui| {
+    ///         ui.horizontal(|ui| {
+    ///             ui.add_sized([200.0, 20.0], egui::Label::new("Name"));
+    ///             ui.add_sized([200.0, 20.0], egui::Label::new("Size"));
+    ///         });
+    ///     },
+    ///     |ui| {
+    ///         for row in 0..100 {
+    ///             ui.horizontal(|ui| {
+    ///                 ui.add_sized([200.0, 20.0], egui::Label::new(format!("File {row}")));
+    ///                 ui.add_sized([200.0, 20.0], egui::Label::new("1 kB"));
+    ///             });
+    ///         }
+    ///     },
+    /// );
+    /// # });
+    /// ```
+    ///
+    /// The height of the header is measured the first time it is shown, which costs an extra pass
+    /// (see [`Context::request_discard`]).
+    pub fn show_with_header<R>(
+        self,
+        ui: &mut Ui,
+        add_header: impl FnOnce(&mut Ui),
+        add_body: impl FnOnce(&mut Ui) -> R,
+    ) -> ScrollAreaOutput<R> {
+        let header_id = ui.make_persistent_id(("scroll_area_header", self.id, self.id_salt));
+        let header_height = ui.data(|d| d.get_temp::<f32>(header_id));
+        let (header_rect, _) = ui.allocate_exact_size(
+            Vec2::new(ui.available_width(), header_height.unwrap_or(0.0)),
+            Sense::hover(),
+        );
+
+        // Where the body is scrolled to this frame, so that the header matches it exactly:
+        let mut body_offset = 0.0;
+        let output = self.show_viewport_dyn(
+            ui,
+            Box::new(|ui, viewport, _inner_rect| {
+                body_offset = viewport.min.x;
+                add_body(ui)
+            }),
+        );
+
+        let band = Rect::from_x_y_ranges(output.inner_rect.x_range(), header_rect.y_range());
+        let height = show_band(ui, header_id, band, body_offset, &output, add_header);
+        if header_height != Some(height) {
+            ui.data_mut(|d| d.insert_temp(header_id, height));
+            ui.ctx().request_discard("ScrollArea::show_with_header");
+        }
+
+        output
+    }
+
+    /// Show the [`ScrollArea`] with a sticky footer below it, e.g. the totals row of a table.
+    ///
+    /// This mirrors [`Self::show_with_header`]: the footer scrolls horizontally together with the body,
+    /// and is clipped to the same width, but stays pinned to the bottom of the viewport
+    /// when the body is scrolled vertically.
+    /// Lay out the footer to be as wide as the body, so that the columns line up.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::ScrollArea::both().show_with_footer(
+    ///     ui,
+    ///     |ui| {
+    ///         for row in 0..100 {
+    ///             ui.horizontal(|ui| {
+    ///                 ui.add_sized([200.0, 20.0], egui::Label::new(format!("File {row}")));
+    ///                 ui.add_sized([200.0, 20.0], egui::Label::new("1 kB"));
+    ///             });
+    ///         }
+    ///     },
+    ///     |ui| {
+    ///         ui.horizontal(|ui| {
+    ///             ui.add_sized([200.0, 20.0], egui::Label::new("Total"));
+    ///             ui.add_sized([200.0, 20.0], egui::Label::new("100 kB"));
+    ///         });
+    ///     },
+    /// );
+    /// # });
+    /// ```
+    ///
+    /// The height of the footer is measured the first time it is shown, which costs an extra pass
+    /// (see [`Context::request_discard`]).
+    pub fn show_with_footer<R>(
+        self,
+        ui: &mut Ui,
+        add_body: impl FnOnce(&mut Ui) -> R,
+        add_footer: impl FnOnce(&mut Ui),
+    ) -> ScrollAreaOutput<R> {
+        let footer_id = ui.make_persistent_id(("scroll_area_footer", self.id, self.id_salt));
+        let footer_height = ui.data(|d| d.get_temp::<f32>(footer_id));
+
+        // Leave room for the footer below the body:
+        let body_height =
+            ui.available_height() - footer_height.unwrap_or(0.0) - ui.spacing().item_spacing.y;
+        let mut body_offset = 0.0;
+        let output = ui
+            .allocate_ui(
+                Vec2::new(ui.available_width(), body_height.at_least(0.0)),
+                |ui| {
+                    self.show_viewport_dyn(
+                        ui,
+                        Box::new(|ui, viewport, _inner_rect| {
+                            body_offset = viewport.min.x;
+                            add_body(ui)
+                        }),
+                    )
+                },
+            )
+            .inner;
+
+        let (footer_rect, _) = ui.allocate_exact_size(
+            Vec2::new(ui.available_width(), footer_height.unwrap_or(0.0)),
+            Sense::hover(),
+        );
+        let band = Rect::from_x_y_ranges(output.inner_rect.x_range(), footer_rect.y_range());
+        let height = show_band(ui, footer_id, band, body_offset, &output, add_footer);
+        if footer_height != Some(height) {
+            ui.data_mut(|d| d.insert_temp(footer_id, height));
+            ui.ctx().request_discard("ScrollArea::show_with_footer");
+        }
+
+        output
+    }
+
+    /// Returns `true` the first time the row with the given id is visible in the enclosing [`ScrollArea`].
+    ///
+    /// Call this for each row you show with the rect of the row, e.g. `response.rect`.
+    /// Unlike checking which rows are visible every frame, this only fires once per row id,
+    /// so you can use it to e.g. start loading an image for a row without starting it again
+    /// each frame the row is visible. The seen ids are remembered by the [`ScrollArea`]
+    /// until you call [`Self::forget_seen_rows`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # fn start_loading_thumbnail(_row: usize) {}
+    /// egui::ScrollArea::vertical().show_rows(ui, 18.0, 1_000, |ui, row_range| {
+    ///     for row in row_range {
+    ///         let response = ui.label(format!("Row {row}"));
+    ///         if egui::ScrollArea::row_first_seen(ui, egui::Id::new(row), response.rect) {
+    ///             start_loading_thumbnail(row);
+    ///         }
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn row_first_seen(ui: &Ui, row_id: Id, row_rect: Rect) -> bool {
+        if !ui.is_rect_visible(row_rect) {
+            return false;
+        }
+        let scroll_area_id = ui
+            .stack()
+            .iter()
+            .find_map(|stack| stack.info.tags.get_downcast::<Id>(SCROLL_AREA_ID_TAG))
+            .copied()
+            .unwrap_or(Id::NULL);
+        ui.data_mut(|d| {
+            d.get_temp_mut_or_default::<crate::id::IdSet>(seen_rows_id(scroll_area_id))
+                .insert(row_id)
+        })
+    }
+
+    /// Forget which rows [`Self::row_first_seen`] has seen in the [`ScrollArea`] with the given id,
+    /// e.g. after the rows were replaced with different ones.
+    ///
+    /// The id is the one in [`ScrollAreaOutput::id`].
+    pub fn forget_seen_rows(ctx: &Context, scroll_area_id: Id) {
+        ctx.data_mut(|d| d.remove::<crate::id::IdSet>(seen_rows_id(scroll_area_id)));
     }
 
+    /// `add_contents` is given the viewport and the inner rect (in screen space).
     fn show_viewport_dyn<'c, R>(
         self,
         ui: &mut Ui,
-        add_contents: Box<dyn FnOnce(&mut Ui, Rect) -> R + 'c>,
+        add_contents: Box<dyn FnOnce(&mut Ui, Rect, Rect) -> R + 'c>,
     ) -> ScrollAreaOutput<R> {
         let mut prepared = self.begin(ui);
         let id = prepared.id;
         let inner_rect = prepared.inner_rect;
-        let inner = add_contents(&mut prepared.content_ui, prepared.viewport);
-        let (content_size, state) = prepared.end(ui);
+        let refresh_requested = prepared.refresh_requested;
+        let inner = add_contents(&mut prepared.content_ui, prepared.viewport, inner_rect);
+        let (content_size, state, centered_child, reached_end) = prepared.end(ui);
         ScrollAreaOutput {
             inner,
             id,
             state,
             content_size,
             inner_rect,
+            viewport_rect: Rect::from_min_size(Pos2::ZERO + state.offset, inner_rect.size()),
+            delta: state.scroll_delta,
+            velocity: state.scroll_velocity,
+            centered_child,
+            reached_end,
+            refresh_requested,
+            zoom: state.zoom,
         }
     }
 }
 
 impl Prepared {
-    /// Returns content size and state
-    fn end(self, ui: &mut Ui) -> (Vec2, State) {
+    /// Returns the content size, the new state, the centered child (see [`ScrollArea::snap_to_children`])
+    /// and whether the end was reached (see [`ScrollArea::reach_end_threshold`]).
+    fn end(self, ui: &mut Ui) -> (Vec2, State, Option<usize>, bool) {
         let Self {
             id,
             mut state,
@@ -829,15 +2041,30 @@ impl Prepared {
             current_bar_use,
             scroll_bar_visibility,
             scroll_bar_rect,
-            content_ui,
-            viewport: _,
+            scroll_bar_position,
+            mut content_ui,
+            viewport,
             scrolling_enabled,
+            scroll_source,
             stick_to_end,
+            offset_last_frame,
             saved_scroll_target,
+            offset_override,
+            offset_fraction,
             animated,
+            edge_shadows,
+            fade_edges,
+            smooth_scroll,
+            wheel_scroll_multiplier,
+            scroll_requires_focus,
+            snap_to_children,
+            reach_end_threshold,
+            scroll_beyond_content,
+            refresh_requested: _,
         } = self;
 
         let content_size = content_ui.min_size();
+        let child_rects = content_ui.take_child_rects();
 
         let scroll_delta = content_ui
             .ctx()
@@ -857,17 +2084,32 @@ impl Prepared {
             if scroll_enabled[d] {
                 if let Some(target) = scroll_target {
                     let pass_state::ScrollTarget {
-                        range,
+                        mut range,
                         align,
                         animation: animation_update,
+                        margin,
                     } = target;
                     let min = content_ui.min_rect().min[d];
                     let clip_rect = content_ui.clip_rect();
                     let visible_range = min..=min + clip_rect.size()[d];
-                    let (start, end) = (range.min, range.max);
                     let clip_start = clip_rect.min[d];
                     let clip_end = clip_rect.max[d];
                     let mut spacing = content_ui.spacing().item_spacing[d];
+                    if let Some(margin) = margin {
+                        // Bring the range and its margin into view, but never so much margin
+                        // that the range itself no longer fits:
+                        let margin = margin
+                            .at_most((clip_rect.size()[d] - range.span()) / 2.0)
+                            .at_least(0.0);
+                        // …and no margin beyond the ends of the content, which we can't scroll to:
+                        let content = content_ui.min_rect();
+                        range = Rangef::new(
+                            (range.min - margin).max(content.min[d].min(range.min)),
+                            (range.max + margin).min(content.max[d].max(range.max)),
+                        );
+                        spacing = 0.0;
+                    }
+                    let (start, end) = (range.min, range.max);
 
                     let delta_update = if let Some(align) = align {
                         let center_factor = align.to_factor();
@@ -902,14 +2144,12 @@ impl Prepared {
                         // so we don't want to reset the animation, but perhaps update the target:
                         animation.target_offset = target_offset;
                     } else {
-                        // The further we scroll, the more time we take.
-                        let now = ui.input(|i| i.time);
-                        let animation_duration = (delta.abs() / animation.points_per_second)
-                            .clamp(animation.duration.min, animation.duration.max);
-                        state.offset_target[d] = Some(ScrollingToTarget {
-                            animation_time_span: (now, now + animation_duration as f64),
+                        state.offset_target[d] = Some(ScrollingToTarget::new(
+                            ui,
+                            animation,
+                            state.offset[d],
                             target_offset,
-                        });
+                        ));
                     }
                     ui.ctx().request_repaint();
                 }
@@ -941,7 +2181,20 @@ impl Prepared {
             Rect::from_min_size(inner_rect.min, inner_size)
         };
 
-        let outer_rect = Rect::from_min_size(inner_rect.min, inner_rect.size() + current_bar_use);
+        let bars_at_start = scroll_bar_position == Side::Left;
+        let bar_offset = if bars_at_start {
+            current_bar_use
+        } else {
+            Vec2::ZERO
+        };
+        let outer_rect = Rect::from_min_size(
+            inner_rect.min - bar_offset,
+            inner_rect.size() + current_bar_use,
+        );
+
+        // From here on, include the room to scroll past the end of the content:
+        let real_content_size = content_size;
+        let content_size = content_size + scroll_beyond_content * scroll_enabled.to_vec2();
 
         let content_is_too_large = Vec2b::new(
             scroll_enabled[0] && inner_rect.width() < content_size.x,
@@ -950,10 +2203,60 @@ impl Prepared {
 
         let max_offset = content_size - inner_rect.size();
         let is_hovering_outer_rect = ui.rect_contains_pointer(outer_rect);
-        if scrolling_enabled && is_hovering_outer_rect {
+        if ui.input(|i| i.pointer.any_pressed()) {
+            state.has_scroll_focus = is_hovering_outer_rect;
+        }
+        let wheel_allowed = !scroll_requires_focus || state.has_scroll_focus;
+        if scrolling_enabled && scroll_source.mouse_wheel && wheel_allowed && is_hovering_outer_rect
+        {
             let always_scroll_enabled_direction = ui.style().always_scroll_the_only_direction
                 && scroll_enabled[0] != scroll_enabled[1];
             for d in 0..2 {
+                if scroll_enabled[d] && smooth_scroll {
+                    let wheel_delta = ui.ctx().input(|input| {
+                        let is_zoom = input.modifiers.ctrl || input.modifiers.command;
+                        let raw_delta = if always_scroll_enabled_direction {
+                            input.raw_scroll_delta[0] + input.raw_scroll_delta[1]
+                        } else {
+                            input.raw_scroll_delta[d]
+                        };
+                        // Small deltas come from trackpads, which are already smooth.
+                        (!is_zoom && raw_delta.abs() >= 8.0)
+                            .then_some(raw_delta * wheel_scroll_multiplier)
+                    });
+
+                    if let Some(wheel_delta) = wheel_delta {
+                        let current_target = state.offset_target[d]
+                            .map_or(state.offset[d], |target| target.target_offset);
+                        let target_offset =
+                            (current_target - wheel_delta).clamp(0.0, max_offset[d].at_least(0.0));
+
+                        if target_offset != current_target {
+                            state.offset_target[d] = Some(ScrollingToTarget::new(
+                                ui,
+                                ui.style().scroll_animation,
+                                state.offset[d],
+                                target_offset,
+                            ));
+                            state.scroll_stuck_to_end[d] = false;
+
+                            // We handled this wheel step, so don't let it be smoothed
+                            // into later frames, nor used by any parent scroll area:
+                            ui.ctx().input_mut(|input| {
+                                if always_scroll_enabled_direction {
+                                    input.smooth_scroll_delta = Vec2::ZERO;
+                                    input.unprocessed_scroll_delta = Vec2::ZERO;
+                                } else {
+                                    input.smooth_scroll_delta[d] = 0.0;
+                                    input.unprocessed_scroll_delta[d] = 0.0;
+                                }
+                            });
+                            ui.ctx().request_repaint();
+                        }
+                        continue;
+                    }
+                }
+
                 if scroll_enabled[d] {
                     let scroll_delta = ui.ctx().input_mut(|input| {
                         if always_scroll_enabled_direction {
@@ -962,7 +2265,7 @@ impl Prepared {
                         } else {
                             input.smooth_scroll_delta[d]
                         }
-                    });
+                    }) * wheel_scroll_multiplier;
 
                     let scrolling_up = state.offset[d] > 0.0 && scroll_delta > 0.0;
                     let scrolling_down = state.offset[d] < max_offset[d] && scroll_delta < 0.0;
@@ -987,11 +2290,10 @@ impl Prepared {
             }
         }
 
-        let show_scroll_this_frame = match scroll_bar_visibility {
-            ScrollBarVisibility::AlwaysHidden => Vec2b::FALSE,
-            ScrollBarVisibility::VisibleWhenNeeded => content_is_too_large,
-            ScrollBarVisibility::AlwaysVisible => scroll_enabled,
-        };
+        let show_scroll_this_frame = Vec2b::new(
+            scroll_bar_visibility[0].show(content_is_too_large[0], scroll_enabled[0]),
+            scroll_bar_visibility[1].show(content_is_too_large[1], scroll_enabled[1]),
+        );
 
         // Avoid frame delay; start showing scroll bar right away:
         if show_scroll_this_frame[0] && show_bars_factor.x <= 0.0 {
@@ -1001,10 +2303,50 @@ impl Prepared {
             show_bars_factor.y = ui.ctx().animate_bool_responsive(id.with("v"), true);
         }
 
+        // Use the offset the content was painted with this frame:
+        let offset = viewport.min.to_vec2();
+        if fade_edges > 0.0 {
+            let background = ui
+                .stack()
+                .iter()
+                .map(|stack| stack.frame().fill)
+                .find(|fill| *fill != epaint::Color32::TRANSPARENT)
+                .unwrap_or(ui.visuals().panel_fill);
+            paint_edge_gradients(
+                ui,
+                inner_rect,
+                (scroll_enabled, offset, max_offset),
+                fade_edges,
+                background,
+            );
+        }
+        if edge_shadows {
+            paint_edge_gradients(
+                ui,
+                inner_rect,
+                (scroll_enabled, offset, max_offset),
+                8.0,
+                ui.visuals().window_shadow.color,
+            );
+        }
+
         let scroll_style = ui.spacing().scroll;
 
         // Paint the bars:
         let scroll_bar_rect = scroll_bar_rect.unwrap_or(inner_rect);
+        // Each axis is restored on its own, so that e.g. filtering the rows of a wide table
+        // doesn't lose the horizontal scroll position:
+        let scrolled = Vec2b::new(
+            state.offset.x != offset_last_frame.x || offset_override[0].is_some(),
+            state.offset.y != offset_last_frame.y || offset_override[1].is_some(),
+        );
+        for d in 0..2 {
+            if !scrolled[d] {
+                state.offset[d] = state.offset[d].max(state.unclamped_offset[d]);
+            }
+        }
+        let mut unclamped_offset = [None; 2];
+
         for d in 0..2 {
             // maybe force increase in offset to keep scroll stuck to end position
             if stick_to_end[d] && state.scroll_stuck_to_end[d] {
@@ -1026,11 +2368,14 @@ impl Prepared {
             let mut cross = if scroll_style.floating {
                 // The bounding rect of a fully visible bar.
                 // When we hover this area, we should show the full bar:
-                let max_bar_rect = if d == 0 {
-                    outer_rect.with_min_y(outer_rect.max.y - outer_margin - scroll_style.bar_width)
+                let mut max_bar_rect = outer_rect;
+                if bars_at_start {
+                    max_bar_rect.max[1 - d] =
+                        outer_rect.min[1 - d] + outer_margin + scroll_style.bar_width;
                 } else {
-                    outer_rect.with_min_x(outer_rect.max.x - outer_margin - scroll_style.bar_width)
-                };
+                    max_bar_rect.min[1 - d] =
+                        outer_rect.max[1 - d] - outer_margin - scroll_style.bar_width;
+                }
 
                 let is_hovering_bar_area = is_hovering_outer_rect
                     && ui.rect_contains_pointer(max_bar_rect)
@@ -1046,8 +2391,16 @@ impl Prepared {
                         is_hovering_bar_area_t,
                     );
 
-                let max_cross = outer_rect.max[1 - d] - outer_margin;
-                let min_cross = max_cross - width;
+                if bars_at_start {
+                    let min_cross = outer_rect.min[1 - d] + outer_margin;
+                    Rangef::new(min_cross, min_cross + width)
+                } else {
+                    let max_cross = outer_rect.max[1 - d] - outer_margin;
+                    Rangef::new(max_cross - width, max_cross)
+                }
+            } else if bars_at_start {
+                let min_cross = outer_rect.min[1 - d] + outer_margin;
+                let max_cross = inner_rect.min[1 - d] - inner_margin;
                 Rangef::new(min_cross, max_cross)
             } else {
                 let min_cross = inner_rect.max[1 - d] + inner_margin;
@@ -1055,7 +2408,7 @@ impl Prepared {
                 Rangef::new(min_cross, max_cross)
             };
 
-            if ui.clip_rect().max[1 - d] < cross.max + outer_margin {
+            if !bars_at_start && ui.clip_rect().max[1 - d] < cross.max + outer_margin {
                 // Move the scrollbar so it is visible. This is needed in some cases.
                 // For instance:
                 // * When we have a vertical-only scroll area in a top level panel,
@@ -1068,6 +2421,11 @@ impl Prepared {
                 let width = cross.max - cross.min;
                 cross.max = ui.clip_rect().max[1 - d] - outer_margin;
                 cross.min = cross.max - width;
+            } else if bars_at_start && cross.min - outer_margin < ui.clip_rect().min[1 - d] {
+                // Same for bars on the left/top edge:
+                let width = cross.max - cross.min;
+                cross.min = ui.clip_rect().min[1 - d] + outer_margin;
+                cross.max = cross.min + width;
             }
 
             let outer_scroll_bar_rect = if d == 0 {
@@ -1120,7 +2478,7 @@ impl Prepared {
             let handle_rect = calculate_handle_rect(d, &state.offset);
 
             let interact_id = id.with(d);
-            let sense = if self.scrolling_enabled {
+            let sense = if self.scrolling_enabled && scroll_source.scroll_bar {
                 Sense::click_and_drag()
             } else {
                 Sense::hover()
@@ -1130,6 +2488,41 @@ impl Prepared {
             state.scroll_bar_interaction[d] = response.hovered() || response.dragged();
 
             if let Some(pointer_pos) = response.interact_pointer_pos() {
+                let press_started = state.scroll_start_offset_from_top_left[d].is_none()
+                    && !state.scroll_bar_paging[d];
+                if press_started
+                    && !handle_rect.contains(pointer_pos)
+                    && !ui.input(|i| i.modifiers.shift)
+                {
+                    // Clicking the track pages toward the pointer. Shift-click jumps straight to it.
+                    state.scroll_bar_paging[d] = true;
+                    let page = inner_rect.size()[d];
+                    let direction = if pointer_pos[d] < handle_rect.min[d] {
+                        -1.0
+                    } else {
+                        1.0
+                    };
+                    let current_target = state.offset_target[d]
+                        .map_or(state.offset[d], |target| target.target_offset);
+                    let target_offset =
+                        (current_target + direction * page).clamp(0.0, max_offset[d].at_least(0.0));
+
+                    state.offset_target[d] = Some(ScrollingToTarget::new(
+                        ui,
+                        ui.style().scroll_animation,
+                        state.offset[d],
+                        target_offset,
+                    ));
+                    state.scroll_stuck_to_end[d] = false;
+                    ui.ctx().request_repaint();
+                }
+            }
+
+            if state.scroll_bar_paging[d] {
+                if response.interact_pointer_pos().is_none() {
+                    state.scroll_bar_paging[d] = false;
+                }
+            } else if let Some(pointer_pos) = response.interact_pointer_pos() {
                 let scroll_start_offset_from_top_left = state.scroll_start_offset_from_top_left[d]
                     .get_or_insert_with(|| {
                         if handle_rect.contains(pointer_pos) {
@@ -1159,6 +2552,7 @@ impl Prepared {
             }
 
             let unbounded_offset = state.offset[d];
+            unclamped_offset[d] = Some(unbounded_offset);
             state.offset[d] = state.offset[d].max(0.0);
             state.offset[d] = state.offset[d].min(max_offset[d]);
 
@@ -1245,6 +2639,22 @@ impl Prepared {
             }
         }
 
+        if !scroll_style.floating && show_bars_factor.min_elem() > 0.0 {
+            // Fill the corner between the two bars, so it matches their background:
+            let corner = if bars_at_start {
+                Rect::from_min_max(outer_rect.min, inner_rect.min)
+            } else {
+                Rect::from_min_max(inner_rect.max, outer_rect.max)
+            };
+            ui.painter().rect_filled(
+                corner,
+                0.0,
+                ui.visuals()
+                    .extreme_bg_color
+                    .gamma_multiply(show_bars_factor.min_elem()),
+            );
+        }
+
         ui.advance_cursor_after_rect(outer_rect);
 
         if show_scroll_this_frame != state.show_scroll {
@@ -1252,8 +2662,56 @@ impl Prepared {
         }
 
         let available_offset = content_size - inner_rect.size();
+
+        // An offset set with `ScrollArea::scroll_offset` wins over any scrolling this frame:
+        for d in 0..2 {
+            if let Some(offset) = offset_override[d] {
+                state.offset[d] = offset;
+                unclamped_offset[d] = Some(offset);
+            }
+            if let Some(fraction) = offset_fraction[d] {
+                if scroll_enabled[d] {
+                    let offset = fraction.clamp(0.0, 1.0) * available_offset[d].at_least(0.0);
+                    if offset != state.offset[d] {
+                        ui.ctx().request_repaint(); // we showed the content at the old offset
+                    }
+                    state.offset[d] = offset;
+                    unclamped_offset[d] = Some(offset);
+                }
+            }
+        }
+
+        state.unclamped_offset = Vec2::new(
+            unclamped_offset[0].unwrap_or(state.offset.x),
+            unclamped_offset[1].unwrap_or(state.offset.y),
+        );
         state.offset = state.offset.min(available_offset);
         state.offset = state.offset.max(Vec2::ZERO);
+        for d in 0..2 {
+            if scrolled[d] || offset_fraction[d].is_some() {
+                // Only remember offsets clamped by the content or the scroll area shrinking,
+                // not where scrolling tried to go past the end:
+                state.unclamped_offset[d] = state.offset[d];
+            }
+        }
+
+        state.scroll_delta = state.offset - offset_last_frame;
+        let stable_dt = ui.input(|i| i.stable_dt);
+        let dt = stable_dt.at_most(0.1);
+        if state.scroll_delta == Vec2::ZERO {
+            // Nothing to estimate, so just let the velocity fade over the time that actually passed,
+            // until what is left of it would add up to less than half a point:
+            state.scroll_velocity *= (-stable_dt / VELOCITY_SMOOTHING_TIME).exp();
+            if state.scroll_velocity.length() * VELOCITY_SMOOTHING_TIME < 0.5 {
+                state.scroll_velocity = Vec2::ZERO;
+            }
+        } else if dt > 0.0 {
+            let t = 1.0 - (-dt / VELOCITY_SMOOTHING_TIME).exp();
+            state.scroll_velocity = lerp(state.scroll_velocity..=state.scroll_delta / dt, t);
+        }
+        if state.scroll_velocity != Vec2::ZERO {
+            ui.ctx().request_repaint(); // let the velocity settle
+        }
 
         // Is scroll handle at end of content, or is there no scrollbar
         // yet (not enough content), but sticking is requested? If so, enter sticky mode.
@@ -1267,12 +2725,191 @@ impl Prepared {
                 || (self.stick_to_end[1] && available_offset[1] < 0.0),
         );
 
+        let centered_child = if snap_to_children {
+            snap_to_centered_child(
+                ui,
+                &mut state,
+                &child_rects,
+                inner_rect,
+                viewport.min.to_vec2(),
+                (scroll_enabled, available_offset),
+            )
+        } else {
+            None
+        };
+
+        let reached_end = reach_end_threshold.is_some_and(|threshold| {
+            let near_end = (0..2)
+                .any(|d| scroll_enabled[d] && available_offset[d] - state.offset[d] <= threshold);
+            if near_end {
+                // Once per load cycle:
+                let content_grew = state.reached_end_at != Some(real_content_size);
+                state.reached_end_at = Some(real_content_size);
+                content_grew
+            } else {
+                state.reached_end_at = None;
+                false
+            }
+        });
+
         state.show_scroll = show_scroll_this_frame;
         state.content_is_too_large = content_is_too_large;
         state.interact_rect = Some(inner_rect);
 
         state.store(ui.ctx(), id);
 
-        (content_size, state)
+        (real_content_size, state, centered_child, reached_end)
+    }
+}
+
+/// Find the child closest to the middle of the viewport for [`ScrollArea::snap_to_children`],
+/// and start animating towards centering it once the scrolling has stopped.
+///
+/// `child_rects` are in screen space, laid out with the offset `content_offset`.
+fn snap_to_centered_child(
+    ui: &Ui,
+    state: &mut State,
+    child_rects: &[Rect],
+    inner_rect: Rect,
+    content_offset: Vec2,
+    (scroll_enabled, available_offset): (Vec2b, Vec2),
+) -> Option<usize> {
+    let content_origin = inner_rect.min - content_offset;
+    // Both in content space:
+    let center_of = |rect: &Rect| rect.center() - content_origin;
+    let viewport_center = state.offset + inner_rect.size() / 2.0;
+    let distance_sq =
+        |rect: &Rect| (scroll_enabled.to_vec2() * (center_of(rect) - viewport_center)).length_sq();
+    let (centered_child, centered_rect) = child_rects
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| distance_sq(a).total_cmp(&distance_sq(b)))?;
+
+    if state.scroll_delta != Vec2::ZERO {
+        state.snap_pending = true;
+    }
+
+    let is_scrolling = state.scroll_velocity != Vec2::ZERO
+        || state.vel != Vec2::ZERO
+        || state.offset_target.iter().any(Option::is_some)
+        || state.scroll_bar_interaction.any()
+        || ui.input(|i| i.pointer.any_down() || i.smooth_scroll_delta != Vec2::ZERO);
+
+    if state.snap_pending && !is_scrolling {
+        state.snap_pending = false;
+        let center = center_of(centered_rect);
+        let animation = ui.style().scroll_animation;
+        for d in 0..2 {
+            if !scroll_enabled[d] {
+                continue;
+            }
+            let target_offset = (center[d] - inner_rect.size()[d] / 2.0)
+                .clamp(0.0, available_offset[d].at_least(0.0));
+            let delta = target_offset - state.offset[d];
+            if delta.abs() < 0.5 {
+                continue;
+            }
+            state.offset_target[d] = Some(ScrollingToTarget::new(
+                ui,
+                animation,
+                state.offset[d],
+                target_offset,
+            ));
+            ui.ctx().request_repaint();
+        }
+    }
+
+    Some(centered_child)
+}
+
+/// Show the header or footer of [`ScrollArea::show_with_header`] and [`ScrollArea::show_with_footer`]
+/// in `band`, scrolled horizontally by `body_offset` like the body.
+///
+/// Returns the height it took up.
+fn show_band<R>(
+    ui: &mut Ui,
+    id: Id,
+    band: Rect,
+    body_offset: f32,
+    output: &ScrollAreaOutput<R>,
+    add_contents: impl FnOnce(&mut Ui),
+) -> f32 {
+    let max_rect = Rect::from_min_size(
+        pos2(band.left() - body_offset, band.top()),
+        Vec2::new(output.content_size.x.max(band.width()), f32::INFINITY),
+    );
+    let mut band_ui = ui.new_child(
+        UiBuilder::new()
+            .id_salt(id)
+            .max_rect(max_rect)
+            .layout(*ui.layout()),
+    );
+    band_ui.set_clip_rect(band.intersect(ui.clip_rect()));
+    add_contents(&mut band_ui);
+    band_ui.min_rect().height()
+}
+
+/// Paint a gradient at each edge of `inner_rect` where there is more content to scroll to.
+fn paint_edge_gradients(
+    ui: &Ui,
+    inner_rect: Rect,
+    (scroll_enabled, offset, max_offset): (Vec2b, Vec2, Vec2),
+    size: f32,
+    color: epaint::Color32,
+) {
+    let shadow_size = size.at_most(inner_rect.width().min(inner_rect.height()) / 2.0);
+    if shadow_size <= 0.0 {
+        return;
+    }
+
+    let painter = ui
+        .painter()
+        .with_clip_rect(ui.clip_rect().intersect(inner_rect));
+
+    for d in 0..2 {
+        if !scroll_enabled[d] {
+            continue;
+        }
+
+        // How much content is hidden before and after the visible area, faded in over `shadow_size`:
+        let before = remap_clamp(offset[d], 0.0..=shadow_size, 0.0..=1.0);
+        let after = remap_clamp(max_offset[d] - offset[d], 0.0..=shadow_size, 0.0..=1.0);
+
+        for (strength, at_start) in [(before, true), (after, false)] {
+            if strength <= 0.0 {
+                continue;
+            }
+
+            let mut rect = inner_rect;
+            if at_start {
+                rect.max[d] = rect.min[d] + shadow_size;
+            } else {
+                rect.min[d] = rect.max[d] - shadow_size;
+            }
+
+            let edge_color = color.gamma_multiply(strength);
+            let transparent = epaint::Color32::TRANSPARENT;
+            let (min_color, max_color) = if at_start {
+                (edge_color, transparent)
+            } else {
+                (transparent, edge_color)
+            };
+
+            let mut mesh = epaint::Mesh::default();
+            if d == 0 {
+                mesh.colored_vertex(rect.left_top(), min_color);
+                mesh.colored_vertex(rect.left_bottom(), min_color);
+                mesh.colored_vertex(rect.right_top(), max_color);
+                mesh.colored_vertex(rect.right_bottom(), max_color);
+            } else {
+                mesh.colored_vertex(rect.left_top(), min_color);
+                mesh.colored_vertex(rect.right_top(), min_color);
+                mesh.colored_vertex(rect.left_bottom(), max_color);
+                mesh.colored_vertex(rect.right_bottom(), max_color);
+            }
+            mesh.add_triangle(0, 1, 2);
+            mesh.add_triangle(1, 2, 3);
+            painter.add(epaint::Shape::mesh(mesh));
+        }
     }
 }
@@ -0,0 +1,261 @@
+//! Transient notifications ("toasts") stacked in a corner of the screen.
+//!
+//! ```
+//! # egui::__run_test_ctx(|ctx| {
+//! let mut toasts = egui::Toasts::new();
+//! # let saved = true;
+//! if saved {
+//!     toasts = toasts.add(egui::Toast::new("File saved"));
+//! }
+//! let clicked = toasts.show(ctx);
+//! # let _ = clicked;
+//! # });
+//! ```
+
+use crate::{Align, Area, Context, Frame, Id, Layout, Order, Ui, UiKind, WidgetText};
+use emath::{Align2, Vec2};
+
+/// How long a toast takes to fade out at the end of its lifetime, in seconds.
+const FADE_OUT_TIME: f32 = 0.3;
+
+/// A single transient notification, shown by [`Toasts`].
+#[derive(Clone)]
+pub struct Toast {
+    id: Option<Id>,
+    text: WidgetText,
+    duration: Option<f32>,
+    action: Option<WidgetText>,
+    closable: bool,
+}
+
+impl Toast {
+    pub fn new(text: impl Into<WidgetText>) -> Self {
+        Self {
+            id: None,
+            text: text.into(),
+            duration: Some(4.0),
+            action: None,
+            closable: true,
+        }
+    }
+
+    /// Identify this toast, e.g. to recognize it in the output of [`Toasts::show`].
+    ///
+    /// Adding a toast with the same id as one that is already shown replaces it.
+    ///
+    /// By default a unique id is generated.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// How long to show the toast for, in seconds.
+    ///
+    /// `None` means the toast stays until it is dismissed.
+    ///
+    /// Default: 4 seconds.
+    #[inline]
+    pub fn duration(mut self, seconds: Option<f32>) -> Self {
+        self.duration = seconds;
+        self
+    }
+
+    /// Show an action button in the toast.
+    ///
+    /// Clicking it dismisses the toast and reports its id from [`Toasts::show`].
+    #[inline]
+    pub fn action(mut self, text: impl Into<WidgetText>) -> Self {
+        self.action = Some(text.into());
+        self
+    }
+
+    /// Show a button for dismissing the toast.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+}
+
+#[derive(Clone)]
+struct ToastState {
+    id: Id,
+    toast: Toast,
+
+    /// Seconds left before the toast disappears, if it has a duration.
+    remaining: Option<f32>,
+}
+
+#[derive(Clone, Default)]
+struct ToastsState {
+    toasts: Vec<ToastState>,
+    next_id: u64,
+}
+
+/// Shows a stack of [`Toast`]s in a corner of the screen.
+///
+/// Create one each frame, [`Self::add`] any new toasts and call [`Self::show`].
+/// Toasts are remembered between frames and removed when they expire or are dismissed.
+/// Hovering a toast pauses its timer.
+#[must_use = "You should call .show()"]
+pub struct Toasts {
+    id: Id,
+    anchor: Align2,
+    offset: Vec2,
+    new_toasts: Vec<Toast>,
+}
+
+impl Default for Toasts {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Toasts {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new("__toasts"),
+            anchor: Align2::RIGHT_BOTTOM,
+            offset: Vec2::new(-8.0, -8.0),
+            new_toasts: Vec::new(),
+        }
+    }
+
+    /// Use a different id to keep several independent stacks of toasts.
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id = Id::new(id_salt);
+        self
+    }
+
+    /// Which corner of the screen to stack the toasts in, and how far from it.
+    ///
+    /// Default: bottom right, 8 points from the edges.
+    #[inline]
+    pub fn anchor(mut self, align: Align2, offset: impl Into<Vec2>) -> Self {
+        self.anchor = align;
+        self.offset = offset.into();
+        self
+    }
+
+    /// Queue a new toast.
+    #[inline]
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(mut self, toast: Toast) -> Self {
+        self.new_toasts.push(toast);
+        self
+    }
+
+    /// Show all live toasts.
+    ///
+    /// Returns the ids of the toasts whose action button was clicked.
+    pub fn show(self, ctx: &Context) -> Vec<Id> {
+        let Self {
+            id,
+            anchor,
+            offset,
+            new_toasts,
+        } = self;
+
+        let mut state = ctx.data_mut(|d| d.get_temp::<ToastsState>(id).unwrap_or_default());
+
+        for toast in new_toasts {
+            let toast_id = toast.id.unwrap_or_else(|| {
+                state.next_id += 1;
+                id.with(state.next_id)
+            });
+            state.toasts.retain(|t| t.id != toast_id);
+            state.toasts.push(ToastState {
+                id: toast_id,
+                remaining: toast.duration,
+                toast,
+            });
+        }
+
+        let dt = ctx.input(|i| i.stable_dt).min(0.1);
+        let mut clicked = Vec::new();
+
+        if !state.toasts.is_empty() {
+            let layout = if anchor.y() == Align::Max {
+                Layout::bottom_up(anchor.x())
+            } else {
+                Layout::top_down(anchor.x())
+            };
+
+            Area::new(id)
+                .kind(UiKind::Popup)
+                .order(Order::Foreground)
+                .anchor(anchor, offset)
+                .interactable(true)
+                .show(ctx, |ui| {
+                    ui.with_layout(layout, |ui| {
+                        state.toasts.retain_mut(|toast| {
+                            let keep = show_toast(ui, toast, dt, &mut clicked);
+                            ui.add_space(ui.spacing().item_spacing.y);
+                            keep
+                        });
+                    });
+                });
+        }
+
+        if state.toasts.iter().any(|t| t.remaining.is_some()) {
+            ctx.request_repaint();
+        }
+
+        if state.toasts.is_empty() {
+            ctx.data_mut(|d| d.remove::<ToastsState>(id));
+        } else {
+            ctx.data_mut(|d| d.insert_temp(id, state));
+        }
+
+        clicked
+    }
+}
+
+/// Returns `false` if the toast should be removed.
+fn show_toast(ui: &mut Ui, toast: &mut ToastState, dt: f32, clicked: &mut Vec<Id>) -> bool {
+    let mut keep = true;
+
+    let opacity = toast
+        .remaining
+        .map_or(1.0, |remaining| (remaining / FADE_OUT_TIME).clamp(0.0, 1.0));
+
+    let response = ui
+        .push_id(toast.id, |ui| {
+            ui.set_opacity(opacity);
+            Frame::popup(ui.style())
+                .show(ui, |ui| {
+                    // Always left-to-right, even when stacked in a right corner:
+                    let size = Vec2::new(ui.available_width(), ui.spacing().interact_size.y);
+                    let layout = Layout::left_to_right(Align::Center);
+                    ui.allocate_ui_with_layout(size, layout, |ui| {
+                        ui.label(toast.toast.text.clone());
+                        if let Some(action) = &toast.toast.action {
+                            if ui.button(action.clone()).clicked() {
+                                clicked.push(toast.id);
+                                keep = false;
+                            }
+                        }
+                        if toast.toast.closable && ui.small_button("🗙").clicked() {
+                            keep = false;
+                        }
+                    });
+                })
+                .response
+        })
+        .inner;
+
+    if let Some(remaining) = &mut toast.remaining {
+        if !response.contains_pointer() {
+            *remaining -= dt;
+        }
+        if *remaining <= 0.0 {
+            keep = false;
+        }
+    }
+
+    keep
+}
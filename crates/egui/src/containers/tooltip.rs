@@ -53,6 +53,17 @@ impl Tooltip<'_> {
         tooltip
     }
 
+    /// Show a tooltip when hovering an enabled widget, after `delay` seconds.
+    ///
+    /// This overrides [`crate::style::Interaction::tooltip_delay`] for this tooltip.
+    pub fn for_enabled_with_delay(response: &Response, delay: f32) -> Self {
+        let mut tooltip = Self::for_widget(response);
+        tooltip.popup = tooltip
+            .popup
+            .open(response.enabled() && Self::should_show_tooltip_with_delay(response, delay));
+        tooltip
+    }
+
     /// Show a tooltip when hovering a disabled widget.
     pub fn for_disabled(response: &Response) -> Self {
         let mut tooltip = Self::for_widget(response);
@@ -189,6 +200,15 @@ impl Tooltip<'_> {
 
     /// Should we show a tooltip for this response?
     pub fn should_show_tooltip(response: &Response) -> bool {
+        let tooltip_delay = response.ctx.style().interaction.tooltip_delay;
+        Self::should_show_tooltip_with_delay(response, tooltip_delay)
+    }
+
+    /// Should we show a tooltip for this response, using the given `tooltip_delay` (in seconds)?
+    ///
+    /// Like [`Self::should_show_tooltip`], but overrides
+    /// [`crate::style::Interaction::tooltip_delay`].
+    pub fn should_show_tooltip_with_delay(response: &Response, tooltip_delay: f32) -> bool {
         if response.ctx.memory(|mem| mem.everything_is_visible()) {
             return true;
         }
@@ -205,7 +225,6 @@ impl Tooltip<'_> {
 
         let style = response.ctx.style();
 
-        let tooltip_delay = style.interaction.tooltip_delay;
         let tooltip_grace_time = style.interaction.tooltip_grace_time;
 
         let (
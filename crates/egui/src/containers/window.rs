@@ -36,14 +36,34 @@ use super::{area, resize, Area, Frame, Resize, ScrollArea};
 pub struct Window<'open> {
     title: WidgetText,
     open: Option<&'open mut bool>,
+    pinned: Option<&'open mut bool>,
+    help: Option<Box<dyn FnMut(&mut Ui) + 'open>>,
     area: Area,
     frame: Option<Frame>,
     resize: Resize,
+    aspect_ratio: Option<f32>,
+    snap_size: Option<Box<dyn Fn(Vec2) -> Vec2 + 'open>>,
+    resize_edge_thickness: Option<f32>,
     scroll: ScrollArea,
     collapsible: bool,
     default_open: bool,
+    open_collapsed: Option<bool>,
     with_title_bar: bool,
+    title_bar_side: TitleBarSide,
     fade_out: bool,
+    measure_first: bool,
+    defer_content_during_drag: bool,
+    on_tear_off: Option<Box<dyn FnMut(Rect) + 'open>>,
+    on_shown: Option<Box<dyn FnOnce() + 'open>>,
+    on_hidden: Option<Box<dyn FnOnce() + 'open>>,
+    tear_off_threshold: f32,
+    tear_off_modifiers: Modifiers,
+    keyboard_resize_modifiers: Option<Modifiers>,
+    keyboard_step: (f32, f32),
+    tab_grouping: bool,
+    resize_group: Option<Id>,
+    keep_on_screen_min: Option<Vec2>,
+    dense: bool,
 }
 
 impl<'open> Window<'open> {
@@ -56,17 +76,37 @@ impl<'open> Window<'open> {
         Self {
             title,
             open: None,
+            pinned: None,
+            help: None,
             area,
             frame: None,
             resize: Resize::default()
                 .with_stroke(false)
                 .min_size([96.0, 32.0])
                 .default_size([340.0, 420.0]), // Default inner size of a window
+            aspect_ratio: None,
+            snap_size: None,
+            resize_edge_thickness: None,
             scroll: ScrollArea::neither().auto_shrink(false),
             collapsible: true,
             default_open: true,
+            open_collapsed: None,
             with_title_bar: true,
+            title_bar_side: TitleBarSide::Top,
             fade_out: true,
+            measure_first: false,
+            defer_content_during_drag: false,
+            on_tear_off: None,
+            on_shown: None,
+            on_hidden: None,
+            tear_off_threshold: 32.0,
+            tear_off_modifiers: Modifiers::COMMAND,
+            keyboard_resize_modifiers: None,
+            keyboard_step: (8.0, 64.0),
+            tab_grouping: false,
+            resize_group: None,
+            keep_on_screen_min: None,
+            dense: false,
         }
     }
 
@@ -106,6 +146,30 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Only let the pointer interact with the window inside these rectangles.
+    ///
+    /// See [`Area::interactable_rects`].
+    #[inline]
+    pub fn interactable_rects(mut self, rects: impl IntoIterator<Item = Rect>) -> Self {
+        self.area = self.area.interactable_rects(rects);
+        self
+    }
+
+    /// Only let the pointer interact with the window where `contains` returns `true`.
+    ///
+    /// Use this for non-rectangular windows, like a round HUD.
+    /// Clicks and drags outside of the shape pass through to what is behind the window.
+    /// Combine it with a [`Frame`] without fill and stroke, and paint the shape yourself.
+    ///
+    /// `contains` gets positions relative to the top-left corner of the window.
+    ///
+    /// See [`Area::hit_shape`].
+    #[inline]
+    pub fn hit_shape(mut self, contains: impl Fn(Pos2) -> bool + Send + Sync + 'static) -> Self {
+        self.area = self.area.hit_shape(contains);
+        self
+    }
+
     /// If `false` the window will be immovable.
     #[inline]
     pub fn movable(mut self, movable: bool) -> Self {
@@ -113,6 +177,63 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Along which axes a movable window can be dragged, e.g. `[true, false]` for only horizontally.
+    ///
+    /// See [`Area::movable_axes`].
+    #[inline]
+    pub fn movable_axes(mut self, movable_axes: impl Into<Vec2b>) -> Self {
+        self.area = self.area.movable_axes(movable_axes);
+        self
+    }
+
+    /// Magnetically snap the edges of the window to other windows and the panels while it is dragged.
+    ///
+    /// See [`Area::snap_to_areas`].
+    #[inline]
+    pub fn snap_to_areas(mut self, distance: f32) -> Self {
+        self.area = self.area.snap_to_areas(distance);
+        self
+    }
+
+    /// Only move the window when these modifiers are held down while dragging it.
+    ///
+    /// Plain drags then go to the window contents, or whatever is behind the window.
+    /// See [`Area::drag_with_modifier`] for more.
+    #[inline]
+    pub fn move_modifier(mut self, modifiers: Modifiers) -> Self {
+        self.area = self.area.drag_with_modifier(modifiers);
+        self
+    }
+
+    /// Let the user move the window with the arrow keys while holding down these modifiers,
+    /// and resize it with the arrow keys while holding down ctrl/cmd as well.
+    ///
+    /// This only works while the keyboard focus is in the window.
+    /// Resizing moves the right and bottom edges, within [`Self::min_size`] and [`Self::max_size`].
+    /// Holding down shift as well uses the larger step, see [`Self::keyboard_step`].
+    ///
+    /// For instance, with `.keyboard_move_and_resize(Modifiers::ALT)`,
+    /// alt+arrows move the window and alt+ctrl+arrows resize it.
+    ///
+    /// Default: off.
+    #[inline]
+    pub fn keyboard_move_and_resize(mut self, modifiers: Modifiers) -> Self {
+        self.area = self.area.keyboard_movable(modifiers);
+        self.keyboard_resize_modifiers = Some(modifiers.plus(Modifiers::COMMAND));
+        self
+    }
+
+    /// How many points each arrow key press moves or resizes the window
+    /// with [`Self::keyboard_move_and_resize`], and how many when shift is held down as well.
+    ///
+    /// Default: `8.0` and `64.0`.
+    #[inline]
+    pub fn keyboard_step(mut self, step: f32, large_step: f32) -> Self {
+        self.area = self.area.keyboard_step(step, large_step);
+        self.keyboard_step = (step, large_step);
+        self
+    }
+
     /// `order(Order::Foreground)` for a Window that should always be on top
     #[inline]
     pub fn order(mut self, order: Order) -> Self {
@@ -120,6 +241,68 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Keep the window above all normal windows, even when they are clicked.
+    ///
+    /// The window stays movable and resizable. Windows that are on top are still
+    /// brought to the front among themselves when interacted with.
+    ///
+    /// This is the same as `order(Order::Foreground)` if `true`, and `order(Order::Middle)` if `false`.
+    ///
+    /// See also [`Self::pin_button`] to let the user decide.
+    #[inline]
+    pub fn always_on_top(self, always_on_top: bool) -> Self {
+        self.order(if always_on_top {
+            Order::Foreground
+        } else {
+            Order::Middle
+        })
+    }
+
+    /// Call this to add a pin-button to the window title bar, for keeping the window on top.
+    ///
+    /// * If `*pinned == true`, the window is shown as if [`Self::always_on_top`] was set.
+    /// * Clicking the pin button toggles `*pinned`.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let mut pinned = false;
+    /// egui::Window::new("Palette")
+    ///     .pin_button(&mut pinned)
+    ///     .show(ctx, |ui| {
+    ///         ui.label("Tools");
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn pin_button(mut self, pinned: &'open mut bool) -> Self {
+        self.pinned = Some(pinned);
+        self
+    }
+
+    /// Call this to add a help-button ("?") to the window title bar.
+    ///
+    /// Clicking it toggles a popover below the button showing `add_help`.
+    /// The popover is closed by clicking the button again, or anywhere outside of it.
+    ///
+    /// Whether the help is shown is remembered per window.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// egui::Window::new("Settings")
+    ///     .help(|ui| {
+    ///         ui.label("Changes are applied immediately.");
+    ///     })
+    ///     .show(ctx, |ui| {
+    ///         ui.label("Settings go here");
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn help(mut self, add_help: impl FnMut(&mut Ui) + 'open) -> Self {
+        self.help = Some(Box::new(add_help));
+        self
+    }
+
     /// If `true`, quickly fade in the `Window` when it first appears.
     ///
     /// Default: `true`.
@@ -140,6 +323,117 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// If `true`, lay out the window again before painting it whenever its size changed.
+    ///
+    /// An auto-sized window only knows its size after its contents have been added,
+    /// so normally it is shown one frame with its old size (and position) after the contents grow or shrink,
+    /// and spends its first frame invisible while measuring itself.
+    /// With this, the change is instead detected and the pass discarded
+    /// (see [`Context::request_discard`]), so the window is correct on the first frame it is shown.
+    ///
+    /// This is not free: the contents closure can be run twice in the same frame,
+    /// as is the rest of your ui, so make sure it has no side effects that must only happen once.
+    /// At most [`crate::Options::max_passes`] passes are run per frame.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn measure_first(mut self, measure_first: bool) -> Self {
+        self.measure_first = measure_first;
+        self
+    }
+
+    /// If `true`, don't add the contents while the user is moving or resizing the window.
+    ///
+    /// Only the frame and title bar are shown, at the size of the window,
+    /// and the contents are laid out again once the user lets go.
+    /// This is useful for windows with contents that are expensive to lay out,
+    /// such as a large [`ScrollArea`] or a plot.
+    ///
+    /// While the contents are deferred, the `inner` of the returned [`InnerResponse`] is `None`,
+    /// just as when the window is collapsed.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn defer_content_during_drag(mut self, defer_content_during_drag: bool) -> Self {
+        self.defer_content_during_drag = defer_content_during_drag;
+        self
+    }
+
+    /// Let the user "tear off" the window into a native OS window.
+    ///
+    /// The callback is called once per drag, when the user drags the window
+    /// while holding down [`Self::tear_off_modifiers`], and the pointer goes more than
+    /// [`Self::tear_off_threshold`] points past the edge of the screen.
+    ///
+    /// It is given the rectangle the window would have if it followed the pointer,
+    /// in the points of the current viewport (so it is partly outside [`Context::screen_rect`]).
+    /// egui can't create OS windows by itself, so it's up to you to act on it,
+    /// e.g. by closing this window and showing its contents in a new viewport
+    /// with [`Context::show_viewport_immediate`], positioned with [`ViewportBuilder::with_position`]
+    /// (remember to add the position of the current viewport, see [`ViewportInfo::outer_rect`]).
+    #[inline]
+    pub fn on_tear_off(mut self, on_tear_off: impl FnMut(Rect) + 'open) -> Self {
+        self.on_tear_off = Some(Box::new(on_tear_off));
+        self
+    }
+
+    /// Called once when the window becomes fully visible,
+    /// i.e. after it is opened and done fading in (see [`Self::fade_out`]).
+    ///
+    /// It is called again only after the window has been fully hidden,
+    /// see [`Self::on_hidden`].
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let mut open = true;
+    /// let polling = std::cell::Cell::new(false);
+    /// egui::Window::new("Status")
+    ///     .open(&mut open)
+    ///     .on_shown(|| polling.set(true))
+    ///     .on_hidden(|| polling.set(false))
+    ///     .show(ctx, |ui| {
+    ///         ui.label("Polling the server…");
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn on_shown(mut self, on_shown: impl FnOnce() + 'open) -> Self {
+        self.on_shown = Some(Box::new(on_shown));
+        self
+    }
+
+    /// Called once when the window becomes fully hidden,
+    /// i.e. after it is closed and done fading out, if it was fully visible before.
+    ///
+    /// This only works if you use [`Self::open`] to close the window,
+    /// and keep calling [`Self::show`] while it is closed.
+    /// A window that you simply stop showing can't tell that it was hidden.
+    #[inline]
+    pub fn on_hidden(mut self, on_hidden: impl FnOnce() + 'open) -> Self {
+        self.on_hidden = Some(Box::new(on_hidden));
+        self
+    }
+
+    /// How far past the edge of the screen the pointer must be dragged to tear off the window,
+    /// see [`Self::on_tear_off`].
+    ///
+    /// Default: `32.0`.
+    #[inline]
+    pub fn tear_off_threshold(mut self, tear_off_threshold: f32) -> Self {
+        self.tear_off_threshold = tear_off_threshold;
+        self
+    }
+
+    /// Which modifier keys must be held down while dragging to tear off the window,
+    /// see [`Self::on_tear_off`].
+    ///
+    /// Default: [`Modifiers::COMMAND`].
+    #[inline]
+    pub fn tear_off_modifiers(mut self, tear_off_modifiers: Modifiers) -> Self {
+        self.tear_off_modifiers = tear_off_modifiers;
+        self
+    }
+
     /// Usage: `Window::new(…).mutate(|w| w.resize = w.resize.auto_expand_width(true))`
     // TODO(emilk): I'm not sure this is a good interface for this.
     #[inline]
@@ -205,6 +499,82 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Keep the width/height ratio of the window contents fixed while the user resizes the window.
+    ///
+    /// Dragging a corner resizes along the ratio,
+    /// and dragging an edge adjusts the other dimension to match.
+    /// The result is kept within [`Self::min_size`] and [`Self::max_size`] where possible.
+    ///
+    /// This only constrains resizing by the user; the initial size is up to you,
+    /// e.g. via [`Self::default_size`].
+    #[inline]
+    pub fn lock_aspect_ratio(mut self, aspect_ratio: f32) -> Self {
+        self.aspect_ratio = Some(aspect_ratio);
+        self
+    }
+
+    /// Round the size of the window contents to the nearest multiple of `step` while the user resizes it.
+    ///
+    /// The sides that are not being dragged stay put.
+    /// See [`Resize::size_step`] for details, and [`Self::size_step_disable_modifiers`]
+    /// for how to temporarily turn it off.
+    /// [`Self::lock_aspect_ratio`] takes precedence over this.
+    #[inline]
+    pub fn size_step(mut self, step: impl Into<Vec2>) -> Self {
+        self.resize = self.resize.size_step(step);
+        self
+    }
+
+    /// Holding down these modifiers while resizing temporarily disables [`Self::size_step`].
+    ///
+    /// Default: [`Modifiers::NONE`], i.e. snapping can't be disabled.
+    #[inline]
+    pub fn size_step_disable_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.resize = self.resize.size_step_disable_modifiers(modifiers);
+        self
+    }
+
+    /// Adjust the size of the window contents picked by the user while they resize it.
+    ///
+    /// `snap_size` is given the proposed size of the contents, after [`Self::size_step`]
+    /// and clamping to the min/max size, and returns the size to use instead.
+    /// The window is then sized to fit it, and the sides that are not being dragged stay put.
+    ///
+    /// For instance, for a terminal that should always show whole character cells:
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// let cell = egui::vec2(8.0, 16.0);
+    /// egui::Window::new("Terminal")
+    ///     .snap_size(move |size| (size / cell).floor().max(egui::Vec2::splat(1.0)) * cell)
+    ///     .show(ctx, |ui| {
+    ///         ui.label("$");
+    ///     });
+    /// # });
+    /// ```
+    ///
+    /// [`Self::lock_aspect_ratio`] takes precedence over this.
+    #[inline]
+    pub fn snap_size(mut self, snap_size: impl Fn(Vec2) -> Vec2 + 'open) -> Self {
+        self.snap_size = Some(Box::new(snap_size));
+        self
+    }
+
+    /// How wide the band along the window edges is where the user can grab to resize the window.
+    ///
+    /// The band is centered on the edge, so half of it is outside the window.
+    /// This only changes what the pointer can hit; the visible border stays the same.
+    /// Corners get at least as large a grab area as the edges.
+    ///
+    /// Making this larger is helpful for touch screens and high-DPI displays.
+    ///
+    /// Default: twice [`crate::style::Interaction::resize_grab_radius_side`].
+    #[inline]
+    pub fn resize_edge_thickness(mut self, thickness: f32) -> Self {
+        self.resize_edge_thickness = Some(thickness);
+        self
+    }
+
     /// Set current position of the window.
     /// If the window is movable it is up to you to keep track of where it moved to!
     #[inline]
@@ -247,6 +617,19 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// When not [constrained](Self::constrain), how much of the top of the window
+    /// must stay within the screen (or [`Self::constrain_to`]) while dragging it around.
+    ///
+    /// This makes sure the window can't be dragged out of reach,
+    /// and nudges it back in when the screen shrinks.
+    ///
+    /// Default: enough of the title bar to grab it.
+    #[inline]
+    pub fn keep_on_screen_min(mut self, min: impl Into<Vec2>) -> Self {
+        self.keep_on_screen_min = Some(min.into());
+        self
+    }
+
     /// Where the "root" of the window is.
     ///
     /// For instance, if you set this to [`Align2::RIGHT_TOP`]
@@ -277,13 +660,43 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Keep the window at the same distance from a corner of the screen when the screen
+    /// is resized, while still letting the user move it.
+    ///
+    /// See [`Area::movable_anchor`] for more.
+    #[inline]
+    pub fn movable_anchor(mut self, align: Align2, default_offset: impl Into<Vec2>) -> Self {
+        self.area = self.area.movable_anchor(align, default_offset);
+        self
+    }
+
     /// Set initial collapsed state of the window
+    ///
+    /// `false` means the window starts out collapsed to just its title bar.
+    /// See [`Self::open_collapsed`] for how collapsing interacts with resizing.
     #[inline]
     pub fn default_open(mut self, default_open: bool) -> Self {
         self.default_open = default_open;
         self
     }
 
+    /// Calling `.open_collapsed(Some(true))` will collapse the window this frame (or keep it collapsed).
+    ///
+    /// Calling `.open_collapsed(Some(false))` will expand the window this frame (or keep it expanded).
+    ///
+    /// Calling `.open_collapsed(None)` has no effect (default).
+    ///
+    /// To collapse or expand a window from elsewhere, e.g. a menu, use [`Self::set_collapsed`].
+    ///
+    /// A collapsed window only shows its title bar, and can be moved but not resized.
+    /// A resizable window remembers its size and gets it back when expanded,
+    /// while an auto-sized window grows to fit its contents again.
+    #[inline]
+    pub fn open_collapsed(mut self, collapsed: Option<bool>) -> Self {
+        self.open_collapsed = collapsed;
+        self
+    }
+
     /// Set initial size of the window.
     #[inline]
     pub fn default_size(mut self, default_size: impl Into<Vec2>) -> Self {
@@ -357,6 +770,67 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Let the user merge this window with other such windows into one tabbed window,
+    /// by dragging it by its title bar onto the title bar of another.
+    ///
+    /// The title bar of the merged window becomes a strip of tabs, one per window,
+    /// of which the selected one is shown.
+    /// Drag a tab out of the strip to split that window off again.
+    /// Closing a window (see [`Self::open`]) also takes it out of its tab group.
+    ///
+    /// The grouping is remembered by the ids of the windows, see [`WindowTabGroups`].
+    /// The tabs are only shown in a horizontal title bar (see [`Self::title_bar_side`]);
+    /// in a vertical one, only the title of the selected window is shown.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn tab_grouping(mut self, tab_grouping: bool) -> Self {
+        self.tab_grouping = tab_grouping;
+        self
+    }
+
+    /// Use [`crate::Spacing::dense`] for the contents of this window,
+    /// e.g. to make a property inspector tighter than the rest of the UI.
+    ///
+    /// Only the contents are affected; the title bar and the global style are left as they are.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn dense(mut self, dense: bool) -> Self {
+        self.dense = dense;
+        self
+    }
+
+    /// Resize this window together with the other windows of the same group that it is flush with.
+    ///
+    /// Dragging an edge that another window of the group lies against (e.g. the right edge of
+    /// this window and the left edge of the other) moves the edge of both windows,
+    /// like the divider of a split, so they stay flush.
+    /// The edge stops where one of the windows reaches its minimum size.
+    ///
+    /// This lets you tile windows by hand, e.g. side by side with [`Self::default_rect`],
+    /// without a full docking system.
+    ///
+    /// Default: `None`, i.e. only this window is resized.
+    #[inline]
+    pub fn resize_group(mut self, group: impl std::hash::Hash) -> Self {
+        self.resize_group = Some(Id::new(group));
+        self
+    }
+
+    /// Which edge of the window the title bar is on.
+    ///
+    /// The collapse-, close-, pin- and help-buttons go along with it,
+    /// and the body of the window fills the rest.
+    /// On the left or right edge the title bar is vertical, with the title reading upwards.
+    ///
+    /// Default: [`TitleBarSide::Top`].
+    #[inline]
+    pub fn title_bar_side(mut self, side: TitleBarSide) -> Self {
+        self.title_bar_side = side;
+        self
+    }
+
     /// Not resizable, just takes the size of its contents.
     /// Also disabled scrolling.
     /// Text will not wrap, but will instead make your window width expand.
@@ -370,6 +844,12 @@ impl<'open> Window<'open> {
     /// Enable/disable horizontal/vertical scrolling. `false` by default.
     ///
     /// You can pass in `false`, `true`, `[false, true]` etc.
+    ///
+    /// The body of the window is then wrapped in a [`ScrollArea`] that fills the window,
+    /// and follows its size when the user resizes it.
+    /// The size of the window is decided by [`Self::default_size`] and the user,
+    /// not by the contents, so there is no need to add a [`ScrollArea`] of your own
+    /// (which would give you double scroll bars).
     #[inline]
     pub fn scroll(mut self, scroll: impl Into<Vec2b>) -> Self {
         self.scroll = self.scroll.scroll(scroll);
@@ -407,17 +887,341 @@ impl<'open> Window<'open> {
         self
     }
 
-    /// Sets the [`ScrollBarVisibility`] of the window.
+    /// Sets the [`ScrollBarVisibility`] of the window, for both axes or as `[horizontal, vertical]`.
+    ///
+    /// See [`ScrollArea::scroll_bar_visibility`] for more.
     #[inline]
-    pub fn scroll_bar_visibility(mut self, visibility: ScrollBarVisibility) -> Self {
+    pub fn scroll_bar_visibility(
+        mut self,
+        visibility: impl Into<[ScrollBarVisibility; 2]>,
+    ) -> Self {
         self.scroll = self.scroll.scroll_bar_visibility(visibility);
         self
     }
 }
 
+/// Which edge of a [`Window`] its title bar is on.
+///
+/// See [`Window::title_bar_side`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TitleBarSide {
+    /// Above the body of the window.
+    #[default]
+    Top,
+
+    /// Below the body of the window.
+    Bottom,
+
+    /// A vertical bar left of the body of the window.
+    Left,
+
+    /// A vertical bar right of the body of the window.
+    Right,
+}
+
+impl TitleBarSide {
+    fn is_vertical(self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
+
+    fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    /// The size taken up by a title bar of the given thickness.
+    fn thickness_vec(self, thickness: f32) -> Vec2 {
+        if self.is_vertical() {
+            vec2(thickness, 0.0)
+        } else {
+            vec2(0.0, thickness)
+        }
+    }
+
+    /// The length of a title bar on this edge, along the edge, for the given window `size`.
+    fn length(self, size: Vec2) -> f32 {
+        if self.is_vertical() {
+            size.y
+        } else {
+            size.x
+        }
+    }
+
+    /// The two corners on this edge.
+    fn corners(self, corner_radius: &mut CornerRadius) -> [&mut u8; 2] {
+        let CornerRadius { nw, ne, sw, se } = corner_radius;
+        match self {
+            Self::Top => [nw, ne],
+            Self::Bottom => [sw, se],
+            Self::Left => [nw, sw],
+            Self::Right => [ne, se],
+        }
+    }
+
+    /// The part of `rect` covered by a title bar of the given thickness.
+    fn bar_rect(self, rect: Rect, thickness: f32) -> Rect {
+        let mut bar = rect;
+        match self {
+            Self::Top => bar.max.y = rect.min.y + thickness,
+            Self::Bottom => bar.min.y = rect.max.y - thickness,
+            Self::Left => bar.max.x = rect.min.x + thickness,
+            Self::Right => bar.min.x = rect.max.x - thickness,
+        }
+        bar
+    }
+}
+
+/// Information about a [`Window`] that was shown, as returned by [`Context::windows`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WindowInfo {
+    /// The id of the window, as given to [`Window::id`], or `Id::new(title)` by default.
+    pub id: Id,
+
+    /// The layer of the window, e.g. for [`Context::move_to_top`].
+    pub layer_id: LayerId,
+
+    /// The title text of the window.
+    pub title: String,
+
+    /// The outer rectangle of the window, including the title bar.
+    pub rect: Rect,
+
+    /// Was the window collapsed to just its title bar?
+    pub collapsed: bool,
+
+    /// The title bar of the window, if other windows can be dropped onto it
+    /// to merge them into a tab group, see [`Window::tab_grouping`].
+    pub tab_bar_rect: Option<Rect>,
+}
+
+/// Where the user left a [`Window`], as returned by [`Context::window_state`].
+///
+/// Save this with your app and restore it with [`Context::set_window_state`]
+/// to put the window back where it was.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct WindowState {
+    /// The left-top corner of the window, including the title bar.
+    pub pos: Pos2,
+
+    /// The inner size of the window, as in [`Window::default_size`].
+    ///
+    /// `None` if the window has never been expanded, in which case it keeps its default size.
+    pub size: Option<Vec2>,
+
+    /// Is the window collapsed to just its title bar?
+    pub collapsed: bool,
+}
+
+impl WindowState {
+    pub(crate) fn load(ctx: &Context, id: Id) -> Option<Self> {
+        let area = area::AreaState::load(ctx, id)?;
+        let resize = resize::State::load(ctx, id.with("resize"));
+        Some(Self {
+            pos: area.left_top_pos(),
+            size: resize.map(|state| state.desired_size),
+            collapsed: Window::is_collapsed(ctx, id),
+        })
+    }
+
+    pub(crate) fn store(self, ctx: &Context, id: Id) {
+        let Self {
+            pos,
+            size,
+            collapsed,
+        } = self;
+        set_next_pos(ctx, id, pos);
+        if let Some(size) = size {
+            resize::State::request_size(ctx, id.with("resize"), size);
+        }
+        Window::set_collapsed(ctx, id, collapsed);
+    }
+}
+
+/// Which [`Window`]s the user merged into tabbed windows, see [`Window::tab_grouping`].
+///
+/// This is persisted, by the ids of the windows.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct WindowTabGroups {
+    pub groups: Vec<WindowTabGroup>,
+}
+
+/// Several [`Window`]s shown as one window, with a tab for each, see [`Window::tab_grouping`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct WindowTabGroup {
+    /// The id of the [`Area`] of the tabbed window, as listed in [`Context::windows`].
+    pub id: Id,
+
+    /// The ids of the windows, in tab order.
+    pub tabs: Vec<Id>,
+
+    /// Index of the window that is currently shown.
+    pub active: usize,
+}
+
+impl WindowTabGroups {
+    fn id() -> Id {
+        Id::new("egui::WindowTabGroups")
+    }
+
+    pub fn load(ctx: &Context) -> Self {
+        ctx.data_mut(|d| d.get_persisted(Self::id()))
+            .unwrap_or_default()
+    }
+
+    pub fn store(self, ctx: &Context) {
+        ctx.data_mut(|d| d.insert_persisted(Self::id(), self));
+    }
+
+    /// The tab group that the window with this id is in, if any.
+    pub fn group_of(&self, window_id: Id) -> Option<&WindowTabGroup> {
+        self.groups
+            .iter()
+            .find(|group| group.tabs.contains(&window_id))
+    }
+
+    /// Merge the windows shown in the area `source` into the window (or tab group) shown in the area `target`.
+    ///
+    /// Returns the id of the new tab group, if one was created.
+    fn merge(&mut self, source: Id, source_tabs: &[Id], target: Id) -> Option<Id> {
+        self.groups.retain(|group| group.id != source);
+        if let Some(group) = self.groups.iter_mut().find(|group| group.id == target) {
+            group.tabs.extend_from_slice(source_tabs);
+            group.active = group.tabs.len() - 1;
+            None
+        } else {
+            let id = target.with("window_tab_group");
+            let mut tabs = vec![target];
+            tabs.extend_from_slice(source_tabs);
+            self.groups.push(WindowTabGroup {
+                id,
+                active: tabs.len() - 1,
+                tabs,
+            });
+            Some(id)
+        }
+    }
+
+    /// Take the window out of its tab group, dissolving the group if only one window is left.
+    ///
+    /// The windows that leave the group are put where the group was.
+    fn remove(&mut self, ctx: &Context, window_id: Id) {
+        let Some(index) = self
+            .groups
+            .iter()
+            .position(|group| group.tabs.contains(&window_id))
+        else {
+            return;
+        };
+        let group = &mut self.groups[index];
+        let group_id = group.id;
+        if let Some(tab) = group.tabs.iter().position(|&tab| tab == window_id) {
+            group.tabs.remove(tab);
+            if tab < group.active {
+                group.active -= 1;
+            }
+        }
+        group.active = group.active.min(group.tabs.len().saturating_sub(1));
+
+        let mut left = vec![window_id];
+        if group.tabs.len() <= 1 {
+            left.extend(self.groups.remove(index).tabs);
+        }
+        if let Some(rect) = ctx.memory(|mem| mem.area_rect(group_id)) {
+            for window_id in left {
+                set_next_pos(ctx, window_id, rect.min);
+            }
+        }
+    }
+}
+
+/// Handle the user selecting, detaching and merging windows of tab groups, see [`Window::tab_grouping`].
+///
+/// `area_id` is the id of the area that the window is shown in, which is that of the `tab_group` if it is in one.
+fn update_tab_groups(
+    ctx: &Context,
+    window_id: Id,
+    area_id: Id,
+    tab_group: Option<&WindowTabGroup>,
+    tab_action: Option<TabAction>,
+    response: &Response,
+) {
+    let mut groups = WindowTabGroups::load(ctx);
+    let mut changed = false;
+
+    if let Some(group) = tab_group {
+        match tab_action {
+            Some(TabAction::Select(index)) => {
+                if let Some(stored) = groups.groups.iter_mut().find(|g| g.id == group.id) {
+                    stored.active = index;
+                    changed = true;
+                }
+            }
+            Some(TabAction::Detach(index, pos)) => {
+                if let Some(&tab) = group.tabs.get(index) {
+                    groups.remove(ctx, tab);
+                    set_next_pos(ctx, tab, pos);
+                    changed = true;
+                }
+            }
+            None => {}
+        }
+    }
+
+    // Dropping the window onto the title bar of another one merges them:
+    if response.drag_stopped() {
+        let target = ctx.pointer_interact_pos().and_then(|pointer| {
+            ctx.windows().into_iter().rev().find(|info| {
+                info.id != area_id && info.tab_bar_rect.is_some_and(|rect| rect.contains(pointer))
+            })
+        });
+        if let Some(target) = target {
+            let source_tabs = tab_group.map_or_else(|| vec![window_id], |group| group.tabs.clone());
+            if let Some(new_group) = groups.merge(area_id, &source_tabs, target.id) {
+                set_next_pos(ctx, new_group, target.rect.min);
+            }
+            changed = true;
+        }
+    }
+
+    if changed {
+        groups.store(ctx);
+        ctx.request_repaint();
+    }
+}
+
+/// Move the window (or tab group) with the area `id` here the next time it is shown.
+fn set_next_pos(ctx: &Context, id: Id, pos: Pos2) {
+    ctx.data_mut(|d| d.insert_temp(id.with("window_next_pos"), pos));
+}
+
 impl Window<'_> {
+    /// Is the window with the given id collapsed?
+    ///
+    /// The id is the one given to [`Self::id`], or `Id::new(title)` by default.
+    pub fn is_collapsed(ctx: &Context, id: Id) -> bool {
+        CollapsingState::load(ctx, id.with("collapsing")).is_some_and(|state| !state.is_open())
+    }
+
+    /// Collapse or expand the window with the given id, with an animation.
+    ///
+    /// The id is the one given to [`Self::id`], or `Id::new(title)` by default.
+    pub fn set_collapsed(ctx: &Context, id: Id, collapsed: bool) {
+        ctx.set_collapsing_open(id.with("collapsing"), !collapsed);
+    }
+
     /// Returns `None` if the window is not open (if [`Window::open`] was called with `&mut false`).
-    /// Returns `Some(InnerResponse { inner: None })` if the window is collapsed.
+    /// Returns `Some(InnerResponse { inner: None })` if the window is collapsed,
+    /// see also [`Self::is_collapsed`].
+    ///
+    /// Use [`Response::moved`] and [`Response::resized`] on the returned response
+    /// to find out if the user moved or resized the window this frame.
     #[inline]
     pub fn show<R>(
         self,
@@ -435,20 +1239,76 @@ impl Window<'_> {
         let Window {
             title,
             mut open,
-            area,
+            pinned,
+            mut help,
+            mut area,
             frame,
             resize,
+            aspect_ratio,
+            snap_size,
+            resize_edge_thickness,
             scroll,
             collapsible,
             default_open,
+            open_collapsed,
             with_title_bar,
+            title_bar_side,
             fade_out,
+            measure_first,
+            defer_content_during_drag,
+            mut on_tear_off,
+            on_shown,
+            on_hidden,
+            tear_off_threshold,
+            tear_off_modifiers,
+            keyboard_resize_modifiers,
+            keyboard_step,
+            tab_grouping,
+            resize_group,
+            keep_on_screen_min,
+            dense,
         } = self;
 
+        let window_id = area.id;
+        let mut tab_group = None;
+        if tab_grouping {
+            // Remembered for the tab strip, which may be shown by another window of the group:
+            ctx.data_mut(|d| {
+                d.insert_temp(window_id.with("window_tab_title"), title.text().to_owned());
+            });
+            let mut groups = WindowTabGroups::load(ctx);
+            if let Some(group) = groups.group_of(window_id) {
+                if matches!(open, Some(false)) {
+                    groups.remove(ctx, window_id);
+                    groups.store(ctx);
+                } else if group.tabs.get(group.active) == Some(&window_id) {
+                    // We show the whole group:
+                    area = area.id(group.id);
+                    tab_group = Some(group.clone());
+                } else {
+                    return None;
+                }
+            }
+        }
+        if let Some(pos) = ctx.data_mut(|d| d.remove_temp::<Pos2>(area.id.with("window_next_pos")))
+        {
+            if area.is_movable() {
+                // A `fixed_pos` wins
+                area = area.current_pos(pos);
+            }
+        }
+
         let header_color =
             frame.map_or_else(|| ctx.style().visuals.widgets.open.weak_bg_fill, |f| f.fill);
         let mut window_frame = frame.unwrap_or_else(|| Frame::window(&ctx.style()));
 
+        let keep_on_screen_min = keep_on_screen_min.unwrap_or_else(|| {
+            // Enough of the title bar to grab it:
+            let height = ctx.style().spacing.interact_size.y + window_frame.inner_margin.sum().y;
+            Vec2::new(3.0 * height, height)
+        });
+        area = area.keep_on_screen_min(keep_on_screen_min);
+
         let is_explicitly_closed = matches!(open, Some(false));
         let is_open = !is_explicitly_closed || ctx.memory(|mem| mem.everything_is_visible());
         let opacity = ctx.animate_bool_with_easing(
@@ -457,14 +1317,38 @@ impl Window<'_> {
             emath::easing::cubic_out,
         );
         if opacity <= 0.0 {
+            let was_visible = ctx.memory_mut(|mem| {
+                mem.areas_mut()
+                    .get_mut(area.id)
+                    .is_some_and(|state| std::mem::take(&mut state.fully_visible))
+            });
+            if let Some(on_hidden) = on_hidden.filter(|_| was_visible) {
+                on_hidden();
+            }
             return None;
         }
 
+        if pinned.as_deref() == Some(&true) {
+            area = area.order(Order::Foreground);
+        }
+        if pinned.is_some() {
+            // (Un)pinning moves the window to another layer; don't fade it in again there.
+            let layer_id_last_pass = area.id.with("pinned_layer");
+            let previous = ctx.data_mut(|d| d.get_temp::<LayerId>(layer_id_last_pass));
+            if previous.is_some_and(|previous| previous != area.layer()) {
+                area = area.fade_in(false);
+            }
+            ctx.data_mut(|d| d.insert_temp(layer_id_last_pass, area.layer()));
+        }
+
         let area_id = area.id;
         let area_layer_id = area.layer();
         let resize_id = area_id.with("resize");
         let mut collapsing =
             CollapsingState::load_with_default_open(ctx, area_id.with("collapsing"), default_open);
+        if let Some(collapsed) = open_collapsed {
+            collapsing.set_open(!collapsed);
+        }
 
         let is_collapsed = with_title_bar && !collapsing.is_open();
         let possible = PossibleInteractions::new(&area, &resize, is_collapsed);
@@ -473,9 +1357,16 @@ impl Window<'_> {
         let mut resize = resize.id(resize_id);
 
         let on_top = Some(area_layer_id) == ctx.top_layer_id();
+        let last_frame_area_rect = area::AreaState::load(ctx, area_id).map(|state| state.rect());
         let mut area = area.begin(ctx);
 
+        let became_visible = 1.0 <= opacity && !area.sizing_pass() && !area.state().fully_visible;
+        if became_visible {
+            area.state_mut().fully_visible = true;
+        }
+
         area.with_widget_info(|| WidgetInfo::labeled(WidgetType::Window, true, title.text()));
+        let title_text = title.text().to_owned();
 
         // Calculate roughly how much larger the full window inner size is compared to the content rect
         let (title_bar_height_with_margin, title_content_spacing) = if with_title_bar {
@@ -485,8 +1376,9 @@ impl Window<'_> {
                 .at_least(style.spacing.interact_size.y);
             let title_bar_inner_height = title_bar_inner_height + window_frame.inner_margin.sum().y;
             let half_height = (title_bar_inner_height / 2.0).round() as _;
-            window_frame.corner_radius.ne = window_frame.corner_radius.ne.clamp(0, half_height);
-            window_frame.corner_radius.nw = window_frame.corner_radius.nw.clamp(0, half_height);
+            for corner in title_bar_side.corners(&mut window_frame.corner_radius) {
+                *corner = (*corner).clamp(0, half_height);
+            }
 
             let title_content_spacing = if is_collapsed {
                 0.0
@@ -501,11 +1393,10 @@ impl Window<'_> {
         {
             // Prevent window from becoming larger than the constrain rect.
             let constrain_rect = area.constrain_rect();
-            let max_width = constrain_rect.width();
-            let max_height =
-                constrain_rect.height() - title_bar_height_with_margin - title_content_spacing;
-            resize.max_size.x = resize.max_size.x.min(max_width);
-            resize.max_size.y = resize.max_size.y.min(max_height);
+            let max_size = constrain_rect.size()
+                - title_bar_side
+                    .thickness_vec(title_bar_height_with_margin + title_content_spacing);
+            resize.max_size = resize.max_size.min(max_size);
         }
 
         // First check for resize to avoid frame delay:
@@ -514,26 +1405,51 @@ impl Window<'_> {
             resize_interaction(
                 ctx,
                 possible,
+                resize_edge_thickness,
                 area_layer_id,
                 last_frame_outer_rect,
                 window_frame,
             )
         });
 
+        let margins = window_frame.total_margin().sum()
+            + title_bar_side.thickness_vec(title_bar_height_with_margin + title_content_spacing);
         {
-            let margins = window_frame.total_margin().sum()
-                + vec2(0.0, title_bar_height_with_margin + title_content_spacing);
-
             resize_response(
                 resize_interaction,
                 ctx,
                 margins,
+                &resize,
+                aspect_ratio,
+                snap_size.as_deref(),
+                resize_group,
                 area_layer_id,
                 &mut area,
                 resize_id,
             );
+
+            if let Some(modifiers) = keyboard_resize_modifiers {
+                if area::has_focus_within(ctx, area_layer_id) {
+                    keyboard_resize(
+                        ctx,
+                        modifiers,
+                        keyboard_step,
+                        possible,
+                        margins,
+                        &resize,
+                        &mut area,
+                        resize_id,
+                    );
+                }
+            }
         }
 
+        let is_resizing = resize_interaction.any_dragged();
+        let defer_content = defer_content_during_drag
+            && (is_resizing || ctx.is_being_dragged(area_layer_id.id.with("move")));
+        let last_content_size =
+            resize::State::load(ctx, resize_id).map_or(Vec2::ZERO, |state| state.last_content_size);
+
         let mut area_content_ui = area.content_ui(ctx);
         if is_open {
             // `Area` already takes care of fade-in animations,
@@ -542,32 +1458,65 @@ impl Window<'_> {
             area_content_ui.multiply_opacity(opacity);
         }
 
+        let mut tab_action = None;
+        let mut tab_bar_rect = None;
         let content_inner = {
             ctx.with_accessibility_parent(area.id(), || {
                 // BEGIN FRAME --------------------------------
                 let mut frame = window_frame.begin(&mut area_content_ui);
 
-                let show_close_button = open.is_some();
+                let num_right_buttons = usize::from(open.is_some())
+                    + usize::from(pinned.is_some())
+                    + usize::from(help.is_some());
 
                 let where_to_put_header_background = &area_content_ui.painter().add(Shape::Noop);
 
                 let title_bar = if with_title_bar {
-                    let title_bar = TitleBar::new(
+                    let mut title_bar = TitleBar::new(
                         &frame.content_ui,
                         title,
-                        show_close_button,
+                        title_bar_side,
+                        num_right_buttons,
                         collapsible,
                         window_frame,
                         title_bar_height_with_margin,
+                        title_bar_side.length(resize.max_size),
                     );
-                    resize.min_size.x = resize.min_size.x.at_least(title_bar.inner_rect.width()); // Prevent making window smaller than title bar width
+                    if let Some(group) = &tab_group {
+                        let tabs = group
+                            .tabs
+                            .iter()
+                            .map(|&tab| {
+                                let title = ctx
+                                    .data(|d| d.get_temp::<String>(tab.with("window_tab_title")))
+                                    .unwrap_or_default();
+                                (tab, WidgetText::from(title))
+                            })
+                            .collect();
+                        title_bar.set_tabs(
+                            &frame.content_ui,
+                            tabs,
+                            group.active,
+                            title_bar_side.length(resize.max_size),
+                        );
+                    }
+                    // Prevent making window smaller than the length of the title bar:
+                    if title_bar_side.is_vertical() {
+                        resize.min_size.y =
+                            resize.min_size.y.at_least(title_bar.inner_rect.height());
+                    } else {
+                        resize.min_size.x =
+                            resize.min_size.x.at_least(title_bar.inner_rect.width());
+                    }
 
                     frame.content_ui.set_min_size(title_bar.inner_rect.size());
 
                     // Skip the title bar (and separator):
                     if is_collapsed {
-                        frame.content_ui.add_space(title_bar.inner_rect.height());
-                    } else {
+                        if !title_bar_side.is_vertical() {
+                            frame.content_ui.add_space(title_bar.inner_rect.height());
+                        }
+                    } else if title_bar_side == TitleBarSide::Top {
                         frame.content_ui.add_space(
                             title_bar.inner_rect.height()
                                 + title_content_spacing
@@ -580,17 +1529,59 @@ impl Window<'_> {
                     None
                 };
 
-                let (content_inner, content_response) = collapsing
-                    .show_body_unindented(&mut frame.content_ui, |ui| {
-                        resize.show(ui, |ui| {
-                            if scroll.is_any_scroll_enabled() {
-                                scroll.show(ui, add_contents).inner
-                            } else {
-                                add_contents(ui)
-                            }
+                let body_right_of_title_bar = with_title_bar
+                    && title_bar_side == TitleBarSide::Left
+                    && collapsing.openness(ctx) > 0.0;
+                let show_body = |ui: &mut Ui| {
+                    collapsing
+                        .show_body_unindented(ui, |ui| {
+                            resize.show(ui, |ui| {
+                                if dense {
+                                    *ui.spacing_mut() = ui.spacing().dense();
+                                }
+                                if defer_content {
+                                    // Keep the size of the contents, without adding them:
+                                    ui.set_min_size(if is_resizing {
+                                        ui.available_size()
+                                    } else {
+                                        last_content_size
+                                    });
+                                    None
+                                } else if scroll.is_any_scroll_enabled() {
+                                    Some(scroll.show(ui, add_contents).inner)
+                                } else {
+                                    Some(add_contents(ui))
+                                }
+                            })
                         })
-                    })
-                    .map_or((None, None), |ir| (Some(ir.inner), Some(ir.response)));
+                        .map_or((None, None), |ir| (ir.inner, Some(ir.response)))
+                };
+                let title_bar_and_spacing = title_bar_height_with_margin + title_content_spacing;
+                let (content_inner, content_response) = if body_right_of_title_bar {
+                    // Skip the title bar (and separator):
+                    let mut body_rect = frame.content_ui.max_rect();
+                    body_rect.min.x = frame.content_ui.cursor().min.x + title_bar_and_spacing;
+                    frame
+                        .content_ui
+                        .scope_builder(UiBuilder::new().max_rect(body_rect), show_body)
+                        .inner
+                } else {
+                    show_body(&mut frame.content_ui)
+                };
+
+                if with_title_bar && content_response.is_some() {
+                    // Make room for the title bar (and separator) after the body:
+                    let min_rect = frame.content_ui.min_rect();
+                    match title_bar_side {
+                        TitleBarSide::Top | TitleBarSide::Left => {}
+                        TitleBarSide::Bottom => frame
+                            .content_ui
+                            .expand_to_include_y(min_rect.bottom() + title_bar_and_spacing),
+                        TitleBarSide::Right => frame
+                            .content_ui
+                            .expand_to_include_x(min_rect.right() + title_bar_and_spacing),
+                    }
+                }
 
                 let outer_rect = frame.end(&mut area_content_ui).rect;
                 paint_resize_corner(
@@ -604,17 +1595,19 @@ impl Window<'_> {
                 // END FRAME --------------------------------
 
                 if let Some(mut title_bar) = title_bar {
-                    title_bar.inner_rect = outer_rect.shrink(window_frame.stroke.width);
-                    title_bar.inner_rect.max.y =
-                        title_bar.inner_rect.min.y + title_bar_height_with_margin;
+                    title_bar.inner_rect = title_bar_side.bar_rect(
+                        outer_rect.shrink(window_frame.stroke.width),
+                        title_bar_height_with_margin,
+                    );
 
                     if on_top && area_content_ui.visuals().window_highlight_topmost {
                         let mut round =
                             window_frame.corner_radius - window_frame.stroke.width.round() as u8;
 
                         if !is_collapsed {
-                            round.se = 0;
-                            round.sw = 0;
+                            for corner in title_bar_side.opposite().corners(&mut round) {
+                                *corner = 0;
+                            }
                         }
 
                         area_content_ui.painter().set(
@@ -631,13 +1624,24 @@ impl Window<'_> {
                         );
                     }
 
-                    title_bar.ui(
+                    let help_response = title_bar.ui(
                         &mut area_content_ui,
                         &content_response,
                         open.as_deref_mut(),
+                        pinned,
+                        help.is_some(),
                         &mut collapsing,
                         collapsible,
                     );
+
+                    if let (Some(help_response), Some(add_help)) = (help_response, &mut help) {
+                        show_help_popover(ctx, area_id, &help_response, add_help);
+                    }
+
+                    tab_action = title_bar.tabs_ui(&area_content_ui);
+                    if tab_grouping {
+                        tab_bar_rect = Some(title_bar.inner_rect);
+                    }
                 }
 
                 collapsing.store(ctx);
@@ -648,7 +1652,81 @@ impl Window<'_> {
             })
         };
 
-        let full_response = area.end(ctx, area_content_ui);
+        let mut full_response = area.end(ctx, area_content_ui);
+
+        ctx.pass_state_mut(|state| {
+            state.windows.push(WindowInfo {
+                id: area_id,
+                layer_id: area_layer_id,
+                title: title_text,
+                rect: full_response.rect,
+                collapsed: is_collapsed,
+                tab_bar_rect,
+            });
+        });
+
+        if let Some(group) = resize_group.filter(|_| !is_collapsed) {
+            ResizeGroup::register(
+                ctx,
+                group,
+                GroupedWindow {
+                    layer_id: area_layer_id,
+                    resize_id,
+                    rect: full_response.rect,
+                    margins,
+                    min_size: resize.min_size + margins,
+                    pass_nr: ctx.cumulative_pass_nr(),
+                },
+            );
+        }
+
+        if tab_grouping {
+            update_tab_groups(
+                ctx,
+                window_id,
+                area_id,
+                tab_group.as_ref(),
+                tab_action,
+                &full_response,
+            );
+        }
+
+        if let Some(last_frame_area_rect) = last_frame_area_rect {
+            let user_interacted = full_response.dragged() || resize_interaction.any_dragged();
+            if user_interacted && full_response.rect.min != last_frame_area_rect.min {
+                full_response.flags.set(response::Flags::MOVED, true);
+            }
+            if resize_interaction.any_dragged()
+                && full_response.rect.size() != last_frame_area_rect.size()
+            {
+                full_response.flags.set(response::Flags::RESIZED, true);
+            }
+        }
+
+        if measure_first
+            && !resize_interaction.any_dragged()
+            && last_frame_area_rect.map(|rect| rect.size()) != Some(full_response.rect.size())
+        {
+            // We were laid out with the wrong size, and maybe in the wrong place.
+            ctx.request_discard("Window::measure_first");
+        }
+
+        if let Some(on_tear_off) = &mut on_tear_off {
+            if let Some(rect) = tear_off_rect(
+                ctx,
+                area_id.with("tear_off"),
+                &full_response,
+                last_frame_area_rect,
+                tear_off_threshold,
+                tear_off_modifiers,
+            ) {
+                on_tear_off(rect);
+            }
+        }
+
+        if let Some(on_shown) = on_shown.filter(|_| became_visible) {
+            on_shown();
+        }
 
         if full_response.should_close() {
             if let Some(open) = open {
@@ -664,6 +1742,64 @@ impl Window<'_> {
     }
 }
 
+/// Remembered while dragging a window with [`Window::on_tear_off`].
+#[derive(Clone, Copy)]
+struct TearOffState {
+    /// Where the pointer grabbed the window, relative to its top left corner.
+    grab_offset: Vec2,
+
+    /// Did we already tear off the window during this drag?
+    torn_off: bool,
+}
+
+/// Returns the rect of the window following the pointer,
+/// the first time it is dragged far enough past the edge of the screen.
+fn tear_off_rect(
+    ctx: &Context,
+    id: Id,
+    response: &Response,
+    last_frame_rect: Option<Rect>,
+    threshold: f32,
+    modifiers: Modifiers,
+) -> Option<Rect> {
+    if !response.dragged() {
+        ctx.data_mut(|d| d.remove::<TearOffState>(id));
+        return None;
+    }
+    let (pointer, press_origin) =
+        ctx.input(|i| (i.pointer.interact_pos(), i.pointer.press_origin()));
+    let pointer = pointer?;
+
+    let mut state = ctx
+        .data(|d| d.get_temp::<TearOffState>(id))
+        .unwrap_or_else(|| {
+            // Where the window was before this drag moved it (and the screen edge stopped it):
+            let grab_offset = match (press_origin, last_frame_rect) {
+                (Some(press_origin), Some(rect)) => press_origin - rect.min,
+                _ => pointer - response.rect.min,
+            };
+            TearOffState {
+                grab_offset,
+                torn_off: false,
+            }
+        });
+
+    let screen_rect = ctx.screen_rect();
+    let distance_outside = (pointer - screen_rect.clamp(pointer)).length();
+    let matches_modifiers = ctx.input(|i| i.modifiers.matches_logically(modifiers));
+
+    let mut rect = None;
+    if !state.torn_off && matches_modifiers && threshold < distance_outside {
+        state.torn_off = true;
+        rect = Some(Rect::from_min_size(
+            pointer - state.grab_offset,
+            response.rect.size(),
+        ));
+    }
+    ctx.data_mut(|d| d.insert_temp(id, state));
+    rect
+}
+
 fn paint_resize_corner(
     ui: &Ui,
     possible: &PossibleInteractions,
@@ -825,10 +1961,15 @@ impl ResizeInteraction {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn resize_response(
     resize_interaction: ResizeInteraction,
     ctx: &Context,
     margins: Vec2,
+    resize: &Resize,
+    aspect_ratio: Option<f32>,
+    snap_size: Option<&dyn Fn(Vec2) -> Vec2>,
+    resize_group: Option<Id>,
     area_layer_id: LayerId,
     area: &mut area::Prepared,
     resize_id: Id,
@@ -837,10 +1978,38 @@ fn resize_response(
         return;
     };
 
+    let mut snapped_size = resize.snap_size(ctx, new_rect.size() - margins);
+    if let Some(snap_size) = snap_size {
+        snapped_size = snap_size(snapped_size.clamp(resize.min_size, resize.max_size));
+    }
+    new_rect = resize_dragged_sides(&resize_interaction, new_rect, snapped_size + margins);
+
+    if let Some(aspect_ratio) = aspect_ratio {
+        new_rect = lock_aspect_ratio(
+            &resize_interaction,
+            new_rect,
+            margins,
+            aspect_ratio,
+            resize.min_size,
+            resize.max_size,
+        );
+    }
+
     if area.constrain() {
         new_rect = Context::constrain_window_rect_to_area(new_rect, area.constrain_rect());
     }
 
+    if let Some(group) = resize_group {
+        new_rect = ResizeGroup::resize_neighbors(
+            ctx,
+            group,
+            area_layer_id,
+            &resize_interaction,
+            new_rect,
+            resize.min_size + margins,
+        );
+    }
+
     // TODO(emilk): add this to a Window state instead as a command "move here next frame"
     area.state_mut().set_left_top_pos(new_rect.left_top());
 
@@ -854,6 +2023,166 @@ fn resize_response(
     ctx.memory_mut(|mem| mem.areas_mut().move_to_top(area_layer_id));
 }
 
+/// A [`Window`] in a [`Window::resize_group`], as it was last shown.
+#[derive(Clone, Copy, Debug)]
+struct GroupedWindow {
+    layer_id: LayerId,
+    resize_id: Id,
+
+    /// Outer rect, including the title bar.
+    rect: Rect,
+
+    /// The size of the window minus the size of its contents.
+    margins: Vec2,
+
+    /// The smallest outer size of the window.
+    min_size: Vec2,
+
+    /// When it was last shown, so we can forget windows that are no longer shown.
+    pass_nr: u64,
+}
+
+/// The windows of a [`Window::resize_group`], stored in [`crate::Memory::data`].
+#[derive(Clone, Debug, Default)]
+struct ResizeGroup {
+    windows: Vec<GroupedWindow>,
+}
+
+impl ResizeGroup {
+    fn id(group: Id) -> Id {
+        group.with("egui::ResizeGroup")
+    }
+
+    fn register(ctx: &Context, group: Id, window: GroupedWindow) {
+        ctx.data_mut(|d| {
+            let windows = &mut d.get_temp_mut_or_default::<Self>(Self::id(group)).windows;
+            windows.retain(|w| w.layer_id != window.layer_id && window.pass_nr <= w.pass_nr + 1);
+            windows.push(window);
+        });
+    }
+
+    /// Move the edges of the other windows of the group that are flush with the dragged edges,
+    /// and limit the dragged edges so that all these windows keep their minimum (outer) size.
+    ///
+    /// Returns the limited `new_rect`.
+    fn resize_neighbors(
+        ctx: &Context,
+        group: Id,
+        layer_id: LayerId,
+        interaction: &ResizeInteraction,
+        mut new_rect: Rect,
+        min_size: Vec2,
+    ) -> Rect {
+        const FLUSH_DISTANCE: f32 = 1.0;
+
+        let old_rect = interaction.outer_rect;
+        let Some(group_state) = ctx.data(|d| d.get_temp::<Self>(Self::id(group))) else {
+            return new_rect;
+        };
+
+        // The dragged edges, as (axis, is the max side):
+        let dragged_edges = [
+            (0, false, interaction.left.drag),
+            (0, true, interaction.right.drag),
+            (1, false, interaction.top.drag),
+            (1, true, interaction.bottom.drag),
+        ];
+
+        let mut neighbors = vec![];
+        for window in group_state
+            .windows
+            .iter()
+            .filter(|w| w.layer_id != layer_id)
+        {
+            for &(d, is_max, dragged) in &dragged_edges {
+                let other = 1 - d;
+                let overlap = old_rect.min[other].max(window.rect.min[other])
+                    < old_rect.max[other].min(window.rect.max[other]);
+                let (edge, neighbor_edge) = if is_max {
+                    (old_rect.max[d], window.rect.min[d])
+                } else {
+                    (old_rect.min[d], window.rect.max[d])
+                };
+                if dragged && overlap && (edge - neighbor_edge).abs() <= FLUSH_DISTANCE {
+                    // Keep the neighbor at least its minimum size:
+                    if is_max {
+                        new_rect.max[d] = new_rect.max[d]
+                            .min(window.rect.max[d] - window.min_size[d])
+                            .max(new_rect.min[d] + min_size[d]);
+                    } else {
+                        new_rect.min[d] = new_rect.min[d]
+                            .max(window.rect.min[d] + window.min_size[d])
+                            .min(new_rect.max[d] - min_size[d]);
+                    }
+                    neighbors.push((*window, d, is_max));
+                }
+            }
+        }
+
+        for (window, d, is_max) in neighbors {
+            let mut rect = window.rect;
+            if is_max {
+                rect.min[d] = new_rect.max[d];
+            } else {
+                rect.max[d] = new_rect.min[d];
+            }
+            if rect == window.rect {
+                continue;
+            }
+
+            ctx.memory_mut(|mem| {
+                if let Some(state) = mem.areas_mut().get_mut(window.layer_id.id) {
+                    state.size = Some(rect.size());
+                    state.set_left_top_pos(rect.left_top());
+                }
+            });
+            if let Some(mut state) = resize::State::load(ctx, window.resize_id) {
+                state.requested_size = Some(rect.size() - window.margins);
+                state.store(ctx, window.resize_id);
+            }
+            ctx.request_repaint();
+        }
+
+        new_rect
+    }
+}
+
+/// Move the right and bottom edges of the window with the arrow keys.
+#[allow(clippy::too_many_arguments)]
+fn keyboard_resize(
+    ctx: &Context,
+    modifiers: Modifiers,
+    step: (f32, f32),
+    possible: PossibleInteractions,
+    margins: Vec2,
+    resize: &Resize,
+    area: &mut area::Prepared,
+    resize_id: Id,
+) {
+    let delta = area::consume_arrow_keys(ctx, modifiers, step);
+    let delta = vec2(
+        if possible.resize_right { delta.x } else { 0.0 },
+        if possible.resize_bottom { delta.y } else { 0.0 },
+    );
+    if delta == Vec2::ZERO {
+        return;
+    }
+    let Some(mut state) = resize::State::load(ctx, resize_id) else {
+        return;
+    };
+
+    let size = (state.desired_size + delta).clamp(resize.min_size, resize.max_size);
+    let mut new_rect = Rect::from_min_size(area.state().left_top_pos(), size + margins);
+    if area.constrain() {
+        new_rect = Context::constrain_window_rect_to_area(new_rect, area.constrain_rect());
+    }
+    area.state_mut().set_left_top_pos(new_rect.left_top());
+
+    state.requested_size = Some(new_rect.size() - margins);
+    state.store(ctx, resize_id);
+    ctx.request_repaint();
+}
+
 /// Acts on outer rect (outside the stroke)
 fn move_and_resize_window(ctx: &Context, interaction: &ResizeInteraction) -> Option<Rect> {
     if !interaction.any_dragged() {
@@ -884,9 +2213,73 @@ fn move_and_resize_window(ctx: &Context, interaction: &ResizeInteraction) -> Opt
     Some(rect.round_ui())
 }
 
+/// Adjust the resized outer rect so that the inner size has the given width/height ratio.
+///
+/// The sides that are not being dragged stay put.
+fn lock_aspect_ratio(
+    interaction: &ResizeInteraction,
+    rect: Rect,
+    margins: Vec2,
+    aspect_ratio: f32,
+    min_size: Vec2,
+    max_size: Vec2,
+) -> Rect {
+    if !(aspect_ratio.is_finite() && 0.0 < aspect_ratio) {
+        return rect;
+    }
+
+    let horizontal = interaction.left.drag || interaction.right.drag;
+    let vertical = interaction.top.drag || interaction.bottom.drag;
+    let size = rect.size() - margins;
+
+    let mut width = match (horizontal, vertical) {
+        (true, false) => size.x,
+        (false, true) => size.y * aspect_ratio,
+        // Corner: follow whichever side the pointer pulls furthest.
+        _ => size.x.max(size.y * aspect_ratio),
+    };
+
+    // Respect min/max size, giving the minimum precedence if they conflict:
+    let max_width = max_size.x.min(max_size.y * aspect_ratio);
+    let min_width = min_size.x.max(min_size.y * aspect_ratio);
+    width = width.min(max_width).max(min_width);
+
+    let new_size = vec2(width, width / aspect_ratio) + margins;
+
+    let mut new_rect = rect;
+    if interaction.left.drag {
+        new_rect.min.x = rect.max.x - new_size.x;
+    } else {
+        new_rect.max.x = rect.min.x + new_size.x;
+    }
+    if interaction.top.drag {
+        new_rect.min.y = rect.max.y - new_size.y;
+    } else {
+        new_rect.max.y = rect.min.y + new_size.y;
+    }
+    new_rect.round_ui()
+}
+
+/// Give the resized outer rect the given size by moving only the sides that are being dragged.
+fn resize_dragged_sides(interaction: &ResizeInteraction, rect: Rect, new_size: Vec2) -> Rect {
+    let mut new_rect = rect;
+    if interaction.left.drag {
+        new_rect.min.x = rect.max.x - new_size.x;
+    } else if interaction.right.drag {
+        new_rect.max.x = rect.min.x + new_size.x;
+    }
+    if interaction.top.drag {
+        new_rect.min.y = rect.max.y - new_size.y;
+    } else if interaction.bottom.drag {
+        new_rect.max.y = rect.min.y + new_size.y;
+    }
+    new_rect.round_ui()
+}
+
 fn resize_interaction(
     ctx: &Context,
     possible: PossibleInteractions,
+    resize_edge_thickness: Option<f32>,
     layer_id: LayerId,
     outer_rect: Rect,
     window_frame: Frame,
@@ -925,8 +2318,19 @@ fn resize_interaction(
 
     let id = Id::new(layer_id).with("edge_drag");
 
-    let side_grab_radius = ctx.style().interaction.resize_grab_radius_side;
-    let corner_grab_radius = ctx.style().interaction.resize_grab_radius_corner;
+    let (side_grab_radius, corner_grab_radius) = {
+        let interaction = &ctx.style().interaction;
+        match resize_edge_thickness {
+            Some(thickness) => {
+                let side = 0.5 * thickness.at_least(0.0);
+                (side, interaction.resize_grab_radius_corner.max(side))
+            }
+            None => (
+                interaction.resize_grab_radius_side,
+                interaction.resize_grab_radius_corner,
+            ),
+        }
+    };
 
     let vetrtical_rect = |a: Pos2, b: Pos2| {
         Rect::from_min_max(a, b).expand2(vec2(side_grab_radius, -corner_grab_radius))
@@ -1113,27 +2517,65 @@ fn paint_frame_interaction(ui: &Ui, rect: Rect, interaction: ResizeInteraction)
 
 // ----------------------------------------------------------------------------
 
+/// The title of a tab in the title bar of a tab group, see [`Window::tab_grouping`].
+fn tab_galley(ui: &Ui, text: &WidgetText) -> Arc<Galley> {
+    text.clone().into_galley(
+        ui,
+        Some(crate::TextWrapMode::Extend),
+        f32::INFINITY,
+        TextStyle::Button,
+    )
+}
+
 struct TitleBar {
     window_frame: Frame,
 
+    /// Which edge of the window the title bar is on.
+    side: TitleBarSide,
+
+    /// The full title, shown as a tooltip if it doesn't fit.
+    title: WidgetText,
+
     /// Prepared text in the title
     title_galley: Arc<Galley>,
 
+    /// Length of the title bar taken up by the padding and buttons around the title.
+    title_padding: f32,
+
     /// Size of the title bar in an expanded state. This size become known only
     /// after expanding window and painting its content.
     ///
     /// Does not include the stroke, nor the separator line between the title bar and the window contents.
     inner_rect: Rect,
+
+    /// The windows of the tab group, shown instead of the title, see [`Window::tab_grouping`].
+    tabs: Vec<(Id, WidgetText)>,
+
+    /// Index of the selected tab.
+    active_tab: usize,
+}
+
+/// What the user did with the tabs of a tab group, see [`Window::tab_grouping`].
+#[derive(Clone, Copy, Debug)]
+enum TabAction {
+    /// Show this window of the group.
+    Select(usize),
+
+    /// Take this window out of the group, putting it here.
+    Detach(usize, Pos2),
 }
 
 impl TitleBar {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         ui: &Ui,
         title: WidgetText,
-        show_close_button: bool,
+        side: TitleBarSide,
+        num_right_buttons: usize,
         collapsible: bool,
         window_frame: Frame,
         title_bar_height_with_margin: f32,
+        max_length: f32,
     ) -> Self {
         if false {
             ui.ctx()
@@ -1141,27 +2583,45 @@ impl TitleBar {
                 .debug_rect(ui.min_rect(), Color32::GREEN, "outer_min_rect");
         }
 
-        let inner_height = title_bar_height_with_margin - window_frame.inner_margin.sum().y;
+        let inner_margin = window_frame.inner_margin.sum();
+        let inner_height = title_bar_height_with_margin
+            - if side.is_vertical() {
+                inner_margin.x
+            } else {
+                inner_margin.y
+            };
 
         let item_spacing = ui.spacing().item_spacing;
         let button_size = Vec2::splat(ui.spacing().icon_width.at_most(inner_height));
 
         let left_pad = ((inner_height - button_size.y) / 2.0).round_ui(); // calculated so that the icon is on the diagonal (if window padding is symmetrical)
 
-        let title_galley = title.into_galley(
+        let title_galley = title.clone().into_galley(
             ui,
             Some(crate::TextWrapMode::Extend),
             f32::INFINITY,
             TextStyle::Heading,
         );
 
-        let minimum_width = if collapsible || show_close_button {
-            // If at least one button is shown we make room for both buttons (since title should be centered):
-            2.0 * (left_pad + button_size.x + item_spacing.x) + title_galley.size().x
+        let title_padding = if collapsible || num_right_buttons > 0 {
+            // If at least one button is shown we make room for buttons on both sides (since title should be centered):
+            let num_side_buttons = num_right_buttons.max(1) as f32;
+            2.0 * (left_pad + num_side_buttons * (button_size.x + item_spacing.x))
         } else {
-            left_pad + title_galley.size().x + left_pad
+            2.0 * left_pad
+        };
+        // A title that doesn't fit is truncated (see `Self::ui`), rather than making the window too large:
+        let title_width = title_galley
+            .size()
+            .x
+            .at_most(max_length - title_padding)
+            .at_least(0.0);
+        let minimum_width = title_padding + title_width;
+        let min_inner_size = if side.is_vertical() {
+            vec2(inner_height, minimum_width)
+        } else {
+            vec2(minimum_width, inner_height)
         };
-        let min_inner_size = vec2(minimum_width, inner_height);
         let min_rect = Rect::from_min_size(ui.min_rect().min, min_inner_size);
 
         if false {
@@ -1172,9 +2632,34 @@ impl TitleBar {
 
         Self {
             window_frame,
+            side,
+            title,
             title_galley,
+            title_padding,
             inner_rect: min_rect, // First estimate - will be refined later
+            tabs: Vec::new(),
+            active_tab: 0,
+        }
+    }
+
+    /// Show a strip of tabs instead of the title, see [`Window::tab_grouping`].
+    ///
+    /// Only a horizontal title bar has room for them.
+    fn set_tabs(&mut self, ui: &Ui, tabs: Vec<(Id, WidgetText)>, active: usize, max_length: f32) {
+        if self.side.is_vertical() {
+            return;
         }
+        let tabs_width: f32 = tabs
+            .iter()
+            .map(|(_, text)| tab_galley(ui, text).size().x + 2.0 * ui.spacing().button_padding.x)
+            .sum();
+        self.inner_rect.set_width(
+            (self.title_padding + tabs_width)
+                .at_most(max_length)
+                .at_least(self.title_padding),
+        );
+        self.tabs = tabs;
+        self.active_tab = active;
     }
 
     /// Finishes painting of the title bar when the window content size already known.
@@ -1187,18 +2672,24 @@ impl TitleBar {
     ///   a result of rendering the window content
     /// - `open`: if `None`, no "Close" button will be rendered, otherwise renders and processes
     ///   the "Close" button and writes a `false` if window was closed
+    /// - `pinned`: if `None`, no "Pin" button will be rendered, otherwise renders and processes
+    ///   the "Pin" button and toggles the flag when clicked
+    /// - `has_help`: if `true`, renders the "Help" button and returns its response
     /// - `collapsing`: holds the current expanding state. Can be changed by double click on the
     ///   title if `collapsible` is `true`
     /// - `collapsible`: if `true`, double click on the title bar will be handled for a change
     ///   of `collapsing` state
+    #[allow(clippy::too_many_arguments)]
     fn ui(
-        self,
+        &self,
         ui: &mut Ui,
         content_response: &Option<Response>,
         open: Option<&mut bool>,
+        pinned: Option<&mut bool>,
+        has_help: bool,
         collapsing: &mut CollapsingState,
         collapsible: bool,
-    ) {
+    ) -> Option<Response> {
         let window_frame = self.window_frame;
         let title_inner_rect = self.inner_rect;
 
@@ -1210,9 +2701,7 @@ impl TitleBar {
 
         if collapsible {
             // Show collapse-button:
-            let button_center = Align2::LEFT_CENTER
-                .align_size_within_rect(Vec2::splat(self.inner_rect.height()), self.inner_rect)
-                .center();
+            let button_center = self.button_center(false, 0.0);
             let button_size = Vec2::splat(ui.spacing().icon_width);
             let button_rect = Rect::from_center_size(button_center, button_size);
             let button_rect = button_rect.round_ui();
@@ -1222,6 +2711,9 @@ impl TitleBar {
             });
         }
 
+        let button_step = ui.spacing().icon_width + ui.spacing().item_spacing.x;
+
+        let has_close_button = open.is_some();
         if let Some(open) = open {
             // Add close button now that we know our full width:
             if self.close_button_ui(ui).clicked() {
@@ -1229,15 +2721,61 @@ impl TitleBar {
             }
         }
 
-        let text_pos =
-            emath::align::center_size_in_rect(self.title_galley.size(), title_inner_rect)
-                .left_top();
-        let text_pos = text_pos - self.title_galley.rect.min.to_vec2();
-        ui.painter().galley(
-            text_pos,
-            self.title_galley.clone(),
-            ui.visuals().text_color(),
-        );
+        // Extra buttons go from the end inwards, next to the close button (if any):
+        let mut offset = if has_close_button { button_step } else { 0.0 };
+
+        if let Some(pinned) = pinned {
+            if self.pin_button_ui(ui, *pinned, offset).clicked() {
+                *pinned = !*pinned;
+            }
+            offset += button_step;
+        }
+
+        let help_response = has_help.then(|| {
+            let response = self.help_button_ui(ui, offset);
+            offset += button_step;
+            response
+        });
+
+        let extra_buttons_width = offset - if has_close_button { button_step } else { 0.0 };
+
+        // Truncate the title with an ellipsis if it doesn't fit between the buttons:
+        let available_title_width = self.side.length(title_inner_rect.size()) - self.title_padding;
+        let title_galley = if self.title_galley.size().x > available_title_width + 0.5 {
+            self.title.clone().into_galley(
+                ui,
+                Some(crate::TextWrapMode::Truncate),
+                available_title_width.at_least(0.0),
+                TextStyle::Heading,
+            )
+        } else {
+            self.title_galley.clone()
+        };
+        let is_title_truncated = title_galley.elided;
+
+        if !self.tabs.is_empty() {
+            // The tabs are shown instead, see `Self::tabs_ui`.
+        } else if self.side.is_vertical() {
+            // Turned a quarter counter-clockwise, so it reads upwards:
+            let angle = -std::f32::consts::TAU / 4.0;
+            let galley_size = title_galley.size();
+            let text_rect = emath::align::center_size_in_rect(
+                vec2(galley_size.y, galley_size.x),
+                title_inner_rect,
+            );
+            let text_pos = text_rect.left_bottom()
+                - emath::Rot2::from_angle(angle) * title_galley.rect.min.to_vec2();
+            ui.painter().add(
+                epaint::TextShape::new(text_pos, title_galley, ui.visuals().text_color())
+                    .with_angle(angle),
+            );
+        } else {
+            let text_pos =
+                emath::align::center_size_in_rect(title_galley.size(), title_inner_rect).left_top();
+            let text_pos = text_pos - title_galley.rect.min.to_vec2();
+            ui.painter()
+                .galley(text_pos, title_galley, ui.visuals().text_color());
+        }
 
         if let Some(content_response) = &content_response {
             // Paint separator between title and content:
@@ -1247,15 +2785,43 @@ impl TitleBar {
                     .debug_painter()
                     .debug_rect(content_rect, Color32::RED, "content_rect");
             }
-            let y = title_inner_rect.bottom() + window_frame.stroke.width / 2.0;
+            let half_stroke = window_frame.stroke.width / 2.0;
 
             // To verify the sanity of this, use a very wide window stroke
-            ui.painter()
-                .hline(title_inner_rect.x_range(), y, window_frame.stroke);
+            let painter = ui.painter();
+            match self.side {
+                TitleBarSide::Top => painter.hline(
+                    title_inner_rect.x_range(),
+                    title_inner_rect.bottom() + half_stroke,
+                    window_frame.stroke,
+                ),
+                TitleBarSide::Bottom => painter.hline(
+                    title_inner_rect.x_range(),
+                    title_inner_rect.top() - half_stroke,
+                    window_frame.stroke,
+                ),
+                TitleBarSide::Left => painter.vline(
+                    title_inner_rect.right() + half_stroke,
+                    title_inner_rect.y_range(),
+                    window_frame.stroke,
+                ),
+                TitleBarSide::Right => painter.vline(
+                    title_inner_rect.left() - half_stroke,
+                    title_inner_rect.y_range(),
+                    window_frame.stroke,
+                ),
+            };
         }
 
-        // Don't cover the close- and collapse buttons:
-        let double_click_rect = title_inner_rect.shrink2(vec2(32.0, 0.0));
+        // Don't cover the close-, pin-, help- and collapse buttons:
+        let mut double_click_rect = title_inner_rect;
+        if self.side.is_vertical() {
+            double_click_rect = double_click_rect.shrink2(vec2(0.0, 32.0));
+            double_click_rect.min.y += extra_buttons_width;
+        } else {
+            double_click_rect = double_click_rect.shrink2(vec2(32.0, 0.0));
+            double_click_rect.max.x -= extra_buttons_width;
+        }
 
         if false {
             ui.ctx().debug_painter().debug_rect(
@@ -1267,29 +2833,225 @@ impl TitleBar {
 
         let id = ui.unique_id().with("__window_title_bar");
 
-        if ui
-            .interact(double_click_rect, id, Sense::click())
-            .double_clicked()
-            && collapsible
-        {
+        let mut title_response = ui.interact(double_click_rect, id, Sense::click());
+        if is_title_truncated && self.tabs.is_empty() {
+            title_response = title_response.on_hover_text(self.title.clone());
+        }
+        if title_response.double_clicked() && collapsible {
             collapsing.toggle(ui);
         }
+
+        help_response
+    }
+
+    /// Show the tabs set with [`Self::set_tabs`], centered in the title bar.
+    ///
+    /// Call this after [`Self::ui`], so the tabs are on top of the title bar.
+    fn tabs_ui(&self, ui: &Ui) -> Option<TabAction> {
+        if self.tabs.is_empty() {
+            return None;
+        }
+
+        let padding = ui.spacing().button_padding.x;
+        let galleys: Vec<_> = self
+            .tabs
+            .iter()
+            .map(|(_, text)| tab_galley(ui, text))
+            .collect();
+        let total_width: f32 = galleys.iter().map(|g| g.size().x + 2.0 * padding).sum();
+
+        // Between the buttons at either end:
+        let strip_rect = self
+            .inner_rect
+            .shrink2(vec2(self.title_padding / 2.0, 0.0))
+            .intersect(ui.clip_rect());
+        let painter = ui.painter().with_clip_rect(strip_rect);
+        let visuals = ui.visuals().clone();
+        let corner_radius = visuals.widgets.inactive.corner_radius;
+        let tab_height = (self.inner_rect.height() - 4.0).at_least(0.0);
+
+        let mut x = self.inner_rect.center().x - total_width.at_most(strip_rect.width()) / 2.0;
+        let mut action = None;
+        for (i, ((tab, _), galley)) in self.tabs.iter().zip(galleys).enumerate() {
+            let width = galley.size().x + 2.0 * padding;
+            let rect = Rect::from_center_size(
+                pos2(x + width / 2.0, self.inner_rect.center().y),
+                vec2(width, tab_height),
+            );
+            x += width;
+
+            let selected = i == self.active_tab;
+            let response = ui.interact(
+                rect.intersect(strip_rect),
+                tab.with("window_tab"),
+                Sense::click_and_drag(),
+            );
+            let label = galley.text().to_owned();
+            response.widget_info(|| {
+                WidgetInfo::selected(
+                    WidgetType::SelectableLabel,
+                    ui.is_enabled(),
+                    selected,
+                    &label,
+                )
+            });
+
+            if selected {
+                painter.rect_filled(rect, corner_radius, visuals.selection.bg_fill);
+            } else if response.hovered() || response.dragged() {
+                painter.rect_filled(rect, corner_radius, visuals.widgets.hovered.weak_bg_fill);
+            }
+            let text_color = if selected {
+                visuals.selection.stroke.color
+            } else {
+                visuals.text_color()
+            };
+            let text_pos = emath::align::center_size_in_rect(galley.size(), rect).left_top();
+            painter.galley(text_pos, galley, text_color);
+
+            if response.clicked() {
+                action = Some(TabAction::Select(i));
+            }
+            if response.drag_stopped() {
+                if let Some(pointer) = ui
+                    .ctx()
+                    .pointer_interact_pos()
+                    .filter(|pointer| !self.inner_rect.contains(*pointer))
+                {
+                    action = Some(TabAction::Detach(i, pointer));
+                }
+            }
+        }
+        action
+    }
+
+    /// The center of a button `offset` from the start (where the collapse button is)
+    /// or the end (where the close button is) of the title bar.
+    ///
+    /// A vertical title bar starts at the bottom, like a horizontal one turned to read upwards.
+    fn button_center(&self, at_end: bool, offset: f32) -> Pos2 {
+        let (align, inwards, thickness) = match (self.side.is_vertical(), at_end) {
+            (false, false) => (Align2::LEFT_CENTER, Vec2::RIGHT, self.inner_rect.height()),
+            (false, true) => (Align2::RIGHT_CENTER, Vec2::LEFT, self.inner_rect.height()),
+            (true, false) => (Align2::CENTER_BOTTOM, Vec2::UP, self.inner_rect.width()),
+            (true, true) => (Align2::CENTER_TOP, Vec2::DOWN, self.inner_rect.width()),
+        };
+        align
+            .align_size_within_rect(Vec2::splat(thickness), self.inner_rect)
+            .center()
+            + offset * inwards
     }
 
-    /// Paints the "Close" button at the right side of the title bar
+    /// Paints the "Close" button at the end of the title bar
     /// and processes clicks on it.
     ///
     /// The button is square and its size is determined by the
     /// [`crate::style::Spacing::icon_width`] setting.
     fn close_button_ui(&self, ui: &mut Ui) -> Response {
-        let button_center = Align2::RIGHT_CENTER
-            .align_size_within_rect(Vec2::splat(self.inner_rect.height()), self.inner_rect)
-            .center();
+        let button_center = self.button_center(true, 0.0);
         let button_size = Vec2::splat(ui.spacing().icon_width);
         let button_rect = Rect::from_center_size(button_center, button_size);
         let button_rect = button_rect.round_to_pixels(ui.pixels_per_point());
         close_button(ui, button_rect)
     }
+
+    /// Paints the "Pin" button `offset` from the end of the title bar
+    /// and processes clicks on it.
+    fn pin_button_ui(&self, ui: &mut Ui, pinned: bool, offset: f32) -> Response {
+        let button_center = self.button_center(true, offset);
+        let button_size = Vec2::splat(ui.spacing().icon_width);
+        let button_rect = Rect::from_center_size(button_center, button_size);
+        let button_rect = button_rect.round_to_pixels(ui.pixels_per_point());
+        pin_button(ui, button_rect, pinned)
+    }
+
+    /// Paints the "Help" button `offset` from the end of the title bar
+    /// and processes clicks on it.
+    fn help_button_ui(&self, ui: &mut Ui, offset: f32) -> Response {
+        let button_center = self.button_center(true, offset);
+        let button_size = Vec2::splat(ui.spacing().icon_width);
+        let button_rect = Rect::from_center_size(button_center, button_size);
+        let button_rect = button_rect.round_to_pixels(ui.pixels_per_point());
+        help_button(ui, button_rect)
+    }
+}
+
+/// Paints the "Help" button of the window and processes clicks on it.
+///
+/// The help button is a `?` in the color of the foreground stroke.
+fn help_button(ui: &mut Ui, rect: Rect) -> Response {
+    let help_id = ui.auto_id_with("window_help_button");
+    let response = ui.interact(rect, help_id, Sense::click());
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), "Help"));
+
+    ui.expand_to_include_rect(response.rect);
+
+    let visuals = ui.style().interact(&response);
+    let font_id = TextStyle::Button.resolve(ui.style());
+    ui.painter().text(
+        rect.center(),
+        Align2::CENTER_CENTER,
+        "?",
+        font_id,
+        visuals.fg_stroke.color,
+    );
+    response
+}
+
+/// Toggles and shows the help popover of [`Window::help`] below the help button.
+fn show_help_popover(
+    ctx: &Context,
+    window_id: Id,
+    help_response: &Response,
+    add_help: &mut dyn FnMut(&mut Ui),
+) {
+    let help_open_id = window_id.with("help_open");
+    let mut help_open = ctx.data(|d| d.get_temp::<bool>(help_open_id).unwrap_or(false));
+    if help_response.clicked() {
+        help_open = !help_open;
+    }
+
+    Popup::from_response(help_response)
+        .open_bool(&mut help_open)
+        .close_behavior(PopupCloseBehavior::CloseOnClickOutside)
+        .align(RectAlign::BOTTOM_END)
+        .width(ctx.style().spacing.tooltip_width)
+        .show(add_help);
+
+    ctx.data_mut(|d| d.insert_temp(help_open_id, help_open));
+}
+
+/// Paints the "Pin" button of the window and processes clicks on it.
+///
+/// The pin is a round head on a needle, filled while `pinned`.
+fn pin_button(ui: &mut Ui, rect: Rect, pinned: bool) -> Response {
+    let pin_id = ui.auto_id_with("window_pin_button");
+    let response = ui.interact(rect, pin_id, Sense::click());
+    response.widget_info(|| {
+        WidgetInfo::selected(
+            WidgetType::Checkbox,
+            ui.is_enabled(),
+            pinned,
+            "Keep window on top",
+        )
+    });
+
+    ui.expand_to_include_rect(response.rect);
+
+    let visuals = ui.style().interact(&response);
+    let rect = rect.shrink(2.0).expand(visuals.expansion);
+    let stroke = visuals.fg_stroke;
+    let radius = rect.width() / 4.0;
+    let head = rect.center_top() + vec2(0.0, radius);
+    let fill = if pinned {
+        stroke.color
+    } else {
+        Color32::TRANSPARENT
+    };
+    ui.painter() // paints the needle
+        .line_segment([head + vec2(0.0, radius), rect.center_bottom()], stroke);
+    ui.painter().circle(head, radius, fill, stroke);
+    response
 }
 
 /// Paints the "Close" button of the window and processes clicks on it.
@@ -274,6 +274,8 @@ pub struct ViewportState {
     // The output of a pass:
     //
     pub graphics: GraphicLayers,
+    /// Which of the shapes output by the last pass came from which layer.
+    pub layer_shape_ranges: Vec<(LayerId, std::ops::Range<usize>)>,
     // Most of the things in `PlatformOutput` are not actually viewport dependent.
     pub output: PlatformOutput,
     pub commands: Vec<ViewportCommand>,
@@ -503,13 +505,37 @@ impl ContextImpl {
             viewport.hits = if let Some(pos) = viewport.input.pointer.interact_pos() {
                 let interact_radius = self.memory.options.style().interaction.interact_radius;
 
-                crate::hit_test::hit_test(
+                // Let the pointer pass through the parts of areas that are not interactable:
+                layers.retain(|&layer_id| {
+                    !self
+                        .memory
+                        .areas()
+                        .is_pass_through(layer_id, pos, &self.memory.to_global)
+                });
+
+                let mut hits = crate::hit_test::hit_test(
                     &viewport.prev_pass.widgets,
                     &layers,
                     &self.memory.to_global,
                     pos,
                     interact_radius,
-                )
+                );
+
+                // Let drags that the areas on top don't want go to what is behind them:
+                let areas = self.memory.areas();
+                if hits.drag.is_none() && layers.iter().any(|&l| areas.passes_drags_through(l)) {
+                    layers.retain(|&l| !areas.passes_drags_through(l));
+                    hits.drag = crate::hit_test::hit_test(
+                        &viewport.prev_pass.widgets,
+                        &layers,
+                        &self.memory.to_global,
+                        pos,
+                        interact_radius,
+                    )
+                    .drag;
+                }
+
+                hits
             } else {
                 WidgetHits::default()
             };
@@ -532,6 +558,8 @@ impl ContextImpl {
                 size: Some(screen_rect.size()),
                 interactable: true,
                 last_became_visible_at: None,
+                last_became_invisible_at: None,
+                fully_visible: false,
             },
         );
 
@@ -968,6 +996,15 @@ impl Context {
         self.write(move |ctx| reader(&ctx.viewport().graphics))
     }
 
+    /// Which of the [`FullOutput::shapes`] of the last pass came from which [`LayerId`].
+    ///
+    /// Each range is an index range into the shapes, in paint order.
+    /// Layers without any shapes are left out.
+    /// Useful for e.g. rendering only a single window or tooltip in a test.
+    pub fn layer_shape_ranges(&self) -> Vec<(LayerId, std::ops::Range<usize>)> {
+        self.write(|ctx| ctx.viewport().layer_shape_ranges.clone())
+    }
+
     /// Read-only access to [`PlatformOutput`].
     ///
     /// This is what egui outputs each pass and frame.
@@ -2307,7 +2344,13 @@ impl ContextImpl {
 
         viewport.repaint.cumulative_pass_nr += 1;
 
-        self.memory.end_pass(&viewport.this_pass.used_ids);
+        let any_fading_out = self
+            .memory
+            .areas_mut()
+            .paint_fading_out(&mut viewport.graphics, viewport.input.time);
+
+        self.memory
+            .end_pass(&viewport.this_pass.used_ids, &viewport.this_pass.widgets);
 
         if let Some(fonts) = self.fonts.get(&pixels_per_point.into()) {
             let tex_mngr = &mut self.tex_manager.0.write();
@@ -2366,11 +2409,12 @@ impl ContextImpl {
             }
         }
 
-        let shapes = viewport
+        let (shapes, layer_shape_ranges) = viewport
             .graphics
-            .drain(self.memory.areas().order(), &self.memory.to_global);
+            .drain_with_layers(self.memory.areas().order(), &self.memory.to_global);
+        viewport.layer_shape_ranges = layer_shape_ranges;
 
-        let mut repaint_needed = false;
+        let mut repaint_needed = any_fading_out;
 
         if self.memory.options.repaint_on_widget_change {
             profiling::scope!("compare-widget-rects");
@@ -2757,11 +2801,53 @@ impl Context {
         }
     }
 
+    /// All [`crate::Window`]s that were shown in the previous pass, in the order they were shown.
+    ///
+    /// This includes collapsed windows, but not closed ones.
+    /// Useful for e.g. a taskbar: use [`Self::move_to_top`] with [`crate::WindowInfo::layer_id`]
+    /// to focus a window, and [`crate::Window::set_collapsed`] to restore it.
+    pub fn windows(&self) -> Vec<crate::WindowInfo> {
+        self.prev_pass_state(|s| s.windows.clone())
+    }
+
+    /// The position, size and collapsed state of the [`crate::Window`] with the given id,
+    /// e.g. to save the layout of your app between sessions.
+    ///
+    /// The id is the one given to [`crate::Window::id`], or `Id::new(title)` by default.
+    /// Returns `None` if the window has never been shown.
+    pub fn window_state(&self, id: Id) -> Option<crate::WindowState> {
+        crate::WindowState::load(self, id)
+    }
+
+    /// Move, resize and collapse the [`crate::Window`] with the given id
+    /// the next time it is shown, e.g. to restore a [`Self::window_state`] saved earlier.
+    ///
+    /// This takes precedence over [`crate::Window::default_pos`], [`crate::Window::default_size`]
+    /// and [`crate::Window::default_open`], which are only used for windows without any state.
+    /// A [`crate::Window::fixed_pos`] still wins, and the size is still kept within
+    /// the min, max or fixed size of the window.
+    pub fn set_window_state(&self, id: Id, state: crate::WindowState) {
+        state.store(self, id);
+        self.request_repaint();
+    }
+
     /// Top-most layer at the given position.
     pub fn layer_id_at(&self, pos: Pos2) -> Option<LayerId> {
         self.memory(|mem| mem.layer_id_at(pos))
     }
 
+    /// Is the pointer (mouse/touch) over any of the given layers, with no other layer on top?
+    ///
+    /// Useful for keeping a group of linked areas (e.g. a menu and its submenus)
+    /// open while the pointer is over any one of them.
+    ///
+    /// See also [`Response::pointer_over_area`].
+    pub fn is_pointer_over_layers(&self, layer_ids: &[LayerId]) -> bool {
+        self.pointer_interact_pos()
+            .and_then(|pos| self.layer_id_at(pos))
+            .is_some_and(|layer_id| layer_ids.contains(&layer_id))
+    }
+
     /// Moves the given area to the top in its [`Order`].
     ///
     /// [`crate::Area`]:s and [`crate::Window`]:s also do this automatically when being clicked on or interacted with.
@@ -3243,6 +3329,44 @@ impl Context {
     }
 }
 
+/// ## Collapsing headers
+impl Context {
+    /// Open or close the [`crate::CollapsingHeader`] (or [`crate::collapsing_header::CollapsingState`])
+    /// with the given id, with an animation.
+    ///
+    /// Use [`crate::CollapsingHeader::id`] to get the id of a header.
+    /// For instance, `ui.collapsing("Settings", …)` has the id
+    /// `CollapsingHeader::new("Settings").id(ui)`.
+    pub fn set_collapsing_open(&self, id: Id, open: bool) {
+        let mut state =
+            crate::collapsing_header::CollapsingState::load_with_default_open(self, id, open);
+        state.set_open(open);
+        state.store(self);
+        self.request_repaint();
+    }
+
+    /// Open all collapsing headers under `id_prefix`, including nested ones.
+    ///
+    /// `id_prefix` is either the id of a header (see [`crate::CollapsingHeader::id`]),
+    /// which opens that header and everything in its body,
+    /// or the [`Ui::id`] of the [`Ui`] the headers are shown in,
+    /// which opens all headers in that [`Ui`] and everything in their bodies.
+    ///
+    /// Headers that are not visible yet (e.g. the children of a closed header)
+    /// will be opened when they are first shown.
+    /// Useful for an "expand all" button in a tree view.
+    pub fn open_all_collapsing(&self, id_prefix: Id) {
+        crate::collapsing_header::CollapseAllCommands::push(self, id_prefix, true);
+    }
+
+    /// Close all collapsing headers under `id_prefix`, including nested ones.
+    ///
+    /// See [`Self::open_all_collapsing`].
+    pub fn close_all_collapsing(&self, id_prefix: Id) {
+        crate::collapsing_header::CollapseAllCommands::push(self, id_prefix, false);
+    }
+}
+
 /// ## Accessibility
 impl Context {
     /// Call the provided function with the given ID pushed on the stack of
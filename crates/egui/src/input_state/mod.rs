@@ -114,7 +114,7 @@ pub struct InputState {
     last_scroll_time: f64,
 
     /// Used for smoothing the scroll delta.
-    unprocessed_scroll_delta: Vec2,
+    pub(crate) unprocessed_scroll_delta: Vec2,
 
     /// Used for smoothing the scroll delta when zooming.
     unprocessed_scroll_delta_for_zoom: f32,
@@ -298,6 +298,7 @@ impl InputState {
         let mut smooth_scroll_delta = Vec2::ZERO;
         let mut smooth_scroll_delta_for_zoom = 0.0;
 
+        let new_modifiers = new.modifiers;
         for event in &mut new.events {
             match event {
                 Event::Key {
@@ -324,7 +325,8 @@ impl InputState {
                         MouseWheelUnit::Page => screen_rect.height() * *delta,
                     };
 
-                    if modifiers.shift {
+                    // Some integrations only report shift in `RawInput::modifiers`, not in the event:
+                    if modifiers.shift || new_modifiers.shift {
                         // Treat as horizontal scrolling.
                         // Note: one Mac we already get horizontal scroll events when shift is down.
                         delta = vec2(delta.x + delta.y, 0.0);
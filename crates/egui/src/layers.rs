@@ -189,6 +189,20 @@ impl PaintList {
         }
     }
 
+    /// Mutate each [`ClippedShape`] in the given range, in-place.
+    pub fn mutate_range(
+        &mut self,
+        start: ShapeIdx,
+        end: ShapeIdx,
+        mut f: impl FnMut(&mut ClippedShape),
+    ) {
+        let end = end.0.min(self.0.len());
+        let start = start.0.min(end);
+        for clipped_shape in &mut self.0[start..end] {
+            f(clipped_shape);
+        }
+    }
+
     /// Read-only access to all held shapes.
     pub fn all_entries(&self) -> impl ExactSizeIterator<Item = &ClippedShape> {
         self.0.iter()
@@ -222,9 +236,34 @@ impl GraphicLayers {
         area_order: &[LayerId],
         to_global: &ahash::HashMap<LayerId, TSTransform>,
     ) -> Vec<ClippedShape> {
+        self.drain_with_layers(area_order, to_global).0
+    }
+
+    /// Like [`Self::drain`], but also returns which range of the shapes came from which layer,
+    /// in paint order.
+    pub fn drain_with_layers(
+        &mut self,
+        area_order: &[LayerId],
+        to_global: &ahash::HashMap<LayerId, TSTransform>,
+    ) -> (Vec<ClippedShape>, Vec<(LayerId, std::ops::Range<usize>)>) {
         profiling::function_scope!();
 
         let mut all_shapes: Vec<_> = Default::default();
+        let mut layer_ranges = Vec::new();
+
+        let mut append = |layer_id: LayerId, list: &mut PaintList| {
+            if let Some(to_global) = to_global.get(&layer_id) {
+                for clipped_shape in &mut list.0 {
+                    clipped_shape.clip_rect = *to_global * clipped_shape.clip_rect;
+                    clipped_shape.shape.transform(*to_global);
+                }
+            }
+            let start = all_shapes.len();
+            all_shapes.append(&mut list.0);
+            if start < all_shapes.len() {
+                layer_ranges.push((layer_id, start..all_shapes.len()));
+            }
+        };
 
         for &order in &Order::ALL {
             let order_map = &mut self.0[order as usize];
@@ -238,32 +277,17 @@ impl GraphicLayers {
             for layer_id in area_order {
                 if layer_id.order == order {
                     if let Some(list) = order_map.get_mut(&layer_id.id) {
-                        if let Some(to_global) = to_global.get(layer_id) {
-                            for clipped_shape in &mut list.0 {
-                                clipped_shape.clip_rect = *to_global * clipped_shape.clip_rect;
-                                clipped_shape.shape.transform(*to_global);
-                            }
-                        }
-                        all_shapes.append(&mut list.0);
+                        append(*layer_id, list);
                     }
                 }
             }
 
             // Also draw areas that are missing in `area_order`:
             for (id, list) in order_map {
-                let layer_id = LayerId::new(order, *id);
-
-                if let Some(to_global) = to_global.get(&layer_id) {
-                    for clipped_shape in &mut list.0 {
-                        clipped_shape.clip_rect = *to_global * clipped_shape.clip_rect;
-                        clipped_shape.shape.transform(*to_global);
-                    }
-                }
-
-                all_shapes.append(&mut list.0);
+                append(LayerId::new(order, *id), list);
             }
         }
 
-        all_shapes
+        (all_shapes, layer_ranges)
     }
 }
@@ -6,8 +6,8 @@ use ahash::{HashMap, HashSet};
 use epaint::emath::TSTransform;
 
 use crate::{
-    area, vec2, EventFilter, Id, IdMap, LayerId, Order, Pos2, Rangef, RawInput, Rect, Style, Vec2,
-    ViewportId, ViewportIdMap, ViewportIdSet,
+    area, emath, layers::GraphicLayers, vec2, EventFilter, Id, IdMap, LayerId, Order, Pos2, Rangef,
+    RawInput, Rect, Style, Vec2, ViewportId, ViewportIdMap, ViewportIdSet, WidgetRects,
 };
 
 mod theme;
@@ -93,8 +93,16 @@ pub struct Memory {
     /// the user clicked).
     /// If position is [`None`], the popup position will be calculated based on some configuration
     /// (e.g. relative to some other widget).
+    ///
+    /// The popup belongs to the viewport it was opened in, and is only open there.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    popup: Option<(ViewportId, Id, Option<Pos2>)>,
+
+    /// The widget the open popup is anchored to, if any, and the viewport it is in.
+    ///
+    /// The popup is closed at the end of a pass in which that widget wasn't shown.
     #[cfg_attr(feature = "persistence", serde(skip))]
-    popup: Option<(Id, Option<Pos2>)>,
+    popup_anchor: Option<(ViewportId, Id)>,
 
     #[cfg_attr(feature = "persistence", serde(skip))]
     everything_is_visible: bool,
@@ -131,6 +139,7 @@ impl Default for Memory {
             areas: Default::default(),
             to_global: Default::default(),
             popup: Default::default(),
+            popup_anchor: Default::default(),
             everything_is_visible: Default::default(),
             add_fonts: Default::default(),
         };
@@ -701,7 +710,7 @@ impl Focus {
         self.top_modal_layer
     }
 
-    fn reset_focus(&mut self) {
+    pub(crate) fn reset_focus(&mut self) {
         self.focus_direction = FocusDirection::None;
     }
 
@@ -803,10 +812,51 @@ impl Memory {
             .begin_pass(new_raw_input);
     }
 
-    pub(crate) fn end_pass(&mut self, used_ids: &IdMap<Rect>) {
+    pub(crate) fn end_pass(&mut self, used_ids: &IdMap<Rect>, widgets: &WidgetRects) {
         self.caches.update();
         self.areas_mut().end_pass();
         self.focus_mut().end_pass(used_ids);
+        self.keep_focus_above_modal_layer(widgets);
+
+        if let Some((viewport_id, anchor_id)) = self.popup_anchor {
+            if viewport_id == self.viewport_id && !widgets.contains(anchor_id) {
+                // Dead-mans-switch: the widget the popup belongs to has disappeared!
+                self.close_popup();
+            }
+        }
+    }
+
+    /// Take the keyboard focus away from widgets behind the top modal layer,
+    /// so that Tab/Shift+Tab and the arrow keys only move it among the widgets of the modal.
+    fn keep_focus_above_modal_layer(&mut self, widgets: &WidgetRects) {
+        if self.top_modal_layer().is_none() {
+            return;
+        }
+        let is_behind_modal = |id: Id| {
+            widgets
+                .get(id)
+                .is_some_and(|widget| !self.is_above_modal_layer(widget.layer_id))
+        };
+        let focused_is_behind_modal = self.focused().is_some_and(is_behind_modal);
+        let behind_modal: Vec<Id> = self
+            .focus()
+            .map(|focus| {
+                focus
+                    .focus_widgets_cache
+                    .keys()
+                    .copied()
+                    .filter(|id| is_behind_modal(*id))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let focus = self.focus_mut();
+        if focused_is_behind_modal {
+            focus.focused_widget = None;
+        }
+        for id in behind_modal {
+            focus.focus_widgets_cache.remove(&id);
+        }
     }
 
     pub(crate) fn set_viewport_id(&mut self, viewport_id: ViewportId) {
@@ -1071,36 +1121,69 @@ impl Memory {
 /// ## Popups
 /// Popups are things like combo-boxes, color pickers, menus etc.
 /// Only one can be open at a time.
+///
+/// A popup is opened in the current viewport, and is only open there.
+/// So the same popup (e.g. a menu in a panel shown in several viewports)
+/// doesn't show up in the other viewports as well.
 impl Memory {
+    /// The open popup, if it belongs to the current viewport.
+    fn popup_in_this_viewport(&self) -> Option<(Id, Option<Pos2>)> {
+        self.popup
+            .filter(|(viewport_id, _, _)| *viewport_id == self.viewport_id)
+            .map(|(_, id, pos)| (id, pos))
+    }
+
     /// Is the given popup open?
     pub fn is_popup_open(&self, popup_id: Id) -> bool {
-        self.popup.is_some_and(|(id, _)| id == popup_id) || self.everything_is_visible()
+        self.popup_in_this_viewport()
+            .is_some_and(|(id, _)| id == popup_id)
+            || self.everything_is_visible()
     }
 
     /// Is any popup open?
     pub fn any_popup_open(&self) -> bool {
-        self.popup.is_some() || self.everything_is_visible()
+        self.popup_in_this_viewport().is_some() || self.everything_is_visible()
     }
 
     /// Open the given popup and close all others.
     pub fn open_popup(&mut self, popup_id: Id) {
-        self.popup = Some((popup_id, None));
+        self.popup = Some((self.viewport_id, popup_id, None));
+        self.popup_anchor = None;
     }
 
     /// Open the popup and remember its position.
     pub fn open_popup_at(&mut self, popup_id: Id, pos: impl Into<Option<Pos2>>) {
-        self.popup = Some((popup_id, pos.into()));
+        self.popup = Some((self.viewport_id, popup_id, pos.into()));
+        self.popup_anchor = None;
     }
 
     /// Get the position for this popup.
     pub fn popup_position(&self, id: Id) -> Option<Pos2> {
-        self.popup
+        self.popup_in_this_viewport()
             .and_then(|(popup_id, pos)| if popup_id == id { pos } else { None })
     }
 
     /// Close the open popup, if any.
     pub fn close_popup(&mut self) {
         self.popup = None;
+        self.popup_anchor = None;
+    }
+
+    /// Close the given popup automatically once the widget with the given id is no longer shown.
+    ///
+    /// The popup is closed at the end of the first pass in which `widget_id` isn't shown,
+    /// e.g. because it was filtered out of a list.
+    /// Opening another popup forgets the anchor.
+    ///
+    /// Does nothing if the given popup isn't open.
+    /// [`crate::Popup::from_response`] calls this for you.
+    pub fn anchor_popup(&mut self, popup_id: Id, widget_id: Id) {
+        if self
+            .popup_in_this_viewport()
+            .is_some_and(|(id, _)| id == popup_id)
+        {
+            self.popup_anchor = Some((self.viewport_id, widget_id));
+        }
     }
 
     /// Toggle the given popup between closed and open.
@@ -1170,6 +1253,38 @@ pub struct Areas {
     ///
     /// The parent sublayer is moved directly above the child sublayers in the ordering.
     sublayers: ahash::HashMap<LayerId, HashSet<LayerId>>,
+
+    /// For areas that are only interactable in some regions (in layer space).
+    ///
+    /// Outside of these, the pointer passes through to whatever is behind the area.
+    /// See [`crate::Area::interactable_rects`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    interactable_rects: ahash::HashMap<LayerId, Vec<Rect>>,
+
+    /// For areas with a non-rectangular hit area, see [`crate::Area::hit_shape`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    hit_shapes: ahash::HashMap<LayerId, area::HitShape>,
+
+    /// Areas that only move while a modifier is held, which isn't,
+    /// so drags go through them to what is behind.
+    /// See [`crate::Area::drag_with_modifier`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    passes_drags_through: ahash::HashSet<LayerId>,
+
+    /// What areas that fade out look like, so we can keep painting them after they are closed.
+    /// See [`crate::Area::fade_out`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    fade_outs: ahash::HashMap<LayerId, FadeOut>,
+}
+
+/// The last look of an area that fades out when closed.
+#[derive(Clone, Debug)]
+struct FadeOut {
+    /// In seconds.
+    duration: f32,
+
+    /// In layer space.
+    shapes: Vec<epaint::ClippedShape>,
 }
 
 impl Areas {
@@ -1181,6 +1296,10 @@ impl Areas {
         self.areas.get(&id)
     }
 
+    pub(crate) fn get_mut(&mut self, id: Id) -> Option<&mut area::AreaState> {
+        self.areas.get_mut(&id)
+    }
+
     /// All layers back-to-front, top is last.
     pub(crate) fn order(&self) -> &[LayerId] {
         &self.order
@@ -1222,7 +1341,8 @@ impl Areas {
                             rect = *to_global * rect;
                         }
 
-                        if rect.contains(pos) {
+                        if rect.contains(pos) && !self.is_pass_through(*layer, pos, layer_to_global)
+                        {
                             return Some(*layer);
                         }
                     }
@@ -1232,6 +1352,132 @@ impl Areas {
         None
     }
 
+    /// Limit the regions of the layer that the pointer can interact with.
+    ///
+    /// `None` means the whole layer is interactable.
+    /// See [`crate::Area::interactable_rects`].
+    pub(crate) fn set_interactable_rects(&mut self, layer_id: LayerId, rects: Option<Vec<Rect>>) {
+        if let Some(rects) = rects {
+            self.interactable_rects.insert(layer_id, rects);
+        } else {
+            self.interactable_rects.remove(&layer_id);
+        }
+    }
+
+    /// Limit the region of the layer that the pointer can interact with to a shape.
+    ///
+    /// `None` means the whole layer is interactable.
+    /// See [`crate::Area::hit_shape`].
+    pub(crate) fn set_hit_shape(&mut self, layer_id: LayerId, hit_shape: Option<area::HitShape>) {
+        if let Some(hit_shape) = hit_shape {
+            self.hit_shapes.insert(layer_id, hit_shape);
+        } else {
+            self.hit_shapes.remove(&layer_id);
+        }
+    }
+
+    /// Let drags go through the layer to whatever is behind it, see [`crate::Area::drag_with_modifier`].
+    pub(crate) fn set_passes_drags_through(&mut self, layer_id: LayerId, passes_drags: bool) {
+        if passes_drags {
+            self.passes_drags_through.insert(layer_id);
+        } else {
+            self.passes_drags_through.remove(&layer_id);
+        }
+    }
+
+    /// Do drags go through this layer to whatever is behind it?
+    ///
+    /// See [`crate::Area::drag_with_modifier`].
+    pub fn passes_drags_through(&self, layer_id: LayerId) -> bool {
+        self.passes_drags_through.contains(&layer_id)
+    }
+
+    /// Remember how an area looks, so it can fade out once it is no longer shown.
+    pub(crate) fn set_fade_out(
+        &mut self,
+        layer_id: LayerId,
+        duration: f32,
+        shapes: Vec<epaint::ClippedShape>,
+    ) {
+        self.fade_outs
+            .insert(layer_id, FadeOut { duration, shapes });
+    }
+
+    pub(crate) fn clear_fade_out(&mut self, layer_id: LayerId) {
+        self.fade_outs.remove(&layer_id);
+    }
+
+    /// Paint the areas that were closed recently and are still fading out.
+    ///
+    /// Call this at the end of the pass, before [`Self::end_pass`].
+    /// Returns `true` if any area is still fading out, and so we need to repaint.
+    pub(crate) fn paint_fading_out(&mut self, graphics: &mut GraphicLayers, time: f64) -> bool {
+        let Self {
+            areas,
+            visible_areas_current_frame,
+            fade_outs,
+            ..
+        } = self;
+
+        let mut any_fading = false;
+        fade_outs.retain(|layer_id, fade_out| {
+            if visible_areas_current_frame.contains(layer_id) {
+                return true; // Still shown
+            }
+            let Some(state) = areas.get_mut(&layer_id.id) else {
+                return false;
+            };
+            let closed_at = *state.last_became_invisible_at.get_or_insert(time);
+            let t = (time - closed_at) as f32 / fade_out.duration;
+            if 1.0 <= t {
+                return false; // Done fading out
+            }
+
+            let opacity = 1.0 - emath::easing::quadratic_out(t);
+            let list = graphics.entry(*layer_id);
+            for clipped_shape in &fade_out.shapes {
+                let mut shape = clipped_shape.shape.clone();
+                crate::painter::multiply_opacity(&mut shape, opacity);
+                list.add(clipped_shape.clip_rect, shape);
+            }
+            any_fading = true;
+            true
+        });
+        any_fading
+    }
+
+    /// Does the pointer pass through this layer at the given (global) position?
+    ///
+    /// This is the case if the layer has limited its interactable regions
+    /// using [`crate::Area::interactable_rects`], and the position is outside all of them,
+    /// or if the position is outside its [`crate::Area::hit_shape`].
+    pub fn is_pass_through(
+        &self,
+        layer_id: LayerId,
+        pos: Pos2,
+        layer_to_global: &HashMap<LayerId, TSTransform>,
+    ) -> bool {
+        let rects = self.interactable_rects.get(&layer_id);
+        let hit_shape = self.hit_shapes.get(&layer_id);
+        if rects.is_none() && hit_shape.is_none() {
+            return false;
+        }
+        let pos = layer_to_global
+            .get(&layer_id)
+            .map_or(pos, |to_global| to_global.inverse() * pos);
+        if let Some(rects) = rects {
+            if !rects.iter().any(|rect| rect.contains(pos)) {
+                return true;
+            }
+        }
+        if let (Some(hit_shape), Some(state)) = (hit_shape, self.areas.get(&layer_id.id)) {
+            if !hit_shape.contains((pos - state.rect().min).to_pos2()) {
+                return true;
+            }
+        }
+        false
+    }
+
     pub fn visible_last_frame(&self, layer_id: &LayerId) -> bool {
         self.visible_areas_last_frame.contains(layer_id)
     }
@@ -1325,9 +1571,17 @@ impl Areas {
             order,
             wants_to_be_on_top,
             sublayers,
+            interactable_rects,
+            hit_shapes,
+            passes_drags_through,
             ..
         } = self;
 
+        // Areas that were not shown this pass should not affect hit-testing:
+        interactable_rects.retain(|layer_id, _| visible_areas_current_frame.contains(layer_id));
+        hit_shapes.retain(|layer_id, _| visible_areas_current_frame.contains(layer_id));
+        passes_drags_through.retain(|layer_id| visible_areas_current_frame.contains(layer_id));
+
         std::mem::swap(visible_areas_last_frame, visible_areas_current_frame);
         visible_areas_current_frame.clear();
 
@@ -1369,6 +1623,27 @@ fn memory_impl_send_sync() {
     assert_send_sync::<Memory>();
 }
 
+#[test]
+fn popups_are_per_viewport() {
+    let viewport_a = ViewportId::from_hash_of("a");
+    let viewport_b = ViewportId::from_hash_of("b");
+    let popup_id = Id::new("menu");
+
+    let mut memory = Memory::default();
+    memory.set_viewport_id(viewport_a);
+    memory.open_popup_at(popup_id, Pos2::new(1.0, 2.0));
+    assert!(memory.is_popup_open(popup_id));
+
+    memory.set_viewport_id(viewport_b);
+    assert!(!memory.is_popup_open(popup_id));
+    assert!(!memory.any_popup_open());
+    assert_eq!(memory.popup_position(popup_id), None);
+
+    memory.set_viewport_id(viewport_a);
+    assert!(memory.is_popup_open(popup_id));
+    assert_eq!(memory.popup_position(popup_id), Some(Pos2::new(1.0, 2.0)));
+}
+
 #[test]
 fn order_map_total_ordering() {
     let mut layers = [
@@ -1412,3 +1687,54 @@ fn order_map_total_ordering() {
         }
     }
 }
+
+#[test]
+fn pass_through_regions_are_forgotten_when_the_area_is_hidden() {
+    let layer_id = LayerId::new(Order::Middle, Id::new("overlay"));
+    let rect = Rect::from_min_size(Pos2::ZERO, Vec2::splat(100.0));
+    let pos = Pos2::new(50.0, 50.0);
+    let to_global = Default::default();
+    let mut areas = Areas::default();
+
+    areas.set_interactable_rects(layer_id, Some(vec![Rect::NOTHING]));
+    areas.set_state(
+        layer_id,
+        crate::AreaState {
+            pivot_pos: Some(rect.min),
+            size: Some(rect.size()),
+            ..Default::default()
+        },
+    );
+    areas.end_pass();
+    assert!(areas.is_pass_through(layer_id, pos, &to_global));
+
+    // Not shown this pass:
+    areas.end_pass();
+    assert!(!areas.is_pass_through(layer_id, pos, &to_global));
+    assert!(areas.interactable_rects.is_empty());
+}
+
+#[test]
+fn hit_shapes_are_forgotten_when_the_area_is_hidden() {
+    let layer_id = LayerId::new(Order::Middle, Id::new("overlay"));
+    let pos = Pos2::new(50.0, 50.0);
+    let to_global = Default::default();
+    let mut areas = Areas::default();
+
+    let nowhere = area::HitShape(std::sync::Arc::new(|_| false));
+    areas.set_hit_shape(layer_id, Some(nowhere));
+    areas.set_state(
+        layer_id,
+        crate::AreaState {
+            pivot_pos: Some(Pos2::ZERO),
+            size: Some(Vec2::splat(100.0)),
+            ..Default::default()
+        },
+    );
+    areas.end_pass();
+    assert!(areas.is_pass_through(layer_id, pos, &to_global));
+
+    // Not shown this pass:
+    areas.end_pass();
+    assert!(areas.hit_shapes.is_empty());
+}
@@ -12,6 +12,9 @@ use crate::{
     Color32, Context, FontId,
 };
 
+/// How far outside the rect of a widget [`Painter::focus_ring`] is painted, in points.
+const FOCUS_RING_GAP: f32 = 2.0;
+
 /// Helper to paint shapes and text to a specific region on a specific layer.
 ///
 /// All coordinates are screen coordinates in the unit points (one point can consist of many physical pixels).
@@ -445,6 +448,49 @@ impl Painter {
         self.add(RectShape::stroke(rect, corner_radius, stroke, stroke_kind))
     }
 
+    /// Paint a keyboard focus ring just outside `rect`, e.g. around a custom widget that has focus.
+    ///
+    /// The ring has the stroke of [`crate::style::Selection`], like a focused [`crate::TextEdit`],
+    /// and is drawn a couple of points outside `rect`, so it covers neither the widget nor its neighbors
+    /// and doesn't affect the layout.
+    ///
+    /// The ring is painted on a layer of its own, directly above the layer of this painter,
+    /// so it is on top of the widget, and the frame of a container around the widget
+    /// (e.g. with [`crate::Frame::clip_content`]) doesn't cut it off.
+    /// For the same reason it may extend that far past the clip rect of this painter.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let (rect, response) = ui.allocate_exact_size(egui::vec2(40.0, 20.0), egui::Sense::click());
+    /// ui.painter().rect_filled(rect, 4.0, egui::Color32::DARK_GRAY);
+    /// if response.has_focus() {
+    ///     ui.painter().focus_ring(rect, 4.0, ui.visuals());
+    /// }
+    /// # });
+    /// ```
+    pub fn focus_ring(
+        &self,
+        rect: Rect,
+        corner_radius: impl Into<CornerRadius>,
+        visuals: &crate::Visuals,
+    ) -> ShapeIdx {
+        let stroke = visuals.selection.stroke;
+        let layer_id = LayerId::new(self.layer_id.order, self.layer_id.id.with("focus_ring"));
+        self.ctx.set_sublayer(self.layer_id, layer_id);
+        if let Some(transform) = self.ctx.layer_transform_to_global(self.layer_id) {
+            self.ctx.set_transform_layer(layer_id, transform);
+        }
+
+        let mut painter = self.clone().with_layer_id(layer_id);
+        painter.clip_rect = self.clip_rect.expand(FOCUS_RING_GAP + stroke.width);
+        painter.add(RectShape::stroke(
+            rect.expand(FOCUS_RING_GAP),
+            corner_radius.into() + FOCUS_RING_GAP as u8,
+            stroke,
+            StrokeKind::Outside,
+        ))
+    }
+
     /// Show an arrow starting at `origin` and going in the direction of `vec`, with the length `vec.length()`.
     pub fn arrow(&self, origin: Pos2, vec: Vec2, stroke: impl Into<Stroke>) {
         use crate::emath::Rot2;
@@ -602,7 +648,7 @@ fn tint_shape_towards(shape: &mut Shape, target: Color32) {
     });
 }
 
-fn multiply_opacity(shape: &mut Shape, opacity: f32) {
+pub(crate) fn multiply_opacity(shape: &mut Shape, opacity: f32) {
     epaint::shape_transform::adjust_colors(shape, move |color| {
         if *color != Color32::PLACEHOLDER {
             *color = color.gamma_multiply(opacity);
@@ -1,6 +1,8 @@
 use ahash::HashMap;
 
-use crate::{id::IdSet, style, Align, Id, IdMap, LayerId, Rangef, Rect, Vec2, WidgetRects};
+use crate::{
+    id::IdSet, style, Align, Id, IdMap, LayerId, Rangef, Rect, Vec2, WidgetRects, WindowInfo,
+};
 
 #[cfg(debug_assertions)]
 use crate::{pos2, Align2, Color32, FontId, NumExt, Painter};
@@ -55,6 +57,11 @@ pub struct ScrollTarget {
 
     /// How should the scroll be animated?
     pub animation: style::ScrollAnimation,
+
+    /// Keep this much space around the range visible, if there is room for it.
+    ///
+    /// If `None`, [`crate::style::Spacing::item_spacing`] is used.
+    pub margin: Option<f32>,
 }
 
 impl ScrollTarget {
@@ -63,8 +70,16 @@ impl ScrollTarget {
             range,
             align,
             animation,
+            margin: None,
         }
     }
+
+    /// Keep this much space around the range visible, see [`Self::margin`].
+    #[inline]
+    pub fn with_margin(mut self, margin: f32) -> Self {
+        self.margin = Some(margin);
+        self
+    }
 }
 
 #[cfg(feature = "accesskit")]
@@ -231,6 +246,9 @@ pub struct PassState {
     /// Highlight these widgets the next pass.
     pub highlight_next_pass: IdSet,
 
+    /// All [`crate::Window`]s shown this pass, in the order they were shown.
+    pub windows: Vec<WindowInfo>,
+
     #[cfg(debug_assertions)]
     pub debug_rect: Option<DebugRect>,
 }
@@ -250,6 +268,7 @@ impl Default for PassState {
             #[cfg(feature = "accesskit")]
             accesskit_state: None,
             highlight_next_pass: Default::default(),
+            windows: Default::default(),
 
             #[cfg(debug_assertions)]
             debug_rect: None,
@@ -273,6 +292,7 @@ impl PassState {
             #[cfg(feature = "accesskit")]
             accesskit_state,
             highlight_next_pass,
+            windows,
 
             #[cfg(debug_assertions)]
             debug_rect,
@@ -299,6 +319,7 @@ impl PassState {
         }
 
         highlight_next_pass.clear();
+        windows.clear();
     }
 
     /// How much space is still available after panels has been added.
@@ -136,6 +136,15 @@ bitflags::bitflags! {
 
         /// Should this container be closed?
         const CLOSE = 1<<12;
+
+        /// Was this container (e.g. a [`Window`](crate::Window)) moved by the user this frame?
+        const MOVED = 1<<13;
+
+        /// Was this container (e.g. a [`Window`](crate::Window)) resized by the user this frame?
+        const RESIZED = 1<<14;
+
+        /// Is the pointer over this container (e.g. an [`Area`](crate::Area)), with no other layer on top?
+        const POINTER_OVER_AREA = 1<<15;
     }
 }
 
@@ -546,6 +555,33 @@ impl Response {
         self.flags.set(Flags::CLOSE, true);
     }
 
+    /// Did the user move this container this frame?
+    ///
+    /// Set on the response of a [`Window`](crate::Window) when it was dragged to a new position,
+    /// including when resizing it from the left or top edge.
+    /// Useful for only persisting the layout when it changes.
+    pub fn moved(&self) -> bool {
+        self.flags.contains(Flags::MOVED)
+    }
+
+    /// Did the user resize this container this frame?
+    ///
+    /// Set on the response of a [`Window`](crate::Window) when its size was changed
+    /// by dragging one of its edges or corners.
+    pub fn resized(&self) -> bool {
+        self.flags.contains(Flags::RESIZED)
+    }
+
+    /// Is the pointer over this container, with no other layer on top of it?
+    ///
+    /// Set on the response of an [`Area`](crate::Area) (and so also of a [`Window`](crate::Window)
+    /// and popups), regardless of which widget inside it the pointer is over.
+    ///
+    /// See also [`Context::is_pointer_over_layers`].
+    pub fn pointer_over_area(&self) -> bool {
+        self.flags.contains(Flags::POINTER_OVER_AREA)
+    }
+
     /// Show this UI if the widget was hovered (i.e. a tooltip).
     ///
     /// The text will not be visible if the widget is not enabled.
@@ -572,6 +608,17 @@ impl Response {
         self
     }
 
+    /// Like [`Self::on_hover_ui`], but only show the tooltip after hovering for `delay` seconds.
+    ///
+    /// This overrides [`style::Interaction::tooltip_delay`](crate::style::Interaction::tooltip_delay)
+    /// for this tooltip.
+    /// For instance, use a `delay` of `0.0` for help icons that should explain themselves right away.
+    #[doc(alias = "tooltip")]
+    pub fn on_hover_ui_with_delay(self, delay: f32, add_contents: impl FnOnce(&mut Ui)) -> Self {
+        Tooltip::for_enabled_with_delay(&self, delay).show(add_contents);
+        self
+    }
+
     /// Show this UI when hovering if the widget is disabled.
     pub fn on_disabled_hover_ui(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
         Tooltip::for_disabled(&self).show(add_contents);
@@ -639,6 +686,20 @@ impl Response {
         })
     }
 
+    /// Like [`Self::on_hover_text`], but only show the tooltip after hovering for `delay` seconds.
+    ///
+    /// See [`Self::on_hover_ui_with_delay`].
+    #[doc(alias = "tooltip")]
+    pub fn on_hover_text_with_delay(self, delay: f32, text: impl Into<WidgetText>) -> Self {
+        self.on_hover_ui_with_delay(delay, |ui| {
+            // Prevent `Area` auto-sizing from shrinking tooltips with dynamic content.
+            // See https://github.com/emilk/egui/issues/5167
+            ui.set_max_width(ui.spacing().tooltip_width);
+
+            ui.add(crate::widgets::Label::new(text));
+        })
+    }
+
     /// Highlight this widget, to make it look like it is hovered, even if it isn't.
     ///
     /// The highlight takes one frame to take effect if you call this after the widget has been fully rendered.
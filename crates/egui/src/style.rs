@@ -119,6 +119,42 @@ impl TextStyle {
 
 // ----------------------------------------------------------------------------
 
+/// What a [`crate::Frame`] is used for, so that a theme can style all frames of the same kind at once.
+///
+/// Use [`crate::Frame::role`] to get the frame for a role.
+/// It is found in [`Style::frame_roles`], or derived from the rest of the [`Style`] if not there.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum FrameRole {
+    /// A box grouping related content, e.g. an item in a list or a settings section.
+    Card,
+
+    /// A small frame around something in a line of text or widgets, e.g. a tag or a keyboard shortcut.
+    Inline,
+
+    /// The main frame of something the user is asked to deal with, e.g. a window or a modal.
+    Dialog,
+
+    /// A user-chosen role, found in [`Style::frame_roles`].
+    /// ```
+    /// egui::FrameRole::Name("toolbar".into());
+    /// ````
+    Name(std::sync::Arc<str>),
+}
+
+impl std::fmt::Display for FrameRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Card => "Card".fmt(f),
+            Self::Inline => "Inline".fmt(f),
+            Self::Dialog => "Dialog".fmt(f),
+            Self::Name(name) => (*name).fmt(f),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// A way to select [`FontId`], either by picking one directly or by using a [`TextStyle`].
 pub enum FontSelection {
     /// Default text style - will use [`TextStyle::Body`], unless
@@ -273,6 +309,22 @@ pub struct Style {
     /// ```
     pub text_styles: BTreeMap<TextStyle, FontId>,
 
+    /// The [`crate::Frame`] to use for a specific [`FrameRole`], see [`crate::Frame::role`].
+    ///
+    /// Roles that are not in here get a frame derived from the rest of the style,
+    /// so this is empty by default.
+    ///
+    /// ```
+    /// # let mut ctx = egui::Context::default();
+    /// use egui::{Frame, FrameRole};
+    ///
+    /// ctx.all_styles_mut(|style| {
+    ///     let card = Frame::role(style, &FrameRole::Card).inner_margin(12).corner_radius(8);
+    ///     style.frame_roles.insert(FrameRole::Card, card);
+    /// });
+    /// ```
+    pub frame_roles: BTreeMap<FrameRole, crate::Frame>,
+
     /// The style to use for [`DragValue`] text.
     pub drag_value_text_style: TextStyle,
 
@@ -469,6 +521,18 @@ impl Spacing {
 
         (small_icon_rect, big_icon_rect)
     }
+
+    /// A more compact version of this spacing, e.g. for a tool window next to the main UI.
+    ///
+    /// Halves the spacing between and inside widgets, and makes widgets a little lower.
+    pub fn dense(&self) -> Self {
+        Self {
+            item_spacing: self.item_spacing * 0.5,
+            button_padding: self.button_padding * 0.5,
+            interact_size: vec2(self.interact_size.x, (self.interact_size.y * 0.8).round()),
+            ..self.clone()
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -747,6 +811,9 @@ impl ScrollStyle {
 /// Scroll animation configuration, used when programmatically scrolling somewhere (e.g. with `[crate::Ui::scroll_to_cursor]`)
 /// The animation duration is calculated based on the distance to be scrolled via `[ScrollAnimation::points_per_second]`
 /// and can be clamped to a min / max duration via `[ScrollAnimation::duration]`.
+///
+/// The [`crate::Style::scroll_animation`] is also used when scrolling with the mouse wheel
+/// or by clicking the scroll bar track, so that is where to set an easing for all scrolling.
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(default))]
@@ -756,6 +823,15 @@ pub struct ScrollAnimation {
 
     /// The min / max scroll duration.
     pub duration: Rangef,
+
+    /// Maps the elapsed fraction of the animation time (`0..=1`)
+    /// to the fraction of the distance scrolled, e.g. one of the functions in [`emath::easing`].
+    ///
+    /// It may return values outside `0..=1` to overshoot, like a spring.
+    ///
+    /// Default: [`emath::ease_in_ease_out`]. Not serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub easing: fn(f32) -> f32,
 }
 
 impl Default for ScrollAnimation {
@@ -763,6 +839,7 @@ impl Default for ScrollAnimation {
         Self {
             points_per_second: 1000.0,
             duration: Rangef::new(0.1, 0.3),
+            easing: emath::ease_in_ease_out,
         }
     }
 }
@@ -773,6 +850,7 @@ impl ScrollAnimation {
         Self {
             points_per_second,
             duration,
+            easing: emath::ease_in_ease_out,
         }
     }
 
@@ -781,6 +859,7 @@ impl ScrollAnimation {
         Self {
             points_per_second: f32::INFINITY,
             duration: Rangef::new(0.0, 0.0),
+            easing: emath::ease_in_ease_out,
         }
     }
 
@@ -789,9 +868,19 @@ impl ScrollAnimation {
         Self {
             points_per_second: f32::INFINITY,
             duration: Rangef::new(t, t),
+            easing: emath::ease_in_ease_out,
         }
     }
 
+    /// Use this easing function, see [`Self::easing`].
+    ///
+    /// For instance, `ScrollAnimation::default().with_easing(emath::easing::linear)`.
+    #[inline]
+    pub fn with_easing(mut self, easing: fn(f32) -> f32) -> Self {
+        self.easing = easing;
+        self
+    }
+
     pub fn ui(&mut self, ui: &mut crate::Ui) {
         crate::Grid::new("scroll_animation").show(ui, |ui| {
             ui.label("Scroll animation:");
@@ -1263,6 +1352,7 @@ impl Default for Style {
             override_text_style: None,
             override_text_valign: Some(Align::Center),
             text_styles: default_text_styles(),
+            frame_roles: BTreeMap::new(),
             drag_value_text_style: TextStyle::Button,
             number_formatter: NumberFormatter(Arc::new(emath::format_with_decimals_in_range)),
             wrap: None,
@@ -1564,6 +1654,7 @@ impl Style {
             override_text_style,
             override_text_valign,
             text_styles,
+            frame_roles: _, // no editor for frames yet
             drag_value_text_style,
             number_formatter: _, // can't change callbacks in the UI
             wrap: _,
@@ -2564,8 +2655,12 @@ impl Widget for &mut crate::Frame {
             outer_margin,
             corner_radius,
             shadow,
+            backdrop_blur,
             fill,
             stroke,
+            stroke_sides,
+            clip_content,
+            sense: _,
         } = self;
 
         crate::Grid::new("frame")
@@ -2590,6 +2685,10 @@ impl Widget for &mut crate::Frame {
                 ui.add(shadow);
                 ui.end_row();
 
+                ui.label("Backdrop blur");
+                ui.add(DragValue::new(backdrop_blur).range(0..=100));
+                ui.end_row();
+
                 ui.label("Fill");
                 ui.color_edit_button_srgba(fill);
                 ui.end_row();
@@ -2597,6 +2696,26 @@ impl Widget for &mut crate::Frame {
                 ui.label("Stroke");
                 ui.add(stroke);
                 ui.end_row();
+
+                ui.label("Stroke sides");
+                ui.horizontal(|ui| {
+                    for (name, side) in [
+                        ("Left", crate::StrokeSides::LEFT),
+                        ("Right", crate::StrokeSides::RIGHT),
+                        ("Top", crate::StrokeSides::TOP),
+                        ("Bottom", crate::StrokeSides::BOTTOM),
+                    ] {
+                        let mut checked = stroke_sides.contains(side);
+                        if ui.checkbox(&mut checked, name).changed() {
+                            stroke_sides.set(side, checked);
+                        }
+                    }
+                });
+                ui.end_row();
+
+                ui.label("Clip content");
+                ui.checkbox(clip_content, "");
+                ui.end_row();
             })
             .response
     }
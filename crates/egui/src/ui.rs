@@ -23,7 +23,7 @@ use crate::{
     vec2, widgets,
     widgets::{
         color_picker, Button, Checkbox, DragValue, Hyperlink, Image, ImageSource, Label, Link,
-        RadioButton, SelectableLabel, Separator, Spinner, TextEdit, Widget,
+        RadioButton, SelectableLabel, Separator, Skeleton, Spinner, TextEdit, Widget,
     },
     Align, Color32, Context, CursorIcon, DragAndDrop, Id, InnerResponse, InputState, LayerId,
     Memory, Order, Painter, PlatformOutput, Pos2, Rangef, Rect, Response, Rgba, RichText, Sense,
@@ -111,6 +111,11 @@ pub struct Ui {
     /// This is an optimization, so we don't call [`Ui::remember_min_rect`] multiple times at the
     /// end of a [`Ui::scope`].
     min_rect_already_remembered: bool,
+
+    /// If set, the rects of the widgets placed directly in this [`Ui`] are recorded here.
+    ///
+    /// Used by [`crate::ScrollArea::snap_to_children`].
+    child_rects: Option<Vec<Rect>>,
 }
 
 impl Ui {
@@ -171,6 +176,7 @@ impl Ui {
             stack: Arc::new(ui_stack),
             sense,
             min_rect_already_remembered: false,
+            child_rects: None,
         };
 
         // Register in the widget stack early, to ensure we are behind all widgets we contain:
@@ -315,6 +321,7 @@ impl Ui {
             stack: Arc::new(ui_stack),
             sense,
             min_rect_already_remembered: false,
+            child_rects: None,
         };
 
         if disabled {
@@ -1404,12 +1411,29 @@ impl Ui {
 
         self.placer
             .advance_after_rects(frame_rect, widget_rect, item_spacing);
+        self.record_child_rect(widget_rect);
 
         register_rect(self, widget_rect);
 
         widget_rect
     }
 
+    /// Start recording the rects of the widgets placed directly in this [`Ui`].
+    pub(crate) fn start_recording_child_rects(&mut self) {
+        self.child_rects = Some(Vec::new());
+    }
+
+    /// The rects recorded since [`Self::start_recording_child_rects`], in placement order.
+    pub(crate) fn take_child_rects(&mut self) -> Vec<Rect> {
+        self.child_rects.take().unwrap_or_default()
+    }
+
+    fn record_child_rect(&mut self, rect: Rect) {
+        if let Some(child_rects) = &mut self.child_rects {
+            child_rects.push(rect);
+        }
+    }
+
     /// Allocate a specific part of the [`Ui`].
     ///
     /// Ignore the layout of the [`Ui`]: just put my widget here!
@@ -1427,6 +1451,7 @@ impl Ui {
 
         let item_spacing = self.spacing().item_spacing;
         self.placer.advance_after_rects(rect, rect, item_spacing);
+        self.record_child_rect(rect);
         register_rect(self, rect);
 
         let id = Id::new(self.next_auto_id_salt);
@@ -1602,6 +1627,39 @@ impl Ui {
         }
     }
 
+    /// Scroll any parent [`crate::ScrollArea`] as little as possible,
+    /// so that the given [`Rect`] is visible with `margin` points of space around it.
+    ///
+    /// If the rect is already visible with that margin, nothing happens.
+    /// This is the "keep the caret in view" behavior of text editors:
+    /// unlike [`Self::scroll_to_rect`] with an [`Align`], the view is not re-centered.
+    /// If there is not room for the full margin, as much as fits is used.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::ScrollArea::vertical().show(ui, |ui| {
+    ///     for i in 0..100 {
+    ///         ui.label(format!("line {i}"));
+    ///     }
+    ///     # let caret_rect = egui::Rect::from_min_size(egui::pos2(0.0, 500.0), egui::vec2(1.0, 14.0));
+    ///     // Keep two lines of context above and below the caret:
+    ///     ui.scroll_to_rect_with_margin(caret_rect, 28.0);
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// See also: [`Ui::scroll_to_rect`].
+    pub fn scroll_to_rect_with_margin(&self, rect: Rect, margin: f32) {
+        let animation = self.style.scroll_animation;
+        for d in 0..2 {
+            let range = Rangef::new(rect.min[d], rect.max[d]);
+            self.ctx().pass_state_mut(|state| {
+                state.scroll_target[d] =
+                    Some(pass_state::ScrollTarget::new(range, None, animation).with_margin(margin));
+            });
+        }
+    }
+
     /// Adjust the scroll position of any parent [`crate::ScrollArea`] so that the cursor (where the next widget goes) becomes visible.
     ///
     /// If `align` is [`Align::TOP`] it means "put the top of the rect at the top of the scroll area", etc.
@@ -2167,6 +2225,16 @@ impl Ui {
         Spinner::new().ui(self)
     }
 
+    /// A shimmering placeholder of the given size, for content that is still loading.
+    ///
+    /// Shortcut for `add(Skeleton::new(size))`
+    ///
+    /// See also [`Skeleton`].
+    #[inline]
+    pub fn skeleton(&mut self, size: impl Into<Vec2>) -> Response {
+        Skeleton::new(size).ui(self)
+    }
+
     /// Modify an angle. The given angle should be in radians, but is shown to the user in degrees.
     /// The angle is NOT wrapped, so the user may select, for instance 720° = 2𝞃 = 4π
     pub fn drag_angle(&mut self, radians: &mut f32) -> Response {
@@ -2417,6 +2485,63 @@ impl Ui {
         InnerResponse::new(ret, response)
     }
 
+    /// Like [`Self::scope`], but skips `add_contents` while the scope is scrolled out of view.
+    ///
+    /// Use this to lazily show widgets in a long [`crate::ScrollArea`]
+    /// without having to give all rows the same height, like [`crate::ScrollArea::show_rows`] needs.
+    ///
+    /// An off-screen scope still reserves the size it had the last time it was shown,
+    /// so that the content size, and with it the scroll bar, stays correct.
+    /// This means the contents should keep the same size while hidden;
+    /// if they change, the scope is only resized once it is shown again.
+    /// The first time a scope is seen, it is always shown to learn its size.
+    ///
+    /// Returns `None` when the contents were skipped.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::ScrollArea::vertical().show(ui, |ui| {
+    ///     for i in 0..1000 {
+    ///         ui.visible_scope(|ui| {
+    ///             ui.label(format!("Item {i}"));
+    ///             if i % 10 == 0 {
+    ///                 ui.label("Every tenth item is taller");
+    ///             }
+    ///         });
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn visible_scope<R>(
+        &mut self,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<Option<R>> {
+        self.visible_scope_dyn(Box::new(add_contents))
+    }
+
+    fn visible_scope_dyn<'c, R>(
+        &mut self,
+        add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+    ) -> InnerResponse<Option<R>> {
+        let size_id = self.next_auto_id().with("visible_scope_size");
+        let last_size = self.ctx().data(|d| d.get_temp::<Vec2>(size_id));
+
+        if let Some(size) = last_size {
+            let item_spacing = self.spacing().item_spacing;
+            let frame_rect = self.placer.next_space(size, item_spacing);
+            let rect = self.placer.justify_and_align(frame_rect, size);
+            if !self.is_rect_visible(rect) {
+                let (_, response) = self.allocate_exact_size(size, Sense::hover());
+                return InnerResponse::new(None, response);
+            }
+        }
+
+        let InnerResponse { inner, response } = self.scope_dyn(UiBuilder::new(), add_contents);
+        self.ctx()
+            .data_mut(|d| d.insert_temp(size_id, response.rect.size()));
+        InnerResponse::new(Some(inner), response)
+    }
+
     /// Redirect shapes to another paint layer.
     ///
     /// ```
@@ -2449,6 +2574,99 @@ impl Ui {
         CollapsingHeader::new(heading).show(self, add_contents)
     }
 
+    /// A section with a header that sticks to the top of the visible area
+    /// while the rest of the section is scrolled past it, e.g. in a [`crate::ScrollArea`].
+    ///
+    /// The header is pushed up again by the end of the section,
+    /// so the headers of consecutive sections never overlap.
+    /// While stuck, the header is painted on top of the section with the fill of the enclosing [`Frame`].
+    ///
+    /// If a widget in the section gains keyboard focus, it is scrolled into view below the header.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::ScrollArea::vertical().show(ui, |ui| {
+    ///     for section in ["Fruit", "Vegetables"] {
+    ///         ui.sticky_section(
+    ///             |ui| {
+    ///                 ui.strong(section);
+    ///             },
+    ///             |ui| {
+    ///                 for i in 0..10 {
+    ///                     ui.label(format!("{section} {i}"));
+    ///                 }
+    ///             },
+    ///         );
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn sticky_section<R>(
+        &mut self,
+        add_header: impl FnOnce(&mut Ui),
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.scope(|ui| {
+            let height_id = ui.id().with("sticky_header_height");
+            let header_height = ui.data(|d| d.get_temp::<f32>(height_id)).unwrap_or(0.0);
+
+            // Reserve the space the header takes up when it isn't stuck:
+            let (header_slot, _) =
+                ui.allocate_exact_size(vec2(ui.available_width(), header_height), Sense::hover());
+
+            let inner = add_contents(ui);
+
+            // Keep a newly focused widget from hiding below the stuck header:
+            let focused_rect = ui
+                .memory(|mem| mem.focused().filter(|&id| mem.gained_focus(id)))
+                .and_then(|id| ui.ctx().read_response(id))
+                .filter(|response| {
+                    response.layer_id == ui.layer_id() && ui.min_rect().contains_rect(response.rect)
+                })
+                .map(|response| response.rect);
+            if let Some(rect) = focused_rect {
+                ui.scroll_to_rect(rect.with_min_y(rect.min.y - header_height), None);
+            }
+
+            let top = ui
+                .clip_rect()
+                .top()
+                .min(ui.min_rect().bottom() - header_height)
+                .max(header_slot.top());
+            let is_stuck = top > header_slot.top();
+
+            let background = ui.painter().add(epaint::Shape::Noop);
+            let mut header_ui = ui.new_child(UiBuilder::new().max_rect(Rect::from_x_y_ranges(
+                header_slot.x_range(),
+                top..=f32::INFINITY,
+            )));
+            add_header(&mut header_ui);
+            let header_rect = header_ui.min_rect();
+
+            if is_stuck {
+                let fill = ui
+                    .stack()
+                    .iter()
+                    .map(|stack| stack.frame().fill)
+                    .find(|fill| *fill != Color32::TRANSPARENT)
+                    .unwrap_or(ui.visuals().panel_fill);
+                let rect = header_rect
+                    .with_min_x(header_slot.left())
+                    .with_max_x(header_slot.right());
+                ui.painter()
+                    .set(background, epaint::Shape::rect_filled(rect, 0.0, fill));
+            }
+
+            if (header_rect.height() - header_height).abs() > 0.5 {
+                ui.data_mut(|d| d.insert_temp(height_id, header_rect.height()));
+                ui.ctx()
+                    .request_discard("sticky_section header height changed");
+            }
+
+            inner
+        })
+    }
+
     /// Create a child ui which is indented to the right.
     ///
     /// The `id_salt` here be anything at all.
@@ -3112,6 +3330,102 @@ impl Ui {
         };
         InnerResponse::new(inner.map(|i| i.inner), response)
     }
+
+    /// A line separating groups of items in a menu.
+    ///
+    /// Always horizontal within a menu, even if the menu has a horizontal [`Layout`].
+    /// Outside a menu, this is the same as [`Self::separator`].
+    pub fn menu_separator(&mut self) -> Response {
+        let separator = if menu::is_in_menu(self) {
+            Separator::default().horizontal()
+        } else {
+            Separator::default()
+        };
+        self.add(separator)
+    }
+
+    /// A greyed out menu item that can't be clicked or selected with the keyboard.
+    ///
+    /// It still shows tooltips added with [`Response::on_disabled_hover_text`].
+    ///
+    /// To disable a sub-menu, use [`menu::SubMenuButton::enabled`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let can_undo = false;
+    /// ui.menu_button("Edit", |ui| {
+    ///     if can_undo {
+    ///         if ui.button("Undo").clicked() { /* … */ }
+    ///     } else {
+    ///         ui.disabled_menu_item("Undo")
+    ///             .on_disabled_hover_text("Nothing to undo");
+    ///     }
+    ///     ui.menu_separator();
+    ///     if ui.button("Select all").clicked() { /* … */ }
+    /// });
+    /// # });
+    /// ```
+    pub fn disabled_menu_item(&mut self, text: impl Into<WidgetText>) -> Response {
+        self.add_enabled(false, Button::new(text))
+    }
+
+    /// A menu item with a check mark in the left gutter while `checked` is `true`.
+    ///
+    /// Clicking it toggles `checked`.
+    /// The menu stays open unless [`menu::MenuConfig::close_on_check`] is set,
+    /// so several items can be toggled in one go.
+    ///
+    /// The gutter has the same width as the one of [`Self::menu_item_with_icon`],
+    /// so check marks, icons and their labels line up.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut show_grid = true;
+    /// # let mut show_rulers = false;
+    /// ui.menu_button("View", |ui| {
+    ///     ui.menu_item_checked("Grid", &mut show_grid);
+    ///     ui.menu_item_checked("Rulers", &mut show_rulers);
+    /// });
+    /// # });
+    /// ```
+    pub fn menu_item_checked(
+        &mut self,
+        text: impl Into<WidgetText>,
+        checked: &mut bool,
+    ) -> Response {
+        let mut response = menu::gutter_item(self, menu::Gutter::Check(*checked), text.into());
+        if response.clicked() {
+            *checked = !*checked;
+            response.mark_changed();
+            if !menu::MenuConfig::find(self).close_on_check {
+                menu::keep_open_this_pass(self.ctx());
+            }
+        }
+        response
+    }
+
+    /// A menu item with an icon in the left gutter.
+    ///
+    /// The icon is scaled to fit the gutter, which has the same width as the one of
+    /// [`Self::menu_item_checked`], so icons, check marks and their labels line up.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.menu_button("File", |ui| {
+    ///     if ui
+    ///         .menu_item_with_icon(egui::include_image!("../assets/ferris.png"), "Open")
+    ///         .clicked()
+    ///     { /* … */ }
+    /// });
+    /// # });
+    /// ```
+    pub fn menu_item_with_icon<'a>(
+        &mut self,
+        icon: impl Into<Image<'a>>,
+        text: impl Into<WidgetText>,
+    ) -> Response {
+        menu::gutter_item(self, menu::Gutter::Icon(icon.into()), text.into())
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -16,8 +16,10 @@ mod image_button;
 mod label;
 mod progress_bar;
 mod radio_button;
+mod scroll_bar;
 mod selected_label;
 mod separator;
+mod skeleton;
 mod slider;
 mod spinner;
 pub mod text_edit;
@@ -35,8 +37,10 @@ pub use self::{
     label::Label,
     progress_bar::ProgressBar,
     radio_button::RadioButton,
+    scroll_bar::ScrollBar,
     selected_label::SelectableLabel,
     separator::Separator,
+    skeleton::Skeleton,
     slider::{Slider, SliderClamping, SliderOrientation},
     spinner::Spinner,
     text_edit::{TextBuffer, TextEdit},
@@ -0,0 +1,206 @@
+use crate::{
+    emath::remap_clamp, pos2, vec2, Id, NumExt as _, Rect, Response, Sense, Ui, Widget, WidgetInfo,
+    WidgetType,
+};
+
+/// A passive scroll bar that only edits an offset.
+///
+/// Unlike [`crate::ScrollArea`] this does not clip, move or even know about any content.
+/// You tell it how big the content is and it renders a scroll bar for it,
+/// handling thumb drags and track clicks by changing the given offset.
+/// This is useful when the content already scrolls itself, e.g. a virtualized list
+/// or a custom painted canvas.
+///
+/// Clicking the track pages toward the pointer. Shift-clicking it jumps straight there.
+///
+/// The look follows [`crate::style::ScrollStyle`] for non-floating scroll bars.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut offset = 0.0;
+/// let response = ui.add(egui::ScrollBar::vertical(&mut offset, 1000.0).length(200.0));
+/// if response.changed() {
+///     // Scroll your content to `offset`.
+/// }
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct ScrollBar<'a> {
+    offset: &'a mut f32,
+    content_size: f32,
+    vertical: bool,
+    viewport_size: Option<f32>,
+    length: Option<f32>,
+}
+
+impl<'a> ScrollBar<'a> {
+    /// A vertical scroll bar for content of the given height.
+    pub fn vertical(offset: &'a mut f32, content_size: f32) -> Self {
+        Self::new(offset, content_size, true)
+    }
+
+    /// A horizontal scroll bar for content of the given width.
+    pub fn horizontal(offset: &'a mut f32, content_size: f32) -> Self {
+        Self::new(offset, content_size, false)
+    }
+
+    fn new(offset: &'a mut f32, content_size: f32, vertical: bool) -> Self {
+        Self {
+            offset,
+            content_size,
+            vertical,
+            viewport_size: None,
+            length: None,
+        }
+    }
+
+    /// How much of the content is visible at once.
+    ///
+    /// This decides the size of the handle and how far a track click pages.
+    ///
+    /// Defaults to the length of the scroll bar.
+    #[inline]
+    pub fn viewport_size(mut self, viewport_size: f32) -> Self {
+        self.viewport_size = Some(viewport_size);
+        self
+    }
+
+    /// The length of the scroll bar along its axis.
+    ///
+    /// Defaults to the available height (vertical) or width (horizontal).
+    #[inline]
+    pub fn length(mut self, length: f32) -> Self {
+        self.length = Some(length);
+        self
+    }
+}
+
+/// Where the pointer grabbed the handle, while it is held down on the scroll bar.
+#[derive(Clone, Copy)]
+enum PressState {
+    /// Dragging the handle, grabbed this far from its start.
+    Dragging { grab_offset: f32 },
+
+    /// Clicked the track and paged; ignores the pointer until released.
+    Paged,
+}
+
+impl Widget for ScrollBar<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            offset,
+            content_size,
+            vertical,
+            viewport_size,
+            length,
+        } = self;
+
+        let d = vertical as usize;
+        let scroll_style = ui.spacing().scroll;
+        let length = length.unwrap_or_else(|| ui.available_size()[d]);
+        let desired_size = if vertical {
+            vec2(scroll_style.bar_width, length)
+        } else {
+            vec2(length, scroll_style.bar_width)
+        };
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click_and_drag());
+
+        let viewport_size = viewport_size.unwrap_or(length).at_least(0.0);
+        let content_size = content_size.at_least(viewport_size);
+        let max_offset = content_size - viewport_size;
+        let old_offset = *offset;
+        *offset = offset.clamp(0.0, max_offset);
+
+        let handle_size = if content_size > 0.0 {
+            (viewport_size / content_size * rect.size()[d])
+                .at_least(scroll_style.handle_min_length)
+                .at_most(rect.size()[d])
+        } else {
+            rect.size()[d]
+        };
+        let handle_range = rect.min[d]..=(rect.max[d] - handle_size);
+        let handle_rect = |offset: f32| {
+            let start = remap_clamp(offset, 0.0..=max_offset, handle_range.clone());
+            if vertical {
+                Rect::from_min_max(
+                    pos2(rect.min.x, start),
+                    pos2(rect.max.x, start + handle_size),
+                )
+            } else {
+                Rect::from_min_max(
+                    pos2(start, rect.min.y),
+                    pos2(start + handle_size, rect.max.y),
+                )
+            }
+        };
+
+        let press_id: Id = response.id.with("scroll_bar_press");
+        if let Some(pointer_pos) = response.interact_pointer_pos() {
+            let handle = handle_rect(*offset);
+            let press_state = ui.data(|data| data.get_temp::<PressState>(press_id));
+            let press_state = press_state.unwrap_or_else(|| {
+                if handle.contains(pointer_pos) {
+                    PressState::Dragging {
+                        grab_offset: pointer_pos[d] - handle.min[d],
+                    }
+                } else if ui.input(|i| i.modifiers.shift) {
+                    // Jump, centering the handle on the pointer, and keep dragging from there:
+                    PressState::Dragging {
+                        grab_offset: handle_size / 2.0,
+                    }
+                } else {
+                    let direction = if pointer_pos[d] < handle.min[d] {
+                        -1.0
+                    } else {
+                        1.0
+                    };
+                    *offset = (*offset + direction * viewport_size).clamp(0.0, max_offset);
+                    PressState::Paged
+                }
+            });
+
+            if let PressState::Dragging { grab_offset } = press_state {
+                if max_offset > 0.0 && handle_range.end() > handle_range.start() {
+                    *offset = remap_clamp(
+                        pointer_pos[d] - grab_offset,
+                        handle_range.clone(),
+                        0.0..=max_offset,
+                    );
+                }
+            }
+            ui.data_mut(|data| data.insert_temp(press_id, press_state));
+        } else {
+            ui.data_mut(|data| data.remove::<PressState>(press_id));
+        }
+
+        if *offset != old_offset {
+            response.mark_changed();
+        }
+
+        response.widget_info(|| WidgetInfo::new(WidgetType::Other));
+
+        if ui.is_rect_visible(rect) {
+            let handle = handle_rect(*offset);
+            let is_hovering_handle = response.hovered()
+                && ui.input(|i| i.pointer.latest_pos().is_some_and(|p| handle.contains(p)));
+            let visuals = if response.is_pointer_button_down_on() {
+                &ui.visuals().widgets.active
+            } else if is_hovering_handle {
+                &ui.visuals().widgets.hovered
+            } else {
+                &ui.visuals().widgets.inactive
+            };
+            let handle_color = if scroll_style.foreground_color {
+                visuals.fg_stroke.color
+            } else {
+                visuals.bg_fill
+            };
+
+            let painter = ui.painter();
+            painter.rect_filled(rect, visuals.corner_radius, ui.visuals().extreme_bg_color);
+            painter.rect_filled(handle, visuals.corner_radius, handle_color);
+        }
+
+        response
+    }
+}
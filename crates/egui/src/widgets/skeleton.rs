@@ -0,0 +1,137 @@
+use epaint::{emath::remap_clamp, pos2, Color32, CornerRadius, Mesh, Rect, Vec2};
+
+use crate::{Response, Sense, Ui, Widget, WidgetInfo, WidgetType};
+
+/// A placeholder box with a shimmer sweeping across it, used to indicate content that is still loading.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// ui.add(egui::Skeleton::new([200.0, 16.0]));
+/// ui.add(egui::Skeleton::new([120.0, 16.0]).period(2.0));
+/// # });
+/// ```
+///
+/// See also: [`crate::Spinner`].
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct Skeleton {
+    size: Vec2,
+    corner_radius: Option<CornerRadius>,
+    color: Option<Color32>,
+    highlight_color: Option<Color32>,
+    period: f32,
+}
+
+impl Skeleton {
+    /// Create a skeleton placeholder of the given size.
+    pub fn new(size: impl Into<Vec2>) -> Self {
+        Self {
+            size: size.into(),
+            corner_radius: None,
+            color: None,
+            highlight_color: None,
+            period: 1.5,
+        }
+    }
+
+    /// Uses the style's `widgets.inactive.corner_radius` if not set.
+    #[inline]
+    pub fn corner_radius(mut self, corner_radius: impl Into<CornerRadius>) -> Self {
+        self.corner_radius = Some(corner_radius.into());
+        self
+    }
+
+    /// The base color of the placeholder.
+    ///
+    /// Uses the style's `widgets.inactive.weak_bg_fill` if not set.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// The color at the center of the sweeping shimmer.
+    ///
+    /// Uses the style's `widgets.hovered.weak_bg_fill` if not set.
+    #[inline]
+    pub fn highlight_color(mut self, color: impl Into<Color32>) -> Self {
+        self.highlight_color = Some(color.into());
+        self
+    }
+
+    /// How many seconds it takes the shimmer to sweep once across the placeholder.
+    ///
+    /// Default: `1.5`.
+    #[inline]
+    pub fn period(mut self, seconds: f32) -> Self {
+        self.period = seconds;
+        self
+    }
+
+    /// Paint the skeleton in the given rectangle.
+    pub fn paint_at(&self, ui: &Ui, rect: Rect) {
+        if !ui.is_rect_visible(rect) {
+            return;
+        }
+        ui.ctx().request_repaint(); // because it is animated
+
+        let visuals = &ui.visuals().widgets;
+        let color = self.color.unwrap_or(visuals.inactive.weak_bg_fill);
+        let highlight = self.highlight_color.unwrap_or(visuals.hovered.weak_bg_fill);
+        let corner_radius = self.corner_radius.unwrap_or(visuals.inactive.corner_radius);
+
+        // The shimmer is a soft band, `band` wide (as a fraction of the width),
+        // that travels from fully left of the rect to fully right of it.
+        let band = 0.4;
+        let period = self.period.max(f32::EPSILON) as f64;
+        let t = (ui.input(|i| i.time) / period).fract() as f32;
+        let band_center = -band + t * (1.0 + 2.0 * band);
+        let color_at = |x: f32| {
+            let dist = (remap_clamp(x, rect.x_range(), 0.0..=1.0) - band_center).abs();
+            let intensity = (1.0 - dist / band).max(0.0);
+            color.lerp_to_gamma(highlight, intensity * intensity * (3.0 - 2.0 * intensity))
+        };
+
+        // Vertical strips, inset at the ends to follow the rounded corners.
+        let radius = corner_radius
+            .average()
+            .min(rect.width() / 2.0)
+            .min(rect.height() / 2.0);
+        let inset_at = |x: f32| {
+            let from_edge = (x - rect.left()).min(rect.right() - x);
+            if radius <= 0.0 || radius <= from_edge {
+                0.0
+            } else {
+                let dx = radius - from_edge.max(0.0);
+                radius - (radius * radius - dx * dx).max(0.0).sqrt()
+            }
+        };
+
+        let n_strips = ((rect.width() / 2.0).ceil() as u32).max(1);
+        let mut mesh = Mesh::default();
+        mesh.reserve_vertices(2 * (n_strips as usize + 1));
+        mesh.reserve_triangles(2 * n_strips as usize);
+        for i in 0..=n_strips {
+            let x = rect.left() + rect.width() * i as f32 / n_strips as f32;
+            let inset = inset_at(x);
+            let c = color_at(x);
+            mesh.colored_vertex(pos2(x, rect.top() + inset), c);
+            mesh.colored_vertex(pos2(x, rect.bottom() - inset), c);
+            if i > 0 {
+                let idx = 2 * i;
+                mesh.add_triangle(idx - 2, idx - 1, idx);
+                mesh.add_triangle(idx - 1, idx, idx + 1);
+            }
+        }
+        ui.painter().add(mesh);
+    }
+}
+
+impl Widget for Skeleton {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let (rect, response) = ui.allocate_exact_size(self.size, Sense::hover());
+        response.widget_info(|| WidgetInfo::new(WidgetType::ProgressIndicator));
+        self.paint_at(ui, rect);
+
+        response
+    }
+}
@@ -72,6 +72,20 @@ impl TessellationTest {
                 "Blurred",
                 RectShape::filled(sized([20.0, 16.0]), 2.0, baby_blue).with_blur_width(50.0),
             ),
+            (
+                "Blurred, uneven rounding",
+                RectShape::filled(
+                    sized([20.0, 16.0]),
+                    egui::CornerRadius {
+                        nw: 0,
+                        ne: 8,
+                        sw: 2,
+                        se: 4,
+                    },
+                    baby_blue,
+                )
+                .with_blur_width(4.0),
+            ),
             (
                 "Thick stroke, minimal rounding",
                 RectShape::new(
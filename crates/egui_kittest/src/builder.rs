@@ -7,8 +7,11 @@ use std::marker::PhantomData;
 pub struct HarnessBuilder<State = ()> {
     pub(crate) screen_rect: Rect,
     pub(crate) pixels_per_point: f32,
+    pub(crate) zoom_factor: f32,
     pub(crate) max_steps: u64,
     pub(crate) step_dt: f32,
+    pub(crate) frozen_time: Option<f64>,
+    pub(crate) multiple_viewports: bool,
     pub(crate) state: PhantomData<State>,
     pub(crate) renderer: Box<dyn TestRenderer>,
 }
@@ -18,10 +21,13 @@ impl<State> Default for HarnessBuilder<State> {
         Self {
             screen_rect: Rect::from_min_size(Pos2::ZERO, Vec2::new(800.0, 600.0)),
             pixels_per_point: 1.0,
+            zoom_factor: 1.0,
             state: PhantomData,
             renderer: Box::new(LazyRenderer::default()),
             max_steps: 4,
             step_dt: 1.0 / 4.0,
+            frozen_time: None,
+            multiple_viewports: false,
         }
     }
 }
@@ -43,6 +49,20 @@ impl<State> HarnessBuilder<State> {
         self
     }
 
+    /// Set the zoom factor of the ui, see [`egui::Context::zoom_factor`].
+    ///
+    /// Unlike [`Self::with_pixels_per_point`], this is the zoom a user picks (e.g. with cmd +),
+    /// not a property of the screen. Like in a real window, the window keeps its size in pixels,
+    /// so at a zoom of 1.5 the ui gets the size set with [`Self::with_size`] divided by 1.5 in points,
+    /// and everything is rendered 1.5 times larger.
+    ///
+    /// Default is 1.0.
+    #[inline]
+    pub fn with_zoom_factor(mut self, zoom_factor: f32) -> Self {
+        self.zoom_factor = zoom_factor;
+        self
+    }
+
     /// Set the maximum number of steps to run when calling [`Harness::run`].
     ///
     /// Default is 4.
@@ -63,6 +83,48 @@ impl<State> HarnessBuilder<State> {
         self
     }
 
+    /// Pin the time seen by egui ([`egui::InputState::time`]) at `time` for every step.
+    ///
+    /// Use this for golden images that must come out byte-identical no matter how many
+    /// steps were run to get there.
+    ///
+    /// By default the harness never looks at the wall clock either: time starts at 0 and moves
+    /// forward by [`Self::with_step_dt`] each step, so a test that runs the same steps is
+    /// already deterministic. Frozen time additionally makes the output independent of the
+    /// number of steps.
+    ///
+    /// Things in egui that consult the time, and so look the same on every step while it is frozen:
+    /// - Everything animated with [`egui::Context::animate_bool`] and friends, e.g. collapsing
+    ///   headers and scroll bars. These would never finish, so the harness also
+    ///   sets [`egui::Style::animation_time`] to zero and turns off
+    ///   [`egui::Style::scroll_animation`], making them jump straight to their end state.
+    /// - Widgets that are always animated, like [`egui::Spinner`] and [`egui::Skeleton`].
+    ///   They keep requesting repaints, so use [`Harness::step`] rather than [`Harness::run`].
+    /// - Tooltip and popup delays, which will never elapse.
+    /// - Double- and triple-click detection: with no time passing between them,
+    ///   two clicks are always a double-click.
+    /// - Kinetic scrolling, smoothed scroll deltas and pointer velocity.
+    #[inline]
+    pub fn with_frozen_time(mut self, time: f64) -> Self {
+        self.frozen_time = Some(time);
+        self
+    }
+
+    /// Show viewports created with [`egui::Context::show_viewport_immediate`] and
+    /// [`egui::Context::show_viewport_deferred`] as separate viewports,
+    /// like a native integration with multi-viewport support would.
+    ///
+    /// Each viewport then gets its own frame and output on every step, and can be rendered and
+    /// snapshotted on its own, see [`Harness::viewport_ids`] and [`Harness::render_viewport`].
+    /// Only the root viewport receives the simulated input events though.
+    ///
+    /// Default is `false`, which embeds the viewports as windows in the root viewport.
+    #[inline]
+    pub fn with_multiple_viewports(mut self, multiple_viewports: bool) -> Self {
+        self.multiple_viewports = multiple_viewports;
+        self
+    }
+
     /// Set the [`TestRenderer`] to use for rendering.
     ///
     /// By default, a [`LazyRenderer`] is used.
@@ -80,6 +142,15 @@ impl<State> HarnessBuilder<State> {
         self.renderer(crate::wgpu::WgpuTestRenderer::default())
     }
 
+    /// Enable wgpu rendering, reusing a wgpu device shared by all harnesses in the process,
+    /// and the [`egui_wgpu::Renderer`] of the context.
+    ///
+    /// See [`crate::wgpu::WgpuTestRenderer::shared`].
+    #[cfg(feature = "wgpu")]
+    pub fn wgpu_shared(self) -> Self {
+        self.renderer(crate::wgpu::WgpuTestRenderer::shared())
+    }
+
     /// Enable wgpu rendering with the given setup.
     #[cfg(feature = "wgpu")]
     pub fn wgpu_setup(self, setup: egui_wgpu::WgpuSetup) -> Self {
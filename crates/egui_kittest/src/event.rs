@@ -192,3 +192,33 @@ fn pointer_button_to_egui(value: MouseButton) -> Option<egui::PointerButton> {
         MouseButton::Other(_) => None,
     }
 }
+
+/// Turn the key presses of clipboard shortcuts into [`Event::Cut`], [`Event::Copy`] and [`Event::Paste`],
+/// like the egui integrations do, pasting the given clipboard contents.
+pub(crate) fn clipboard_events(events: &mut Vec<Event>, clipboard: &str) {
+    events.retain_mut(|event| {
+        let Event::Key {
+            key,
+            pressed: true,
+            modifiers,
+            ..
+        } = *event
+        else {
+            return true;
+        };
+        *event = if key == egui::Key::Cut || modifiers.command && key == egui::Key::X {
+            Event::Cut
+        } else if key == egui::Key::Copy || modifiers.command && key == egui::Key::C {
+            Event::Copy
+        } else if key == egui::Key::Paste || modifiers.command && key == egui::Key::V {
+            if clipboard.is_empty() {
+                // Nothing to paste:
+                return false;
+            }
+            Event::Paste(clipboard.replace("\r\n", "\n"))
+        } else {
+            return true;
+        };
+        true
+    });
+}
@@ -10,7 +10,10 @@ mod snapshot;
 
 #[cfg(feature = "snapshot")]
 pub use snapshot::*;
+use std::cell::RefCell;
 use std::fmt::{Debug, Display, Formatter};
+use std::rc::Rc;
+use std::sync::Arc;
 use std::time::Duration;
 
 mod app_kind;
@@ -28,7 +31,7 @@ use crate::event::EventState;
 pub use builder::*;
 pub use renderer::*;
 
-use egui::{Modifiers, Pos2, Rect, RepaintCause, Vec2, ViewportId};
+use egui::{Modifiers, Pos2, Rect, RepaintCause, Vec2, ViewportId, ViewportIdMap};
 use kittest::{Node, Queryable};
 
 pub struct ExceededMaxStepsError {
@@ -48,6 +51,41 @@ impl Display for ExceededMaxStepsError {
     }
 }
 
+/// What happened during a [`Harness::step`].
+///
+/// Use this to assert on what each frame of a multi-frame interaction did:
+///
+/// ```
+/// # use egui_kittest::Harness;
+/// let mut harness = Harness::new_ui(|ui| {
+///     ui.label("Hello");
+/// });
+/// let step = harness.step();
+/// assert!(step.shapes > 0);
+/// assert_eq!(step.focused, None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct StepOutput {
+    /// The number of shapes painted in the last frame of the step.
+    pub shapes: usize,
+
+    /// The textures that were allocated, changed or freed during the step.
+    ///
+    /// If the step ran several frames, these are the deltas of all of them, in order.
+    pub textures_delta: egui::TexturesDelta,
+
+    /// The cursor icon the last frame of the step asked for.
+    pub cursor_icon: egui::CursorIcon,
+
+    /// The widget that has keyboard focus after the step, if any.
+    pub focused: Option<egui::Id>,
+
+    /// Did the last frame of the step request an immediate repaint?
+    ///
+    /// See [`Harness::repaint_requested`].
+    pub repaint_requested: bool,
+}
+
 /// The test Harness. This contains everything needed to run the test.
 /// Create a new Harness using [`Harness::new`] or [`Harness::builder`].
 ///
@@ -59,6 +97,7 @@ pub struct Harness<'a, State = ()> {
     input: egui::RawInput,
     kittest: kittest::State,
     output: egui::FullOutput,
+    viewport_outputs: ViewportIdMap<egui::FullOutput>,
     app: AppKind<'a, State>,
     event_state: EventState,
     response: Option<egui::Response>,
@@ -66,6 +105,11 @@ pub struct Harness<'a, State = ()> {
     renderer: Box<dyn TestRenderer>,
     max_steps: u64,
     step_dt: f32,
+    clipboard: String,
+
+    /// The size of the window at a zoom factor of 1.
+    size: Vec2,
+    zoom_factor: f32,
 }
 
 impl<State> Debug for Harness<'_, State> {
@@ -84,17 +128,35 @@ impl<'a, State> Harness<'a, State> {
         let HarnessBuilder {
             screen_rect,
             pixels_per_point,
+            zoom_factor,
             max_steps,
             step_dt,
+            frozen_time,
+            multiple_viewports,
             state: _,
             mut renderer,
         } = builder;
         let ctx = ctx.unwrap_or_default();
         ctx.enable_accesskit();
+        if multiple_viewports {
+            ctx.set_embed_viewports(false);
+        }
         // Disable cursor blinking so it doesn't interfere with snapshots
         ctx.all_styles_mut(|style| style.visuals.text_cursor.blink = false);
+        if frozen_time.is_some() {
+            // Animations can't progress without time, so skip straight to the end
+            ctx.all_styles_mut(|style| {
+                style.animation_time = 0.0;
+                style.scroll_animation = egui::style::ScrollAnimation::none();
+            });
+        }
+        ctx.set_zoom_factor(zoom_factor);
         let mut input = egui::RawInput {
-            screen_rect: Some(screen_rect),
+            screen_rect: Some(Rect::from_min_size(
+                screen_rect.min,
+                screen_rect.size() / zoom_factor,
+            )),
+            time: frozen_time,
             ..Default::default()
         };
         let viewport = input.viewports.get_mut(&ViewportId::ROOT).unwrap();
@@ -104,11 +166,10 @@ impl<'a, State> Harness<'a, State> {
 
         // We need to run egui for a single frame so that the AccessKit state can be initialized
         // and users can immediately start querying for widgets.
-        let mut output = ctx.run(input.clone(), |ctx| {
-            response = app.run(ctx, &mut state, false);
-        });
-
-        renderer.handle_delta(&output.textures_delta);
+        let (mut output, viewport_outputs) =
+            run_viewports(&ctx, input.clone(), renderer.as_mut(), |ctx| {
+                response = app.run(ctx, &mut state, false);
+            });
 
         let mut harness = Self {
             app,
@@ -122,12 +183,16 @@ impl<'a, State> Harness<'a, State> {
                     .expect("AccessKit was disabled"),
             ),
             output,
+            viewport_outputs,
             response,
             event_state: EventState::default(),
             state,
             renderer,
             max_steps,
             step_dt,
+            clipboard: String::new(),
+            size: screen_rect.size(),
+            zoom_factor,
         };
         // Run the harness until it is stable, ensuring that all Areas are shown and animations are done
         harness.run_ok();
@@ -204,12 +269,50 @@ impl<'a, State> Harness<'a, State> {
     /// Set the size of the window.
     /// Note: If you only want to set the size once at the beginning,
     /// prefer using [`HarnessBuilder::with_size`].
+    ///
+    /// This is the size at a zoom factor of 1, see [`Self::set_zoom_factor`].
     #[inline]
     pub fn set_size(&mut self, size: Vec2) -> &mut Self {
-        self.input.screen_rect = Some(Rect::from_min_size(Pos2::ZERO, size));
+        self.size = size;
+        self.input.screen_rect = Some(Rect::from_min_size(Pos2::ZERO, size / self.zoom_factor));
         self
     }
 
+    /// Set the size of the screen in ui points, i.e. what [`egui::Context::screen_rect`] will be,
+    /// taking the zoom factor into account (see [`Self::set_zoom_factor`]).
+    ///
+    /// Use this between runs to test responsive layouts: the next frame is laid out for the
+    /// new size, so panels reflow and windows are moved back onto the screen.
+    ///
+    /// ```
+    /// # use egui_kittest::Harness;
+    /// let mut harness = Harness::new(|ctx| {
+    ///     egui::CentralPanel::default().show(ctx, |ui| {
+    ///         ui.label("Hello");
+    ///     });
+    /// });
+    /// harness.set_screen_size(egui::vec2(320.0, 480.0));
+    /// harness.run();
+    /// assert_eq!(harness.ctx.screen_rect().size(), egui::vec2(320.0, 480.0));
+    /// ```
+    #[inline]
+    pub fn set_screen_size(&mut self, size: Vec2) -> &mut Self {
+        self.set_size(size * self.zoom_factor)
+    }
+
+    /// Set the zoom factor of the ui.
+    /// Note: If you only want to set the zoom factor once at the beginning,
+    /// prefer using [`HarnessBuilder::with_zoom_factor`], which explains how it differs from
+    /// [`Self::set_pixels_per_point`].
+    ///
+    /// The window keeps its size in pixels, so the ui gets less room in points as the zoom grows.
+    #[inline]
+    pub fn set_zoom_factor(&mut self, zoom_factor: f32) -> &mut Self {
+        self.zoom_factor = zoom_factor;
+        self.ctx.set_zoom_factor(zoom_factor);
+        self.set_size(self.size)
+    }
+
     /// Set the `pixels_per_point` of the window.
     /// Note: If you only want to set the `pixels_per_point` once at the beginning,
     /// prefer using [`HarnessBuilder::with_pixels_per_point`].
@@ -222,24 +325,43 @@ impl<'a, State> Harness<'a, State> {
     /// Run a frame for each queued event (or a single frame if there are no events).
     /// This will call the app closure with each queued event and
     /// update the Harness.
-    pub fn step(&mut self) {
+    ///
+    /// Returns what happened during the step, see [`StepOutput`].
+    /// Queue at most one event before each step to get exactly one frame per step.
+    pub fn step(&mut self) -> StepOutput {
         let events = self.kittest.take_events();
+        let mut textures_delta = egui::TexturesDelta::default();
         if events.is_empty() {
             self._step(false);
+            textures_delta.append(self.output.textures_delta.clone());
         }
         for event in events {
             self.event_state.update(event, &mut self.input);
             self._step(false);
+            textures_delta.append(self.output.textures_delta.clone());
+        }
+        StepOutput {
+            shapes: self.output.shapes.len(),
+            textures_delta,
+            cursor_icon: self.output.platform_output.cursor_icon,
+            focused: self.ctx.memory(|mem| mem.focused()),
+            repaint_requested: self.repaint_requested(),
         }
     }
 
     /// Run a single step. This will not process any events.
     fn _step(&mut self, sizing_pass: bool) {
         self.input.predicted_dt = self.step_dt;
-
-        let mut output = self.ctx.run(self.input.take(), |ctx| {
-            self.response = self.app.run(ctx, &mut self.state, sizing_pass);
-        });
+        event::clipboard_events(&mut self.input.events, &self.clipboard);
+
+        let (mut output, viewport_outputs) = run_viewports(
+            &self.ctx,
+            self.input.take(),
+            self.renderer.as_mut(),
+            |ctx| {
+                self.response = self.app.run(ctx, &mut self.state, sizing_pass);
+            },
+        );
         self.kittest.update(
             output
                 .platform_output
@@ -247,8 +369,23 @@ impl<'a, State> Harness<'a, State> {
                 .take()
                 .expect("AccessKit was disabled"),
         );
-        self.renderer.handle_delta(&output.textures_delta);
+        self.update_clipboard(&output);
         self.output = output;
+        self.viewport_outputs = viewport_outputs;
+    }
+
+    /// Put the text the ui copied this step in [`Self::clipboard`].
+    fn update_clipboard(&mut self, output: &egui::FullOutput) {
+        #![allow(deprecated)]
+        for command in &output.platform_output.commands {
+            if let egui::OutputCommand::CopyText(text) = command {
+                self.clipboard.clone_from(text);
+            }
+        }
+        if !output.platform_output.copied_text.is_empty() {
+            self.clipboard
+                .clone_from(&output.platform_output.copied_text);
+        }
     }
 
     /// Resize the test harness to fit the contents. This only works when creating the Harness via
@@ -257,7 +394,7 @@ impl<'a, State> Harness<'a, State> {
     pub fn fit_contents(&mut self) {
         self._step(true);
         if let Some(response) = &self.response {
-            self.set_size(response.rect.size());
+            self.set_size(response.rect.size() * self.zoom_factor);
         }
         self.run_ok();
     }
@@ -360,6 +497,125 @@ impl<'a, State> Harness<'a, State> {
         &self.output
     }
 
+    /// The ids of all viewports that were shown during the last frame, starting with
+    /// [`ViewportId::ROOT`].
+    ///
+    /// Viewports are only shown separately from the root viewport if the harness was built with
+    /// [`HarnessBuilder::with_multiple_viewports`]. Otherwise they are embedded as windows,
+    /// and this only returns the root viewport.
+    pub fn viewport_ids(&self) -> Vec<ViewportId> {
+        let mut ids: Vec<_> = self.viewport_outputs.keys().copied().collect();
+        ids.sort_by_key(|id| id.0.value());
+        ids.insert(0, ViewportId::ROOT);
+        ids
+    }
+
+    /// Access the [`egui::FullOutput`] of the given viewport for the last frame.
+    ///
+    /// Returns `None` if the viewport wasn't shown, see [`Self::viewport_ids`].
+    /// For [`ViewportId::ROOT`] this is the same as [`Self::output`].
+    ///
+    /// Note that only the root viewport is part of the accessibility tree that is queried with
+    /// [`Queryable`].
+    pub fn viewport_output(&self, viewport_id: ViewportId) -> Option<&egui::FullOutput> {
+        if viewport_id == ViewportId::ROOT {
+            Some(&self.output)
+        } else {
+            self.viewport_outputs.get(&viewport_id)
+        }
+    }
+
+    /// Did the last frame request an immediate repaint?
+    ///
+    /// This is what [`Harness::run`] waits on, so it is always `false` right after a successful run.
+    /// Use [`egui::Context::repaint_causes`] to find out what requested it.
+    pub fn repaint_requested(&self) -> bool {
+        self.root_viewport_output().repaint_delay == Duration::ZERO
+    }
+
+    /// After how long the last frame asked to be repainted, if at all.
+    ///
+    /// `Some(Duration::ZERO)` means an immediate repaint (see [`Self::repaint_requested`]),
+    /// and `None` means no repaint was requested.
+    /// Use this to check that your ui goes idle once it has settled:
+    ///
+    /// ```
+    /// # use egui_kittest::Harness;
+    /// let mut harness = Harness::new_ui(|ui| {
+    ///     ui.label("Nothing animates here");
+    /// });
+    /// harness.run();
+    /// assert_eq!(harness.repaint_delay(), None);
+    /// ```
+    pub fn repaint_delay(&self) -> Option<Duration> {
+        let delay = self.root_viewport_output().repaint_delay;
+        (delay != Duration::MAX).then_some(delay)
+    }
+
+    /// The [`egui::Id`] and [`Rect`] of every widget egui registered during the last frame.
+    ///
+    /// This is the same bookkeeping egui uses for hit testing (see [`egui::WidgetRects`]),
+    /// so it includes non-interactive widgets like labels.
+    /// Useful for asserting layout without relying on snapshots, e.g. that widget `a` is above widget `b`.
+    ///
+    /// The widgets are ordered by layer, and within a layer in the order they were added.
+    pub fn widget_rects(&self) -> impl Iterator<Item = (egui::Id, Rect)> {
+        let rects: Vec<_> = self.ctx.viewport(|viewport| {
+            viewport
+                .prev_pass
+                .widgets
+                .layers()
+                .flat_map(|(_, widgets)| widgets.iter().map(|w| (w.id, w.rect)))
+                .collect()
+        });
+        rects.into_iter()
+    }
+
+    /// The laid out text painted by the widget with the given id in the last run,
+    /// in the order it was painted.
+    ///
+    /// Use this to assert how text was laid out without relying on snapshots,
+    /// e.g. that a label wrapped to two rows (see [`egui::Galley::rows`]),
+    /// was truncated (see [`egui::Galley::elided`]), or how large it ended up (see [`egui::Galley::size`]).
+    ///
+    /// Text is attributed to the widget if it is centered within the widget's rect (see [`Self::widget_rects`]),
+    /// so a container returns the text of all its children.
+    /// Returns an empty list if the widget wasn't shown.
+    pub fn galleys(&self, id: egui::Id) -> Vec<Arc<egui::Galley>> {
+        let Some(rect) = self
+            .ctx
+            .viewport(|viewport| viewport.prev_pass.widgets.get(id).map(|w| w.rect))
+        else {
+            return Vec::new();
+        };
+        let mut galleys = Vec::new();
+        for clipped in &self.output.shapes {
+            collect_galleys(&clipped.shape, rect, &mut galleys);
+        }
+        galleys
+    }
+
+    /// Get the node whose label or value exactly matches `text`.
+    ///
+    /// Like [`Queryable::get_by_label`], but also finds widgets by the text they hold,
+    /// e.g. a [`egui::TextEdit`] by what has been typed into it.
+    ///
+    /// # Panics
+    /// If no node, or more than one node, matches.
+    #[track_caller]
+    pub fn get_by_text<'t>(&'t self, text: &'t str) -> Node<'t> {
+        self.get_by(move |node| node_has_text(node, text))
+    }
+
+    /// Like [`Self::get_by_text`], but returns `None` if no node matches.
+    ///
+    /// # Panics
+    /// If more than one node matches.
+    #[track_caller]
+    pub fn query_by_text<'t>(&'t self, text: &'t str) -> Option<Node<'t>> {
+        self.query_by(move |node| node_has_text(node, text))
+    }
+
     /// Access the [`kittest::State`].
     pub fn kittest_state(&self) -> &kittest::State {
         &self.kittest
@@ -375,6 +631,23 @@ impl<'a, State> Harness<'a, State> {
         &mut self.state
     }
 
+    /// Set the contents of the simulated clipboard.
+    ///
+    /// Pressing the paste shortcut (e.g. with [`Self::press_key_modifiers`] and
+    /// [`Modifiers::COMMAND`] + [`egui::Key::V`]) then pastes this text.
+    /// The cut and copy shortcuts are turned into [`egui::Event::Cut`] and [`egui::Event::Copy`].
+    pub fn set_clipboard(&mut self, text: impl Into<String>) {
+        self.clipboard = text.into();
+    }
+
+    /// The contents of the simulated clipboard.
+    ///
+    /// This is the text last copied by the ui (see [`egui::OutputCommand::CopyText`]),
+    /// or set with [`Self::set_clipboard`].
+    pub fn clipboard(&self) -> &str {
+        &self.clipboard
+    }
+
     /// Press a key.
     /// This will create a key down event and a key up event.
     pub fn press_key(&mut self, key: egui::Key) {
@@ -432,6 +705,81 @@ impl<'a, State> Harness<'a, State> {
         self.renderer.render(&self.ctx, &self.output)
     }
 
+    /// Render the last output of the given viewport to an image.
+    ///
+    /// See [`Self::viewport_ids`] and [`TestRenderer::render_viewport`].
+    ///
+    /// # Errors
+    /// Returns an error if the viewport wasn't shown during the last frame or if the rendering fails.
+    #[cfg(any(feature = "wgpu", feature = "snapshot"))]
+    pub fn render_viewport(&mut self, viewport_id: ViewportId) -> Result<image::RgbaImage, String> {
+        let output = if viewport_id == ViewportId::ROOT {
+            &self.output
+        } else {
+            self.viewport_outputs
+                .get(&viewport_id)
+                .ok_or_else(|| format!("The viewport {viewport_id:?} wasn't shown"))?
+        };
+        self.renderer
+            .render_viewport(&self.ctx, output, viewport_id)
+    }
+
+    /// Render the last output to an image, together with stats about what was drawn.
+    ///
+    /// # Errors
+    /// Returns an error if the rendering fails.
+    #[cfg(any(feature = "wgpu", feature = "snapshot"))]
+    pub fn render_with_stats(&mut self) -> Result<(image::RgbaImage, RenderStats), String> {
+        self.renderer.render_with_stats(&self.ctx, &self.output)
+    }
+
+    /// Render only what was painted on the given layer in the last output,
+    /// e.g. to snapshot a single window or tooltip.
+    ///
+    /// See [`TestRenderer::render_layer`].
+    ///
+    /// # Errors
+    /// Returns an error if the rendering fails.
+    #[cfg(any(feature = "wgpu", feature = "snapshot"))]
+    pub fn render_layer(&mut self, layer_id: egui::LayerId) -> Result<image::RgbaImage, String> {
+        self.renderer
+            .render_layer(&self.ctx, &self.output, layer_id)
+    }
+
+    /// Render the last output centered within an image of `target_size` pixels,
+    /// e.g. for fixed-size thumbnails.
+    ///
+    /// See [`TestRenderer::render_letterboxed`].
+    ///
+    /// # Errors
+    /// Returns an error if the rendering fails.
+    #[cfg(any(feature = "wgpu", feature = "snapshot"))]
+    pub fn render_letterboxed(
+        &mut self,
+        target_size: [u32; 2],
+    ) -> Result<image::RgbaImage, String> {
+        self.renderer
+            .render_letterboxed(&self.ctx, &self.output, target_size)
+    }
+
+    /// Return the current font atlas as an image, e.g. to debug glyph coverage or atlas growth.
+    ///
+    /// See [`TestRenderer::render_font_atlas`].
+    ///
+    /// # Errors
+    /// Returns an error if the atlas can't be read.
+    #[cfg(any(feature = "wgpu", feature = "snapshot"))]
+    pub fn render_font_atlas(&mut self) -> Result<image::RgbaImage, String> {
+        self.renderer.render_font_atlas(&self.ctx)
+    }
+
+    /// Stats about the tessellated last output, without rendering it.
+    ///
+    /// See also [`Self::render_with_stats`].
+    pub fn render_stats(&self) -> RenderStats {
+        RenderStats::from_output(&self.ctx, &self.output)
+    }
+
     /// Get the root viewport output
     fn root_viewport_output(&self) -> &egui::ViewportOutput {
         self.output
@@ -487,6 +835,118 @@ impl<'a> Harness<'a> {
     }
 }
 
+/// Run a frame of the root viewport with `run_ui`, and a frame of every viewport it shows.
+///
+/// Unless the viewports are embedded (see [`egui::Context::embed_viewports`]), immediate viewports
+/// are run from within the root frame, like a native integration would, and deferred viewports
+/// are run right after it.
+/// The texture deltas of all frames are handed to the `renderer` in the order they were produced.
+///
+/// Returns the output of the root viewport and the outputs of all other viewports.
+fn run_viewports(
+    ctx: &egui::Context,
+    input: egui::RawInput,
+    renderer: &mut dyn TestRenderer,
+    run_ui: impl FnMut(&egui::Context),
+) -> (egui::FullOutput, ViewportIdMap<egui::FullOutput>) {
+    let immediate_outputs: Rc<RefCell<Vec<(ViewportId, egui::FullOutput)>>> = Rc::default();
+    if !ctx.embed_viewports() {
+        // This is thread local, so we install it for every frame in case there are several harnesses.
+        let immediate_outputs = Rc::clone(&immediate_outputs);
+        egui::Context::set_immediate_viewport_renderer(move |ctx, mut viewport| {
+            let input = viewport_input(ctx, viewport.ids.this, &viewport.builder);
+            let output = ctx.run(input, |ctx| (viewport.viewport_ui_cb)(ctx));
+            immediate_outputs
+                .borrow_mut()
+                .push((viewport.ids.this, output));
+        });
+    }
+
+    let output = ctx.run(input, run_ui);
+
+    let mut viewport_outputs = ViewportIdMap::default();
+    for (id, output) in immediate_outputs.take() {
+        renderer.handle_delta(&output.textures_delta);
+        viewport_outputs.insert(id, output);
+    }
+    renderer.handle_delta(&output.textures_delta);
+
+    let deferred = |output: &egui::FullOutput| {
+        output
+            .viewport_output
+            .iter()
+            .filter(|(id, _)| **id != ViewportId::ROOT)
+            .filter_map(|(id, viewport)| {
+                let viewport_ui_cb = viewport.viewport_ui_cb.clone()?;
+                Some((*id, viewport.builder.clone(), viewport_ui_cb))
+            })
+            .collect::<Vec<_>>()
+    };
+    let mut pending = deferred(&output);
+    while let Some((id, builder, viewport_ui_cb)) = pending.pop() {
+        if viewport_outputs.contains_key(&id) {
+            continue;
+        }
+        let viewport_output = ctx.run(viewport_input(ctx, id, &builder), |ctx| {
+            viewport_ui_cb(ctx);
+        });
+        renderer.handle_delta(&viewport_output.textures_delta);
+        pending.extend(deferred(&viewport_output));
+        viewport_outputs.insert(id, viewport_output);
+    }
+
+    (output, viewport_outputs)
+}
+
+/// The input for a frame of the viewport `viewport_id`, shown from the current viewport of `ctx`.
+///
+/// The viewport gets the size from its `builder`, or the size of the current viewport if it has none.
+fn viewport_input(
+    ctx: &egui::Context,
+    viewport_id: ViewportId,
+    builder: &egui::ViewportBuilder,
+) -> egui::RawInput {
+    let (time, predicted_dt, screen_size) =
+        ctx.input(|i| (i.time, i.predicted_dt, i.screen_rect.size()));
+    let size = builder.inner_size.unwrap_or(screen_size);
+    let mut input = egui::RawInput {
+        viewport_id,
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, size)),
+        time: Some(time),
+        predicted_dt,
+        ..Default::default()
+    };
+    input.viewports.insert(
+        viewport_id,
+        egui::ViewportInfo {
+            native_pixels_per_point: ctx.native_pixels_per_point(),
+            inner_rect: Some(Rect::from_min_size(Pos2::ZERO, size)),
+            ..Default::default()
+        },
+    );
+    input
+}
+
+fn collect_galleys(shape: &egui::Shape, rect: Rect, galleys: &mut Vec<Arc<egui::Galley>>) {
+    match shape {
+        egui::Shape::Vec(shapes) => {
+            for shape in shapes {
+                collect_galleys(shape, rect, galleys);
+            }
+        }
+        egui::Shape::Text(text) if rect.contains(text.visual_bounding_rect().center()) => {
+            galleys.push(Arc::clone(&text.galley));
+        }
+        _ => {}
+    }
+}
+
+fn node_has_text(node: &Node<'_>, text: &str) -> bool {
+    // Text runs are the laid out pieces of a text edit, which already has the value:
+    node.role() != egui::accesskit::Role::TextRun
+        && (node.label().as_deref() == Some(text) || node.value().as_deref() == Some(text))
+}
+
 impl<'t, 'n, State> Queryable<'t, 'n> for Harness<'_, State>
 where
     'n: 't,
@@ -1,4 +1,81 @@
-use egui::TexturesDelta;
+use egui::{epaint::Primitive, ClippedPrimitive, TexturesDelta};
+
+/// Statistics about the tessellated output of a frame, i.e. what a renderer has to draw.
+///
+/// Useful for asserting that a change didn't explode the amount of geometry.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RenderStats {
+    /// Number of [`ClippedPrimitive`]s, i.e. draw calls.
+    pub clipped_primitives: usize,
+
+    /// Total number of vertices in all meshes.
+    pub vertices: usize,
+
+    /// Total number of indices in all meshes. Divide by 3 to get the number of triangles.
+    pub indices: usize,
+
+    /// How many times the texture changes between consecutive meshes.
+    pub texture_switches: usize,
+}
+
+impl RenderStats {
+    pub fn from_primitives(primitives: &[ClippedPrimitive]) -> Self {
+        let mut stats = Self {
+            clipped_primitives: primitives.len(),
+            ..Default::default()
+        };
+        let mut last_texture = None;
+        for primitive in primitives {
+            if let Primitive::Mesh(mesh) = &primitive.primitive {
+                stats.vertices += mesh.vertices.len();
+                stats.indices += mesh.indices.len();
+                if last_texture.is_some_and(|texture| texture != mesh.texture_id) {
+                    stats.texture_switches += 1;
+                }
+                last_texture = Some(mesh.texture_id);
+            }
+        }
+        stats
+    }
+
+    /// Tessellate the shapes of `output` and collect the stats.
+    pub fn from_output(ctx: &egui::Context, output: &egui::FullOutput) -> Self {
+        Self::from_primitives(&ctx.tessellate(output.shapes.clone(), ctx.pixels_per_point()))
+    }
+}
+
+/// Convert an image with straight (unmultiplied) alpha, like the ones [`TestRenderer::render`]
+/// returns, to premultiplied alpha, in place.
+///
+/// Each color channel is scaled by the alpha of its pixel: `c' = round(c * a / 255)`,
+/// in the (gamma) space the channels are stored in, just like [`egui::Color32`]
+/// is premultiplied. The alpha channel is left as is, so fully transparent pixels end up
+/// with zero RGB.
+///
+/// Use this before compositing a snapshot over other content with the usual
+/// `dst = src + dst * (1 - src_alpha)` blending, which expects premultiplied colors.
+/// Compositing straight alpha that way makes antialiased edges too bright.
+#[cfg(any(feature = "wgpu", feature = "snapshot"))]
+pub fn premultiply_alpha(image: &mut image::RgbaImage) {
+    for pixel in image.pixels_mut() {
+        let [r, g, b, a] = pixel.0;
+        let premultiply = |c: u8| ((u16::from(c) * u16::from(a) + 127) / 255) as u8;
+        pixel.0 = [premultiply(r), premultiply(g), premultiply(b), a];
+    }
+}
+
+/// The font atlas egui keeps on the CPU, white with the glyph coverage in straight alpha.
+#[cfg(any(feature = "wgpu", feature = "snapshot"))]
+pub(crate) fn font_atlas_image(ctx: &egui::Context) -> Result<image::RgbaImage, String> {
+    let font_image = ctx.fonts(|fonts| fonts.image());
+    let [width, height] = font_image.size;
+    let bytes = font_image
+        .srgba_pixels(None)
+        .flat_map(|color| color.to_srgba_unmultiplied())
+        .collect();
+    image::RgbaImage::from_raw(width as u32, height as u32, bytes)
+        .ok_or_else(|| "The font atlas has the wrong number of pixels".to_owned())
+}
 
 pub trait TestRenderer {
     /// We use this to pass the glow / wgpu render state to [`eframe::Frame`].
@@ -18,6 +95,113 @@ pub trait TestRenderer {
         ctx: &egui::Context,
         output: &egui::FullOutput,
     ) -> Result<image::RgbaImage, String>;
+
+    /// Render the output of a single viewport and return the resulting image.
+    ///
+    /// The image has the size of that viewport, see [`crate::Harness::viewport_ids`].
+    ///
+    /// The default implementation can only render the root viewport, using [`Self::render`].
+    ///
+    /// # Errors
+    /// Returns an error if the rendering fails, or the renderer doesn't support the viewport.
+    #[cfg(any(feature = "wgpu", feature = "snapshot"))]
+    fn render_viewport(
+        &mut self,
+        ctx: &egui::Context,
+        output: &egui::FullOutput,
+        viewport_id: egui::ViewportId,
+    ) -> Result<image::RgbaImage, String> {
+        if viewport_id == egui::ViewportId::ROOT {
+            self.render(ctx, output)
+        } else {
+            Err(format!(
+                "This renderer can't render the viewport {viewport_id:?}"
+            ))
+        }
+    }
+
+    /// Render the [`crate::Harness`] and return the resulting image,
+    /// together with stats about what was drawn.
+    ///
+    /// # Errors
+    /// Returns an error if the rendering fails.
+    #[cfg(any(feature = "wgpu", feature = "snapshot"))]
+    fn render_with_stats(
+        &mut self,
+        ctx: &egui::Context,
+        output: &egui::FullOutput,
+    ) -> Result<(image::RgbaImage, RenderStats), String> {
+        let image = self.render(ctx, output)?;
+        Ok((image, RenderStats::from_output(ctx, output)))
+    }
+
+    /// Render only what was painted on the given layer, e.g. a single window or tooltip.
+    ///
+    /// Everything else is left transparent (the clear color), as if the layer was painted alone.
+    /// Shapes of other layers placed on top of it with [`egui::Context::set_sublayer`] are left out too.
+    /// See [`egui::Context::layer_shape_ranges`].
+    ///
+    /// # Errors
+    /// Returns an error if the rendering fails.
+    #[cfg(any(feature = "wgpu", feature = "snapshot"))]
+    fn render_layer(
+        &mut self,
+        ctx: &egui::Context,
+        output: &egui::FullOutput,
+        layer_id: egui::LayerId,
+    ) -> Result<image::RgbaImage, String> {
+        let shapes = ctx
+            .layer_shape_ranges()
+            .into_iter()
+            .filter(|(id, _)| *id == layer_id)
+            .flat_map(|(_, range)| output.shapes[range].iter().cloned())
+            .collect();
+        let layer_output = egui::FullOutput {
+            shapes,
+            pixels_per_point: output.pixels_per_point,
+            ..Default::default()
+        };
+        self.render(ctx, &layer_output)
+    }
+
+    /// Render the [`crate::Harness`] centered within an image of `target_size` pixels.
+    ///
+    /// The content is rendered at its natural size and pixels per point,
+    /// and the margins around it are left transparent (the clear color).
+    /// If the content is larger than the target, it is cropped, keeping the center.
+    ///
+    /// # Errors
+    /// Returns an error if the rendering fails.
+    #[cfg(any(feature = "wgpu", feature = "snapshot"))]
+    fn render_letterboxed(
+        &mut self,
+        ctx: &egui::Context,
+        output: &egui::FullOutput,
+        target_size: [u32; 2],
+    ) -> Result<image::RgbaImage, String> {
+        let content = self.render(ctx, output)?;
+        let mut image = image::RgbaImage::new(target_size[0], target_size[1]);
+        let x = (i64::from(target_size[0]) - i64::from(content.width())) / 2;
+        let y = (i64::from(target_size[1]) - i64::from(content.height())) / 2;
+        image::imageops::replace(&mut image, &content, x, y);
+        Ok(image)
+    }
+
+    /// Return the current font atlas of the context as an image, e.g. to debug glyph coverage
+    /// or atlas growth in a test.
+    ///
+    /// The image is white, with the glyph coverage in straight (unmultiplied) alpha,
+    /// like the images [`Self::render`] returns.
+    ///
+    /// The default implementation copies the atlas egui keeps on the CPU,
+    /// which is what gets uploaded to the renderer.
+    ///
+    /// # Errors
+    /// Returns an error if the atlas can't be read.
+    #[cfg(any(feature = "wgpu", feature = "snapshot"))]
+    fn render_font_atlas(&mut self, ctx: &egui::Context) -> Result<image::RgbaImage, String> {
+        font_atlas_image(ctx)
+    }
 }
 
 /// A lazy renderer that initializes the renderer on the first render call.
@@ -54,6 +238,31 @@ impl LazyRenderer {
     }
 }
 
+impl LazyRenderer {
+    /// The renderer, initializing it if needed.
+    #[cfg(any(feature = "wgpu", feature = "snapshot"))]
+    fn renderer(&mut self) -> Result<&mut dyn TestRenderer, String> {
+        if let Self::Uninitialized {
+            texture_ops,
+            builder: build,
+        } = self
+        {
+            let mut renderer = build.take().ok_or({
+                "No default renderer available. \
+                Enable the wgpu feature or set one via HarnessBuilder::renderer"
+            })?();
+            for delta in texture_ops.drain(..) {
+                renderer.handle_delta(&delta);
+            }
+            *self = Self::Initialized { renderer };
+        }
+        match self {
+            Self::Initialized { renderer } => Ok(renderer.as_mut()),
+            Self::Uninitialized { .. } => unreachable!(),
+        }
+    }
+}
+
 impl TestRenderer for LazyRenderer {
     fn handle_delta(&mut self, delta: &TexturesDelta) {
         match self {
@@ -68,23 +277,21 @@ impl TestRenderer for LazyRenderer {
         ctx: &egui::Context,
         output: &egui::FullOutput,
     ) -> Result<image::RgbaImage, String> {
-        match self {
-            Self::Uninitialized {
-                texture_ops,
-                builder: build,
-            } => {
-                let mut renderer = build.take().ok_or({
-                    "No default renderer available. \
-                    Enable the wgpu feature or set one via HarnessBuilder::renderer"
-                })?();
-                for delta in texture_ops.drain(..) {
-                    renderer.handle_delta(&delta);
-                }
-                let image = renderer.render(ctx, output)?;
-                *self = Self::Initialized { renderer };
-                Ok(image)
-            }
-            Self::Initialized { renderer } => renderer.render(ctx, output),
-        }
+        self.renderer()?.render(ctx, output)
+    }
+
+    #[cfg(any(feature = "wgpu", feature = "snapshot"))]
+    fn render_viewport(
+        &mut self,
+        ctx: &egui::Context,
+        output: &egui::FullOutput,
+        viewport_id: egui::ViewportId,
+    ) -> Result<image::RgbaImage, String> {
+        self.renderer()?.render_viewport(ctx, output, viewport_id)
+    }
+
+    #[cfg(any(feature = "wgpu", feature = "snapshot"))]
+    fn render_font_atlas(&mut self, ctx: &egui::Context) -> Result<image::RgbaImage, String> {
+        self.renderer()?.render_font_atlas(ctx)
     }
 }
@@ -447,6 +447,58 @@ impl<State> Harness<'_, State> {
             }
         }
     }
+
+    /// Render the given viewport using the setup [`crate::TestRenderer`] and compare it to the
+    /// snapshot, e.g. to snapshot a tool window that is shown as a separate viewport.
+    ///
+    /// See [`Harness::viewport_ids`] and [`Self::try_snapshot_options`].
+    ///
+    /// # Errors
+    /// Returns a [`SnapshotError`] if the image does not match the snapshot, if there was an
+    /// error reading or writing the snapshot, if the viewport wasn't shown or if the rendering fails.
+    pub fn try_snapshot_viewport_options(
+        &mut self,
+        name: &str,
+        viewport_id: egui::ViewportId,
+        options: &SnapshotOptions,
+    ) -> SnapshotResult {
+        let image = self
+            .render_viewport(viewport_id)
+            .map_err(|err| SnapshotError::RenderError { err })?;
+        try_image_snapshot_options(&image, name, options)
+    }
+
+    /// Render the given viewport using the setup [`crate::TestRenderer`] and compare it to the snapshot.
+    ///
+    /// See [`Self::try_snapshot_viewport_options`].
+    ///
+    /// # Errors
+    /// Returns a [`SnapshotError`] if the image does not match the snapshot, if there was an
+    /// error reading or writing the snapshot, if the viewport wasn't shown or if the rendering fails.
+    pub fn try_snapshot_viewport(
+        &mut self,
+        name: &str,
+        viewport_id: egui::ViewportId,
+    ) -> SnapshotResult {
+        self.try_snapshot_viewport_options(name, viewport_id, &SnapshotOptions::default())
+    }
+
+    /// Render the given viewport using the setup [`crate::TestRenderer`] and compare it to the snapshot.
+    ///
+    /// See [`Self::try_snapshot_viewport_options`].
+    ///
+    /// # Panics
+    /// Panics if the image does not match the snapshot, if there was an error reading or writing the
+    /// snapshot, if the viewport wasn't shown or if the rendering fails.
+    #[track_caller]
+    pub fn snapshot_viewport(&mut self, name: &str, viewport_id: egui::ViewportId) {
+        match self.try_snapshot_viewport(name, viewport_id) {
+            Ok(_) => {}
+            Err(err) => {
+                panic!("{}", err);
+            }
+        }
+    }
 }
 
 // Deprecated wgpu_snapshot functions
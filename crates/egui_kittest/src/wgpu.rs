@@ -1,6 +1,7 @@
 use std::iter::once;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
+use egui::mutex::RwLock;
 use egui::TexturesDelta;
 use egui_wgpu::{wgpu, RenderState, ScreenDescriptor, WgpuSetup};
 use image::RgbaImage;
@@ -66,9 +67,44 @@ pub fn create_render_state(setup: WgpuSetup) -> egui_wgpu::RenderState {
     .expect("Failed to create render state")
 }
 
+/// The [`RenderState`] used by [`WgpuTestRenderer::shared`], created on first use.
+fn shared_render_state() -> &'static RenderState {
+    static SHARED: OnceLock<RenderState> = OnceLock::new();
+    SHARED.get_or_init(|| create_render_state(default_wgpu_setup()))
+}
+
+fn create_renderer(render_state: &RenderState) -> egui_wgpu::Renderer {
+    egui_wgpu::Renderer::new(
+        &render_state.device,
+        render_state.target_format,
+        None,
+        1,
+        false,
+    )
+}
+
+/// Where [`WgpuTestRenderer::shared`] keeps the [`egui_wgpu::Renderer`] of a context,
+/// in [`egui::Memory::data`].
+const SHARED_RENDERER_ID: &str = "egui_kittest::wgpu::shared_renderer";
+
+/// The [`egui_wgpu::Renderer`] of a context, shared by all [`WgpuTestRenderer::shared`] rendering it.
+#[derive(Clone)]
+struct SharedRenderer(Arc<RwLock<egui_wgpu::Renderer>>);
+
 /// Utility to render snapshots from a [`crate::Harness`] using [`egui_wgpu`].
+///
+/// Paint callbacks ([`egui_wgpu::Callback`]) are prepared and painted just like in a real integration,
+/// so snapshots include custom wgpu-rendered regions.
+/// Set up the resources your callbacks need via [`Self::render_state`].
 pub struct WgpuTestRenderer {
     render_state: RenderState,
+    premultiplied_alpha: bool,
+
+    /// For [`Self::shared`]: the deltas to apply once we know the [`egui::Context`]
+    /// whose [`egui_wgpu::Renderer`] to use, see [`Self::bind_to_context`].
+    ///
+    /// `None` once bound, or if this renderer has its own [`egui_wgpu::Renderer`].
+    pending_deltas: Option<Vec<TexturesDelta>>,
 }
 
 impl Default for WgpuTestRenderer {
@@ -82,6 +118,33 @@ impl WgpuTestRenderer {
     pub fn new() -> Self {
         Self {
             render_state: create_render_state(default_wgpu_setup()),
+            premultiplied_alpha: false,
+            pending_deltas: None,
+        }
+    }
+
+    /// Create a new [`WgpuTestRenderer`] that reuses a wgpu device shared by the whole process,
+    /// and an [`egui_wgpu::Renderer`] shared by everything rendering the same [`egui::Context`].
+    ///
+    /// Setting up a wgpu instance, adapter and device is by far the slowest part of
+    /// rendering a snapshot, so test suites with many harnesses should prefer this over [`Self::new`].
+    ///
+    /// The device and queue (with the default setup) live as long as the process.
+    /// The [`egui_wgpu::Renderer`], with the textures of the context, is kept in the temporary
+    /// [`egui::Memory::data`] of the context, so it lives as long as the context does,
+    /// and its textures are freed as the context frees them.
+    /// The renderer is picked on the first render, so until then the renderer of
+    /// [`Self::render_state`] is a placeholder shared by the whole process:
+    /// set up callback resources only after the first render.
+    ///
+    /// Every [`WgpuTestRenderer`] rendering a context must be fed all the [`TexturesDelta`]s of that
+    /// context from the time it was created on, and never deltas or output from another context.
+    /// Mixing this with other renderers for the same context can leave textures missing.
+    pub fn shared() -> Self {
+        Self {
+            render_state: shared_render_state().clone(),
+            premultiplied_alpha: false,
+            pending_deltas: Some(Vec::new()),
         }
     }
 
@@ -89,6 +152,8 @@ impl WgpuTestRenderer {
     pub fn from_setup(setup: WgpuSetup) -> Self {
         Self {
             render_state: create_render_state(setup),
+            premultiplied_alpha: false,
+            pending_deltas: None,
         }
     }
 
@@ -105,18 +170,73 @@ impl WgpuTestRenderer {
                 .is_none(),
             "The RenderState passed in has been used before, pass in a fresh RenderState instead."
         );
-        Self { render_state }
+        Self {
+            render_state,
+            premultiplied_alpha: false,
+            pending_deltas: None,
+        }
     }
-}
 
-impl crate::TestRenderer for WgpuTestRenderer {
-    #[cfg(feature = "eframe")]
-    fn setup_eframe(&self, cc: &mut eframe::CreationContext<'_>, frame: &mut eframe::Frame) {
-        cc.wgpu_render_state = Some(self.render_state.clone());
-        frame.wgpu_render_state = Some(self.render_state.clone());
+    /// Return images with premultiplied instead of straight alpha from [`crate::TestRenderer::render`].
+    ///
+    /// Useful when compositing the rendered images over other content.
+    /// See [`crate::premultiply_alpha`] for the math.
+    ///
+    /// Snapshots compare whatever the renderer returns, so don't mix this with
+    /// snapshot images that were saved with straight alpha.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn with_premultiplied_alpha(mut self, premultiplied_alpha: bool) -> Self {
+        self.premultiplied_alpha = premultiplied_alpha;
+        self
     }
 
-    fn handle_delta(&mut self, delta: &TexturesDelta) {
+    /// The [`RenderState`] used for rendering.
+    ///
+    /// Use this to create the pipelines, buffers etc. your [`egui_wgpu::CallbackTrait`]s need,
+    /// and insert them into [`egui_wgpu::Renderer::callback_resources`],
+    /// like you would with [`egui_wgpu::RenderState`] in your app.
+    ///
+    /// ```no_run
+    /// # struct MyResources;
+    /// let renderer = egui_kittest::wgpu::WgpuTestRenderer::new();
+    /// renderer
+    ///     .render_state()
+    ///     .renderer
+    ///     .write()
+    ///     .callback_resources
+    ///     .insert(MyResources);
+    /// let harness = egui_kittest::Harness::builder()
+    ///     .renderer(renderer)
+    ///     .build_ui(|ui| {
+    ///         // Add your `egui_wgpu::Callback`s here
+    ///     });
+    /// ```
+    pub fn render_state(&self) -> &RenderState {
+        &self.render_state
+    }
+
+    /// For [`Self::shared`]: switch to the [`egui_wgpu::Renderer`] of `ctx`, creating it if needed,
+    /// and apply the deltas received so far.
+    fn bind_to_context(&mut self, ctx: &egui::Context) {
+        let Some(pending_deltas) = self.pending_deltas.take() else {
+            return;
+        };
+        let renderer = ctx.data_mut(|data| {
+            data.get_temp_mut_or_insert_with(egui::Id::new(SHARED_RENDERER_ID), || {
+                SharedRenderer(Arc::new(RwLock::new(create_renderer(&self.render_state))))
+            })
+            .0
+            .clone()
+        });
+        self.render_state.renderer = renderer;
+        for delta in &pending_deltas {
+            self.apply_delta(delta);
+        }
+    }
+
+    fn apply_delta(&self, delta: &TexturesDelta) {
         let mut renderer = self.render_state.renderer.write();
         for (id, image) in &delta.set {
             renderer.update_texture(
@@ -126,6 +246,25 @@ impl crate::TestRenderer for WgpuTestRenderer {
                 image,
             );
         }
+        for id in &delta.free {
+            renderer.free_texture(id);
+        }
+    }
+}
+
+impl crate::TestRenderer for WgpuTestRenderer {
+    #[cfg(feature = "eframe")]
+    fn setup_eframe(&self, cc: &mut eframe::CreationContext<'_>, frame: &mut eframe::Frame) {
+        cc.wgpu_render_state = Some(self.render_state.clone());
+        frame.wgpu_render_state = Some(self.render_state.clone());
+    }
+
+    fn handle_delta(&mut self, delta: &TexturesDelta) {
+        if let Some(pending_deltas) = &mut self.pending_deltas {
+            pending_deltas.push(delta.clone());
+        } else {
+            self.apply_delta(delta);
+        }
     }
 
     /// Render the [`crate::Harness`] and return the resulting image.
@@ -134,6 +273,16 @@ impl crate::TestRenderer for WgpuTestRenderer {
         ctx: &egui::Context,
         output: &egui::FullOutput,
     ) -> Result<RgbaImage, String> {
+        self.render_viewport(ctx, output, ctx.viewport_id())
+    }
+
+    fn render_viewport(
+        &mut self,
+        ctx: &egui::Context,
+        output: &egui::FullOutput,
+        viewport_id: egui::ViewportId,
+    ) -> Result<RgbaImage, String> {
+        self.bind_to_context(ctx);
         let mut renderer = self.render_state.renderer.write();
 
         let mut encoder =
@@ -143,13 +292,15 @@ impl crate::TestRenderer for WgpuTestRenderer {
                     label: Some("Egui Command Encoder"),
                 });
 
-        let size = ctx.screen_rect().size() * ctx.pixels_per_point();
+        let (screen_rect, pixels_per_point) =
+            ctx.input_for(viewport_id, |i| (i.screen_rect(), i.pixels_per_point()));
+        let size = screen_rect.size() * pixels_per_point;
         let screen = ScreenDescriptor {
-            pixels_per_point: ctx.pixels_per_point(),
+            pixels_per_point,
             size_in_pixels: [size.x.round() as u32, size.y.round() as u32],
         };
 
-        let tessellated = ctx.tessellate(output.shapes.clone(), ctx.pixels_per_point());
+        let tessellated = ctx.tessellate(output.shapes.clone(), pixels_per_point);
 
         let user_buffers = renderer.update_buffers(
             &self.render_state.device,
@@ -204,10 +355,23 @@ impl crate::TestRenderer for WgpuTestRenderer {
 
         self.render_state.device.poll(wgpu::Maintain::Wait);
 
-        Ok(texture_to_image(
+        let mut image = texture_to_image(
             &self.render_state.device,
             &self.render_state.queue,
             &texture,
-        ))
+        );
+        if self.premultiplied_alpha {
+            crate::premultiply_alpha(&mut image);
+        }
+        Ok(image)
+    }
+
+    /// The font atlas egui keeps on the CPU, premultiplied if [`Self::with_premultiplied_alpha`] is set.
+    fn render_font_atlas(&mut self, ctx: &egui::Context) -> Result<RgbaImage, String> {
+        let mut image = crate::renderer::font_atlas_image(ctx)?;
+        if self.premultiplied_alpha {
+            crate::premultiply_alpha(&mut image);
+        }
+        Ok(image)
     }
 }
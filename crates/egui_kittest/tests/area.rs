@@ -0,0 +1,336 @@
+mod common;
+
+use common::{drag_pointer, move_pointer, press_pointer, release_pointer, window_rect};
+use egui::Vec2;
+use egui_kittest::{kittest::Queryable, Harness};
+
+#[test]
+fn area_interactable_rects_pass_through() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 200.0))
+        .build_ui_state(
+            |ui, clicked| {
+                if ui.button("Behind").clicked() {
+                    *clicked = true;
+                }
+
+                // An overlay covering everything, but only interactable in the bottom right corner:
+                egui::Area::new(egui::Id::new("overlay"))
+                    .order(egui::Order::Foreground)
+                    .fixed_pos(egui::Pos2::ZERO)
+                    .interactable_rects([egui::Rect::from_min_size(
+                        egui::pos2(250.0, 150.0),
+                        Vec2::splat(50.0),
+                    )])
+                    .show(ui.ctx(), |ui| {
+                        ui.allocate_exact_size(Vec2::new(300.0, 200.0), egui::Sense::click());
+                    });
+            },
+            false,
+        );
+
+    harness.run();
+    harness.get_by_label("Behind").simulate_click();
+    harness.run();
+
+    assert!(harness.state(), "The click should pass through the overlay");
+}
+
+#[test]
+fn area_snaps_to_the_edges_of_other_areas() {
+    let palette_rect = |snap_centers: bool, drag_by: Vec2| {
+        let mut harness = Harness::builder()
+            .with_size(Vec2::new(300.0, 300.0))
+            .build(|ctx| {
+                egui::Area::new(egui::Id::new("anchor"))
+                    .fixed_pos(egui::pos2(100.0, 100.0))
+                    .show(ctx, |ui| {
+                        ui.allocate_space(Vec2::splat(50.0));
+                    });
+                egui::Area::new(egui::Id::new("palette"))
+                    .default_pos(egui::pos2(20.0, 200.0))
+                    .constrain(false)
+                    .snap_to_areas(10.0)
+                    .snap_centers(snap_centers)
+                    .show(ctx, |ui| {
+                        ui.allocate_space(Vec2::splat(30.0));
+                    });
+            });
+        harness.run();
+
+        let start = egui::pos2(35.0, 215.0);
+        drag_pointer(&mut harness, start, start + drag_by);
+        window_rect(&harness, egui::Id::new("palette"))
+    };
+
+    // Close to the right edge of the anchor, slightly below its top:
+    let rect = palette_rect(false, Vec2::new(134.0, -96.0));
+    assert_eq!(rect.min, egui::pos2(150.0, 100.0), "snapped side by side");
+
+    // Too far away to snap:
+    let rect = palette_rect(false, Vec2::new(150.0, -80.0));
+    assert_eq!(rect.min, egui::pos2(170.0, 120.0));
+
+    // Centers only snap when asked to, otherwise the nearest edges do:
+    let near_center = Vec2::new(91.0, -89.0);
+    let rect = palette_rect(false, near_center);
+    assert_eq!(
+        rect.max,
+        egui::pos2(150.0, 150.0),
+        "lined up with the anchor"
+    );
+    let rect = palette_rect(true, near_center);
+    assert_eq!(
+        rect.center(),
+        egui::pos2(125.0, 125.0),
+        "centered on the anchor"
+    );
+}
+
+#[test]
+fn area_snaps_to_grid_while_dragging() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 300.0))
+        .build(|ctx| {
+            egui::Area::new(egui::Id::new("snapping"))
+                .default_pos(egui::pos2(20.0, 20.0))
+                .constrain(false)
+                .snap_to_grid(Vec2::splat(25.0), Vec2::splat(20.0))
+                .show(ctx, |ui| {
+                    ui.allocate_space(Vec2::splat(50.0));
+                });
+        });
+    harness.run();
+
+    // Drag in small steps, which should add up rather than each snap back:
+    let start = egui::pos2(40.0, 40.0);
+    press_pointer(&mut harness, start);
+    for i in 1..=10 {
+        move_pointer(
+            &mut harness,
+            start + Vec2::new(3.0 * i as f32, 1.0 * i as f32),
+        );
+    }
+    release_pointer(&mut harness, start + Vec2::new(30.0, 10.0));
+    harness.run();
+
+    let rect = window_rect(&harness, egui::Id::new("snapping"));
+    assert_eq!(rect.left_top(), egui::pos2(45.0, 20.0));
+}
+
+#[test]
+fn area_max_size_scrolls_overflowing_contents() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 200.0))
+        .build(|ctx| {
+            egui::Area::new(egui::Id::new("capped"))
+                .fixed_pos([10.0, 10.0])
+                .max_size([150.0, 100.0])
+                .show(ctx, |ui| {
+                    for i in 0..30 {
+                        ui.label(format!("Item {i}"));
+                    }
+                });
+            egui::Area::new(egui::Id::new("fit"))
+                .fixed_pos([180.0, 10.0])
+                .fit_to_screen(true)
+                .show(ctx, |ui| {
+                    for i in 0..30 {
+                        ui.label(format!("Line {i}"));
+                    }
+                });
+        });
+    harness.run();
+
+    let capped = window_rect(&harness, egui::Id::new("capped"));
+    assert!(capped.height() <= 100.0, "capped: {capped:?}");
+    let fit = window_rect(&harness, egui::Id::new("fit"));
+    assert!(
+        fit.top() >= 0.0 && fit.bottom() <= 200.0,
+        "fits on screen: {fit:?}"
+    );
+
+    let item_top = |harness: &Harness<'_>| harness.get_by_label("Item 0").raw_bounds().unwrap().y0;
+    let top_before = item_top(&harness);
+
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(capped.center()));
+    harness.input_mut().events.push(egui::Event::MouseWheel {
+        unit: egui::MouseWheelUnit::Point,
+        delta: Vec2::new(0.0, -50.0),
+        modifiers: egui::Modifiers::NONE,
+    });
+    harness.run_steps(4);
+
+    assert!(
+        item_top(&harness) < top_before,
+        "the overflowing contents scroll"
+    );
+}
+
+#[test]
+fn area_show_with_placement_tells_the_contents_where_the_area_is() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 200.0))
+        .build_state(
+            |ctx, placements: &mut Vec<egui::AreaPlacement>| {
+                placements.clear();
+                let response = egui::Area::new(egui::Id::new("upwards"))
+                    .fixed_pos([20.0, 190.0])
+                    .pivot(egui::Align2::LEFT_BOTTOM)
+                    .constrain(true)
+                    .show_with_placement(ctx, |ui, placement| {
+                        placements.push(placement);
+                        ui.label("Opened upwards");
+                    });
+                if !placements[0].sizing_pass {
+                    assert_eq!(
+                        response.response.rect.left_bottom(),
+                        placements[0].rect.left_bottom(),
+                        "The placement is where the area ends up"
+                    );
+                }
+            },
+            Vec::new(),
+        );
+    harness.run();
+
+    let placement = harness.state()[0];
+    assert!(!placement.sizing_pass);
+    assert_eq!(placement.pivot, egui::Align2::LEFT_BOTTOM);
+    assert_eq!(placement.constrain_rect, harness.ctx.screen_rect());
+    assert_eq!(placement.rect.left_bottom(), egui::pos2(20.0, 190.0));
+    assert!(placement.rect.height() > 0.0);
+}
+
+#[test]
+fn area_reports_pointer_over_area_for_hover_groups() {
+    #[derive(Default)]
+    struct State {
+        menu_hovered: bool,
+        submenu_hovered: bool,
+        over_group: bool,
+    }
+
+    let mut harness = Harness::new_state(
+        |ctx, state: &mut State| {
+            let menu = egui::Area::new(egui::Id::new("menu"))
+                .fixed_pos(egui::pos2(10.0, 10.0))
+                .show(ctx, |ui| ui.label("Menu item"));
+            let submenu = egui::Area::new(egui::Id::new("submenu"))
+                .fixed_pos(egui::pos2(200.0, 10.0))
+                .show(ctx, |ui| ui.label("Submenu item"));
+            state.menu_hovered = menu.response.pointer_over_area();
+            state.submenu_hovered = submenu.response.pointer_over_area();
+            state.over_group =
+                ctx.is_pointer_over_layers(&[menu.response.layer_id, submenu.response.layer_id]);
+        },
+        State::default(),
+    );
+
+    let center = |harness: &Harness<'_, State>, label: &str| {
+        let bounds = harness.get_by_label(label).raw_bounds().unwrap();
+        egui::pos2(
+            (bounds.x0 + bounds.x1) as f32 / 2.0,
+            (bounds.y0 + bounds.y1) as f32 / 2.0,
+        )
+    };
+    let move_to = |harness: &mut Harness<'_, State>, pos: egui::Pos2| {
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(pos));
+        harness.run();
+    };
+
+    let menu_pos = center(&harness, "Menu item");
+    let submenu_pos = center(&harness, "Submenu item");
+
+    move_to(&mut harness, menu_pos);
+    assert!(harness.state().menu_hovered);
+    assert!(!harness.state().submenu_hovered);
+    assert!(harness.state().over_group);
+
+    move_to(&mut harness, submenu_pos);
+    assert!(!harness.state().menu_hovered);
+    assert!(harness.state().submenu_hovered);
+    assert!(harness.state().over_group);
+
+    move_to(&mut harness, egui::pos2(100.0, 150.0));
+    assert!(!harness.state().menu_hovered);
+    assert!(!harness.state().submenu_hovered);
+    assert!(!harness.state().over_group);
+}
+
+#[test]
+fn area_fades_out_after_it_is_closed() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 200.0))
+        .with_step_dt(0.1)
+        .build_state(
+            |ctx, open: &mut bool| {
+                if *open {
+                    egui::Area::new(egui::Id::new("toast"))
+                        .fixed_pos(egui::pos2(10.0, 10.0))
+                        .fade_in(false)
+                        .fade_out(0.5)
+                        .show(ctx, |ui| {
+                            ui.label("Saved!");
+                        });
+                }
+            },
+            true,
+        );
+    let shown_shapes = harness.output().shapes.len();
+    assert!(shown_shapes > 0);
+
+    *harness.state_mut() = false;
+    harness.step();
+    assert_eq!(harness.output().shapes.len(), shown_shapes, "Still painted");
+    assert!(
+        harness.query_by_label("Saved!").is_none(),
+        "But no longer a widget"
+    );
+    assert!(harness.repaint_requested());
+
+    harness.run_steps(6);
+    assert!(harness.output().shapes.is_empty(), "Gone after fading out");
+    assert!(!harness.repaint_requested());
+}
+
+#[test]
+fn area_movable_axes_locks_the_other_coordinate() {
+    let area_rect = |snap_to_grid: bool| {
+        let mut harness = Harness::builder()
+            .with_size(Vec2::new(300.0, 300.0))
+            .build(|ctx| {
+                let mut area = egui::Area::new(egui::Id::new("scrubber"))
+                    .default_pos(egui::pos2(20.0, 100.0))
+                    .constrain(false)
+                    .movable_axes([true, false]);
+                if snap_to_grid {
+                    area = area.snap_to_grid(Vec2::splat(25.0), Vec2::ZERO);
+                }
+                area.show(ctx, |ui| {
+                    ui.allocate_space(Vec2::splat(30.0));
+                });
+            });
+        harness.run();
+
+        let start = egui::pos2(35.0, 115.0);
+        drag_pointer(&mut harness, start, start + Vec2::new(62.0, 58.0));
+        window_rect(&harness, egui::Id::new("scrubber"))
+    };
+
+    let rect = area_rect(false);
+    assert_eq!(rect.min, egui::pos2(82.0, 100.0), "only moved horizontally");
+
+    let rect = area_rect(true);
+    assert_eq!(
+        rect.min,
+        egui::pos2(75.0, 100.0),
+        "snapped horizontally only"
+    );
+}
@@ -0,0 +1,454 @@
+mod common;
+
+use common::{drag_pointer, move_pointer, press_pointer, release_pointer};
+use egui::Vec2;
+use egui_kittest::{kittest::Queryable, Harness};
+
+#[test]
+fn open_and_close_all_collapsing_headers() {
+    let mut harness = Harness::new_ui_state(
+        |ui, parent_id: &mut egui::Id| {
+            let tree_id = ui.id();
+            if ui.button("Expand all").clicked() {
+                ui.ctx().open_all_collapsing(tree_id);
+            }
+            if ui.button("Collapse all").clicked() {
+                ui.ctx().close_all_collapsing(tree_id);
+            }
+            *parent_id = egui::CollapsingHeader::new("Parent").id(ui);
+            ui.collapsing("Parent", |ui| {
+                ui.collapsing("Child", |ui| {
+                    ui.label("Leaf");
+                });
+            });
+        },
+        egui::Id::NULL,
+    );
+    harness.run();
+    assert!(harness.query_by_label("Child").is_none());
+
+    harness.get_by_label("Expand all").click();
+    harness.run();
+    assert!(
+        harness.query_by_label("Leaf").is_some(),
+        "nested headers should open too"
+    );
+
+    harness.get_by_label("Collapse all").click();
+    harness.run();
+    assert!(harness.query_by_label("Child").is_none());
+
+    let parent_id = *harness.state();
+    harness.ctx.set_collapsing_open(parent_id, true);
+    harness.run();
+    assert!(harness.query_by_label("Child").is_some());
+    assert!(harness.query_by_label("Leaf").is_none());
+}
+
+#[test]
+fn open_all_collapsing_with_a_header_id_only_opens_that_subtree() {
+    let mut harness = Harness::new_ui_state(
+        |ui, first_id: &mut egui::Id| {
+            *first_id = egui::CollapsingHeader::new("First").id(ui);
+            ui.collapsing("First", |ui| {
+                ui.collapsing("First child", |ui| {
+                    ui.label("First leaf");
+                });
+            });
+            ui.collapsing("Second", |ui| {
+                ui.label("Second body");
+            });
+        },
+        egui::Id::NULL,
+    );
+    harness.run();
+
+    let first_id = *harness.state();
+    harness.ctx.open_all_collapsing(first_id);
+    harness.run();
+    assert!(harness.query_by_label("First leaf").is_some());
+    assert!(
+        harness.query_by_label("Second body").is_none(),
+        "siblings are not affected"
+    );
+
+    harness.ctx.close_all_collapsing(first_id);
+    harness.run();
+    assert!(harness.query_by_label("First child").is_none());
+}
+
+#[test]
+fn collapsing_header_indent_guides() {
+    let count_line_segments = |indent_guides: bool| {
+        let mut harness = Harness::new_ui(|ui| {
+            egui::CollapsingHeader::new("Root")
+                .default_open(true)
+                .indent_guides(indent_guides)
+                .show(ui, |ui| {
+                    egui::CollapsingHeader::new("Child")
+                        .default_open(true)
+                        .show(ui, |ui| {
+                            ui.label("Leaf");
+                        });
+                });
+        });
+        harness.run();
+        harness
+            .output()
+            .shapes
+            .iter()
+            .filter(|clipped| matches!(clipped.shape, egui::Shape::LineSegment { .. }))
+            .count()
+    };
+
+    assert_eq!(
+        count_line_segments(true) - count_line_segments(false),
+        2,
+        "one guide for each level of indentation"
+    );
+}
+
+#[test]
+fn collapsing_header_selectable_separates_selection_from_expansion() {
+    #[derive(Default)]
+    struct State {
+        selected: bool,
+        row_width: f32,
+        available_width: f32,
+    }
+
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut State| {
+            state.available_width = ui.available_width();
+            let response = egui::CollapsingHeader::new("src")
+                .selectable(true)
+                .selected(state.selected)
+                .show(ui, |ui| {
+                    ui.label("main.rs");
+                });
+            state.row_width = response.header_response.rect.width();
+            if response.header_response.clicked() {
+                state.selected = true;
+            }
+        },
+        State::default(),
+    );
+    harness.run();
+    assert_eq!(harness.state().row_width, harness.state().available_width);
+
+    // Clicking the label selects the node, but doesn't open it:
+    harness.get_by_label("src").simulate_click();
+    harness.run();
+    assert!(harness.state().selected);
+    assert!(harness.query_by_label("main.rs").is_none());
+
+    // Clicking the icon opens it:
+    harness.get_by_label("Expand").simulate_click();
+    harness.run();
+    assert!(harness.query_by_label("main.rs").is_some());
+
+    harness.get_by_label("Collapse").simulate_click();
+    harness.run();
+    assert!(harness.query_by_label("main.rs").is_none());
+}
+
+#[test]
+fn collapsing_header_recursive_toggle_opens_and_closes_the_subtree() {
+    let mut harness = Harness::builder().with_step_dt(0.05).build_ui(|ui| {
+        egui::CollapsingHeader::new("Root")
+            .recursive_toggle(true)
+            .show(ui, |ui| {
+                ui.collapsing("Child", |ui| {
+                    ui.collapsing("Grandchild", |ui| {
+                        ui.label("Leaf");
+                    });
+                });
+            });
+    });
+    harness.run();
+    assert!(harness.query_by_label("Child").is_none());
+
+    // Double-clicking opens everything:
+    harness.get_by_label("Root").simulate_click();
+    harness.step();
+    harness.get_by_label("Root").simulate_click();
+    harness.run();
+    assert!(harness.query_by_label("Leaf").is_some());
+
+    // A single click only closes the root:
+    harness.get_by_label("Root").simulate_click();
+    harness.run_steps(10);
+    harness.get_by_label("Root").simulate_click();
+    harness.run_steps(10);
+    assert!(harness.query_by_label("Leaf").is_some());
+
+    // Alt-clicking closes everything, so reopening the root shows a closed child:
+    harness.get_by_label("Root").key_down(kittest::Key::Alt);
+    harness.get_by_label("Root").simulate_click();
+    harness.get_by_label("Root").key_up(kittest::Key::Alt);
+    harness.run_steps(10);
+    assert!(harness.query_by_label("Child").is_none());
+    harness.get_by_label("Root").simulate_click();
+    harness.run_steps(10);
+    assert!(harness.query_by_label("Child").is_some());
+    assert!(harness.query_by_label("Grandchild").is_none());
+}
+
+#[test]
+fn collapsing_header_full_width_header_spans_the_ui() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 200.0))
+        .build_ui(|ui| {
+            egui::CollapsingHeader::new("Narrow").show(ui, |ui| ui.label("Narrow body"));
+            egui::CollapsingHeader::new("Wide")
+                .full_width_header(true)
+                .show(ui, |ui| ui.label("Wide body"));
+        });
+    harness.run();
+
+    let width = |harness: &Harness<'_>, label: &str| {
+        let bounds = harness.get_by_label(label).raw_bounds().unwrap();
+        bounds.x1 - bounds.x0
+    };
+    let available_width = harness.ctx.screen_rect().width() as f64 - 16.0; // minus the panel margins
+    assert!(width(&harness, "Narrow") < available_width / 2.0);
+    assert!((width(&harness, "Wide") - available_width).abs() < 1.0);
+
+    // Clicking far right of the text still toggles it:
+    let wide = harness.get_by_label("Wide").raw_bounds().unwrap();
+    let pos = egui::pos2(wide.x1 as f32 - 5.0, (wide.y0 + wide.y1) as f32 / 2.0);
+    drag_pointer(&mut harness, pos, pos);
+    assert!(harness.query_by_label("Wide body").is_some());
+}
+
+#[test]
+fn collapsing_header_reorderable_reports_drops_and_still_toggles() {
+    let mut harness = Harness::new_ui_state(
+        |ui, sections: &mut Vec<&'static str>| {
+            let mut reordered = None;
+            for (index, section) in sections.iter().enumerate() {
+                let response = egui::CollapsingHeader::new(*section)
+                    .reorderable(index)
+                    .show(ui, |ui| ui.label(format!("{section} body")));
+                reordered = reordered.or(response.reordered);
+            }
+            if let Some((from, to)) = reordered {
+                let section = sections.remove(from);
+                sections.insert(to, section);
+            }
+        },
+        vec!["First", "Second", "Third"],
+    );
+    harness.run();
+
+    harness.get_by_label("First").simulate_click();
+    harness.run();
+    assert!(
+        harness.query_by_label("First body").is_some(),
+        "Clicking the header opens it"
+    );
+
+    let center = |harness: &Harness<'_, Vec<&'static str>>, label: &str| {
+        let rect = harness.get_by_label(label).raw_bounds().unwrap();
+        egui::pos2(
+            (rect.x0 + rect.x1) as f32 / 2.0,
+            (rect.y0 + rect.y1) as f32 / 2.0,
+        )
+    };
+    // The body of the dragged section is hidden, so look up the target once the drag has started:
+    let drag = |harness: &mut Harness<'_, Vec<&'static str>>, from: &str, to: &str| {
+        let from = center(harness, from);
+        press_pointer(harness, from);
+        move_pointer(harness, from + Vec2::new(20.0, 0.0));
+        harness.run();
+        let to = center(harness, to);
+        move_pointer(harness, to);
+        release_pointer(harness, to);
+        harness.run();
+    };
+    drag(&mut harness, "First", "Third");
+    assert_eq!(*harness.state(), vec!["Second", "Third", "First"]);
+    assert!(
+        harness.query_by_label("First body").is_some(),
+        "Dragging doesn't toggle the header"
+    );
+
+    drag(&mut harness, "First", "Second");
+    assert_eq!(*harness.state(), vec!["First", "Second", "Third"]);
+}
+
+#[test]
+fn collapsing_header_reorder_lists_in_the_same_ui_are_separate() {
+    type Lists = [Vec<&'static str>; 2];
+    let mut harness = Harness::new_ui_state(
+        |ui, lists: &mut Lists| {
+            for (list_index, list) in lists.iter_mut().enumerate() {
+                let mut reordered = None;
+                for (index, section) in list.iter().enumerate() {
+                    let response = egui::CollapsingHeader::new(*section)
+                        .reorderable(index)
+                        .reorder_list(list_index)
+                        .show(ui, |ui| ui.label(format!("{section} body")));
+                    reordered = reordered.or(response.reordered);
+                }
+                if let Some((from, to)) = reordered {
+                    let section = list.remove(from);
+                    list.insert(to, section);
+                }
+            }
+        },
+        [vec!["A1", "A2"], vec!["B1", "B2"]],
+    );
+    harness.run();
+
+    let center = |harness: &Harness<'_, Lists>, label: &str| {
+        let rect = harness.get_by_label(label).raw_bounds().unwrap();
+        egui::pos2(
+            (rect.x0 + rect.x1) as f32 / 2.0,
+            (rect.y0 + rect.y1) as f32 / 2.0,
+        )
+    };
+    let drag = |harness: &mut Harness<'_, Lists>, from: &str, to: &str| {
+        let from = center(harness, from);
+        press_pointer(harness, from);
+        move_pointer(harness, from + Vec2::new(20.0, 0.0));
+        harness.run();
+        let to = center(harness, to);
+        move_pointer(harness, to);
+        release_pointer(harness, to);
+        harness.run();
+    };
+
+    drag(&mut harness, "A1", "B2");
+    assert_eq!(
+        *harness.state(),
+        [vec!["A1", "A2"], vec!["B1", "B2"]],
+        "sections can't be dropped in another list"
+    );
+
+    drag(&mut harness, "A1", "A2");
+    assert_eq!(*harness.state(), [vec!["A2", "A1"], vec!["B1", "B2"]]);
+}
+
+#[test]
+fn collapsing_header_badge_is_shown_after_the_title_and_does_not_toggle() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (open, badge_clicks): &mut (bool, usize)| {
+            let response = egui::CollapsingHeader::new("Errors")
+                .badge(3)
+                .show(ui, |ui| {
+                    ui.label("File not found");
+                });
+            *open = response.body_response.is_some();
+            if response.badge_response.is_some_and(|r| r.clicked()) {
+                *badge_clicks += 1;
+            }
+        },
+        (false, 0),
+    );
+
+    let header = harness.get_by_label("Errors").bounding_box().unwrap();
+    let badge = harness.get_by_label("3").bounding_box().unwrap();
+    assert!(
+        header.x0 + 40.0 < badge.x0 && badge.x1 <= header.x1,
+        "The badge is after the title, in the header: {header:?} {badge:?}"
+    );
+    assert!(badge.width() >= badge.height(), "The badge is a pill");
+
+    harness.get_by_label("3").click();
+    harness.run();
+    assert_eq!(
+        *harness.state(),
+        (false, 1),
+        "Clicking the badge doesn't toggle"
+    );
+
+    harness.get_by_label("Errors").click();
+    harness.run();
+    assert!(harness.state().0);
+}
+
+#[test]
+fn collapsing_header_first_open_animates_from_the_real_height() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 400.0))
+        .with_step_dt(1.0 / 60.0)
+        .with_max_steps(60)
+        .build_ui_state(
+            |ui, heights: &mut Vec<f32>| {
+                let response = egui::CollapsingHeader::new("Header").show(ui, |ui| {
+                    for i in 0..10 {
+                        ui.label(format!("Row {i}"));
+                    }
+                });
+                if let Some(body) = response.body_response {
+                    // Only look at what is shown:
+                    if !ui.ctx().will_discard() {
+                        heights.push(body.rect.height());
+                    }
+                }
+            },
+            vec![],
+        );
+    harness.run();
+    assert!(harness.state().is_empty());
+
+    harness.get_by_label("Header").click();
+    harness.run();
+
+    let heights = harness.state().clone();
+    let full_height = *heights.last().unwrap();
+    assert!(full_height > 100.0);
+    assert!(heights.len() > 2, "it animates: {heights:?}");
+
+    // From the very first frame the body grows towards its real height, without a placeholder:
+    assert!(
+        heights[0] > 0.1 * full_height,
+        "starts at a fraction of the real height: {heights:?}"
+    );
+    assert!(
+        heights.windows(2).all(|w| w[0] <= w[1]),
+        "grows smoothly: {heights:?}"
+    );
+}
+
+#[test]
+fn collapsing_header_toggle_response_is_separate_from_the_header() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 200.0))
+        .build_ui_state(
+            |ui, toggle_clicks: &mut usize| {
+                let response = egui::CollapsingHeader::new("Section").show(ui, |ui| {
+                    ui.label("Body");
+                });
+                if response.toggle_response.clicked() {
+                    *toggle_clicks += 1;
+                }
+                response.toggle_response.on_hover_text("Expand or collapse");
+                response.header_response.on_hover_text("Section tooltip");
+            },
+            0,
+        );
+    harness.run();
+
+    let toggle = harness.get_by_label("Expand");
+    let toggle_rect = toggle.raw_bounds().unwrap();
+    let header_rect = harness.get_by_label("Section").raw_bounds().unwrap();
+    assert!(header_rect.x0 <= toggle_rect.x0 && toggle_rect.x1 < header_rect.x1);
+
+    toggle.hover();
+    harness.run_steps(60);
+    harness.get_by_label("Expand or collapse");
+    assert!(harness.query_by_label("Section tooltip").is_none());
+
+    harness.get_by_label("Expand").simulate_click();
+    harness.run();
+    assert_eq!(*harness.state(), 1);
+    harness.get_by_label("Body");
+
+    // Clicking the rest of the header still toggles it:
+    harness.get_by_label("Section").simulate_click();
+    harness.run();
+    assert_eq!(*harness.state(), 1);
+    assert!(harness.query_by_label("Body").is_none());
+}
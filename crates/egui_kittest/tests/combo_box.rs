@@ -0,0 +1,585 @@
+mod common;
+
+use common::{drag_pointer, window_rect};
+use egui::accesskit::Role;
+use egui::{ComboBox, Vec2};
+use egui_kittest::{kittest::Queryable, Harness};
+
+#[test]
+fn combobox_width_from_items_is_stable() {
+    let items = ["A", "A much longer item"];
+    let mut harness = Harness::new_ui_state(
+        |ui, (selected, width): &mut (usize, f32)| {
+            *width = ComboBox::from_id_salt("combobox")
+                .selected_text(items[*selected])
+                .width_from_items(ui, &items)
+                .show_ui(ui, |ui| {
+                    for (i, item) in items.iter().enumerate() {
+                        ui.selectable_value(selected, i, *item);
+                    }
+                })
+                .response
+                .rect
+                .width();
+        },
+        (0, 0.0),
+    );
+    harness.run();
+    let short_width = harness.state().1;
+
+    harness.state_mut().0 = 1;
+    harness.run();
+    let long_width = harness.state().1;
+
+    assert_eq!(short_width, long_width);
+    assert!(long_width > harness.ctx.style().spacing.combo_width);
+}
+
+#[test]
+fn combobox_width_from_items_only_measures_when_the_items_change() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static FORMATTED: AtomicUsize = AtomicUsize::new(0);
+
+    struct Item(&'static str);
+
+    impl std::fmt::Display for Item {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            FORMATTED.fetch_add(1, Ordering::Relaxed);
+            f.write_str(self.0)
+        }
+    }
+
+    let items = [Item("A"), Item("Bb"), Item("Ccc")];
+    let mut harness = Harness::new_ui_state(
+        |ui, count: &mut usize| {
+            ComboBox::from_id_salt("combobox")
+                .width_from_items(ui, &items[..*count])
+                .show_ui(ui, |_| {});
+        },
+        2,
+    );
+    harness.run();
+    assert_eq!(FORMATTED.load(Ordering::Relaxed), 2, "measured once");
+
+    harness.run_steps(3);
+    assert_eq!(FORMATTED.load(Ordering::Relaxed), 2, "cached");
+
+    *harness.state_mut() = 3;
+    harness.run();
+    assert_eq!(FORMATTED.load(Ordering::Relaxed), 5, "measured again");
+}
+
+#[test]
+fn combobox_type_ahead_selects_matching_item() {
+    let items = ["Apple", "Banana", "Blueberry", "Cherry"];
+    let mut harness = Harness::new_ui_state(
+        |ui, selected: &mut usize| {
+            ComboBox::from_id_salt("fruit").show_index(ui, selected, items.len(), |i| items[i]);
+        },
+        0,
+    );
+    harness.press_key(egui::Key::Tab);
+    harness.run();
+
+    let type_text = |harness: &mut Harness<'_, usize>, text: &str| {
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::Text(text.to_owned()));
+        harness.step();
+    };
+
+    type_text(&mut harness, "b");
+    assert_eq!(*harness.state(), 1);
+    type_text(&mut harness, "L");
+    assert_eq!(*harness.state(), 2, "typed letters accumulate");
+    type_text(&mut harness, "x");
+    assert_eq!(*harness.state(), 2, "no match keeps the selection");
+
+    // After a pause, a new search starts:
+    harness.run_steps(5);
+    type_text(&mut harness, "c");
+    assert_eq!(*harness.state(), 3);
+}
+
+#[test]
+fn combobox_pinned_items_come_first_and_navigate_with_the_rest() {
+    let items = ["Apple", "Banana", "Cherry"];
+    let mut harness = Harness::new_ui_state(
+        |ui, selected: &mut usize| {
+            ComboBox::from_id_salt("fruit").show_index_with_pinned(
+                ui,
+                selected,
+                &[2, 0],
+                items.len(),
+                |i| items[i],
+            );
+        },
+        1,
+    );
+    harness.get_by_role(Role::ComboBox).simulate_click();
+    harness.run();
+
+    let top = |harness: &Harness<'_, usize>, label: &str| -> Vec<f32> {
+        harness
+            .get_all_by_label(label)
+            .map(|node| node.raw_bounds().unwrap().y0 as f32)
+            .collect()
+    };
+    let cherry = top(&harness, "Cherry");
+    let apple = top(&harness, "Apple");
+    let banana = top(&harness, "Banana");
+    assert_eq!((cherry.len(), apple.len(), banana.len()), (2, 2, 1));
+    assert!(
+        cherry[0] < apple[0],
+        "pinned items are shown in the given order"
+    );
+    assert!(apple[0] < banana[0], "followed by the full list");
+    assert!(banana[0] < cherry[1]);
+
+    // Arrow keys move from the pinned group into the full list:
+    harness.get_all_by_label("Apple").next().unwrap().focus();
+    harness.run();
+    harness.press_key(egui::Key::ArrowDown);
+    harness.run();
+    let focused: Vec<_> = harness
+        .get_all_by_label("Apple")
+        .map(|node| node.is_focused())
+        .collect();
+    assert_eq!(focused, [false, true]);
+
+    harness
+        .get_all_by_label("Cherry")
+        .next()
+        .unwrap()
+        .simulate_click();
+    harness.run();
+    assert_eq!(*harness.state(), 2, "pinned items select their index");
+}
+
+#[test]
+fn combobox_none_option_clears_selection() {
+    let mut harness = Harness::new_ui_state(
+        |ui, selected: &mut Option<u32>| {
+            ComboBox::from_label("Priority")
+                .selected_text(format!("P{}", selected.unwrap_or_default()))
+                .show_none_option("No priority")
+                .show_ui_optional(ui, selected, |ui, selected| {
+                    for priority in 1..=3 {
+                        ui.selectable_value(
+                            selected,
+                            Some(priority),
+                            format!("Priority {priority}"),
+                        );
+                    }
+                });
+        },
+        Some(2),
+    );
+    harness.run();
+
+    harness.get_by_role(Role::ComboBox).click();
+    harness.run();
+    harness.get_by_label("No priority").simulate_click();
+    harness.run();
+    assert_eq!(*harness.state(), None);
+    assert!(
+        !harness.ctx.memory(|mem| mem.any_popup_open()),
+        "the menu closes after clearing"
+    );
+
+    harness.get_by_role(Role::ComboBox).click();
+    harness.run();
+    harness.get_by_label("Priority 3").click();
+    harness.run();
+    assert_eq!(*harness.state(), Some(3));
+}
+
+#[test]
+fn combobox_max_visible_items_scrolls_to_selected() {
+    let items: Vec<String> = (0..100).map(|i| format!("Item {i}")).collect();
+    let mut harness = Harness::new_ui_state(
+        |ui, selected: &mut usize| {
+            ComboBox::new("combobox", "Pick")
+                .max_visible_items(5)
+                .show_index(ui, selected, items.len(), |i| items[i].as_str());
+        },
+        50,
+    );
+    harness.run();
+    harness
+        .get_by_role_and_label(Role::ComboBox, "Pick")
+        .click();
+    harness.run_steps(10);
+
+    let combobox = harness
+        .get_by_role_and_label(Role::ComboBox, "Pick")
+        .bounding_box()
+        .unwrap();
+    let selected = harness
+        .get_by_role_and_label(Role::Button, "Item 50")
+        .bounding_box()
+        .unwrap();
+    let row_height = selected.height();
+    assert!(
+        combobox.y1 <= selected.y0 && selected.y1 <= combobox.y1 + 6.0 * row_height,
+        "the selected item should be scrolled into the capped popup: {selected:?}"
+    );
+
+    let first = harness
+        .get_by_role_and_label(Role::Button, "Item 0")
+        .bounding_box()
+        .unwrap();
+    assert!(
+        first.y1 < combobox.y1,
+        "earlier items are scrolled out of view"
+    );
+}
+
+#[test]
+fn combobox_empty_text_is_shown_when_there_are_no_items() {
+    let items = ["Apple", "Banana", "Cherry"];
+    let mut harness = Harness::new_ui_state(
+        |ui, (filter, selected): &mut (String, usize)| {
+            let matches: Vec<&str> = items
+                .iter()
+                .copied()
+                .filter(|item| item.contains(filter.as_str()))
+                .collect();
+            ComboBox::new("fruit", "Fruit")
+                .empty_text("No matches")
+                .show_index(ui, selected, matches.len(), |i| matches[i]);
+            ComboBox::new("rows", "Rows")
+                .empty_text("No rows")
+                .show_indexed(ui, matches.len(), |ui, i| {
+                    ui.selectable_label(false, matches[i])
+                });
+        },
+        ("Durian".to_owned(), 0),
+    );
+    harness
+        .get_by_role_and_label(Role::ComboBox, "Fruit")
+        .click();
+    harness.run();
+    harness.get_by_label("No matches");
+
+    harness
+        .get_by_role_and_label(Role::ComboBox, "Rows")
+        .click();
+    harness.run();
+    harness.get_by_label("No rows");
+
+    harness.state_mut().0.clear();
+    harness.run();
+    assert!(harness.query_by_label("No rows").is_none());
+    harness.get_by_role_and_label(Role::Button, "Banana");
+}
+
+#[test]
+fn combobox_popup_is_not_clipped_by_the_parent_scroll_area() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 300.0))
+        .build_ui_state(
+            |ui, scroll_rect: &mut egui::Rect| {
+                *scroll_rect = egui::ScrollArea::vertical()
+                    .max_height(80.0)
+                    .show(ui, |ui| {
+                        ui.label("Above");
+                        ui.label("the");
+                        let mut selected = 0;
+                        ComboBox::new("combo", "Choice")
+                            .show_index(ui, &mut selected, 10, |i| format!("Option {i}"));
+                    })
+                    .inner_rect;
+            },
+            egui::Rect::NOTHING,
+        );
+    harness
+        .get_by_role_and_label(Role::ComboBox, "Choice")
+        .click();
+    harness.run();
+
+    let scroll_rect = *harness.state();
+    let last = harness.get_by_label("Option 9").bounding_box().unwrap();
+    assert!(
+        scroll_rect.bottom() < last.y0 as f32,
+        "the menu reaches past the scroll area: {scroll_rect:?} {last:?}"
+    );
+
+    // It is shown in a layer of its own, on top of (and not clipped by) the scroll area:
+    let popup_layer = harness
+        .ctx
+        .memory(|mem| mem.areas().top_layer_id(egui::Order::Foreground))
+        .expect("the menu has a foreground layer");
+    let popup_rect = window_rect(&harness, popup_layer.id);
+    assert!(popup_rect.contains_rect(
+        egui::Rect::from_x_y_ranges(
+            last.x0 as f32..=last.x1 as f32,
+            last.y0 as f32..=last.y1 as f32
+        )
+        .shrink(0.5)
+    ));
+}
+
+#[test]
+fn combobox_previews_hovered_item_and_reverts_on_cancel() {
+    #[derive(Default)]
+    struct State {
+        selected: usize,
+        previewed: Option<usize>,
+        committed: bool,
+    }
+
+    let themes = ["Light", "Dark", "Solarized"];
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 200.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let ir = ComboBox::from_label("Theme").show_index_with_preview(
+                    ui,
+                    &mut state.selected,
+                    themes.len(),
+                    |i| themes[i],
+                );
+                state.previewed = ir.inner;
+                state.committed |= ir.response.changed();
+            },
+            State::default(),
+        );
+
+    let hover = |harness: &mut Harness<'_, State>, label: &str| {
+        let rect = harness.get_by_label(label).raw_bounds().unwrap();
+        let center = egui::pos2(
+            ((rect.x0 + rect.x1) / 2.0) as f32,
+            ((rect.y0 + rect.y1) / 2.0) as f32,
+        );
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(center));
+        harness.run();
+        center
+    };
+
+    harness.get_by_role(Role::ComboBox).click();
+    harness.run();
+    assert_eq!(harness.state().previewed, None, "Nothing hovered yet");
+
+    hover(&mut harness, "Solarized");
+    assert_eq!(harness.state().previewed, Some(2));
+    hover(&mut harness, "Dark");
+    assert_eq!(harness.state().previewed, Some(1));
+    assert_eq!(harness.state().selected, 0, "Previewing doesn't commit");
+
+    // Escape closes the menu and ends the preview:
+    harness.press_key(egui::Key::Escape);
+    harness.run();
+    assert_eq!(harness.state().previewed, None);
+    assert_eq!(harness.state().selected, 0);
+    assert!(!harness.state().committed);
+
+    // Clicking an item commits it:
+    harness.get_by_role(Role::ComboBox).click();
+    harness.run();
+    let dark = hover(&mut harness, "Dark");
+    drag_pointer(&mut harness, dark, dark);
+    assert_eq!(harness.state().selected, 1);
+    assert!(harness.state().committed);
+    assert_eq!(harness.state().previewed, None);
+}
+
+#[test]
+fn combo_box_show_indexed_only_builds_visible_items() {
+    #[derive(Default)]
+    struct State {
+        selected: usize,
+        items_built: usize,
+        changed: bool,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 400.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                state.items_built = 0;
+                let selected = state.selected;
+                let items_built = &mut state.items_built;
+                let ir = ComboBox::from_label("Number")
+                    .selected_text(format!("Item {selected}"))
+                    .show_indexed(ui, 100_000, |ui, i| {
+                        *items_built += 1;
+                        ui.selectable_label(i == selected, format!("Item {i}"))
+                    });
+                if let Some(clicked) = ir.inner {
+                    state.selected = clicked;
+                }
+                state.changed |= ir.response.changed();
+            },
+            State::default(),
+        );
+
+    harness.get_by_role(Role::ComboBox).click();
+    harness.run();
+    let items_built = harness.state().items_built;
+    assert!(
+        0 < items_built && items_built < 50,
+        "only the visible items are built, got {items_built}"
+    );
+    assert!(!harness.state().changed);
+
+    harness.get_by_label("Item 3").click();
+    harness.run();
+    assert_eq!(harness.state().selected, 3);
+    assert!(harness.state().changed);
+}
+
+#[test]
+fn combobox_near_the_bottom_opens_upward_and_shows_the_selected_item() {
+    // (space above the combo box, screen height)
+    for (space, screen_height) in [(250.0, 300.0), (60.0, 200.0), (100.0, 200.0)] {
+        let mut harness = Harness::builder()
+            .with_size(Vec2::new(300.0, screen_height))
+            .with_max_steps(30)
+            .build_ui_state(
+                |ui, selected: &mut usize| {
+                    ui.add_space(space);
+                    ComboBox::new("combo", "Choice").height(1000.0).show_index(
+                        ui,
+                        selected,
+                        50,
+                        |i| format!("Option {i}"),
+                    );
+                },
+                30,
+            );
+        harness.run();
+        let button = harness
+            .get_by_role_and_label(Role::ComboBox, "Choice")
+            .bounding_box()
+            .unwrap();
+
+        for _ in 0..2 {
+            harness
+                .get_by_role_and_label(Role::ComboBox, "Choice")
+                .click();
+            harness.run();
+
+            let popup_layer = harness
+                .ctx
+                .memory(|mem| mem.areas().top_layer_id(egui::Order::Foreground))
+                .expect("the menu is open");
+            let popup_rect = window_rect(&harness, popup_layer.id);
+            let screen_rect = harness.ctx.screen_rect();
+            assert!(
+                screen_rect.contains_rect(popup_rect),
+                "{space}: the menu fits on screen: {popup_rect:?}"
+            );
+
+            // It opens towards the side with the most room:
+            let room_above = button.y0 as f32 - screen_rect.top();
+            let room_below = screen_rect.bottom() - button.y1 as f32;
+            if room_above > room_below {
+                assert!(
+                    popup_rect.bottom() <= button.y0 as f32,
+                    "{space}: opens upward: {popup_rect:?} {button:?}"
+                );
+            } else {
+                assert!(
+                    popup_rect.top() >= button.y1 as f32,
+                    "{space}: opens downward: {popup_rect:?} {button:?}"
+                );
+            }
+
+            // The list scrolls, with the selected item in view:
+            let selected = harness.get_by_label("Option 30").bounding_box().unwrap();
+            assert!(
+                popup_rect.contains_rect(egui::Rect::from_x_y_ranges(
+                    selected.x0 as f32..=selected.x1 as f32,
+                    selected.y0 as f32..=selected.y1 as f32,
+                )),
+                "{space}: the selected item is visible: {selected:?} {popup_rect:?}"
+            );
+
+            // Close it again:
+            harness.press_key(egui::Key::Escape);
+            harness.run();
+            assert!(harness.query_by_label("Option 30").is_none());
+        }
+    }
+}
+
+#[test]
+fn combobox_columns_lays_out_a_grid_navigable_in_2d() {
+    let items = ["A0", "A1", "A2", "B0", "B1", "B2", "C0"];
+    let mut harness = Harness::new_ui_state(
+        move |ui, selected: &mut usize| {
+            ComboBox::from_id_salt("grid")
+                .columns(3)
+                .show_index(ui, selected, items.len(), |i| items[i]);
+        },
+        0,
+    );
+    harness.get_by_role(Role::ComboBox).simulate_click();
+    harness.run();
+
+    let min = |harness: &Harness<'_, usize>, label: &str| {
+        let rect = harness.get_by_label(label).raw_bounds().unwrap();
+        (rect.x0 as f32, rect.y0 as f32)
+    };
+    let (a0, a1, b0) = (
+        min(&harness, "A0"),
+        min(&harness, "A1"),
+        min(&harness, "B0"),
+    );
+    assert_eq!(a0.1, a1.1, "side by side");
+    assert!(a0.0 < a1.0);
+    assert_eq!(a0.0, b0.0, "in the same column");
+    assert!(a0.1 < b0.1);
+
+    harness.get_by_label("A0").focus();
+    harness.run();
+    harness.press_key(egui::Key::ArrowRight);
+    harness.run();
+    assert!(harness.get_by_label("A1").is_focused());
+    harness.press_key(egui::Key::ArrowDown);
+    harness.run();
+    assert!(harness.get_by_label("B1").is_focused());
+    harness.press_key(egui::Key::ArrowLeft);
+    harness.run();
+    assert!(harness.get_by_label("B0").is_focused());
+    harness.press_key(egui::Key::ArrowUp);
+    harness.run();
+    assert!(harness.get_by_label("A0").is_focused());
+
+    harness.get_by_label("B2").simulate_click();
+    harness.run();
+    assert_eq!(*harness.state(), 5);
+}
+
+#[test]
+fn combobox_show_indexed_with_columns_adds_rows_of_items() {
+    let mut harness = Harness::new_ui_state(
+        |ui, clicked: &mut Option<usize>| {
+            let ir = ComboBox::from_id_salt("indexed")
+                .columns(4)
+                .show_indexed(ui, 10, |ui, i| ui.selectable_label(false, format!("#{i}")));
+            if ir.inner.is_some() {
+                *clicked = ir.inner;
+            }
+        },
+        None,
+    );
+    harness.get_by_role(Role::ComboBox).simulate_click();
+    harness.run();
+
+    let y = |label: &str| harness.get_by_label(label).raw_bounds().unwrap().y0;
+    assert_eq!(y("#0"), y("#3"));
+    assert!(y("#3") < y("#4"), "the fifth item starts a new row");
+    assert!(y("#4") < y("#8"));
+    assert_eq!(harness.get_all_by_label("#9").count(), 1);
+
+    harness.get_by_label("#6").simulate_click();
+    harness.run();
+    assert_eq!(*harness.state(), Some(6));
+}
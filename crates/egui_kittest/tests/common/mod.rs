@@ -0,0 +1,54 @@
+//! Helpers shared by the integration tests.
+
+// Each test binary only uses some of these:
+#![allow(dead_code)]
+
+use egui_kittest::Harness;
+
+/// The rect of the window (or other area) with the given id, as of the last pass.
+pub fn window_rect<State>(harness: &Harness<'_, State>, id: egui::Id) -> egui::Rect {
+    harness
+        .ctx
+        .memory(|mem| mem.area_rect(id))
+        .expect("window should have an area")
+}
+
+/// Move the pointer to `pos` and press the primary button there.
+pub fn press_pointer<State>(harness: &mut Harness<'_, State>, pos: egui::Pos2) {
+    move_pointer(harness, pos);
+    harness.input_mut().events.push(egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed: true,
+        modifiers: egui::Modifiers::NONE,
+    });
+    harness.step();
+}
+
+/// Move the pointer to `pos`, e.g. while dragging.
+pub fn move_pointer<State>(harness: &mut Harness<'_, State>, pos: egui::Pos2) {
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(pos));
+    harness.step();
+}
+
+/// Release the primary button at `pos`.
+pub fn release_pointer<State>(harness: &mut Harness<'_, State>, pos: egui::Pos2) {
+    harness.input_mut().events.push(egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed: false,
+        modifiers: egui::Modifiers::NONE,
+    });
+    harness.step();
+}
+
+/// Press the primary button at `from`, move to `to` and release.
+pub fn drag_pointer<State>(harness: &mut Harness<'_, State>, from: egui::Pos2, to: egui::Pos2) {
+    press_pointer(harness, from);
+    move_pointer(harness, to);
+    release_pointer(harness, to);
+    harness.run();
+}
@@ -0,0 +1,345 @@
+mod common;
+
+use common::{drag_pointer, press_pointer};
+use egui::Vec2;
+use egui_kittest::{kittest::Queryable, Harness};
+
+#[test]
+fn frame_hoverable_elevation_lifts_on_hover() {
+    let mut harness = Harness::new_ui(|ui| {
+        ui.add_space(20.0);
+        let id = ui.id().with("card");
+        let hovered = ui.data(|d| d.get_temp(id)).unwrap_or(false);
+        let response = egui::Frame::group(ui.style())
+            .hoverable_elevation(ui.ctx(), id, hovered, ui.visuals().popup_shadow, 4.0)
+            .show(ui, |ui| ui.label("Card"))
+            .response;
+        ui.data_mut(|d| d.insert_temp(id, response.contains_pointer()));
+        ui.label("Below");
+    });
+    harness.run();
+
+    let card = |harness: &Harness<'_>| harness.get_by_label("Card").raw_bounds().unwrap();
+    let below = |harness: &Harness<'_>| harness.get_by_label("Below").raw_bounds().unwrap();
+    let (card_before, below_before) = (card(&harness), below(&harness));
+
+    harness.get_by_label("Card").hover();
+    harness.run_steps(4);
+    assert_eq!(card(&harness).y0, card_before.y0 - 4.0);
+    assert_eq!(below(&harness), below_before, "layout should not change");
+}
+
+#[test]
+fn form_layout_aligns_labels_and_inputs() {
+    let mut harness = Harness::new_ui(|ui| {
+        egui::FormLayout::new("form")
+            .max_label_width(120.0)
+            .show(ui, |form| {
+                form.row("Name:", |ui| ui.button("First input"));
+                form.row("Favorite color:", |ui| ui.button("Second input"));
+                form.row(
+                    "A very long label that should wrap onto several lines:",
+                    |ui| ui.button("Third input"),
+                );
+            });
+    });
+    harness.run();
+
+    let bounds = |label: &str| harness.get_by_label(label).raw_bounds().unwrap();
+    let name = bounds("Name:");
+    let color = bounds("Favorite color:");
+    let long = bounds("A very long label that should wrap onto several lines:");
+    assert_eq!(name.x1, color.x1, "labels are right-aligned");
+    assert_eq!(name.x1, long.x1, "labels are right-aligned");
+    assert!(long.x1 - long.x0 <= 120.0, "long label wraps");
+    assert!(long.y1 - long.y0 > color.y1 - color.y0, "long label wraps");
+
+    let first = bounds("First input");
+    assert_eq!(first.x0, bounds("Second input").x0, "inputs are aligned");
+    assert_eq!(first.x0, bounds("Third input").x0, "inputs are aligned");
+    assert!(first.x0 > name.x1);
+}
+
+#[test]
+fn form_layout_inputs_stretch_to_fill_the_input_column() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 200.0))
+        .build_ui_state(
+            |ui, (name, right): &mut (String, f32)| {
+                *right = ui.max_rect().right();
+                egui::FormLayout::new("form").show(ui, |form| {
+                    form.row("Name:", |ui| ui.text_edit_singleline(name));
+                    form.row("Buttons:", |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label("Small");
+                            ui.label("Inputs");
+                        });
+                    });
+                });
+            },
+            (String::new(), 0.0),
+        );
+    harness.run();
+
+    let right = f64::from(harness.state().1);
+    let name = harness.get_by_label("Name:").raw_bounds().unwrap();
+    let text_edit = harness.get_by_role(egui::accesskit::Role::TextInput);
+    let text_edit = text_edit.raw_bounds().unwrap();
+    assert!(text_edit.x0 > name.x1);
+    assert!(
+        (text_edit.x1 - right).abs() < 1.0,
+        "the text edit fills the input column: {text_edit:?}, right edge at {right}"
+    );
+
+    let small = harness.get_by_label("Small").raw_bounds().unwrap();
+    assert!(
+        small.x1 - small.x0 < 100.0,
+        "inputs in a horizontal layout keep their size"
+    );
+}
+
+#[test]
+fn frame_measure_matches_the_shown_size_without_showing_anything() {
+    #[derive(Default)]
+    struct State {
+        measured: Vec2,
+        shown: Option<egui::Rect>,
+        cursor_moved: bool,
+        measured_clicks: usize,
+        shown_clicks: usize,
+    }
+
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut State| {
+            let frame = egui::Frame::group(ui.style()).outer_margin(4.0);
+            let card = |ui: &mut egui::Ui| {
+                ui.label("Card title");
+                ui.button("Card button").clicked()
+            };
+
+            let cursor = ui.cursor();
+            state.measured = frame.measure(ui, |ui| {
+                if card(ui) {
+                    state.measured_clicks += 1;
+                }
+            });
+            state.cursor_moved = ui.cursor() != cursor;
+            let shown = frame.show(ui, card);
+            if shown.inner {
+                state.shown_clicks += 1;
+            }
+            state.shown = Some(shown.response.rect);
+        },
+        State::default(),
+    );
+    harness.run();
+
+    let state = harness.state();
+    let shown = state.shown.unwrap();
+    assert_eq!(state.measured, shown.size());
+    assert!(!state.cursor_moved, "measuring doesn't allocate any space");
+
+    // The measured contents are laid out right where the shown ones are, but can't be clicked:
+    let button = shown.left_bottom() + Vec2::new(30.0, -20.0);
+    drag_pointer(&mut harness, button, button);
+    assert_eq!(harness.state().shown_clicks, 1);
+    assert_eq!(harness.state().measured_clicks, 0);
+}
+
+#[test]
+fn frame_measure_twice_in_the_same_ui_does_not_share_state() {
+    let grid = |ui: &mut egui::Ui, label: &str| {
+        egui::Grid::new("grid").show(ui, |ui| {
+            ui.label(label);
+            ui.end_row();
+        });
+    };
+    let measure = |labels: &'static [&'static str]| {
+        let mut harness = Harness::new_ui_state(
+            |ui, sizes: &mut Vec<Vec2>| {
+                let frame = egui::Frame::group(ui.style());
+                *sizes = labels
+                    .iter()
+                    .map(|label| frame.measure(ui, |ui| grid(ui, label)))
+                    .collect();
+            },
+            vec![],
+        );
+        harness.run();
+        harness.state().clone()
+    };
+
+    let alone = measure(&["Short"]);
+    let together = measure(&["Short", "A much, much longer label"]);
+    assert_eq!(
+        together[0], alone[0],
+        "the grids of the two measurements don't share their column widths"
+    );
+    assert!(together[0].x < together[1].x);
+}
+
+#[test]
+fn frame_sense_makes_the_whole_card_interactive() {
+    struct State {
+        card_rect: egui::Rect,
+        card_clicks: usize,
+        button_clicks: usize,
+        deleted: bool,
+    }
+
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut State| {
+            let response = egui::Frame::group(ui.style())
+                .sense(egui::Sense::click())
+                .show(ui, |ui| {
+                    ui.set_min_size(Vec2::new(200.0, 100.0));
+                    if ui.button("Inner").clicked() {
+                        state.button_clicks += 1;
+                    }
+                })
+                .response;
+            if response.clicked() {
+                state.card_clicks += 1;
+            }
+            response.context_menu(|ui| {
+                if ui.button("Delete").clicked() {
+                    state.deleted = true;
+                }
+            });
+            state.card_rect = response.rect;
+        },
+        State {
+            card_rect: egui::Rect::NOTHING,
+            card_clicks: 0,
+            button_clicks: 0,
+            deleted: false,
+        },
+    );
+    harness.run();
+
+    harness.get_by_label("Inner").simulate_click();
+    harness.run();
+    assert_eq!(harness.state().button_clicks, 1);
+    assert_eq!(
+        harness.state().card_clicks,
+        0,
+        "the inner button gets the click"
+    );
+
+    let empty_spot = harness.state().card_rect.right_bottom() - Vec2::splat(10.0);
+    let click = |harness: &mut Harness<'_, State>, button: egui::PointerButton| {
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(empty_spot));
+        for pressed in [true, false] {
+            harness.input_mut().events.push(egui::Event::PointerButton {
+                pos: empty_spot,
+                button,
+                pressed,
+                modifiers: egui::Modifiers::NONE,
+            });
+        }
+        harness.run();
+    };
+    click(&mut harness, egui::PointerButton::Primary);
+    assert_eq!(harness.state().card_clicks, 1);
+
+    click(&mut harness, egui::PointerButton::Secondary);
+    harness.get_by_label("Delete").simulate_click();
+    harness.run();
+    assert!(harness.state().deleted);
+}
+
+#[test]
+fn frame_press_feedback_fades_out_after_a_press() {
+    for feedback in [egui::PressFeedback::Ripple, egui::PressFeedback::Flash] {
+        let mut harness = Harness::builder()
+            .with_size(Vec2::new(200.0, 100.0))
+            .with_step_dt(0.1)
+            .build_ui(move |ui| {
+                let frame = egui::Frame::group(ui.style()).sense(egui::Sense::click());
+                let response = frame.show(ui, |ui| ui.label("Card")).response;
+                frame.paint_press_feedback(ui, &response, feedback);
+            });
+        harness.run();
+        let idle = harness.step();
+        assert!(!idle.repaint_requested);
+
+        let card = harness.get_by_label("Card").bounding_box().unwrap();
+        // On the margin of the frame, next to the (selectable) label:
+        let card = egui::pos2(card.x0 as f32 - 3.0, card.y0 as f32 + 2.0);
+        press_pointer(&mut harness, card);
+        // The ripple starts out with no size, so look a bit later:
+        let pressed = harness.step();
+        assert_eq!(pressed.shapes, idle.shapes + 1, "{feedback:?} is painted");
+        assert!(pressed.repaint_requested, "{feedback:?} is animated");
+
+        harness.run_steps(5);
+        let faded = harness.step();
+        assert_eq!(faded.shapes, idle.shapes, "{feedback:?} has faded out");
+        assert!(!faded.repaint_requested);
+    }
+}
+
+#[test]
+fn painter_focus_ring_is_drawn_outside_the_clipped_widget() {
+    let mut harness = Harness::new_ui_state(
+        |ui, widget_rect: &mut egui::Rect| {
+            egui::Frame::new().clip_content(true).show(ui, |ui| {
+                let (rect, response) =
+                    ui.allocate_exact_size(Vec2::new(40.0, 20.0), egui::Sense::click());
+                response.widget_info(|| {
+                    egui::WidgetInfo::labeled(egui::WidgetType::Button, true, "Custom")
+                });
+                ui.painter()
+                    .rect_filled(rect, 4.0, egui::Color32::DARK_GRAY);
+                if response.has_focus() {
+                    ui.painter().focus_ring(rect, 4.0, ui.visuals());
+                }
+                *widget_rect = rect;
+            });
+        },
+        egui::Rect::NOTHING,
+    );
+    harness.run();
+    let selection_stroke = harness.ctx.style().visuals.selection.stroke;
+    let rings = |harness: &Harness<'_, egui::Rect>| {
+        harness
+            .output()
+            .shapes
+            .iter()
+            .filter_map(|clipped| match &clipped.shape {
+                egui::Shape::Rect(rect) if rect.stroke == selection_stroke => {
+                    Some((rect.rect, clipped.clip_rect))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+    assert!(rings(&harness).is_empty());
+
+    harness.get_by_label("Custom").focus();
+    harness.run();
+    let widget_rect = *harness.state();
+    let rings = rings(&harness);
+    assert_eq!(rings.len(), 1);
+    let (ring_rect, clip_rect) = rings[0];
+    assert_eq!(ring_rect, widget_rect.expand(2.0));
+    assert!(
+        clip_rect.contains_rect(ring_rect.expand(selection_stroke.width)),
+        "the ring isn't clipped by the frame around the widget"
+    );
+    let shapes = &harness.output().shapes;
+    let index_of = |pred: &dyn Fn(&egui::epaint::RectShape) -> bool| {
+        shapes
+            .iter()
+            .position(|c| matches!(&c.shape, egui::Shape::Rect(rect) if pred(rect)))
+            .unwrap()
+    };
+    assert!(
+        index_of(&|rect| rect.fill == egui::Color32::DARK_GRAY)
+            < index_of(&|rect| rect.stroke == selection_stroke),
+        "the ring is painted on top of the widget"
+    );
+}
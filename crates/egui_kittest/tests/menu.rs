@@ -186,3 +186,142 @@ fn menu_snapshots() {
     harness.run();
     results.add(harness.try_snapshot("menu/subsubmenu"));
 }
+
+#[test]
+fn disabled_menu_items_ignore_clicks_and_hover() {
+    let mut harness = Harness::new_ui_state(
+        |ui, clicked: &mut bool| {
+            ui.menu_button("Edit", |ui| {
+                if ui.disabled_menu_item("Undo").clicked() {
+                    *clicked = true;
+                }
+                ui.menu_separator();
+                SubMenuButton::new("Recent").enabled(false).ui(ui, |ui| {
+                    _ = ui.button("Recent file");
+                });
+                _ = ui.button("Select all");
+            });
+        },
+        false,
+    );
+
+    harness.get_by_label("Edit").simulate_click();
+    harness.run();
+
+    harness.get_by_label("Recent").hover();
+    harness.run();
+    assert!(
+        harness.query_by_label("Recent file").is_none(),
+        "a disabled submenu never opens"
+    );
+
+    harness.get_by_label("Undo").simulate_click();
+    harness.run();
+    assert!(!harness.state(), "a disabled item can't be clicked");
+}
+
+#[test]
+fn menu_item_checked_toggles_and_aligns_with_icons() {
+    #[derive(Default)]
+    struct State {
+        grid: bool,
+        close_on_check: bool,
+        rects: Vec<egui::Rect>,
+    }
+
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut State| {
+            let config = MenuConfig::new().close_on_check(state.close_on_check);
+            egui::containers::menu::MenuButton::new("View")
+                .config(config)
+                .ui(ui, |ui| {
+                    state.rects = vec![
+                        ui.menu_item_checked("Grid", &mut state.grid).rect,
+                        ui.menu_item_with_icon(
+                            include_image!("../../eframe/data/icon.png"),
+                            "Zoom",
+                        )
+                        .rect,
+                    ];
+                });
+        },
+        State::default(),
+    );
+
+    harness.get_by_label("View").simulate_click();
+    harness.run();
+    harness.get_by_label("Grid").simulate_click();
+    harness.run();
+    assert!(harness.state().grid);
+    assert!(
+        harness.query_by_label("Zoom").is_some(),
+        "toggling an item keeps the menu open"
+    );
+    let [grid, zoom] = harness.state().rects[..] else {
+        panic!()
+    };
+    assert_eq!(grid.left(), zoom.left());
+
+    harness.get_by_label("Grid").simulate_click();
+    harness.run();
+    assert!(!harness.state().grid);
+
+    harness.state_mut().close_on_check = true;
+    harness.get_by_label("Grid").simulate_click();
+    harness.run();
+    assert!(harness.state().grid);
+    assert!(harness.query_by_label("Zoom").is_none());
+}
+
+#[test]
+fn menu_max_height_scrolls_with_sticky_headers_and_follows_focus() {
+    let mut harness = Harness::new_ui_state(
+        |ui, menu_rect: &mut Option<egui::Rect>| {
+            let (_, inner) = egui::containers::menu::MenuButton::new("Long menu")
+                .config(MenuConfig::new().max_height(120.0))
+                .ui(ui, |ui| {
+                    for section in 0..5 {
+                        ui.sticky_section(
+                            |ui| {
+                                ui.strong(format!("Section {section}"));
+                            },
+                            |ui| {
+                                for item in 0..6 {
+                                    _ = ui.button(format!("Item {section}.{item}"));
+                                }
+                            },
+                        );
+                    }
+                });
+            *menu_rect = inner.map(|inner| inner.response.rect);
+        },
+        None,
+    );
+
+    harness.get_by_label("Long menu").simulate_click();
+    harness.run();
+    let menu_rect = harness.state().expect("the menu should be open");
+    assert!(
+        menu_rect.height() < 140.0,
+        "the menu should scroll instead of growing: {menu_rect:?}"
+    );
+
+    harness.get_by_label("Item 4.5").focus();
+    harness.run_steps(20);
+
+    let menu_rect = harness.state().unwrap();
+    let item = harness.get_by_label("Item 4.5").bounding_box().unwrap();
+    let header = harness.get_by_label("Section 4").bounding_box().unwrap();
+    assert!(
+        menu_rect.top() <= item.y0 as f32 && item.y1 as f32 <= menu_rect.bottom(),
+        "the focused item is scrolled into view: {item:?} in {menu_rect:?}"
+    );
+    assert!(
+        header.y1 <= item.y0,
+        "the focused item is not hidden below the header"
+    );
+    assert!(
+        (header.y0 as f32 - menu_rect.top()).abs() < 10.0,
+        "the header of the scrolled section sticks to the top: {header:?} in {menu_rect:?}"
+    );
+}
@@ -0,0 +1,143 @@
+mod common;
+
+use common::drag_pointer;
+use egui::Vec2;
+use egui_kittest::{kittest::Queryable, Harness};
+
+#[test]
+fn floating_panel_does_not_shrink_central_panel() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 200.0))
+        .build_state(
+            |ctx, central_rect: &mut egui::Rect| {
+                egui::TopBottomPanel::bottom("toolbar")
+                    .floating(true)
+                    .show(ctx, |ui| {
+                        ui.label("Toolbar");
+                    });
+                *central_rect = egui::CentralPanel::default()
+                    .show(ctx, |ui| {
+                        ui.label("Content");
+                    })
+                    .response
+                    .rect;
+            },
+            egui::Rect::NOTHING,
+        );
+    harness.run();
+
+    assert_eq!(harness.state().bottom(), 200.0);
+    assert!(harness.query_by_label("Toolbar").is_some());
+}
+
+#[test]
+fn side_panel_resize_handle_edge_and_grab_width() {
+    use egui::panel::{HandleEdge, HandleStyle};
+
+    let panel = |edge| {
+        Harness::new_state(
+            move |ctx, width: &mut f32| {
+                *width = egui::SidePanel::left("panel")
+                    .resize_handle(HandleStyle {
+                        edge,
+                        grab_width: Some(40.0),
+                        ..Default::default()
+                    })
+                    .show(ctx, |ui| {
+                        ui.allocate_space(ui.available_size());
+                    })
+                    .response
+                    .rect
+                    .width();
+            },
+            0.0,
+        )
+    };
+
+    // The wide grab zone lets us grab the inner edge from well inside the panel:
+    let mut harness = panel(HandleEdge::Inner);
+    assert_eq!(*harness.state(), 200.0);
+    drag_pointer(
+        &mut harness,
+        egui::pos2(185.0, 300.0),
+        egui::pos2(235.0, 300.0),
+    );
+    assert_eq!(*harness.state(), 235.0);
+
+    // Dragging the outer edge inwards shrinks the panel by as much:
+    let mut harness = panel(HandleEdge::Outer);
+    drag_pointer(
+        &mut harness,
+        egui::pos2(2.0, 300.0),
+        egui::pos2(52.0, 300.0),
+    );
+    assert_eq!(*harness.state(), 150.0);
+
+    // The inner edge is no longer a handle:
+    drag_pointer(
+        &mut harness,
+        egui::pos2(150.0, 300.0),
+        egui::pos2(250.0, 300.0),
+    );
+    assert_eq!(*harness.state(), 150.0);
+}
+
+#[test]
+fn animated_side_panel_slides_and_reflows_central_panel() {
+    #[derive(Default)]
+    struct State {
+        open: bool,
+        central_left: f32,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(600.0, 300.0))
+        .with_step_dt(0.02)
+        .build_state(
+            |ctx, state: &mut State| {
+                egui::SidePanel::left("sidebar")
+                    .resizable(false)
+                    .exact_width(200.0)
+                    .animated(true)
+                    .show_animated(ctx, state.open, |ui| {
+                        ui.label("Sidebar");
+                    });
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    state.central_left = ui.max_rect().left();
+                });
+            },
+            State {
+                open: true,
+                central_left: 0.0,
+            },
+        );
+    let expanded_left = harness.state().central_left;
+    let label_left = harness.get_by_label("Sidebar").raw_bounds().unwrap().x0;
+    assert!(expanded_left > 190.0);
+
+    harness.state_mut().open = false;
+    harness.step();
+    harness.step();
+    let central_left = harness.state().central_left;
+    assert!(
+        0.0 < central_left && central_left < expanded_left,
+        "The central panel reflows during the animation: {central_left}"
+    );
+    let sliding_left = harness.get_by_label("Sidebar").raw_bounds().unwrap().x0;
+    assert!(
+        sliding_left < label_left,
+        "The contents are still shown, sliding out: {sliding_left}"
+    );
+
+    harness.run_steps(20);
+    assert!(harness.state().central_left < 10.0);
+    assert!(harness.query_by_label("Sidebar").is_none());
+
+    harness.state_mut().open = true;
+    harness.run_steps(20);
+    assert_eq!(harness.state().central_left, expanded_left);
+    assert_eq!(
+        harness.get_by_label("Sidebar").raw_bounds().unwrap().x0,
+        label_left
+    );
+}
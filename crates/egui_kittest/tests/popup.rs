@@ -29,3 +29,443 @@ fn test_interactive_tooltip() {
 
     assert!(harness.state().link_clicked);
 }
+
+#[test]
+fn test_popup_open_state_from_code() {
+    let mut harness = egui_kittest::Harness::new_ui_state(
+        |ui, popup_id| {
+            let response = ui.button("Menu");
+            *popup_id = egui::Popup::default_response_id(&response);
+            egui::Popup::menu(&response).show(|ui| {
+                ui.label("Menu content");
+            });
+        },
+        egui::Id::NULL,
+    );
+    harness.run();
+    let popup_id = *harness.state();
+    assert!(!egui::Popup::is_id_open(&harness.ctx, popup_id));
+
+    harness.get_by_label("Menu").simulate_click();
+    harness.run();
+    assert!(egui::Popup::is_id_open(&harness.ctx, popup_id));
+    assert!(harness.query_by_label("Menu content").is_some());
+
+    // Closing some other popup does nothing:
+    egui::Popup::close_id(&harness.ctx, egui::Id::new("other"));
+    harness.run();
+    assert!(egui::Popup::is_id_open(&harness.ctx, popup_id));
+
+    egui::Popup::close_id(&harness.ctx, popup_id);
+    harness.run();
+    assert!(!egui::Popup::is_id_open(&harness.ctx, popup_id));
+    assert!(harness.query_by_label("Menu content").is_none());
+}
+
+#[test]
+fn test_popup_status_reports_open_and_close_transitions() {
+    #[derive(Default)]
+    struct State {
+        statuses: Vec<egui::PopupStatus>,
+        search: String,
+    }
+
+    let mut harness = egui_kittest::Harness::new_ui_state(
+        |ui, state: &mut State| {
+            let response = ui.button("Search");
+            let (inner, status) = egui::Popup::menu(&response)
+                .close_behavior(egui::PopupCloseBehavior::CloseOnClickOutside)
+                .show_with_status(|ui| ui.text_edit_singleline(&mut state.search));
+            if let Some(inner) = inner {
+                if status.just_opened() {
+                    inner.inner.request_focus();
+                }
+            }
+            assert_eq!(
+                status,
+                egui::Popup::id_status(ui.ctx(), egui::Popup::default_response_id(&response))
+            );
+            state.statuses.push(status);
+        },
+        State::default(),
+    );
+    let take_statuses = |harness: &mut egui_kittest::Harness<'_, State>| {
+        let mut statuses = std::mem::take(&mut harness.state_mut().statuses);
+        statuses.dedup();
+        statuses
+    };
+    use egui::PopupStatus::{Closed, JustClosed, JustOpened, Open};
+
+    harness.run();
+    assert_eq!(take_statuses(&mut harness), [Closed]);
+
+    harness.get_by_label("Search").simulate_click();
+    harness.run();
+    assert_eq!(take_statuses(&mut harness), [Closed, JustOpened, Open]);
+
+    // The search field was focused when the popup opened:
+    harness
+        .get_by_role(egui::accesskit::Role::TextInput)
+        .type_text("abc");
+    harness.run();
+    assert_eq!(harness.state().search, "abc");
+    assert_eq!(take_statuses(&mut harness), [Open]);
+
+    harness.press_key(egui::Key::Escape);
+    harness.run();
+    assert_eq!(take_statuses(&mut harness), [JustClosed, Closed]);
+}
+
+#[test]
+fn test_popup_open_on_hover_with_close_delay() {
+    let mut harness = egui_kittest::Harness::new_ui(|ui| {
+        ui.add_space(100.0);
+        let response = ui.label("Trigger");
+        egui::Popup::from_response(&response)
+            .open_on_hover(0.5)
+            .show(|ui| {
+                ui.label("Popup content");
+            });
+    });
+    harness.run();
+    assert!(harness.query_by_label("Popup content").is_none());
+
+    harness.get_by_label("Trigger").hover();
+    harness.run();
+    assert!(harness.query_by_label("Popup content").is_some());
+
+    // Briefly leaving the trigger doesn't close the popup:
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(egui::pos2(5.0, 5.0)));
+    harness.step();
+    assert!(harness.query_by_label("Popup content").is_some());
+
+    // …but it closes once the delay has passed:
+    harness.run_steps(3);
+    assert!(harness.query_by_label("Popup content").is_none());
+}
+
+#[test]
+fn test_popup_toggle_on_click() {
+    let mut harness = egui_kittest::Harness::new_ui(|ui| {
+        let response = ui.button("Toggle");
+        egui::Popup::from_response(&response)
+            .toggle_on_click()
+            .show(|ui| {
+                ui.label("Popup content");
+            });
+        ui.add_space(100.0);
+        ui.label("Outside");
+    });
+    harness.run();
+
+    harness.get_by_label("Toggle").simulate_click();
+    harness.run();
+    assert!(harness.query_by_label("Popup content").is_some());
+
+    // Clicking inside keeps it open:
+    harness.get_by_label("Popup content").simulate_click();
+    harness.run();
+    assert!(harness.query_by_label("Popup content").is_some());
+
+    // Clicking the trigger again closes it:
+    harness.get_by_label("Toggle").simulate_click();
+    harness.run();
+    assert!(harness.query_by_label("Popup content").is_none());
+
+    // So does clicking outside:
+    harness.get_by_label("Toggle").simulate_click();
+    harness.run();
+    assert!(harness.query_by_label("Popup content").is_some());
+    harness.get_by_label("Outside").simulate_click();
+    harness.run();
+    assert!(harness.query_by_label("Popup content").is_none());
+}
+
+#[test]
+fn test_popup_closes_when_its_widget_disappears() {
+    let mut harness = egui_kittest::Harness::new_ui_state(
+        |ui, show_item: &mut bool| {
+            if *show_item {
+                let response = ui.button("Item");
+                egui::Popup::menu(&response).show(|ui| {
+                    ui.label("Item menu");
+                });
+            }
+        },
+        true,
+    );
+    harness.run();
+
+    harness.get_by_label("Item").simulate_click();
+    harness.run();
+    assert!(egui::Popup::is_any_open(&harness.ctx));
+
+    // The item is filtered out:
+    *harness.state_mut() = false;
+    harness.run();
+    assert!(!egui::Popup::is_any_open(&harness.ctx));
+
+    // …and the menu doesn't reappear when it comes back:
+    *harness.state_mut() = true;
+    harness.run();
+    assert!(harness.query_by_label("Item menu").is_none());
+}
+
+#[test]
+#[expect(deprecated)]
+fn test_interactive_widgets_in_popup_below_widget() {
+    #[derive(Default)]
+    struct State {
+        clicks: usize,
+        text: String,
+    }
+
+    let mut harness = egui_kittest::Harness::new_ui_state(
+        |ui, state: &mut State| {
+            let response = ui.button("Open popup");
+            let popup_id = ui.make_persistent_id("popup");
+            if response.clicked() {
+                ui.memory_mut(|mem| mem.toggle_popup(popup_id));
+            }
+            egui::popup_below_widget(
+                ui,
+                popup_id,
+                &response,
+                egui::PopupCloseBehavior::CloseOnClickOutside,
+                |ui| {
+                    if ui.button("Inside").clicked() {
+                        state.clicks += 1;
+                    }
+                    ui.text_edit_singleline(&mut state.text);
+                },
+            );
+            let _ = ui.button("After");
+        },
+        State::default(),
+    );
+
+    // Open it with the keyboard, and Tab into it:
+    harness.press_key(egui::Key::Tab);
+    harness.run();
+    harness.press_key(egui::Key::Enter);
+    harness.run();
+    harness.press_key(egui::Key::Tab);
+    harness.run();
+    assert!(
+        harness.get_by_label("Inside").is_focused(),
+        "Tab moves the focus to the first widget in the popup"
+    );
+
+    let click_at = |harness: &mut egui_kittest::Harness<'_, State>, pos: egui::Pos2| {
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(pos));
+        harness.run();
+        for pressed in [true, false] {
+            harness.input_mut().events.push(egui::Event::PointerButton {
+                pos,
+                button: egui::PointerButton::Primary,
+                pressed,
+                modifiers: egui::Modifiers::NONE,
+            });
+            harness.run();
+        }
+    };
+
+    let inside = harness.get_by_label("Inside").raw_bounds().unwrap();
+    let inside = egui::pos2(
+        (inside.x0 + 4.0) as f32,
+        (inside.y0 + inside.y1) as f32 / 2.0,
+    );
+    click_at(&mut harness, inside);
+    click_at(&mut harness, inside);
+    assert_eq!(
+        harness.state().clicks,
+        2,
+        "The popup stays open and keeps working"
+    );
+
+    let text_edit = harness
+        .get_by_role(egui::accesskit::Role::TextInput)
+        .raw_bounds()
+        .unwrap();
+    click_at(
+        &mut harness,
+        egui::pos2(
+            (text_edit.x0 + 4.0) as f32,
+            (text_edit.y0 + text_edit.y1) as f32 / 2.0,
+        ),
+    );
+    assert!(harness
+        .get_by_role(egui::accesskit::Role::TextInput)
+        .is_focused());
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::Text("hello".to_owned()));
+    harness.run();
+    assert_eq!(harness.state().text, "hello");
+    assert!(
+        harness.query_by_label("Inside").is_some(),
+        "Typing keeps the popup open"
+    );
+}
+
+#[test]
+fn test_modal_popup_traps_the_keyboard_focus() {
+    let mut harness = egui_kittest::Harness::new_ui_state(
+        |ui, open: &mut bool| {
+            let response = ui.button("Background");
+            egui::Popup::from_response(&response)
+                .open(*open)
+                .modal(true)
+                .show(|ui| {
+                    let _ = ui.button("First");
+                    let _ = ui.button("Second");
+                });
+            let _ = ui.button("After");
+        },
+        false,
+    );
+
+    harness.press_key(egui::Key::Tab);
+    harness.run();
+    assert!(harness.get_by_label("Background").is_focused());
+
+    *harness.state_mut() = true;
+    harness.run();
+    assert!(
+        !harness.get_by_label("Background").is_focused(),
+        "The widget behind the popup loses the focus"
+    );
+
+    let focused = |harness: &egui_kittest::Harness<'_, bool>| {
+        ["Background", "First", "Second", "After"]
+            .into_iter()
+            .find(|label| harness.get_by_label(label).is_focused())
+    };
+    let mut tab = |modifiers| {
+        harness.press_key_modifiers(modifiers, egui::Key::Tab);
+        harness.run();
+        focused(&harness)
+    };
+    let shift = egui::Modifiers::SHIFT;
+    let none = egui::Modifiers::NONE;
+    assert_eq!(tab(none), Some("First"));
+    assert_eq!(tab(none), Some("Second"));
+    assert_eq!(
+        tab(none),
+        Some("First"),
+        "Tab wraps around within the popup"
+    );
+    assert_eq!(tab(shift), Some("Second"), "and so does Shift+Tab");
+    assert_eq!(tab(shift), Some("First"));
+}
+
+#[test]
+fn test_popup_stays_above_its_pinned_window() {
+    let mut harness = egui_kittest::Harness::builder()
+        .with_size(egui::Vec2::new(400.0, 400.0))
+        .build(|ctx| {
+            egui::Window::new("Pinned")
+                .order(egui::Order::Foreground)
+                .default_pos(egui::pos2(10.0, 10.0))
+                .default_size(egui::Vec2::splat(300.0))
+                .show(ctx, |ui| {
+                    let response = ui.button("Anchor");
+                    egui::Popup::from_response(&response)
+                        .open(true)
+                        .close_behavior(egui::PopupCloseBehavior::IgnoreClicks)
+                        .show(|ui| {
+                            ui.label("Popup content");
+                        });
+                    ui.allocate_space(ui.available_size());
+                });
+        });
+    harness.run();
+
+    let layer_at_popup = |harness: &egui_kittest::Harness<'_>| {
+        let bounds = harness.get_by_label("Popup content").raw_bounds().unwrap();
+        let center = egui::pos2(
+            ((bounds.x0 + bounds.x1) / 2.0) as f32,
+            ((bounds.y0 + bounds.y1) / 2.0) as f32,
+        );
+        harness.ctx.layer_id_at(center).unwrap()
+    };
+    let popup_layer = layer_at_popup(&harness);
+    assert_eq!(popup_layer.order, egui::Order::Foreground);
+    assert_ne!(popup_layer.id, egui::Id::new("Pinned"));
+
+    // Bring the window to the front, by clicking in it:
+    let window_rect = harness
+        .ctx
+        .memory(|mem| mem.area_rect(egui::Id::new("Pinned")))
+        .unwrap();
+    let pos = window_rect.right_bottom() - egui::Vec2::splat(20.0);
+    harness.input_mut().events.extend([
+        egui::Event::PointerMoved(pos),
+        egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed: true,
+            modifiers: egui::Modifiers::NONE,
+        },
+        egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed: false,
+            modifiers: egui::Modifiers::NONE,
+        },
+    ]);
+    harness.run();
+    assert_eq!(
+        layer_at_popup(&harness),
+        popup_layer,
+        "The popup is still above the window it was opened from"
+    );
+}
+
+#[test]
+fn tooltip_with_custom_delay() {
+    // Short steps, so that egui picks up the pointer velocity.
+    let mut harness = egui_kittest::Harness::builder()
+        .with_step_dt(1.0 / 60.0)
+        .build_ui(|ui| {
+            ui.label("Slow").on_hover_text("Slow tooltip");
+            ui.label("Fast")
+                .on_hover_text_with_delay(0.0, "Fast tooltip");
+        });
+    harness
+        .ctx
+        .style_mut(|style| style.interaction.tooltip_delay = 10.0);
+    harness.run();
+
+    for (label, expect_tooltip) in [("Slow", false), ("Fast", true)] {
+        // Move the pointer towards the label, so egui registers it as recent movement:
+        let bounds = harness.get_by_label(label).raw_bounds().unwrap();
+        let target = egui::pos2(bounds.x0 as f32 + 5.0, bounds.y0 as f32 + 5.0);
+        for dx in (0..4).rev() {
+            let pos = target + egui::vec2(0.0, 100.0 + 10.0 * dx as f32);
+            harness
+                .input_mut()
+                .events
+                .push(egui::Event::PointerMoved(pos));
+            harness.step();
+        }
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(target));
+        for _ in 0..10 {
+            harness.step();
+        }
+
+        let tooltip = format!("{label} tooltip");
+        let shown = harness.query_by_label(&tooltip).is_some();
+        assert_eq!(shown, expect_tooltip, "{label}");
+    }
+}
@@ -0,0 +1,231 @@
+mod common;
+
+use common::{drag_pointer, move_pointer, press_pointer, release_pointer};
+use egui::Vec2;
+use egui_kittest::Harness;
+
+#[test]
+fn resize_reports_when_resizing_stopped() {
+    struct State {
+        rect: egui::Rect,
+        size: Vec2,
+        stopped_count: usize,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 400.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let output = egui::Resize::default()
+                    .default_size(Vec2::splat(100.0))
+                    .show_with_output(ui, |ui| {
+                        state.rect = ui.max_rect();
+                    });
+                if output.resize_stopped {
+                    state.stopped_count += 1;
+                    state.size = output.size;
+                }
+            },
+            State {
+                rect: egui::Rect::NOTHING,
+                size: Vec2::ZERO,
+                stopped_count: 0,
+            },
+        );
+    harness.run();
+    assert_eq!(harness.state().stopped_count, 0);
+
+    let corner = harness.state().rect.right_bottom() - Vec2::splat(2.0);
+    let to = corner + Vec2::new(50.0, 30.0);
+    press_pointer(&mut harness, corner);
+    move_pointer(&mut harness, corner + Vec2::new(25.0, 15.0));
+    move_pointer(&mut harness, to);
+    release_pointer(&mut harness, to);
+    harness.run();
+
+    assert_eq!(harness.state().stopped_count, 1, "should only report once");
+    let size = harness.state().size;
+    assert!(
+        (size - Vec2::new(150.0, 130.0)).length() < 10.0,
+        "unexpected size {size:?}"
+    );
+}
+
+#[test]
+fn resize_auto_sized_initially_until_user_resizes() {
+    struct State {
+        content_size: Vec2,
+        origin: egui::Pos2,
+        size: Vec2,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 400.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let output = egui::Resize::default()
+                    .auto_sized_initially(true)
+                    .show_with_output(ui, |ui| {
+                        state.origin = ui.min_rect().min;
+                        ui.allocate_exact_size(state.content_size, egui::Sense::hover());
+                    });
+                state.size = output.size;
+            },
+            State {
+                content_size: Vec2::new(80.0, 40.0),
+                origin: egui::Pos2::ZERO,
+                size: Vec2::ZERO,
+            },
+        );
+    harness.run();
+    assert_eq!(
+        harness.state().size,
+        Vec2::new(80.0, 40.0),
+        "fits the contents"
+    );
+
+    harness.state_mut().content_size = Vec2::new(120.0, 60.0);
+    harness.run();
+    harness.state_mut().content_size = Vec2::new(100.0, 50.0);
+    harness.run();
+    assert_eq!(
+        harness.state().size,
+        Vec2::new(100.0, 50.0),
+        "follows the contents"
+    );
+
+    let corner = harness.state().origin + harness.state().size - Vec2::splat(2.0);
+    drag_pointer(&mut harness, corner, corner + Vec2::new(100.0, 100.0));
+    let user_size = harness.state().size;
+    assert!(user_size.x > 190.0 && user_size.y > 140.0, "{user_size:?}");
+
+    harness.state_mut().content_size = Vec2::new(50.0, 20.0);
+    harness.run();
+    assert_eq!(
+        harness.state().size,
+        user_size,
+        "keeps the size the user picked"
+    );
+}
+
+#[test]
+fn resize_allow_shrink_below_content_scrolls_the_overflow() {
+    struct State {
+        max_rect: egui::Rect,
+        clip_rect: egui::Rect,
+        size: Vec2,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 400.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let output = egui::Resize::default()
+                    .default_size(Vec2::splat(250.0))
+                    .min_size(Vec2::splat(50.0))
+                    .allow_shrink_below_content(true)
+                    .show_with_output(ui, |ui| {
+                        state.max_rect = ui.max_rect();
+                        state.clip_rect = ui.clip_rect();
+                        ui.allocate_exact_size(Vec2::splat(200.0), egui::Sense::hover());
+                    });
+                state.size = output.size;
+            },
+            State {
+                max_rect: egui::Rect::NOTHING,
+                clip_rect: egui::Rect::NOTHING,
+                size: Vec2::ZERO,
+            },
+        );
+    assert_eq!(harness.state().size, Vec2::splat(250.0));
+
+    let corner = harness.state().max_rect.left_top() + Vec2::splat(248.0);
+    drag_pointer(&mut harness, corner, corner - Vec2::splat(150.0));
+    harness.run();
+
+    let State {
+        clip_rect, size, ..
+    } = *harness.state();
+    assert!(
+        size.x < 110.0 && size.y < 110.0,
+        "The user picked size is kept, even though the contents are bigger: {size:?}"
+    );
+    assert!(
+        clip_rect.height() < 120.0,
+        "The contents are clipped to the picked size: {clip_rect:?}"
+    );
+
+    // Can't be made smaller than the min size:
+    let corner = harness.state().max_rect.left_top() + size - Vec2::splat(2.0);
+    drag_pointer(&mut harness, corner, corner - Vec2::splat(150.0));
+    assert_eq!(harness.state().size, Vec2::splat(50.0));
+}
+
+#[test]
+fn resize_collapsible_to_edge_snaps_closed_and_reopens_to_its_last_size() {
+    struct State {
+        available: egui::Rect,
+        size: Vec2,
+        is_collapsed: bool,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 300.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                state.available = ui.available_rect_before_wrap();
+                let output = egui::Resize::default()
+                    .default_size(Vec2::new(200.0, 150.0))
+                    .min_size(Vec2::splat(50.0))
+                    .collapsible_to_edge(egui::panel::Side::Left)
+                    .collapse_threshold(30.0)
+                    .collapse_handle_width(10.0)
+                    .show_with_output(ui, |ui| {
+                        ui.label("Inspector");
+                    });
+                state.size = output.size;
+                state.is_collapsed = output.is_collapsed;
+            },
+            State {
+                available: egui::Rect::NOTHING,
+                size: Vec2::ZERO,
+                is_collapsed: false,
+            },
+        );
+    assert_eq!(harness.state().size, Vec2::new(200.0, 150.0));
+    let left_top = harness.state().available.left_top();
+
+    // Dragging, but not below the threshold, just resizes:
+    let corner = left_top + Vec2::new(194.0, 144.0);
+    drag_pointer(&mut harness, corner, corner - Vec2::new(80.0, 0.0));
+    let open_size = harness.state().size;
+    assert!(!harness.state().is_collapsed);
+    assert_eq!(open_size, Vec2::new(120.0, 150.0));
+
+    let collapse = |harness: &mut Harness<'_, State>| {
+        let corner = left_top + open_size - Vec2::splat(6.0);
+        drag_pointer(harness, corner, egui::pos2(left_top.x + 10.0, corner.y));
+        assert!(
+            harness.state().is_collapsed,
+            "Snaps closed below the threshold"
+        );
+    };
+    collapse(&mut harness);
+
+    // Clicking the handle reopens to the size from before the drag:
+    let handle = left_top + Vec2::new(5.0, 75.0);
+    drag_pointer(&mut harness, handle, handle);
+    assert!(!harness.state().is_collapsed);
+    assert_eq!(harness.state().size, open_size);
+
+    // So does dragging the handle out past the threshold:
+    collapse(&mut harness);
+    drag_pointer(&mut harness, handle, handle + Vec2::new(80.0, 0.0));
+    assert!(!harness.state().is_collapsed);
+    assert_eq!(harness.state().size, open_size);
+
+    // But dragging it a little doesn't:
+    collapse(&mut harness);
+    drag_pointer(&mut harness, handle, handle + Vec2::new(10.0, 0.0));
+    assert!(harness.state().is_collapsed);
+}
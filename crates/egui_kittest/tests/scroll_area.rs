@@ -0,0 +1,1743 @@
+mod common;
+
+use common::{drag_pointer, move_pointer, press_pointer, release_pointer};
+use egui::accesskit::Role;
+use egui::Vec2;
+use egui_kittest::{kittest::Queryable, Harness};
+
+#[test]
+fn scroll_area_offset_is_clamped_and_reported() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 100.0))
+        .build_ui_state(
+            |ui, (set_offset, offset, max_offset): &mut (Option<Vec2>, Vec2, f32)| {
+                let mut scroll_area = egui::ScrollArea::vertical().max_height(50.0);
+                if let Some(set_offset) = set_offset.take() {
+                    scroll_area = scroll_area.scroll_offset(set_offset);
+                }
+                let output = scroll_area.show(ui, |ui| {
+                    ui.set_min_height(150.0);
+                });
+                *offset = output.state.offset;
+                *max_offset = output.content_size.y - output.inner_rect.height();
+            },
+            (Some(Vec2::new(0.0, 1000.0)), Vec2::ZERO, 0.0),
+        );
+
+    harness.run();
+    let (_, offset, max_offset) = *harness.state();
+    assert!(max_offset > 20.0);
+    assert_eq!(offset, Vec2::new(0.0, max_offset));
+
+    harness.state_mut().0 = Some(Vec2::new(0.0, 20.0));
+    harness.run();
+    assert_eq!(harness.state().1, Vec2::new(0.0, 20.0));
+}
+
+#[test]
+fn scroll_area_offset_round_trips_through_serde() {
+    let scrolled_harness = |restore: Option<Vec2>| {
+        Harness::builder()
+            .with_size(Vec2::new(200.0, 100.0))
+            .build_ui_state(
+                move |ui, state: &mut Option<egui::scroll_area::State>| {
+                    let mut scroll_area = egui::ScrollArea::vertical().max_height(50.0);
+                    if let Some(offset) = restore {
+                        scroll_area = scroll_area.scroll_offset(offset);
+                    }
+                    let output = scroll_area.show(ui, |ui| {
+                        ui.set_min_size(Vec2::new(100.0, 500.0));
+                    });
+                    *state = Some(output.state);
+                },
+                None,
+            )
+    };
+
+    // Scroll like a user would:
+    let mut harness = scrolled_harness(None);
+    move_pointer(&mut harness, egui::pos2(50.0, 25.0));
+    harness.input_mut().events.push(egui::Event::MouseWheel {
+        unit: egui::MouseWheelUnit::Point,
+        delta: Vec2::new(0.0, -120.0),
+        modifiers: egui::Modifiers::NONE,
+    });
+    harness.run_steps(10);
+    let saved = harness.state().unwrap();
+    assert!(saved.offset.y > 0.0, "scrolled");
+
+    let json = serde_json::to_string(&saved).unwrap();
+    let loaded: egui::scroll_area::State = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded.offset, saved.offset);
+
+    let mut harness = scrolled_harness(Some(loaded.offset));
+    harness.run();
+    assert_eq!(harness.state().unwrap().offset, saved.offset, "restored");
+}
+
+#[test]
+fn scroll_area_smooth_scroll_animates_wheel_steps() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .with_step_dt(1.0 / 60.0)
+        .build_ui_state(
+            |ui, offset: &mut f32| {
+                *offset = egui::ScrollArea::vertical()
+                    .smooth_scroll(true)
+                    .show(ui, |ui| {
+                        ui.set_min_size(Vec2::new(100.0, 2000.0));
+                    })
+                    .state
+                    .offset
+                    .y;
+            },
+            0.0,
+        );
+    // A slow animation, so we can observe it:
+    harness.ctx.style_mut(|style| {
+        style.scroll_animation =
+            egui::style::ScrollAnimation::new(1.0, egui::Rangef::new(2.0, 2.0));
+    });
+    harness.run();
+
+    let wheel = |harness: &mut Harness<'_, f32>, delta: f32| {
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(egui::pos2(50.0, 50.0)));
+        harness.input_mut().events.push(egui::Event::MouseWheel {
+            unit: egui::MouseWheelUnit::Point,
+            delta: Vec2::new(0.0, delta),
+            modifiers: egui::Modifiers::NONE,
+        });
+        harness.step();
+        harness.step();
+    };
+
+    // Two wheel steps add up, and the offset eases towards the target:
+    wheel(&mut harness, -50.0);
+    wheel(&mut harness, -50.0);
+    harness.run_steps(30);
+    let animating = *harness.state();
+    assert!(0.0 < animating && animating < 100.0, "offset: {animating}");
+
+    harness.run_steps(120);
+    assert_eq!(*harness.state(), 100.0);
+}
+
+#[test]
+fn scroll_animation_uses_its_easing() {
+    struct State {
+        animation: Option<egui::style::ScrollAnimation>,
+        offset: f32,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let animation = state.animation.take();
+                state.offset = egui::ScrollArea::vertical()
+                    .show(ui, |ui| {
+                        ui.set_min_size(Vec2::new(100.0, 4000.0));
+                        if let Some(animation) = animation {
+                            let target = egui::Rect::from_min_size(
+                                ui.min_rect().min + Vec2::new(0.0, 1000.0),
+                                Vec2::splat(10.0),
+                            );
+                            ui.scroll_to_rect_animation(target, Some(egui::Align::TOP), animation);
+                        }
+                    })
+                    .state
+                    .offset
+                    .y;
+            },
+            State {
+                animation: None,
+                offset: 0.0,
+            },
+        );
+    harness.run();
+
+    // Returns the offsets after each quarter of the one second animation.
+    let scroll = |harness: &mut Harness<'_, State>, easing: fn(f32) -> f32| {
+        harness.state_mut().animation =
+            Some(egui::style::ScrollAnimation::duration(1.0).with_easing(easing));
+        harness.step();
+        let offsets: Vec<f32> = (0..4)
+            .map(|_| {
+                harness.step();
+                harness.state().offset
+            })
+            .collect();
+        harness.run();
+        offsets
+    };
+
+    let offsets = scroll(&mut harness, egui::emath::easing::linear);
+    let end = *offsets.last().unwrap();
+    assert!(end > 900.0, "scrolled to the target, got {end}");
+    assert!(
+        (offsets[1] - end / 2.0).abs() < 1.0,
+        "linear easing is half way after half the time: {offsets:?}"
+    );
+
+    // Start over, with an easing that overshoots like a spring:
+    harness.state_mut().offset = 0.0;
+    harness.ctx.memory_mut(|mem| mem.data.clear());
+    harness.run();
+    let offsets = scroll(&mut harness, egui::emath::easing::back_out);
+    assert!(
+        offsets.iter().any(|&offset| offset > end + 1.0),
+        "back_out overshoots the target: {offsets:?}"
+    );
+    assert_eq!(harness.state().offset, end);
+}
+
+#[test]
+fn scroll_area_per_axis_scroll_bar_visibility() {
+    use egui::scroll_area::ScrollBarVisibility;
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, inner_rect: &mut egui::Rect| {
+                ui.spacing_mut().scroll = egui::style::ScrollStyle::solid();
+                let output = egui::ScrollArea::both()
+                    .auto_shrink(false)
+                    .scroll_bar_visibility([
+                        ScrollBarVisibility::AlwaysHidden,
+                        ScrollBarVisibility::AlwaysVisible,
+                    ])
+                    .show(ui, |ui| {
+                        ui.set_min_size(Vec2::splat(1000.0));
+                    });
+                *inner_rect = output.inner_rect;
+            },
+            egui::Rect::NOTHING,
+        );
+    harness.run();
+
+    let available = harness.ctx.available_rect().shrink(8.0);
+    let inner_rect = *harness.state();
+    let bar_width = egui::style::ScrollStyle::solid().allocated_width();
+    assert_eq!(inner_rect.height(), available.height(), "no horizontal bar");
+    assert_eq!(
+        inner_rect.width(),
+        available.width() - bar_width,
+        "vertical bar"
+    );
+}
+
+#[test]
+fn scroll_area_reserve_scrollbar_space_keeps_the_content_width() {
+    // (rows, reserve_scrollbar_space) -> inner width
+    let inner_width = |rows: usize, reserve: bool| {
+        let mut harness = Harness::builder()
+            .with_size(Vec2::new(200.0, 200.0))
+            .build_ui_state(
+                |ui, width: &mut f32| {
+                    ui.spacing_mut().scroll = egui::style::ScrollStyle::solid();
+                    *width = egui::ScrollArea::vertical()
+                        .auto_shrink(false)
+                        .reserve_scrollbar_space(reserve)
+                        .show(ui, |ui| {
+                            for i in 0..rows {
+                                ui.label(format!("Row {i}"));
+                            }
+                        })
+                        .inner_rect
+                        .width();
+                },
+                0.0,
+            );
+        harness.run();
+        *harness.state()
+    };
+
+    let bar_width = egui::style::ScrollStyle::solid().allocated_width();
+    assert_eq!(inner_width(2, false) - bar_width, inner_width(50, false));
+    assert_eq!(inner_width(2, true), inner_width(50, true));
+    assert_eq!(inner_width(2, true), inner_width(50, false));
+}
+
+#[test]
+fn scroll_area_scroll_bar_position_left_puts_the_bars_before_the_content() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, output: &mut (egui::Rect, Vec2)| {
+                ui.spacing_mut().scroll = egui::style::ScrollStyle::solid();
+                let scroll = egui::ScrollArea::both()
+                    .auto_shrink(false)
+                    .scroll_bar_position(egui::panel::Side::Left)
+                    .show(ui, |ui| {
+                        ui.set_min_size(Vec2::splat(1000.0));
+                    });
+                *output = (scroll.inner_rect, scroll.state.offset);
+            },
+            (egui::Rect::NOTHING, Vec2::ZERO),
+        );
+    harness.run();
+
+    let available = harness.ctx.available_rect().shrink(8.0);
+    let (inner_rect, _) = *harness.state();
+    let bar_width = egui::style::ScrollStyle::solid().allocated_width();
+    assert_eq!(
+        inner_rect.left(),
+        available.left() + bar_width,
+        "vertical bar"
+    );
+    assert_eq!(
+        inner_rect.top(),
+        available.top() + bar_width,
+        "horizontal bar"
+    );
+    assert_eq!(inner_rect.max, available.max);
+
+    // Dragging the vertical bar on the left edge scrolls:
+    let bar_x = available.left() + bar_width / 2.0;
+    drag_pointer(
+        &mut harness,
+        egui::pos2(bar_x, inner_rect.top() + 5.0),
+        egui::pos2(bar_x, inner_rect.top() + 55.0),
+    );
+    let (_, offset) = *harness.state();
+    assert!(
+        offset.y > 0.0,
+        "the left bar scrolls vertically: {offset:?}"
+    );
+    assert_eq!(offset.x, 0.0);
+}
+
+#[test]
+fn scroll_bar_track_click_pages_and_shift_click_jumps() {
+    struct State {
+        inner_rect: egui::Rect,
+        offset: Vec2,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 300.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                ui.spacing_mut().scroll = egui::style::ScrollStyle::solid();
+                let output = egui::ScrollArea::vertical()
+                    .auto_shrink(false)
+                    .max_height(200.0)
+                    .show(ui, |ui| {
+                        ui.set_min_size(Vec2::new(100.0, 2000.0));
+                    });
+                state.inner_rect = output.inner_rect;
+                state.offset = output.state.offset;
+            },
+            State {
+                inner_rect: egui::Rect::NOTHING,
+                offset: Vec2::ZERO,
+            },
+        );
+    harness.run();
+
+    let click_track = |harness: &mut Harness<'_, State>, y: f32, shift: bool| {
+        let rect = harness.state().inner_rect;
+        let pos = egui::pos2(rect.right() + 5.0, rect.top() + y);
+        let modifiers = if shift {
+            egui::Modifiers::SHIFT
+        } else {
+            egui::Modifiers::NONE
+        };
+        harness.input_mut().modifiers = modifiers;
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(pos));
+        harness.step();
+        for pressed in [true, false] {
+            harness.input_mut().events.push(egui::Event::PointerButton {
+                pos,
+                button: egui::PointerButton::Primary,
+                pressed,
+                modifiers,
+            });
+            harness.step();
+        }
+        harness.input_mut().modifiers = egui::Modifiers::NONE;
+        harness.run_steps(8);
+    };
+
+    let page = harness.state().inner_rect.height();
+
+    click_track(&mut harness, 190.0, false);
+    assert_eq!(harness.state().offset.y, page, "one page down");
+    click_track(&mut harness, 190.0, false);
+    assert_eq!(harness.state().offset.y, 2.0 * page, "another page down");
+    click_track(&mut harness, 5.0, false);
+    assert_eq!(harness.state().offset.y, page, "one page up");
+
+    click_track(&mut harness, 190.0, true);
+    assert!(
+        harness.state().offset.y > 1500.0,
+        "shift-click jumps: {}",
+        harness.state().offset.y
+    );
+}
+
+#[test]
+fn scroll_area_scroll_source_can_ignore_mouse_wheel() {
+    use egui::scroll_area::ScrollSource;
+
+    let offset_after_wheel = |scroll_source: ScrollSource| {
+        let mut harness = Harness::new_ui_state(
+            |ui, offset: &mut f32| {
+                *offset = egui::ScrollArea::vertical()
+                    .max_height(100.0)
+                    .scroll_source(scroll_source)
+                    .show(ui, |ui| {
+                        ui.set_min_size(Vec2::new(100.0, 2000.0));
+                    })
+                    .state
+                    .offset
+                    .y;
+            },
+            0.0,
+        );
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(egui::pos2(50.0, 50.0)));
+        harness.step();
+        harness.input_mut().events.push(egui::Event::MouseWheel {
+            unit: egui::MouseWheelUnit::Point,
+            delta: Vec2::new(0.0, -50.0),
+            modifiers: egui::Modifiers::NONE,
+        });
+        harness.run_steps(10);
+        *harness.state()
+    };
+
+    assert!(offset_after_wheel(ScrollSource::ALL) > 0.0);
+    assert_eq!(
+        offset_after_wheel(ScrollSource::SCROLL_BAR | ScrollSource::DRAG),
+        0.0
+    );
+}
+
+#[test]
+fn scroll_area_row_first_seen_fires_once_per_row() {
+    #[derive(Default)]
+    struct State {
+        scroll_to: Option<f32>,
+        forget: bool,
+        first_seen: Vec<usize>,
+    }
+
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut State| {
+            let mut scroll_area = egui::ScrollArea::vertical().max_height(100.0);
+            if let Some(offset) = state.scroll_to.take() {
+                scroll_area = scroll_area.vertical_scroll_offset(offset);
+            }
+            let output = scroll_area.show_rows(ui, 20.0, 1_000, |ui, row_range| {
+                for row in row_range {
+                    let response = ui.label(format!("Row {row}"));
+                    if egui::ScrollArea::row_first_seen(ui, egui::Id::new(row), response.rect) {
+                        state.first_seen.push(row);
+                    }
+                }
+            });
+            if std::mem::take(&mut state.forget) {
+                egui::ScrollArea::forget_seen_rows(ui.ctx(), output.id);
+            }
+        },
+        State::default(),
+    );
+    harness.run();
+    let initially_seen = std::mem::take(&mut harness.state_mut().first_seen);
+    assert!(initially_seen.contains(&0));
+    assert!(!initially_seen.contains(&10), "row 10 is out of sight");
+
+    // Nothing new while nothing scrolls:
+    harness.run_steps(3);
+    assert!(harness.state().first_seen.is_empty());
+
+    harness.state_mut().scroll_to = Some(230.0);
+    harness.run();
+    let seen = std::mem::take(&mut harness.state_mut().first_seen);
+    assert!(seen.contains(&10));
+    assert!(seen.iter().all(|row| !initially_seen.contains(row)));
+
+    // Scrolling back doesn't fire again, until we forget:
+    harness.state_mut().scroll_to = Some(0.0);
+    harness.run();
+    assert!(harness.state().first_seen.is_empty());
+    harness.state_mut().forget = true;
+    harness.run_steps(2);
+    assert_eq!(harness.state().first_seen, initially_seen);
+}
+
+#[test]
+fn scroll_area_fade_edges_fades_where_content_is_hidden() {
+    let mut harness = Harness::new_state(
+        |ctx, offset: &mut Option<f32>| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let mut scroll_area = egui::ScrollArea::horizontal().fade_edges(30.0);
+                if let Some(offset) = offset.take() {
+                    scroll_area = scroll_area.horizontal_scroll_offset(offset);
+                }
+                scroll_area.show(ui, |ui| {
+                    ui.set_min_width(3000.0);
+                    ui.label("Gallery");
+                });
+            });
+        },
+        None,
+    );
+    let fades = |harness: &Harness<'_, Option<f32>>| {
+        let background = harness.ctx.style().visuals.panel_fill;
+        harness
+            .output()
+            .shapes
+            .iter()
+            .filter(|clipped| match &clipped.shape {
+                egui::Shape::Mesh(mesh) => mesh.vertices.iter().any(|v| v.color == background),
+                _ => false,
+            })
+            .count()
+    };
+
+    harness.run();
+    assert_eq!(fades(&harness), 1, "only the end has hidden content");
+
+    *harness.state_mut() = Some(1000.0);
+    harness.run_steps(8); // let the scroll velocity settle
+    assert_eq!(fades(&harness), 2);
+}
+
+#[test]
+fn scroll_area_wheel_scroll_multiplier_scales_wheel_delta() {
+    let offset_after_wheel = |smooth_scroll: bool, multiplier: f32| {
+        let mut harness = Harness::new_ui_state(
+            |ui, offset: &mut f32| {
+                *offset = egui::ScrollArea::vertical()
+                    .max_height(100.0)
+                    .smooth_scroll(smooth_scroll)
+                    .wheel_scroll_multiplier(multiplier)
+                    .show(ui, |ui| {
+                        ui.set_min_size(Vec2::new(100.0, 2000.0));
+                    })
+                    .state
+                    .offset
+                    .y;
+            },
+            0.0,
+        );
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(egui::pos2(50.0, 50.0)));
+        harness.step();
+        harness.input_mut().events.push(egui::Event::MouseWheel {
+            unit: egui::MouseWheelUnit::Point,
+            delta: Vec2::new(0.0, -50.0),
+            modifiers: egui::Modifiers::NONE,
+        });
+        harness.run_steps(20);
+        *harness.state()
+    };
+
+    for smooth_scroll in [false, true] {
+        let normal = offset_after_wheel(smooth_scroll, 1.0);
+        let doubled = offset_after_wheel(smooth_scroll, 2.0);
+        assert!(normal > 0.0);
+        assert!(
+            (doubled - 2.0 * normal).abs() < 1.0,
+            "smooth_scroll: {smooth_scroll}, normal: {normal}, doubled: {doubled}"
+        );
+    }
+}
+
+#[test]
+fn scroll_area_reports_delta_and_velocity() {
+    #[derive(Default)]
+    struct State {
+        delta: Vec2,
+        velocity: Vec2,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let output = egui::ScrollArea::vertical()
+                    .auto_shrink(false)
+                    .show(ui, |ui| {
+                        for i in 0..100 {
+                            ui.label(format!("Row {i}"));
+                        }
+                    });
+                state.delta = output.delta;
+                state.velocity = output.velocity;
+            },
+            State::default(),
+        );
+    harness.run();
+    assert_eq!(harness.state().delta, Vec2::ZERO);
+    assert_eq!(harness.state().velocity, Vec2::ZERO);
+
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(egui::pos2(100.0, 100.0)));
+    harness.input_mut().events.push(egui::Event::MouseWheel {
+        unit: egui::MouseWheelUnit::Point,
+        delta: Vec2::new(0.0, -100.0),
+        modifiers: egui::Modifiers::NONE,
+    });
+    harness.step();
+    assert!(harness.state().delta.y > 0.0, "scrolling down");
+    assert!(harness.state().velocity.y > 0.0);
+
+    harness.run_steps(20);
+    assert_eq!(harness.state().delta, Vec2::ZERO);
+    assert_eq!(
+        harness.state().velocity,
+        Vec2::ZERO,
+        "settles when the scrolling stops"
+    );
+}
+
+#[test]
+fn drag_to_scroll_leaves_drags_on_interactive_children_alone() {
+    struct State {
+        value: f32,
+        clicks: usize,
+        offset: Vec2,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 200.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                // Otherwise dragging a label selects its text:
+                ui.style_mut().interaction.selectable_labels = false;
+                state.offset = egui::ScrollArea::vertical()
+                    .auto_shrink(false)
+                    .drag_to_scroll(true)
+                    .show(ui, |ui| {
+                        ui.add(egui::Slider::new(&mut state.value, 0.0..=100.0).text("Value"));
+                        if ui.button("Button").clicked() {
+                            state.clicks += 1;
+                        }
+                        for i in 0..50 {
+                            ui.label(format!("Row {i}"));
+                        }
+                    })
+                    .state
+                    .offset;
+            },
+            State {
+                value: 0.0,
+                clicks: 0,
+                offset: Vec2::ZERO,
+            },
+        );
+    harness.run();
+
+    let center = |node: egui_kittest::kittest::Node<'_>| {
+        let bounds = node.raw_bounds().unwrap();
+        egui::pos2(
+            (bounds.x0 + bounds.x1) as f32 / 2.0,
+            (bounds.y0 + bounds.y1) as f32 / 2.0,
+        )
+    };
+
+    // Dragging the slider (a bit diagonally) moves the slider, not the scroll area:
+    let slider = center(harness.get_by_role(Role::Slider));
+    drag_pointer(&mut harness, slider, slider + Vec2::new(40.0, -30.0));
+    assert!(harness.state().value > 0.0, "the slider moved");
+    assert_eq!(harness.state().offset, Vec2::ZERO, "no scrolling");
+
+    // Dragging from a button doesn't scroll either:
+    let button = center(harness.get_by_label("Button"));
+    drag_pointer(&mut harness, button, button - Vec2::new(0.0, 60.0));
+    assert_eq!(harness.state().offset, Vec2::ZERO, "no scrolling");
+
+    // Dragging the background still scrolls:
+    let background = center(harness.get_by_label("Row 5"));
+    drag_pointer(&mut harness, background, background - Vec2::new(0.0, 60.0));
+    assert!(harness.state().offset.y > 0.0, "scrolled");
+}
+
+#[test]
+fn scroll_to_rect_with_margin_scrolls_as_little_as_possible() {
+    struct State {
+        caret_y: f32,
+        offset: f32,
+
+        /// Visible space above and below the caret.
+        space: (f32, f32),
+    }
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 300.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                state.offset = egui::ScrollArea::vertical()
+                    .max_height(100.0)
+                    .animated(false)
+                    .show(ui, |ui| {
+                        let top = ui.min_rect().top();
+                        ui.allocate_space(Vec2::new(100.0, 1000.0));
+                        let caret = egui::Rect::from_min_size(
+                            egui::pos2(0.0, top + state.caret_y),
+                            Vec2::new(1.0, 14.0),
+                        );
+                        ui.scroll_to_rect_with_margin(caret, 20.0);
+                        let visible = ui.clip_rect();
+                        state.space = (
+                            caret.top() - visible.top(),
+                            visible.bottom() - caret.bottom(),
+                        );
+                    })
+                    .state
+                    .offset
+                    .y;
+            },
+            State {
+                caret_y: 10.0,
+                offset: 0.0,
+                space: (0.0, 0.0),
+            },
+        );
+    harness.run();
+    assert_eq!(harness.state().offset, 0.0, "the caret is already in view");
+
+    // Below the view: the caret ends up just above the bottom margin.
+    harness.state_mut().caret_y = 300.0;
+    harness.run();
+    assert_eq!(harness.state().space.1, 20.0);
+
+    // Above the view: the caret ends up just below the top margin.
+    harness.state_mut().caret_y = 200.0;
+    harness.run();
+    assert_eq!(harness.state().space.0, 20.0);
+
+    // Within the view (and margin): no scrolling.
+    let offset = harness.state().offset;
+    harness.state_mut().caret_y = 220.0;
+    harness.run();
+    assert_eq!(harness.state().offset, offset);
+}
+
+#[test]
+fn scroll_area_drag_start_threshold_ignores_small_drags() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 200.0))
+        .build_ui_state(
+            |ui, offset: &mut Vec2| {
+                ui.style_mut().interaction.selectable_labels = false;
+                *offset = egui::ScrollArea::vertical()
+                    .auto_shrink(false)
+                    .drag_to_scroll(true)
+                    .drag_start_threshold(20.0)
+                    .show(ui, |ui| {
+                        for i in 0..50 {
+                            ui.label(format!("Row {i}"));
+                        }
+                    })
+                    .state
+                    .offset;
+            },
+            Vec2::ZERO,
+        );
+    harness.run();
+
+    let row = harness.get_by_label("Row 5").bounding_box().unwrap();
+    let start = egui::pos2(row.x0 as f32 + 10.0, row.y0 as f32 + 5.0);
+
+    drag_pointer(&mut harness, start, start - Vec2::new(0.0, 15.0));
+    assert_eq!(*harness.state(), Vec2::ZERO, "within the threshold");
+
+    drag_pointer(&mut harness, start, start - Vec2::new(0.0, 60.0));
+    assert!(harness.state().y > 0.0, "scrolled past the threshold");
+}
+
+#[test]
+fn scroll_area_scroll_requires_focus_lets_wheel_through_until_clicked() {
+    let mut harness = Harness::new_ui_state(
+        |ui, offsets: &mut [f32; 2]| {
+            let outer = egui::ScrollArea::vertical()
+                .id_salt("outer")
+                .max_height(300.0)
+                .show(ui, |ui| {
+                    offsets[1] = egui::ScrollArea::vertical()
+                        .id_salt("inner")
+                        .max_height(100.0)
+                        .scroll_requires_focus(true)
+                        .show(ui, |ui| {
+                            ui.set_min_size(Vec2::new(100.0, 2000.0));
+                        })
+                        .state
+                        .offset
+                        .y;
+                    ui.add_space(2000.0);
+                });
+            offsets[0] = outer.state.offset.y;
+            ui.label("Elsewhere");
+        },
+        [0.0; 2],
+    );
+    harness.run();
+
+    let inner_pos = egui::pos2(50.0, 50.0);
+    let wheel = |harness: &mut Harness<'_, [f32; 2]>| {
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(inner_pos));
+        harness.step();
+        harness.input_mut().events.push(egui::Event::MouseWheel {
+            unit: egui::MouseWheelUnit::Point,
+            delta: Vec2::new(0.0, -50.0),
+            modifiers: egui::Modifiers::NONE,
+        });
+        harness.run_steps(4);
+    };
+    let click = |harness: &mut Harness<'_, [f32; 2]>, pos: egui::Pos2| {
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(pos));
+        for pressed in [true, false] {
+            harness.input_mut().events.push(egui::Event::PointerButton {
+                pos,
+                button: egui::PointerButton::Primary,
+                pressed,
+                modifiers: egui::Modifiers::NONE,
+            });
+            harness.step();
+        }
+    };
+
+    // Not focused: the wheel goes to the outer scroll area.
+    wheel(&mut harness);
+    let [outer, inner] = *harness.state();
+    assert!(outer > 0.0);
+    assert_eq!(inner, 0.0);
+
+    // Clicking the inner area focuses it, so it takes the wheel:
+    click(&mut harness, inner_pos);
+    wheel(&mut harness);
+    let [outer_after, inner] = *harness.state();
+    assert_eq!(outer_after, outer);
+    assert!(inner > 0.0);
+
+    // Clicking elsewhere takes the focus away again:
+    harness.get_by_label("Elsewhere").simulate_click();
+    harness.run();
+    wheel(&mut harness);
+    let [outer_after, inner_after] = *harness.state();
+    assert!(outer_after > outer);
+    assert_eq!(inner_after, inner);
+}
+
+#[test]
+fn scroll_area_horizontal_offset_survives_row_count_changes() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (rows, offset): &mut (usize, Vec2)| {
+            // Solid scroll bars take up space, so the vertical one appearing or disappearing
+            // changes how far you can scroll horizontally:
+            ui.spacing_mut().scroll = egui::style::ScrollStyle::solid();
+            *offset = egui::ScrollArea::both()
+                .max_width(200.0)
+                .max_height(200.0)
+                .show(ui, |ui| {
+                    ui.set_min_width(1000.0);
+                    for row in 0..*rows {
+                        ui.label(format!("Row {row}"));
+                    }
+                })
+                .state
+                .offset;
+        },
+        (100, Vec2::ZERO),
+    );
+    harness.run();
+
+    let wheel = |harness: &mut Harness<'_, (usize, Vec2)>, delta: Vec2| {
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(egui::pos2(50.0, 50.0)));
+        harness.step();
+        harness.input_mut().events.push(egui::Event::MouseWheel {
+            unit: egui::MouseWheelUnit::Point,
+            delta,
+            modifiers: egui::Modifiers::NONE,
+        });
+        harness.run_steps(10);
+    };
+    let set_rows = |harness: &mut Harness<'_, (usize, Vec2)>, rows: usize| {
+        harness.state_mut().0 = rows;
+        harness.run_steps(10);
+        harness.state().1
+    };
+
+    wheel(&mut harness, Vec2::new(-300.0, -100.0));
+    let scrolled = harness.state().1;
+    assert!(scrolled.x > 0.0 && scrolled.y > 0.0);
+
+    // Filtering out rows shrinks the content vertically, so the vertical offset is clamped,
+    // but the horizontal one is left alone:
+    let offset = set_rows(&mut harness, 2);
+    assert_eq!(offset, Vec2::new(scrolled.x, 0.0));
+    assert_eq!(set_rows(&mut harness, 100).x, scrolled.x);
+
+    // Scrolled all the way to the right, the vertical scroll bar going away makes
+    // the horizontal offset clamp a little, but it comes back with the rows:
+    wheel(&mut harness, Vec2::new(-3000.0, 0.0));
+    let scrolled = harness.state().1;
+    let offset = set_rows(&mut harness, 2);
+    assert!(offset.x < scrolled.x);
+    assert_eq!(set_rows(&mut harness, 100).x, scrolled.x);
+}
+
+#[test]
+fn scroll_area_forgets_scrolling_past_the_end_when_the_content_grows() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (height, offset): &mut (f32, f32)| {
+            *offset = egui::ScrollArea::vertical()
+                .max_height(200.0)
+                .show(ui, |ui| {
+                    ui.set_min_size(Vec2::new(100.0, *height));
+                })
+                .state
+                .offset
+                .y;
+        },
+        (500.0, 0.0),
+    );
+    harness.run();
+
+    // The wheel tries to scroll way past the end:
+    move_pointer(&mut harness, egui::pos2(50.0, 50.0));
+    harness.input_mut().events.push(egui::Event::MouseWheel {
+        unit: egui::MouseWheelUnit::Point,
+        delta: Vec2::new(0.0, -3000.0),
+        modifiers: egui::Modifiers::NONE,
+    });
+    harness.run_steps(10);
+    let at_end = harness.state().1;
+    assert!(0.0 < at_end && at_end < 500.0, "clamped: {at_end}");
+
+    harness.state_mut().0 = 5000.0;
+    harness.run_steps(10);
+    assert_eq!(
+        harness.state().1,
+        at_end,
+        "the content growing doesn't scroll to where the wheel overshot"
+    );
+}
+
+#[test]
+fn scroll_area_content_size_hint_avoids_first_frame_layout_shift() {
+    let widths_per_frame = |hint: Option<Vec2>| {
+        let mut harness = Harness::new_ui_state(
+            move |ui, widths: &mut Vec<f32>| {
+                ui.spacing_mut().scroll = egui::style::ScrollStyle::solid();
+                let mut scroll_area = egui::ScrollArea::vertical().max_height(200.0);
+                if let Some(hint) = hint {
+                    scroll_area = scroll_area.content_size_hint(hint);
+                }
+                scroll_area.show(ui, |ui| {
+                    widths.push(ui.available_width());
+                    for row in 0..100 {
+                        ui.label(format!("Row {row}"));
+                    }
+                });
+            },
+            Vec::new(),
+        );
+        harness.run();
+        harness.state().clone()
+    };
+
+    let widths = widths_per_frame(None);
+    assert_ne!(
+        widths.first(),
+        widths.last(),
+        "without a hint, the scroll bar only appears after the first frame"
+    );
+
+    let widths = widths_per_frame(Some(Vec2::new(100.0, 2000.0)));
+    assert!(
+        widths.iter().all(|width| *width == widths[0]),
+        "the scroll bar should be there from the first frame: {widths:?}"
+    );
+}
+
+#[test]
+fn scroll_area_show_rows_is_precise_far_down_a_huge_list() {
+    #[derive(Default)]
+    struct State {
+        offset: f32,
+        inner_top: f32,
+        first_row: usize,
+        row_tops: Vec<f32>,
+    }
+
+    let row_height = 20.0;
+    let mut harness = Harness::new_ui_state(
+        move |ui, state: &mut State| {
+            let output = egui::ScrollArea::vertical()
+                .max_height(200.0)
+                .vertical_scroll_offset(state.offset)
+                .show_rows(ui, row_height, 10_000_000, |ui, rows| {
+                    let first_row = rows.start;
+                    let tops = rows
+                        .map(|_| {
+                            let (rect, _) = ui.allocate_exact_size(
+                                Vec2::new(50.0, row_height),
+                                egui::Sense::hover(),
+                            );
+                            rect.top()
+                        })
+                        .collect();
+                    (first_row, tops)
+                });
+            state.inner_top = output.inner_rect.top();
+            (state.first_row, state.row_tops) = output.inner;
+        },
+        State {
+            // Far enough down that an f32 only holds every 16th point:
+            offset: 200_000_000.0,
+            ..Default::default()
+        },
+    );
+    harness.run();
+
+    let state = harness.state();
+    let row_height_with_spacing =
+        f64::from(row_height + harness.ctx.style().spacing.item_spacing.y);
+    let expected_first_top = f64::from(state.inner_top)
+        + state.first_row as f64 * row_height_with_spacing
+        - f64::from(state.offset);
+    assert!(
+        (f64::from(state.row_tops[0]) - expected_first_top).abs() < 0.5,
+        "first row at {}, expected {expected_first_top}",
+        state.row_tops[0]
+    );
+    for tops in state.row_tops.windows(2) {
+        assert!(
+            (f64::from(tops[1] - tops[0]) - row_height_with_spacing).abs() < 0.01,
+            "rows should be evenly spaced: {:?}",
+            state.row_tops
+        );
+    }
+}
+
+#[test]
+fn scroll_area_scroll_to_fraction_jumps_and_reports_back() {
+    #[derive(Default)]
+    struct State {
+        jump_to: Option<egui::Vec2>,
+        fraction: egui::Vec2,
+        max_offset: egui::Vec2,
+        offset: egui::Vec2,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let mut scroll_area = egui::ScrollArea::vertical();
+                if let Some(fraction) = state.jump_to.take() {
+                    scroll_area = scroll_area.scroll_to_fraction(fraction);
+                }
+                let output = scroll_area.show(ui, |ui| {
+                    for i in 0..100 {
+                        ui.label(format!("Row {i}"));
+                    }
+                });
+                state.fraction = output.scroll_fraction();
+                state.max_offset = output.content_size - output.inner_rect.size();
+                state.offset = output.state.offset;
+            },
+            State::default(),
+        );
+    assert_eq!(harness.state().fraction, egui::Vec2::ZERO);
+
+    harness.state_mut().jump_to = Some(egui::vec2(0.0, 0.75));
+    harness.run_steps(8);
+    let state = harness.state();
+    assert!(
+        (state.fraction.y - 0.75).abs() < 1e-3,
+        "{:?}",
+        state.fraction
+    );
+    assert!((state.offset.y - 0.75 * state.max_offset.y).abs() < 0.5);
+    assert_eq!(state.fraction.x, 0.0, "Horizontal axis doesn't scroll");
+
+    // Out-of-range fractions are clamped to the content:
+    harness.state_mut().jump_to = Some(egui::vec2(0.0, 2.0));
+    harness.run_steps(8);
+    assert_eq!(harness.state().fraction.y, 1.0);
+    assert_eq!(harness.state().offset.y, harness.state().max_offset.y);
+}
+
+#[test]
+fn scroll_area_header_follows_horizontal_scrolling_only() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, scroll_to: &mut Option<Vec2>| {
+                let mut scroll_area = egui::ScrollArea::both();
+                if let Some(offset) = scroll_to.take() {
+                    scroll_area = scroll_area.scroll_offset(offset);
+                }
+                scroll_area.show_with_header(
+                    ui,
+                    |ui| {
+                        ui.horizontal(|ui| {
+                            ui.add_sized([150.0, 20.0], egui::Label::new("Name header"));
+                            ui.add_sized([150.0, 20.0], egui::Label::new("Size header"));
+                        });
+                    },
+                    |ui| {
+                        for row in 0..50 {
+                            ui.horizontal(|ui| {
+                                ui.add_sized(
+                                    [150.0, 20.0],
+                                    egui::Label::new(format!("Name {row}")),
+                                );
+                                ui.add_sized(
+                                    [150.0, 20.0],
+                                    egui::Label::new(format!("Size {row}")),
+                                );
+                            });
+                        }
+                    },
+                );
+            },
+            None,
+        );
+
+    let bounds = |harness: &Harness<'_, Option<Vec2>>, label: &str| {
+        harness.get_by_label(label).raw_bounds().unwrap()
+    };
+    let header_top = bounds(&harness, "Size header").y0;
+    assert!(
+        bounds(&harness, "Name 0").y0 > bounds(&harness, "Name header").y1 - 0.5,
+        "The body is below the header"
+    );
+
+    *harness.state_mut() = Some(Vec2::new(80.0, 100.0));
+    harness.run();
+    let header = bounds(&harness, "Size header");
+    let body = bounds(&harness, "Size 10");
+    assert!((header.x0 - body.x0).abs() < 0.5, "{header:?} vs {body:?}");
+    assert!(header.x0 < 150.0, "The header scrolled horizontally");
+    assert_eq!(header.y0, header_top, "The header didn't scroll vertically");
+}
+
+#[test]
+fn scroll_bar_edits_offset_with_track_clicks_and_thumb_drags() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, (offset, rect): &mut (f32, egui::Rect)| {
+                *rect = ui
+                    .add(egui::ScrollBar::vertical(offset, 1000.0).length(100.0))
+                    .rect;
+            },
+            (0.0, egui::Rect::NOTHING),
+        );
+
+    // Clicking the track below the handle pages down by one viewport:
+    let rect = harness.state().1;
+    let below_handle = egui::pos2(rect.center().x, rect.bottom() - 5.0);
+    drag_pointer(&mut harness, below_handle, below_handle);
+    assert_eq!(harness.state().0, 100.0);
+
+    // Dragging the handle far past the end scrolls to the end, and no further:
+    let handle = egui::pos2(rect.center().x, rect.top() + 12.0);
+    drag_pointer(&mut harness, handle, handle + Vec2::new(0.0, 500.0));
+    assert_eq!(harness.state().0, 900.0);
+
+    // Clicking above the handle pages back up:
+    let above_handle = egui::pos2(rect.center().x, rect.top() + 5.0);
+    drag_pointer(&mut harness, above_handle, above_handle);
+    assert_eq!(harness.state().0, 800.0);
+}
+
+#[test]
+fn scroll_area_snaps_to_the_centered_child_once_scrolling_stops() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (offset, centered_child): &mut (f32, Option<usize>)| {
+            let output = egui::ScrollArea::vertical()
+                .max_height(100.0)
+                .snap_to_children(true)
+                .show(ui, |ui| {
+                    ui.spacing_mut().item_spacing = Vec2::ZERO;
+                    for _ in 0..10 {
+                        ui.allocate_exact_size(Vec2::new(100.0, 50.0), egui::Sense::hover());
+                    }
+                });
+            *offset = output.state.offset.y;
+            *centered_child = output.centered_child;
+        },
+        (0.0, None),
+    );
+    assert_eq!(harness.state().1, Some(0));
+
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(egui::pos2(50.0, 50.0)));
+    harness.step();
+    harness.input_mut().events.push(egui::Event::MouseWheel {
+        unit: egui::MouseWheelUnit::Point,
+        delta: Vec2::new(0.0, -110.0),
+        modifiers: egui::Modifiers::NONE,
+    });
+    harness.run_steps(20);
+
+    // The middle of the viewport was at 160, closest to the fourth child (150..200),
+    // so the scroll area settles with that child centered:
+    assert_eq!(*harness.state(), (125.0, Some(3)));
+}
+
+#[test]
+fn scroll_area_footer_stays_at_the_bottom_and_follows_horizontal_scrolling() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, scroll_to: &mut Option<Vec2>| {
+                let mut scroll_area = egui::ScrollArea::both();
+                if let Some(offset) = scroll_to.take() {
+                    scroll_area = scroll_area.scroll_offset(offset);
+                }
+                scroll_area.show_with_footer(
+                    ui,
+                    |ui| {
+                        for row in 0..50 {
+                            ui.horizontal(|ui| {
+                                ui.add_sized(
+                                    [150.0, 20.0],
+                                    egui::Label::new(format!("Name {row}")),
+                                );
+                                ui.add_sized(
+                                    [150.0, 20.0],
+                                    egui::Label::new(format!("Size {row}")),
+                                );
+                            });
+                        }
+                    },
+                    |ui| {
+                        ui.horizontal(|ui| {
+                            ui.add_sized([150.0, 20.0], egui::Label::new("Name total"));
+                            ui.add_sized([150.0, 20.0], egui::Label::new("Size total"));
+                        });
+                    },
+                );
+            },
+            None,
+        );
+
+    let bounds = |harness: &Harness<'_, Option<Vec2>>, label: &str| {
+        harness.get_by_label(label).raw_bounds().unwrap()
+    };
+    let footer_top = bounds(&harness, "Size total").y0;
+    assert!(
+        bounds(&harness, "Size total").y1 <= 200.0,
+        "The footer is on screen, below the viewport"
+    );
+
+    *harness.state_mut() = Some(Vec2::new(80.0, 300.0));
+    harness.run();
+    let footer = bounds(&harness, "Size total");
+    let body = bounds(&harness, "Size 20");
+    assert!((footer.x0 - body.x0).abs() < 0.5, "{footer:?} vs {body:?}");
+    assert!(footer.x0 < 150.0, "The footer scrolled horizontally");
+    assert_eq!(footer.y0, footer_top, "The footer didn't scroll vertically");
+}
+
+#[test]
+fn scroll_area_zoomable_keeps_the_point_under_the_cursor_fixed() {
+    struct State {
+        offset: Vec2,
+        zoom: f32,
+        inner_rect: egui::Rect,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 300.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let output = egui::ScrollArea::both()
+                    .zoomable(true)
+                    .zoom_range(0.5..=4.0)
+                    .show(ui, |ui| {
+                        let zoom = egui::ScrollArea::zoom_factor(ui);
+                        ui.allocate_exact_size(Vec2::splat(400.0 * zoom), egui::Sense::hover());
+                    });
+                state.offset = output.state.offset;
+                state.zoom = output.zoom;
+                state.inner_rect = output.inner_rect;
+            },
+            State {
+                offset: Vec2::ZERO,
+                zoom: 0.0,
+                inner_rect: egui::Rect::NOTHING,
+            },
+        );
+    assert_eq!(harness.state().zoom, 1.0);
+
+    let cursor = harness.state().inner_rect.min + Vec2::splat(100.0);
+    let point_under_cursor =
+        |state: &State| (cursor - state.inner_rect.min + state.offset) / state.zoom;
+    let point_before = point_under_cursor(harness.state());
+
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(cursor));
+    harness.input_mut().events.push(egui::Event::Zoom(2.0));
+    harness.run();
+    assert_eq!(harness.state().zoom, 2.0);
+    assert_eq!(harness.state().offset, Vec2::splat(100.0));
+    assert_eq!(point_under_cursor(harness.state()), point_before);
+
+    // Clamped to the zoom range:
+    harness.input_mut().events.push(egui::Event::Zoom(10.0));
+    harness.run();
+    assert_eq!(harness.state().zoom, 4.0);
+    assert_eq!(point_under_cursor(harness.state()), point_before);
+
+    harness.input_mut().events.push(egui::Event::Zoom(0.01));
+    harness.run();
+    assert_eq!(harness.state().zoom, 0.5);
+    assert_eq!(
+        harness.state().offset,
+        Vec2::ZERO,
+        "The contents fit, so no scrolling"
+    );
+}
+
+#[test]
+fn scroll_area_allow_scroll_beyond_content_keeps_the_extra_offset() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (offset, content_size): &mut (Vec2, Vec2)| {
+            let output = egui::ScrollArea::vertical()
+                .max_height(100.0)
+                .allow_scroll_beyond_content(50.0)
+                .show(ui, |ui| {
+                    ui.allocate_exact_size(Vec2::new(100.0, 300.0), egui::Sense::hover());
+                });
+            *offset = output.state.offset;
+            *content_size = output.content_size;
+        },
+        (Vec2::ZERO, Vec2::ZERO),
+    );
+
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(egui::pos2(50.0, 50.0)));
+    harness.input_mut().events.push(egui::Event::MouseWheel {
+        unit: egui::MouseWheelUnit::Point,
+        delta: Vec2::new(0.0, -1000.0),
+        modifiers: egui::Modifiers::NONE,
+    });
+    harness.run();
+    harness.run_steps(10);
+
+    let (offset, content_size) = *harness.state();
+    assert_eq!(content_size.y, 300.0, "The real content size is reported");
+    assert_eq!(
+        offset.y, 250.0,
+        "Scrolled 50 points past the end, and stays there"
+    );
+}
+
+#[test]
+fn scroll_area_id_keeps_the_offset_across_parents() {
+    // Scrolls down in the first tab, then switches to the second one.
+    // Returns the offsets before and after the switch.
+    let run = |use_explicit_id: bool| {
+        let mut harness = Harness::builder()
+            .with_size(Vec2::new(200.0, 200.0))
+            .build_ui_state(
+                |ui, (tab, offset): &mut (usize, Vec2)| {
+                    // Each tab is shown in a `Ui` with an id of its own:
+                    ui.push_id(*tab, |ui| {
+                        let mut scroll_area = egui::ScrollArea::vertical();
+                        if use_explicit_id {
+                            scroll_area = scroll_area.id(egui::Id::new("list"));
+                        }
+                        *offset = scroll_area
+                            .show(ui, |ui| {
+                                for i in 0..50 {
+                                    ui.label(format!("Tab {tab}, row {i}"));
+                                }
+                            })
+                            .state
+                            .offset;
+                    });
+                },
+                (0, Vec2::ZERO),
+            );
+        harness.run();
+
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(egui::pos2(50.0, 50.0)));
+        harness.step();
+        harness.input_mut().events.push(egui::Event::MouseWheel {
+            unit: egui::MouseWheelUnit::Point,
+            delta: Vec2::new(0.0, -100.0),
+            modifiers: egui::Modifiers::NONE,
+        });
+        harness.run_steps(10);
+        let scrolled = harness.state().1;
+        assert!(scrolled.y > 0.0);
+
+        harness.state_mut().0 = 1;
+        harness.run();
+        (scrolled, harness.state().1)
+    };
+
+    let (scrolled, after_switch) = run(false);
+    assert_eq!(
+        after_switch,
+        Vec2::ZERO,
+        "a new parent id is a new scroll area"
+    );
+    assert_ne!(scrolled, after_switch);
+
+    let (scrolled, after_switch) = run(true);
+    assert_eq!(after_switch, scrolled, "the explicit id keeps the offset");
+}
+
+#[test]
+fn scroll_area_parallax_layer_moves_with_a_fraction_of_the_offset() {
+    #[derive(Default)]
+    struct State {
+        offset: Vec2,
+        layer_rect: Option<egui::Rect>,
+        clicked: bool,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                state.offset = egui::ScrollArea::vertical()
+                    .show(ui, |ui| {
+                        state.layer_rect = Some(egui::ScrollArea::paint_parallax_layer(
+                            ui,
+                            0.5,
+                            |painter, rect| {
+                                painter.rect_filled(rect, 0.0, egui::Color32::DARK_BLUE);
+                                rect
+                            },
+                        ));
+                        for i in 0..50 {
+                            if i == 20 {
+                                state.clicked |= ui.button("Foreground").clicked();
+                            } else {
+                                ui.label(format!("Row {i}"));
+                            }
+                        }
+                    })
+                    .state
+                    .offset;
+            },
+            State::default(),
+        );
+    harness.run();
+    let rect_before = harness.state().layer_rect.unwrap();
+
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(egui::pos2(50.0, 50.0)));
+    harness.step();
+    harness.input_mut().events.push(egui::Event::MouseWheel {
+        unit: egui::MouseWheelUnit::Point,
+        delta: Vec2::new(0.0, -200.0),
+        modifiers: egui::Modifiers::NONE,
+    });
+    harness.run_steps(10);
+    harness.run();
+
+    let state = harness.state();
+    assert!(state.offset.y > 0.0);
+    let layer_rect = state.layer_rect.unwrap();
+    let moved = layer_rect.min - rect_before.min;
+    assert!(
+        (moved.y + 0.5 * state.offset.y).abs() < 0.01,
+        "moved {moved:?} for an offset of {:?}",
+        state.offset
+    );
+    assert_eq!(layer_rect.size(), rect_before.size());
+
+    harness.get_by_label("Foreground").click();
+    harness.run();
+    assert!(harness.state().clicked, "the layer must not eat the click");
+}
+
+#[test]
+fn scroll_area_shift_wheel_only_scrolls_horizontally() {
+    // Returns the offset after the given wheel event, with shift held as given.
+    let offset_after = |delta: Vec2, event_shift: bool, held_shift: bool| {
+        let mut harness = Harness::builder()
+            .with_size(Vec2::new(200.0, 200.0))
+            .build_ui_state(
+                |ui, offset: &mut Vec2| {
+                    *offset = egui::ScrollArea::both()
+                        .show(ui, |ui| {
+                            ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Extend);
+                            for i in 0..50 {
+                                ui.label(format!("Row {i} {}", "wide ".repeat(20)));
+                            }
+                        })
+                        .state
+                        .offset;
+                },
+                Vec2::ZERO,
+            );
+        harness.run();
+
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(egui::pos2(50.0, 50.0)));
+        harness.step();
+        if held_shift {
+            harness.input_mut().modifiers = egui::Modifiers::SHIFT;
+        }
+        let modifiers = if event_shift {
+            egui::Modifiers::SHIFT
+        } else {
+            egui::Modifiers::NONE
+        };
+        harness.input_mut().events.push(egui::Event::MouseWheel {
+            unit: egui::MouseWheelUnit::Point,
+            delta,
+            modifiers,
+        });
+        harness.run_steps(10);
+        *harness.state()
+    };
+
+    let offset = offset_after(Vec2::new(0.0, -100.0), true, true);
+    assert!(
+        offset.x > 0.0,
+        "shift+wheel scrolls horizontally: {offset:?}"
+    );
+    assert_eq!(offset.y, 0.0, "and not vertically");
+
+    // Some integrations only report shift in the modifiers of the input:
+    let offset = offset_after(Vec2::new(0.0, -100.0), false, true);
+    assert!(offset.x > 0.0, "{offset:?}");
+    assert_eq!(offset.y, 0.0);
+
+    // Trackpads and tilting wheels send horizontal deltas directly:
+    for delta in [Vec2::new(-100.0, 0.0), Vec2::new(-4.0, 0.0)] {
+        let offset = offset_after(delta, false, false);
+        assert!(offset.x > 0.0, "{offset:?}");
+        assert_eq!(offset.y, 0.0);
+    }
+
+    let offset = offset_after(Vec2::new(0.0, -100.0), false, false);
+    assert_eq!(offset.x, 0.0, "a plain wheel scrolls vertically");
+    assert!(offset.y > 0.0);
+}
+
+#[test]
+fn scroll_area_reached_end_fires_once_per_load() {
+    struct State {
+        rows: usize,
+        loads: usize,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let output =
+                    egui::ScrollArea::vertical()
+                        .reach_end_threshold(50.0)
+                        .show(ui, |ui| {
+                            for i in 0..state.rows {
+                                ui.label(format!("Row {i}"));
+                            }
+                        });
+                if output.reached_end {
+                    state.loads += 1;
+                    state.rows += 20;
+                }
+            },
+            State { rows: 3, loads: 0 },
+        );
+    harness.run();
+    assert_eq!(
+        harness.state().loads,
+        1,
+        "the first rows don't fill the viewport, so one page is loaded"
+    );
+    assert_eq!(harness.state().rows, 23);
+
+    let scroll_by = |harness: &mut Harness<'_, State>, dy: f32| {
+        harness
+            .input_mut()
+            .events
+            .push(egui::Event::PointerMoved(egui::pos2(50.0, 50.0)));
+        harness.step();
+        harness.input_mut().events.push(egui::Event::MouseWheel {
+            unit: egui::MouseWheelUnit::Point,
+            delta: Vec2::new(0.0, -dy),
+            modifiers: egui::Modifiers::NONE,
+        });
+        harness.run_steps(10);
+        harness.run();
+    };
+
+    scroll_by(&mut harness, 50.0);
+    assert_eq!(harness.state().loads, 1, "not near the end yet");
+
+    scroll_by(&mut harness, 1000.0);
+    assert_eq!(harness.state().loads, 2, "reaching the end loads once");
+    assert_eq!(harness.state().rows, 43);
+
+    harness.run_steps(5);
+    assert_eq!(harness.state().loads, 2, "and doesn't fire again by itself");
+}
+
+#[test]
+fn scroll_area_pull_to_refresh_fires_on_release_after_pulling_far_enough() {
+    struct State {
+        rows: usize,
+        refreshes: usize,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let output = egui::ScrollArea::vertical()
+                    .auto_shrink(false)
+                    .pull_to_refresh(true)
+                    .show(ui, |ui| {
+                        for i in 0..state.rows {
+                            ui.label(format!("Row {i}"));
+                        }
+                    });
+                if output.refresh_requested {
+                    state.refreshes += 1;
+                }
+            },
+            State {
+                rows: 3,
+                refreshes: 0,
+            },
+        );
+    harness.run();
+
+    let row_top =
+        |harness: &Harness<'_, State>| harness.get_by_label("Row 0").raw_bounds().unwrap().y0;
+    let top = row_top(&harness);
+
+    let pull = |harness: &mut Harness<'_, State>, from: egui::Pos2, distance: f32| {
+        press_pointer(harness, from);
+        let mut pos = from;
+        for _ in 0..10 {
+            pos.y += distance / 10.0;
+            move_pointer(harness, pos);
+        }
+        harness.step();
+        let pulled = row_top(harness);
+        release_pointer(harness, pos);
+        let refreshed = harness.state().refreshes;
+        harness.run_steps(60);
+        harness.run();
+        assert_eq!(harness.state().refreshes, refreshed, "fires only once");
+        pulled
+    };
+
+    let pulled = pull(&mut harness, egui::pos2(100.0, 150.0), 40.0);
+    assert!(top < pulled, "the content follows the pull");
+    assert_eq!(harness.state().refreshes, 0, "not pulled far enough");
+    assert_eq!(row_top(&harness), top, "springs back");
+
+    let pulled = pull(&mut harness, egui::pos2(100.0, 100.0), 200.0);
+    assert!(pulled - top < 200.0, "the pull is resisted");
+    assert_eq!(harness.state().refreshes, 1);
+    assert_eq!(row_top(&harness), top, "springs back");
+
+    // A drag that doesn't start at the top only scrolls:
+    harness.state_mut().rows = 100;
+    harness.run();
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(egui::pos2(100.0, 100.0)));
+    harness.step();
+    harness.input_mut().events.push(egui::Event::MouseWheel {
+        unit: egui::MouseWheelUnit::Point,
+        delta: Vec2::new(0.0, -100.0),
+        modifiers: egui::Modifiers::NONE,
+    });
+    harness.run_steps(30);
+    pull(&mut harness, egui::pos2(100.0, 20.0), 170.0);
+    assert_eq!(harness.state().refreshes, 1);
+}
+
+#[test]
+fn scroll_area_output_viewport_rect_follows_the_offset() {
+    struct State {
+        jump_to: Option<Vec2>,
+        output: Option<(Vec2, egui::Rect, egui::Rect, egui::Rect)>,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let mut scroll_area = egui::ScrollArea::vertical().auto_shrink(false);
+                if let Some(fraction) = state.jump_to.take() {
+                    scroll_area = scroll_area.scroll_to_fraction(fraction);
+                }
+                let output = scroll_area.show(ui, |ui| {
+                    for i in 0..100 {
+                        ui.label(format!("Row {i}"));
+                    }
+                });
+                state.output = Some((
+                    output.content_size,
+                    output.inner_rect,
+                    output.viewport_rect,
+                    output.content_rect(),
+                ));
+            },
+            State {
+                jump_to: None,
+                output: None,
+            },
+        );
+    harness.run();
+    let (content_size, inner_rect, viewport, content_rect) = harness.state().output.unwrap();
+    assert!(content_size.y > inner_rect.height());
+    assert_eq!(viewport.min, egui::Pos2::ZERO);
+    assert_eq!(viewport.size(), inner_rect.size());
+    assert_eq!(content_rect.min, inner_rect.min);
+    assert_eq!(content_rect.size(), content_size);
+
+    harness.state_mut().jump_to = Some(Vec2::new(0.0, 1.0));
+    harness.run_steps(2);
+    let (content_size, inner_rect, viewport, content_rect) = harness.state().output.unwrap();
+    assert_eq!(viewport.max.y, content_size.y, "scrolled to the bottom");
+    assert_eq!(viewport.size(), inner_rect.size());
+    assert_eq!(content_rect.max.y, inner_rect.max.y);
+}
@@ -57,3 +57,789 @@ fn test_modifiers() {
     assert!(state.cmd_z_pressed, "Cmd+Z wasn't pressed");
     assert!(state.cmd_y_pressed, "Cmd+Y wasn't pressed");
 }
+
+#[test]
+fn test_widget_rects() {
+    let mut harness = Harness::new_ui_state(
+        |ui, ids: &mut [egui::Id; 2]| {
+            ids[0] = ui.label("First").id;
+            ids[1] = ui.button("Second").id;
+        },
+        [egui::Id::NULL; 2],
+    );
+    harness.run();
+
+    let rects: std::collections::HashMap<_, _> = harness.widget_rects().collect();
+    let [first, second] = harness.state().map(|id| rects[&id]);
+    assert!(first.bottom() <= second.top());
+    assert_eq!(first.left(), second.left());
+}
+
+#[test]
+fn test_galleys() {
+    let mut harness = Harness::new_ui_state(
+        |ui, ids: &mut [egui::Id; 3]| {
+            ui.set_max_width(120.0);
+            ids[0] = ui.label("Short").id;
+            ids[1] = ui
+                .label("This label is long enough to wrap onto a second row")
+                .id;
+            ids[2] = ui
+                .add(egui::Label::new("This label is truncated instead").truncate())
+                .id;
+        },
+        [egui::Id::NULL; 3],
+    );
+    harness.run();
+
+    let [short, wrapped, truncated] = harness.state().map(|id| harness.galleys(id));
+    assert_eq!(short.len(), 1);
+    assert_eq!(short[0].text(), "Short");
+    assert_eq!(short[0].rows.len(), 1);
+    assert!(!short[0].elided);
+
+    assert_eq!(wrapped.len(), 1);
+    assert!(wrapped[0].rows.len() >= 2);
+    assert!(wrapped[0].size().x <= 120.0);
+
+    assert_eq!(truncated.len(), 1);
+    assert_eq!(truncated[0].rows.len(), 1);
+    assert!(truncated[0].elided);
+
+    assert!(harness.galleys(egui::Id::new("not shown")).is_empty());
+}
+
+#[test]
+fn test_render_stats() {
+    let mut harness = Harness::new_ui_state(
+        |ui, buttons: &mut usize| {
+            for i in 0..*buttons {
+                _ = ui.button(format!("Button {i}"));
+            }
+        },
+        1,
+    );
+    harness.run();
+    let one = harness.render_stats();
+    assert!(one.clipped_primitives > 0);
+    assert!(one.vertices > 0);
+    assert_eq!(one.indices % 3, 0, "indices should form triangles");
+
+    *harness.state_mut() = 10;
+    harness.run();
+    let ten = harness.render_stats();
+    assert!(ten.vertices > one.vertices);
+    assert!(ten.indices > one.indices);
+}
+
+#[cfg(any(feature = "wgpu", feature = "snapshot"))]
+#[test]
+fn test_render_letterboxed() {
+    /// Renders the whole screen as opaque white.
+    struct WhiteRenderer;
+
+    impl egui_kittest::TestRenderer for WhiteRenderer {
+        fn handle_delta(&mut self, _delta: &egui::TexturesDelta) {}
+
+        fn render(
+            &mut self,
+            ctx: &egui::Context,
+            _output: &egui::FullOutput,
+        ) -> Result<image::RgbaImage, String> {
+            let size = ctx.screen_rect().size() * ctx.pixels_per_point();
+            Ok(image::RgbaImage::from_pixel(
+                size.x as u32,
+                size.y as u32,
+                image::Rgba([255; 4]),
+            ))
+        }
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(40.0, 20.0))
+        .renderer(WhiteRenderer)
+        .build_ui(|ui| {
+            ui.label("Hi");
+        });
+
+    // Wider than the content: centered, with transparent bars on the sides.
+    let image = harness.render_letterboxed([60, 30]).unwrap();
+    assert_eq!(image.dimensions(), (60, 30));
+    assert_eq!(image.get_pixel(9, 15)[3], 0);
+    assert_eq!(image.get_pixel(10, 5)[3], 255);
+    assert_eq!(image.get_pixel(49, 24)[3], 255);
+    assert_eq!(image.get_pixel(50, 15)[3], 0);
+
+    // Smaller than the content: cropped around the center.
+    let image = harness.render_letterboxed([20, 10]).unwrap();
+    assert_eq!(image.dimensions(), (20, 10));
+    assert!(image.pixels().all(|p| p[3] == 255));
+}
+
+#[cfg(feature = "wgpu")]
+#[test]
+fn test_wgpu_paint_callbacks_are_rendered() {
+    use egui_wgpu::wgpu;
+
+    /// Fills the callback rect with opaque red.
+    struct FillRed;
+
+    struct FillRedResources {
+        pipeline: wgpu::RenderPipeline,
+        prepared: std::sync::atomic::AtomicUsize,
+    }
+
+    impl egui_wgpu::CallbackTrait for FillRed {
+        fn prepare(
+            &self,
+            _device: &wgpu::Device,
+            _queue: &wgpu::Queue,
+            _screen_descriptor: &egui_wgpu::ScreenDescriptor,
+            _egui_encoder: &mut wgpu::CommandEncoder,
+            callback_resources: &mut egui_wgpu::CallbackResources,
+        ) -> Vec<wgpu::CommandBuffer> {
+            let resources: &FillRedResources = callback_resources.get().unwrap();
+            resources
+                .prepared
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            Vec::new()
+        }
+
+        fn paint(
+            &self,
+            _info: egui::PaintCallbackInfo,
+            render_pass: &mut wgpu::RenderPass<'static>,
+            callback_resources: &egui_wgpu::CallbackResources,
+        ) {
+            let resources: &FillRedResources = callback_resources.get().unwrap();
+            render_pass.set_pipeline(&resources.pipeline);
+            render_pass.draw(0..3, 0..1);
+        }
+    }
+
+    let renderer = egui_kittest::wgpu::WgpuTestRenderer::new();
+    {
+        let render_state = renderer.render_state();
+        let shader = render_state
+            .device
+            .create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("fill_red"),
+                source: wgpu::ShaderSource::Wgsl(
+                    "
+                    @vertex
+                    fn vs_main(@builtin(vertex_index) i: u32) -> @builtin(position) vec4<f32> {
+                        let uv = vec2<f32>(f32((i << 1u) & 2u), f32(i & 2u));
+                        return vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+                    }
+
+                    @fragment
+                    fn fs_main() -> @location(0) vec4<f32> {
+                        return vec4<f32>(1.0, 0.0, 0.0, 1.0);
+                    }
+                    "
+                    .into(),
+                ),
+            });
+        let pipeline =
+            render_state
+                .device
+                .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                    label: Some("fill_red"),
+                    layout: None,
+                    vertex: wgpu::VertexState {
+                        module: &shader,
+                        entry_point: Some("vs_main"),
+                        compilation_options: Default::default(),
+                        buffers: &[],
+                    },
+                    fragment: Some(wgpu::FragmentState {
+                        module: &shader,
+                        entry_point: Some("fs_main"),
+                        compilation_options: Default::default(),
+                        targets: &[Some(render_state.target_format.into())],
+                    }),
+                    primitive: Default::default(),
+                    depth_stencil: None,
+                    multisample: Default::default(),
+                    multiview: None,
+                    cache: None,
+                });
+        render_state
+            .renderer
+            .write()
+            .callback_resources
+            .insert(FillRedResources {
+                pipeline,
+                prepared: Default::default(),
+            });
+    }
+    let render_state = renderer.render_state().clone();
+
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(100.0, 100.0))
+        .renderer(renderer)
+        .build(|ctx| {
+            egui::CentralPanel::default()
+                .frame(egui::Frame::NONE)
+                .show(ctx, |ui| {
+                    let rect =
+                        egui::Rect::from_min_size(egui::pos2(10.0, 10.0), egui::vec2(40.0, 40.0));
+                    ui.painter()
+                        .add(egui_wgpu::Callback::new_paint_callback(rect, FillRed));
+                });
+        });
+
+    let image = harness.render().unwrap();
+    let prepared = render_state
+        .renderer
+        .read()
+        .callback_resources
+        .get::<FillRedResources>()
+        .unwrap()
+        .prepared
+        .load(std::sync::atomic::Ordering::Relaxed);
+    assert!(prepared > 0, "`prepare` should be called");
+
+    let ppp = harness.ctx.pixels_per_point();
+    let pixel = |x: f32, y: f32| *image.get_pixel((x * ppp) as u32, (y * ppp) as u32);
+    assert_eq!(pixel(30.0, 30.0), image::Rgba([255, 0, 0, 255]));
+    assert_ne!(pixel(70.0, 70.0), image::Rgba([255, 0, 0, 255]));
+}
+
+#[cfg(feature = "wgpu")]
+#[test]
+fn test_wgpu_shared_renderer_is_reused_for_the_same_context() {
+    use egui_kittest::TestRenderer as _;
+
+    let ctx = egui::Context::default();
+    let input = egui::RawInput {
+        screen_rect: Some(egui::Rect::from_min_size(
+            egui::Pos2::ZERO,
+            egui::vec2(100.0, 40.0),
+        )),
+        ..Default::default()
+    };
+    let run = || {
+        ctx.run(input.clone(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| ui.label("Text"));
+        })
+    };
+
+    let output = run();
+    let mut first = egui_kittest::wgpu::WgpuTestRenderer::shared();
+    first.handle_delta(&output.textures_delta);
+    let first_image = first.render(&ctx, &output).unwrap();
+
+    // The font atlas was only uploaded to the first renderer,
+    // so the text only shows if the second one renders with the same `egui_wgpu::Renderer`:
+    let output = run();
+    assert!(output.textures_delta.set.is_empty());
+    let mut second = egui_kittest::wgpu::WgpuTestRenderer::shared();
+    let second_image = second.render(&ctx, &output).unwrap();
+    assert!(
+        first_image == second_image,
+        "The second renderer should draw the same text"
+    );
+
+    let mut other = egui_kittest::wgpu::WgpuTestRenderer::shared();
+    let other_ctx = egui::Context::default();
+    let other_output = other_ctx.run(input.clone(), |_| {});
+    other.render(&other_ctx, &other_output).unwrap();
+    assert!(
+        !std::sync::Arc::ptr_eq(
+            &other.render_state().renderer,
+            &first.render_state().renderer
+        ),
+        "Every context gets its own renderer"
+    );
+}
+
+#[cfg(feature = "wgpu")]
+#[test]
+fn test_render_layer() {
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(200.0, 200.0))
+        .build(|ctx| {
+            egui::CentralPanel::default().show(ctx, |_ui| {});
+            egui::Window::new("Layer")
+                .fixed_pos(egui::pos2(100.0, 100.0))
+                .show(ctx, |ui| {
+                    ui.label("Only me");
+                });
+        });
+
+    let window = harness
+        .ctx
+        .memory(|mem| mem.area_rect(egui::Id::new("Layer")))
+        .unwrap();
+    let ppp = harness.ctx.pixels_per_point();
+    let alpha = |image: &image::RgbaImage, pos: egui::Pos2| {
+        image.get_pixel((pos.x * ppp) as u32, (pos.y * ppp) as u32)[3]
+    };
+    let inside = window.center();
+    let outside = egui::pos2(50.0, 50.0);
+
+    let everything = harness.render().unwrap();
+    assert_eq!(alpha(&everything, outside), 255, "the panel is painted");
+
+    let layer = egui::LayerId::new(egui::Order::Middle, egui::Id::new("Layer"));
+    let only_window = harness.render_layer(layer).unwrap();
+    assert_eq!(only_window.dimensions(), everything.dimensions());
+    assert_eq!(alpha(&only_window, inside), 255);
+    assert_eq!(
+        alpha(&only_window, outside),
+        0,
+        "other layers are not painted"
+    );
+}
+
+#[cfg(feature = "wgpu")]
+#[test]
+fn test_wgpu_premultiplied_alpha() {
+    let app = |ctx: &egui::Context| {
+        egui::CentralPanel::default()
+            .frame(egui::Frame::NONE)
+            .show(ctx, |ui| {
+                let rect = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(20.0, 20.0));
+                let color = egui::Color32::from_rgba_unmultiplied(255, 0, 0, 128);
+                ui.painter().rect_filled(rect, 0.0, color);
+            });
+    };
+    let render = |premultiplied: bool| {
+        Harness::builder()
+            .with_size(egui::vec2(40.0, 40.0))
+            .renderer(
+                egui_kittest::wgpu::WgpuTestRenderer::new().with_premultiplied_alpha(premultiplied),
+            )
+            .build(app)
+            .render()
+            .unwrap()
+    };
+
+    let straight = render(false);
+    let premultiplied = render(true);
+
+    let mut converted = straight.clone();
+    egui_kittest::premultiply_alpha(&mut converted);
+    assert_eq!(converted, premultiplied);
+
+    let [r, _, _, a] = premultiplied.get_pixel(5, 5).0;
+    assert!(
+        0 < r && r <= a,
+        "color can't exceed alpha when premultiplied"
+    );
+    assert_eq!(
+        premultiplied.get_pixel(35, 35).0,
+        [0; 4],
+        "transparent pixels have zero rgb"
+    );
+}
+
+#[cfg(any(feature = "wgpu", feature = "snapshot"))]
+#[test]
+fn test_premultiply_alpha() {
+    let mut image = image::RgbaImage::from_fn(3, 1, |x, _| match x {
+        0 => image::Rgba([255, 128, 10, 255]),
+        1 => image::Rgba([255, 128, 10, 128]),
+        _ => image::Rgba([255, 128, 10, 0]),
+    });
+    egui_kittest::premultiply_alpha(&mut image);
+    assert_eq!(image.get_pixel(0, 0).0, [255, 128, 10, 255]);
+    assert_eq!(image.get_pixel(1, 0).0, [128, 64, 5, 128]);
+    assert_eq!(image.get_pixel(2, 0).0, [0, 0, 0, 0]);
+}
+
+#[test]
+fn test_frozen_time() {
+    let mut harness = Harness::builder().with_frozen_time(12.5).build_ui_state(
+        |ui, time: &mut f64| {
+            *time = ui.input(|i| i.time);
+            ui.spinner();
+            ui.skeleton([100.0, 20.0]);
+        },
+        0.0,
+    );
+
+    harness.step();
+    let shapes = harness.output().shapes.clone();
+    harness.run_steps(3);
+    assert_eq!(*harness.state(), 12.5);
+    assert_eq!(
+        harness.output().shapes,
+        shapes,
+        "animated widgets should look the same on every step"
+    );
+}
+
+#[test]
+fn test_repaint_requests() {
+    let mut harness = Harness::new_ui_state(
+        |ui, spin: &mut bool| {
+            ui.checkbox(spin, "Spin");
+            if *spin {
+                ui.spinner();
+            }
+        },
+        false,
+    );
+    harness.run();
+    assert!(!harness.repaint_requested());
+    assert_eq!(
+        harness.repaint_delay(),
+        None,
+        "Nothing should repaint when idle"
+    );
+
+    harness.get_by_label("Spin").click();
+    harness.run_steps(2);
+    assert!(harness.repaint_requested(), "The spinner animates");
+    assert_eq!(harness.repaint_delay(), Some(std::time::Duration::ZERO));
+
+    harness.get_by_label("Spin").click();
+    harness.run();
+    assert_eq!(harness.repaint_delay(), None);
+}
+
+#[test]
+fn test_get_by_text() {
+    let mut harness = Harness::new_ui_state(
+        |ui, text: &mut String| {
+            ui.label("Name");
+            ui.text_edit_singleline(text);
+            let _ = ui.button("Save");
+        },
+        "Ferris".to_owned(),
+    );
+    harness.run();
+
+    assert_eq!(
+        harness.get_by_text("Ferris").role(),
+        egui::accesskit::Role::TextInput,
+        "Found by its value"
+    );
+    assert_eq!(
+        harness.get_by_text("Save").role(),
+        egui::accesskit::Role::Button,
+        "Found by its label"
+    );
+    assert!(harness.query_by_text("Nobody").is_none());
+}
+
+#[test]
+#[should_panic(expected = "Found two or more nodes")]
+fn test_get_by_text_panics_on_multiple_matches() {
+    let harness = Harness::new_ui(|ui| {
+        ui.label("Same");
+        ui.label("Same");
+    });
+    harness.get_by_text("Same");
+}
+
+#[cfg(feature = "wgpu")]
+#[test]
+fn test_render_font_atlas() {
+    let mut harness = Harness::new_ui(|ui| {
+        ui.label("Glyphs");
+    });
+    harness.render().unwrap();
+
+    let atlas = harness.render_font_atlas().unwrap();
+    let [width, height] = harness.ctx.fonts(|fonts| fonts.font_image_size());
+    assert_eq!(atlas.dimensions(), (width as u32, height as u32));
+    assert!(
+        atlas.pixels().any(|pixel| pixel[3] > 0),
+        "The atlas contains glyphs"
+    );
+    assert!(
+        atlas
+            .pixels()
+            .filter(|pixel| pixel[3] == 255)
+            .all(|pixel| pixel.0[..3] == [255, 255, 255]),
+        "The atlas is white"
+    );
+}
+
+#[cfg(feature = "wgpu")]
+#[test]
+fn test_multiple_viewports() {
+    let immediate = egui::ViewportId::from_hash_of("immediate");
+    let deferred = egui::ViewportId::from_hash_of("deferred");
+    let app = move |ctx: &egui::Context| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.label("Root");
+        });
+        ctx.show_viewport_immediate(
+            immediate,
+            egui::ViewportBuilder::default().with_inner_size([120.0, 80.0]),
+            |ctx, _class| {
+                egui::CentralPanel::default()
+                    .frame(egui::Frame::NONE.fill(egui::Color32::RED))
+                    .show(ctx, |ui| {
+                        ui.label("Immediate");
+                    });
+            },
+        );
+        ctx.show_viewport_deferred(
+            deferred,
+            egui::ViewportBuilder::default().with_inner_size([60.0, 40.0]),
+            |ctx, _class| {
+                egui::CentralPanel::default()
+                    .frame(egui::Frame::NONE.fill(egui::Color32::BLUE))
+                    .show(ctx, |_ui| {});
+            },
+        );
+    };
+
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(200.0, 200.0))
+        .with_multiple_viewports(true)
+        .build(app);
+    let mut ids = vec![immediate, deferred];
+    ids.sort_by_key(|id| id.0.value());
+    ids.insert(0, egui::ViewportId::ROOT);
+    assert_eq!(harness.viewport_ids(), ids);
+    assert!(
+        harness.query_by_label("Immediate").is_none(),
+        "the viewports are not embedded in the root"
+    );
+
+    let root = harness.render_viewport(egui::ViewportId::ROOT).unwrap();
+    assert_eq!(root.dimensions(), (200, 200));
+    let image = harness.render_viewport(immediate).unwrap();
+    assert_eq!(image.dimensions(), (120, 80));
+    assert_eq!(image.get_pixel(100, 70).0, [255, 0, 0, 255]);
+    let image = harness.render_viewport(deferred).unwrap();
+    assert_eq!(image.dimensions(), (60, 40));
+    assert_eq!(image.get_pixel(30, 20).0, [0, 0, 255, 255]);
+    assert!(harness
+        .render_viewport(egui::ViewportId::from_hash_of("closed"))
+        .is_err());
+
+    // By default, the viewports are embedded as windows:
+    let harness = Harness::new(app);
+    assert_eq!(harness.viewport_ids(), vec![egui::ViewportId::ROOT]);
+    assert!(harness.query_by_label("Immediate").is_some());
+}
+
+#[cfg(feature = "wgpu")]
+#[test]
+fn test_central_panel_background_gradient() {
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(100.0, 100.0))
+        .build(|ctx| {
+            egui::CentralPanel::default()
+                .background(egui::PanelBackground::linear_gradient(
+                    egui::Direction::TopDown,
+                    egui::Color32::RED,
+                    egui::Color32::BLUE,
+                ))
+                .show(ctx, |ui| {
+                    ui.label("On top");
+                });
+        });
+
+    let image = harness.render().unwrap();
+    let top = image.get_pixel(50, 0).0;
+    let bottom = image.get_pixel(50, 99).0;
+    assert!(top[0] > 200 && top[2] < 50, "red at the top: {top:?}");
+    assert!(
+        bottom[2] > 200 && bottom[0] < 50,
+        "blue at the bottom: {bottom:?}"
+    );
+
+    // The contents are painted over the background:
+    let label = harness.get_by_label("On top").raw_bounds().unwrap();
+    let row = label.y0 as u32 + (label.y1 - label.y0) as u32 / 2;
+    let background = image.get_pixel(90, row).0;
+    assert!(
+        (label.x0 as u32..label.x1 as u32).any(|x| image.get_pixel(x, row).0 != background),
+        "the label is visible"
+    );
+}
+
+#[test]
+fn test_clipboard() {
+    let mut harness = Harness::new_ui_state(
+        |ui, text| {
+            ui.add(egui::TextEdit::singleline(text).id_salt("text"));
+        },
+        String::new(),
+    );
+
+    harness
+        .get_by_role(egui::accesskit::Role::TextInput)
+        .focus();
+    harness.run();
+
+    // Nothing to paste yet:
+    harness.press_key_modifiers(Modifiers::COMMAND, egui::Key::V);
+    assert_eq!(harness.state(), "");
+
+    harness.set_clipboard("hello");
+    harness.press_key_modifiers(Modifiers::COMMAND, egui::Key::V);
+    assert_eq!(harness.state(), "hello");
+
+    harness.press_key_modifiers(Modifiers::COMMAND, egui::Key::A);
+    harness.press_key_modifiers(Modifiers::COMMAND, egui::Key::X);
+    assert_eq!(harness.state(), "");
+    assert_eq!(harness.clipboard(), "hello");
+
+    harness.set_clipboard("bye");
+    harness.press_key_modifiers(Modifiers::COMMAND, egui::Key::V);
+    harness.press_key_modifiers(Modifiers::COMMAND, egui::Key::A);
+    harness.press_key_modifiers(Modifiers::COMMAND, egui::Key::C);
+    assert_eq!(harness.state(), "bye");
+    assert_eq!(harness.clipboard(), "bye");
+}
+
+#[test]
+fn test_step_output() {
+    let mut harness = Harness::new_ui_state(
+        |ui, text| {
+            ui.add(egui::TextEdit::singleline(text).id_salt("text"));
+        },
+        String::new(),
+    );
+
+    let step = harness.step();
+    assert!(step.shapes > 0);
+    assert!(step.textures_delta.is_empty(), "nothing new to upload");
+    assert!(!step.repaint_requested);
+    assert_eq!(step.focused, None);
+    assert_eq!(step.cursor_icon, egui::CursorIcon::Default);
+
+    harness
+        .get_by_role(egui::accesskit::Role::TextInput)
+        .hover();
+    let step = harness.step();
+    assert_eq!(step.cursor_icon, egui::CursorIcon::Text);
+
+    let text_edit = harness.get_by_role(egui::accesskit::Role::TextInput);
+    let text_edit_id = text_edit.id();
+    text_edit.focus();
+    let step = harness.step();
+    assert_eq!(step.focused.map(|id| id.value()), Some(text_edit_id.0));
+
+    // New glyphs are uploaded to the font atlas:
+    harness
+        .get_by_role(egui::accesskit::Role::TextInput)
+        .type_text("Ω");
+    let step = harness.step();
+    assert_eq!(harness.state(), "Ω");
+    assert!(!step.textures_delta.set.is_empty());
+}
+
+#[test]
+fn test_zoom_factor() {
+    let layout_at = |zoom_factor: f32| {
+        let mut harness = Harness::builder()
+            .with_size(egui::Vec2::new(300.0, 200.0))
+            .with_zoom_factor(zoom_factor)
+            .build_ui(|ui| {
+                let _ = ui.button("Button");
+                ui.label("Some text that is long enough to wrap when there is less room");
+            });
+        harness.run();
+
+        let screen = harness.ctx.screen_rect().size();
+        let button = harness.get_by_label("Button").raw_bounds().unwrap();
+        let text = harness
+            .get_by_label("Some text that is long enough to wrap when there is less room")
+            .raw_bounds()
+            .unwrap();
+
+        #[cfg(feature = "wgpu")]
+        {
+            let image = harness.render().unwrap();
+            assert_eq!(
+                (image.width(), image.height()),
+                (300, 200),
+                "the window keeps its size in pixels"
+            );
+        }
+
+        (screen, button.width(), text.height())
+    };
+
+    let (screen, button_width, text_height) = layout_at(1.0);
+    assert_eq!(screen, egui::Vec2::new(300.0, 200.0));
+
+    // There is less room in points, but a button is as many points wide:
+    let (zoomed_screen, zoomed_button_width, zoomed_text_height) = layout_at(1.5);
+    assert!(
+        (zoomed_screen * 1.5 - screen).length() < 0.1,
+        "{zoomed_screen:?}"
+    );
+    assert_eq!(zoomed_button_width, button_width);
+    assert!(
+        zoomed_text_height > text_height,
+        "the text wraps onto more lines"
+    );
+}
+
+#[test]
+fn test_set_screen_size_reflows_panels_and_windows() {
+    struct Rects {
+        side_panel: egui::Rect,
+        central_panel: egui::Rect,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(egui::Vec2::new(800.0, 400.0))
+        .build_state(
+            |ctx, rects: &mut Rects| {
+                rects.side_panel = egui::SidePanel::left("side")
+                    .min_width(300.0)
+                    .show(ctx, |ui| ui.label("Side"))
+                    .response
+                    .rect;
+                rects.central_panel = egui::CentralPanel::default()
+                    .show(ctx, |ui| ui.label("Central"))
+                    .response
+                    .rect;
+                egui::Window::new("Window")
+                    .default_pos(egui::pos2(150.0, 0.0))
+                    .show(ctx, |ui| {
+                        ui.label("Window contents");
+                    });
+            },
+            Rects {
+                side_panel: egui::Rect::NOTHING,
+                central_panel: egui::Rect::NOTHING,
+            },
+        );
+    harness.run();
+    assert_eq!(harness.state().side_panel.width(), 300.0);
+    assert_eq!(harness.state().central_panel.min.x, 300.0);
+    let window_before = harness
+        .ctx
+        .memory(|mem| mem.area_rect(egui::Id::new("Window")))
+        .unwrap();
+    assert_eq!(window_before.min.x, 150.0);
+
+    harness.set_screen_size(egui::Vec2::new(250.0, 300.0));
+    harness.run();
+    assert_eq!(
+        harness.ctx.screen_rect().size(),
+        egui::Vec2::new(250.0, 300.0)
+    );
+
+    let rects = harness.state();
+    assert!(
+        rects.side_panel.width() <= 250.0,
+        "the side panel can't be wider than the screen: {:?}",
+        rects.side_panel
+    );
+    assert!(
+        rects.central_panel.min.x >= rects.side_panel.max.x,
+        "the central panel moves along"
+    );
+    let window = harness
+        .ctx
+        .memory(|mem| mem.area_rect(egui::Id::new("Window")))
+        .unwrap();
+    assert!(
+        window.max.x <= 250.0,
+        "the window is moved back onto the screen: {window:?}"
+    );
+}
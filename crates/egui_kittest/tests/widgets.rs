@@ -0,0 +1,216 @@
+use egui::Vec2;
+use egui_kittest::{kittest::Queryable, Harness};
+
+#[test]
+fn toasts_expire_pause_on_hover_and_report_actions() {
+    #[derive(Default)]
+    struct State {
+        pending: Vec<egui::Toast>,
+        clicked: Vec<egui::Id>,
+    }
+
+    let mut harness = Harness::builder().with_step_dt(0.1).build_state(
+        |ctx, state: &mut State| {
+            let mut toasts = egui::Toasts::new();
+            for toast in state.pending.drain(..) {
+                toasts = toasts.add(toast);
+            }
+            state.clicked.extend(toasts.show(ctx));
+        },
+        State::default(),
+    );
+
+    harness.state_mut().pending.push(
+        egui::Toast::new("Undo me")
+            .id(egui::Id::new("undo"))
+            .duration(Some(1.0))
+            .action("Undo"),
+    );
+    harness.state_mut().pending.push(
+        egui::Toast::new("Short lived")
+            .duration(Some(1.0))
+            .closable(false),
+    );
+    harness.run_steps(3);
+    assert!(harness.query_by_label("Short lived").is_some());
+    assert!(harness.query_by_label("Undo me").is_some());
+
+    // Hovering a toast pauses its timer, while the other one expires:
+    harness.get_by_label("Undo me").hover();
+    harness.run_steps(15);
+    assert!(harness.query_by_label("Short lived").is_none());
+    assert!(harness.query_by_label("Undo me").is_some());
+
+    harness.get_by_label("Undo").simulate_click();
+    harness.run_steps(2);
+    assert_eq!(harness.state().clicked, vec![egui::Id::new("undo")]);
+    assert!(harness.query_by_label("Undo me").is_none());
+}
+
+#[test]
+fn skeleton_shimmer_moves_over_time() {
+    let mut harness = Harness::new_ui_state(
+        |ui, rect: &mut egui::Rect| {
+            *rect = ui.skeleton([100.0, 20.0]).rect;
+        },
+        egui::Rect::NOTHING,
+    );
+    harness.step();
+    assert_eq!(harness.state().size(), Vec2::new(100.0, 20.0));
+    assert!(
+        harness.run_ok().is_none(),
+        "the skeleton should keep requesting repaints"
+    );
+
+    let colors = |harness: &Harness<'_, egui::Rect>| -> Vec<egui::Color32> {
+        harness
+            .output()
+            .shapes
+            .iter()
+            .find_map(|clipped| match &clipped.shape {
+                egui::Shape::Mesh(mesh) => Some(mesh.vertices.iter().map(|v| v.color).collect()),
+                _ => None,
+            })
+            .expect("the skeleton should be painted as a mesh")
+    };
+    let before = colors(&harness);
+    harness.step();
+    assert_ne!(before, colors(&harness), "the shimmer should have moved");
+}
+
+#[test]
+fn visible_scope_skips_off_screen_content_but_keeps_its_size() {
+    struct State {
+        shown: Vec<usize>,
+        content_height: f32,
+        full_height: Option<f32>,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(200.0, 200.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                state.shown.clear();
+                let output = egui::ScrollArea::vertical().show(ui, |ui| {
+                    for i in 0..100 {
+                        ui.visible_scope(|ui| {
+                            state.shown.push(i);
+                            ui.label(format!("Item {i}"));
+                            if i % 10 == 0 {
+                                ui.label("Taller");
+                            }
+                        });
+                    }
+                });
+                state.content_height = output.content_size.y;
+                if state.shown.len() == 100 {
+                    state.full_height = Some(output.content_size.y);
+                }
+            },
+            State {
+                shown: Vec::new(),
+                content_height: 0.0,
+                full_height: None,
+            },
+        );
+    harness.run_steps(2);
+    let full_height = harness
+        .state()
+        .full_height
+        .expect("all sizes are learned first");
+
+    let shown = harness.state().shown.clone();
+    assert!(
+        shown.len() < 20,
+        "only the visible items are shown: {shown:?}"
+    );
+    assert_eq!(shown[0], 0);
+    assert_eq!(harness.state().content_height, full_height);
+    assert!(harness.query_by_label("Item 99").is_none());
+
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(egui::pos2(50.0, 50.0)));
+    harness.step();
+    harness.input_mut().events.push(egui::Event::MouseWheel {
+        unit: egui::MouseWheelUnit::Point,
+        delta: Vec2::new(0.0, -100_000.0),
+        modifiers: egui::Modifiers::NONE,
+    });
+    harness.run_steps(10);
+    harness.run();
+    assert!(harness.state().shown.contains(&99));
+    assert!(!harness.state().shown.contains(&0));
+    harness.get_by_label("Item 99");
+    assert_eq!(harness.state().content_height, full_height);
+}
+
+#[test]
+fn command_palette_filters_and_picks_with_the_keyboard() {
+    struct State {
+        open: bool,
+        ran: Vec<usize>,
+    }
+    let commands = ["Open file", "Save", "Save as…", "Close file", "Quit"];
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(600.0, 400.0))
+        .build_state(
+            move |ctx, state: &mut State| {
+                if state.open {
+                    let response = egui::CommandPalette::new("palette").show(ctx, &commands);
+                    if let Some(index) = response.selected {
+                        state.ran.push(index);
+                    }
+                    if response.selected.is_some() || response.closed {
+                        state.open = false;
+                    }
+                }
+            },
+            State {
+                open: true,
+                ran: Vec::new(),
+            },
+        );
+    harness.run();
+    for command in commands {
+        harness.get_by_label(command);
+    }
+
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::Text("fi".to_owned()));
+    harness.run();
+    assert!(harness.query_by_label("Save").is_none());
+    assert!(harness.query_by_label("Quit").is_none());
+    harness.get_by_label("Open file");
+    harness.get_by_label("Close file");
+
+    harness.press_key(egui::Key::ArrowDown);
+    harness.press_key(egui::Key::Enter);
+    harness.run();
+    assert_eq!(harness.state().ran, vec![3], "the second match is picked");
+    assert!(!harness.state().open);
+
+    // Opening it again starts with an empty query:
+    harness.state_mut().open = true;
+    harness.run();
+    harness.get_by_label("Quit");
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::Text("sva".to_owned()));
+    harness.run();
+    harness.get_by_label("Save as…").simulate_click();
+    harness.run();
+    assert_eq!(harness.state().ran, vec![3, 2]);
+
+    harness.state_mut().open = true;
+    harness.run();
+    harness.press_key(egui::Key::Escape);
+    harness.run();
+    assert!(!harness.state().open);
+    assert_eq!(harness.state().ran, vec![3, 2], "escape picks nothing");
+}
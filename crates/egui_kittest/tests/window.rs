@@ -0,0 +1,1240 @@
+mod common;
+
+use common::{drag_pointer, move_pointer, press_pointer, release_pointer, window_rect};
+use egui::Vec2;
+use egui_kittest::{kittest::Queryable, Harness};
+
+#[test]
+fn window_hit_shape_lets_clicks_and_drags_pass_outside_the_shape() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 300.0))
+        .build_state(
+            |ctx, (clicked, window_rect): &mut (bool, egui::Rect)| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    if ui.button("Behind").clicked() {
+                        *clicked = true;
+                    }
+                });
+
+                // A round window, covering the button with the corner of its bounding rect:
+                let response = egui::Window::new("HUD")
+                    .title_bar(false)
+                    .resizable(false)
+                    .frame(egui::Frame::NONE)
+                    .default_pos(egui::Pos2::ZERO)
+                    .hit_shape(|pos| pos.distance(egui::pos2(100.0, 100.0)) <= 90.0)
+                    .show(ctx, |ui| {
+                        let (rect, _) =
+                            ui.allocate_exact_size(Vec2::splat(200.0), egui::Sense::hover());
+                        ui.painter()
+                            .circle_filled(rect.center(), 90.0, egui::Color32::DARK_GRAY);
+                    })
+                    .unwrap();
+                *window_rect = response.response.rect;
+            },
+            (false, egui::Rect::NOTHING),
+        );
+    harness.run();
+
+    let button = harness.get_by_label("Behind").bounding_box().unwrap();
+    assert!(harness
+        .state()
+        .1
+        .contains(egui::pos2(button.x1 as f32, button.y1 as f32)));
+    harness.get_by_label("Behind").simulate_click();
+    harness.run();
+    assert!(
+        harness.state().0,
+        "The click should pass through the corner"
+    );
+
+    // Dragging outside of the circle doesn't move the window:
+    let rect = harness.state().1;
+    let corner = rect.left_bottom() + Vec2::new(5.0, -5.0);
+    drag_pointer(&mut harness, corner, corner + Vec2::new(50.0, 0.0));
+    assert_eq!(harness.state().1, rect);
+
+    // But dragging inside of it does:
+    drag_pointer(
+        &mut harness,
+        rect.center(),
+        rect.center() + Vec2::new(50.0, 30.0),
+    );
+    assert_eq!(harness.state().1, rect.translate(Vec2::new(50.0, 30.0)));
+}
+
+#[test]
+fn window_move_modifier_only_moves_with_modifier_held() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 400.0))
+        .build_state(
+            |ctx, canvas_drag: &mut Vec2| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let canvas = ui.allocate_rect(ui.max_rect(), egui::Sense::drag());
+                    *canvas_drag += canvas.drag_delta();
+                });
+                egui::Window::new("Tool")
+                    .default_pos(egui::pos2(100.0, 100.0))
+                    .resizable(false)
+                    .move_modifier(egui::Modifiers::ALT)
+                    .show(ctx, |ui| {
+                        ui.label("Tools");
+                    });
+            },
+            Vec2::ZERO,
+        );
+    harness.run();
+
+    let start = window_rect(&harness, egui::Id::new("Tool"));
+    let title = start.left_top() + Vec2::new(20.0, 10.0);
+
+    drag_pointer(&mut harness, title, title + Vec2::new(30.0, 20.0));
+    assert_eq!(
+        window_rect(&harness, egui::Id::new("Tool")),
+        start,
+        "plain drags don't move the window"
+    );
+    assert_eq!(
+        *harness.state(),
+        Vec2::new(30.0, 20.0),
+        "plain drags go to the canvas behind the window"
+    );
+
+    harness.input_mut().modifiers = egui::Modifiers::ALT;
+    drag_pointer(&mut harness, title, title + Vec2::new(30.0, 20.0));
+    assert_eq!(
+        window_rect(&harness, egui::Id::new("Tool")),
+        start.translate(Vec2::new(30.0, 20.0)),
+        "dragging with the modifier held moves the window"
+    );
+    assert_eq!(
+        *harness.state(),
+        Vec2::new(30.0, 20.0),
+        "the canvas doesn't see drags that move the window"
+    );
+}
+
+#[test]
+fn window_defer_content_during_drag_shows_a_ghost_while_moving() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 400.0))
+        .build_state(
+            |ctx, content_built: &mut bool| {
+                *content_built = egui::Window::new("Plot")
+                    .default_pos(egui::pos2(100.0, 100.0))
+                    .resizable(false)
+                    .defer_content_during_drag(true)
+                    .show(ctx, |ui| {
+                        ui.label("Expensive contents");
+                    })
+                    .is_some_and(|ir| ir.inner.is_some());
+            },
+            false,
+        );
+    harness.run();
+    assert!(harness.state());
+
+    let start = window_rect(&harness, egui::Id::new("Plot"));
+    let title = start.left_top() + Vec2::new(20.0, 10.0);
+
+    press_pointer(&mut harness, title);
+    let to = title + Vec2::new(30.0, 20.0);
+    move_pointer(&mut harness, to);
+    harness.step();
+    assert!(!harness.state(), "The contents aren't added while moving");
+    assert!(harness.query_by_label("Expensive contents").is_none());
+    assert_eq!(
+        window_rect(&harness, egui::Id::new("Plot")).size(),
+        start.size(),
+        "The ghost keeps the size of the window"
+    );
+
+    release_pointer(&mut harness, to);
+    harness.run();
+    assert!(harness.state());
+    harness.get_by_label("Expensive contents");
+    assert_eq!(
+        window_rect(&harness, egui::Id::new("Plot")),
+        start.translate(Vec2::new(30.0, 20.0))
+    );
+}
+
+#[test]
+fn window_on_shown_and_on_hidden_are_called_once_per_transition() {
+    #[derive(Default)]
+    struct State {
+        open: bool,
+        shown: usize,
+        hidden: usize,
+    }
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 400.0))
+        .with_step_dt(1.0 / 60.0)
+        .with_max_steps(60)
+        .build_state(
+            |ctx, state: &mut State| {
+                let State {
+                    open,
+                    shown,
+                    hidden,
+                } = state;
+                egui::Window::new("Status")
+                    .open(open)
+                    .on_shown(|| *shown += 1)
+                    .on_hidden(|| *hidden += 1)
+                    .show(ctx, |ui| {
+                        ui.label("Polling");
+                    });
+            },
+            State::default(),
+        );
+
+    harness.run();
+    assert_eq!((harness.state().shown, harness.state().hidden), (0, 0));
+
+    harness.state_mut().open = true;
+    harness.run();
+    assert_eq!((harness.state().shown, harness.state().hidden), (1, 0));
+
+    harness.run();
+    assert_eq!(
+        harness.state().shown,
+        1,
+        "Only called when it becomes visible"
+    );
+
+    harness.state_mut().open = false;
+    harness.step();
+    assert_eq!(harness.state().hidden, 0, "Not hidden while fading out");
+    harness.run();
+    assert_eq!((harness.state().shown, harness.state().hidden), (1, 1));
+
+    harness.state_mut().open = true;
+    harness.run();
+    assert_eq!((harness.state().shown, harness.state().hidden), (2, 1));
+}
+
+#[test]
+fn window_tab_grouping_merges_and_splits_windows() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(500.0, 400.0))
+        .build(|ctx| {
+            for (title, pos) in [("Alpha", [20.0, 20.0]), ("Beta", [250.0, 200.0])] {
+                egui::Window::new(title)
+                    .default_pos(pos)
+                    .tab_grouping(true)
+                    .show(ctx, |ui| {
+                        ui.label(format!("{title} contents"));
+                    });
+            }
+        });
+    harness.run();
+
+    let alpha = window_rect(&harness, egui::Id::new("Alpha"));
+    let beta = window_rect(&harness, egui::Id::new("Beta"));
+
+    // Drop Beta onto the title bar of Alpha:
+    drag_pointer(
+        &mut harness,
+        beta.left_top() + Vec2::new(40.0, 10.0),
+        alpha.left_top() + Vec2::new(40.0, 10.0),
+    );
+    let groups = egui::WindowTabGroups::load(&harness.ctx);
+    assert_eq!(groups.groups.len(), 1);
+    assert_eq!(
+        groups.groups[0].tabs,
+        vec![egui::Id::new("Alpha"), egui::Id::new("Beta")]
+    );
+    assert!(harness.query_by_label("Alpha contents").is_none());
+    harness.get_by_label("Beta contents");
+    let group_rect = window_rect(&harness, groups.groups[0].id);
+    assert_eq!(group_rect.min, alpha.min, "the group is where Alpha was");
+
+    // Pick the other tab:
+    harness.get_by_label("Alpha").click();
+    harness.run();
+    harness.get_by_label("Alpha contents");
+    assert!(harness.query_by_label("Beta contents").is_none());
+
+    // Drag a tab out of the title bar to split the windows again:
+    let tab = harness.get_by_label("Beta").bounding_box().unwrap();
+    let tab = egui::pos2(
+        (tab.x0 + tab.x1) as f32 / 2.0,
+        (tab.y0 + tab.y1) as f32 / 2.0,
+    );
+    drag_pointer(&mut harness, tab, egui::pos2(300.0, 300.0));
+    assert!(egui::WindowTabGroups::load(&harness.ctx).groups.is_empty());
+    harness.get_by_label("Alpha contents");
+    harness.get_by_label("Beta contents");
+    assert_eq!(
+        window_rect(&harness, egui::Id::new("Beta")).min,
+        egui::pos2(300.0, 300.0)
+    );
+}
+
+#[test]
+fn window_keyboard_move_and_resize_with_focus_inside() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 400.0))
+        .build(|ctx| {
+            egui::Window::new("Tool")
+                .default_pos(egui::pos2(100.0, 100.0))
+                .default_size([150.0, 100.0])
+                .max_size([170.0, 500.0])
+                .keyboard_move_and_resize(egui::Modifiers::ALT)
+                .keyboard_step(8.0, 32.0)
+                .show(ctx, |ui| {
+                    let _ = ui.button("Apply");
+                    // Fill the window, so that it follows its resized size:
+                    ui.allocate_space(ui.available_size());
+                });
+        });
+    harness.run();
+
+    let start = window_rect(&harness, egui::Id::new("Tool"));
+
+    harness.press_key_modifiers(egui::Modifiers::ALT, egui::Key::ArrowRight);
+    harness.run();
+    assert_eq!(
+        window_rect(&harness, egui::Id::new("Tool")),
+        start,
+        "nothing happens without focus"
+    );
+
+    harness.get_by_label("Apply").focus();
+    harness.run();
+
+    harness.press_key_modifiers(egui::Modifiers::ALT, egui::Key::ArrowRight);
+    harness.run();
+    let moved = window_rect(&harness, egui::Id::new("Tool"));
+    assert_eq!(moved, start.translate(Vec2::new(8.0, 0.0)));
+
+    harness.press_key_modifiers(
+        egui::Modifiers::ALT | egui::Modifiers::SHIFT,
+        egui::Key::ArrowDown,
+    );
+    harness.run();
+    let moved = window_rect(&harness, egui::Id::new("Tool"));
+    assert_eq!(moved, start.translate(Vec2::new(8.0, 32.0)));
+    assert!(
+        harness.get_by_label("Apply").is_focused(),
+        "the arrow keys don't move the focus"
+    );
+
+    harness.press_key_modifiers(
+        egui::Modifiers::ALT | egui::Modifiers::COMMAND,
+        egui::Key::ArrowDown,
+    );
+    harness.run();
+    let resized = window_rect(&harness, egui::Id::new("Tool"));
+    assert_eq!(resized.min, moved.min, "resizing keeps the left-top corner");
+    assert_eq!(resized.height(), moved.height() + 8.0);
+
+    for _ in 0..2 {
+        harness.press_key_modifiers(
+            egui::Modifiers::ALT | egui::Modifiers::COMMAND | egui::Modifiers::SHIFT,
+            egui::Key::ArrowRight,
+        );
+        harness.run();
+    }
+    assert_eq!(
+        window_rect(&harness, egui::Id::new("Tool")).width(),
+        resized.width() + 20.0,
+        "resizing stops at the max size"
+    );
+}
+
+#[test]
+fn window_reports_moved_and_resized() {
+    #[derive(Default)]
+    struct State {
+        moved: bool,
+        resized: bool,
+    }
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 400.0))
+        .build_state(
+            |ctx, state: &mut State| {
+                let response = egui::Window::new("Window")
+                    .default_pos(egui::pos2(100.0, 100.0))
+                    .default_size(Vec2::splat(100.0))
+                    .show(ctx, |ui| {
+                        // Fill the window, so that it follows its resized size:
+                        ui.allocate_space(ui.available_size());
+                    })
+                    .expect("window is open")
+                    .response;
+                state.moved |= response.moved();
+                state.resized |= response.resized();
+            },
+            State::default(),
+        );
+    harness.run();
+    assert!(!harness.state().moved && !harness.state().resized);
+
+    // Drag the title bar:
+    let title = window_rect(&harness, egui::Id::new("Window")).left_top() + Vec2::new(50.0, 10.0);
+    drag_pointer(&mut harness, title, title + Vec2::new(30.0, 20.0));
+    assert!(
+        harness.state().moved,
+        "dragging the title bar should move the window"
+    );
+    assert!(!harness.state().resized);
+
+    // Drag the right edge:
+    *harness.state_mut() = State::default();
+    let rect = window_rect(&harness, egui::Id::new("Window"));
+    let right_edge = egui::pos2(rect.right(), rect.center().y);
+    drag_pointer(&mut harness, right_edge, right_edge + Vec2::new(40.0, 0.0));
+    assert!(
+        harness.state().resized,
+        "dragging the edge should resize the window"
+    );
+    assert!(!harness.state().moved);
+}
+
+#[test]
+fn window_truncates_long_title_and_shows_it_in_a_tooltip() {
+    const TITLE: &str = "A window title that is much too long to fit the window";
+    let mut harness = Harness::builder().with_step_dt(1.0 / 60.0).build_state(
+        |ctx, window_rect: &mut egui::Rect| {
+            *window_rect = egui::Window::new(TITLE)
+                .max_width(120.0)
+                .show(ctx, |ui| {
+                    ui.label("Content");
+                })
+                .unwrap()
+                .response
+                .rect;
+        },
+        egui::Rect::NOTHING,
+    );
+    harness
+        .ctx
+        .style_mut(|style| style.interaction.tooltip_delay = 0.0);
+    harness.run();
+
+    let window_rect = *harness.state();
+    assert!(
+        window_rect.width() < 160.0,
+        "the title doesn't make the window wider than its max width: {window_rect:?}"
+    );
+    assert_eq!(
+        harness.query_all_by_label(TITLE).count(),
+        1,
+        "only the window itself, no tooltip yet"
+    );
+
+    // Hover the title:
+    let title_pos = egui::pos2(window_rect.center().x, window_rect.top() + 10.0);
+    for dy in (0..4).rev() {
+        harness.input_mut().events.push(egui::Event::PointerMoved(
+            title_pos + egui::vec2(0.0, 10.0 * dy as f32),
+        ));
+        harness.step();
+    }
+    for _ in 0..10 {
+        harness.step();
+    }
+    assert_eq!(
+        harness.query_all_by_label(TITLE).count(),
+        2,
+        "the full title is shown in a tooltip"
+    );
+}
+
+#[test]
+fn window_lock_aspect_ratio() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(600.0, 600.0))
+        .build_state(
+            |ctx, content_size: &mut Vec2| {
+                egui::Window::new("Video")
+                    .default_pos(egui::pos2(50.0, 50.0))
+                    .default_size(Vec2::new(200.0, 100.0))
+                    .lock_aspect_ratio(2.0)
+                    .show(ctx, |ui| {
+                        *content_size = ui.allocate_space(ui.available_size()).1.size();
+                    });
+            },
+            Vec2::ZERO,
+        );
+    harness.run();
+
+    let assert_ratio = |size: Vec2| {
+        assert!(
+            (size.x / size.y - 2.0).abs() < 0.05,
+            "unexpected content size {size:?}"
+        );
+    };
+
+    // Dragging the right edge also grows the height:
+    let rect = window_rect(&harness, egui::Id::new("Video"));
+    let right_edge = egui::pos2(rect.right(), rect.center().y);
+    drag_pointer(&mut harness, right_edge, right_edge + Vec2::new(100.0, 0.0));
+    let size = *harness.state();
+    assert!(size.x > 250.0, "unexpected content size {size:?}");
+    assert_ratio(size);
+
+    // Dragging the bottom edge also grows the width:
+    let rect = window_rect(&harness, egui::Id::new("Video"));
+    let bottom_edge = egui::pos2(rect.center().x, rect.bottom());
+    drag_pointer(
+        &mut harness,
+        bottom_edge,
+        bottom_edge + Vec2::new(0.0, 50.0),
+    );
+    assert!(harness.state().y > size.y + 25.0);
+    assert_ratio(*harness.state());
+}
+
+#[test]
+fn window_measure_first_is_placed_correctly_on_first_shown_frame() {
+    #[derive(Default)]
+    struct State {
+        shown: bool,
+        wide: bool,
+        rect: Option<egui::Rect>,
+    }
+
+    let window_rects = |measure_first: bool| {
+        let mut harness = Harness::new_state(
+            |ctx, state: &mut State| {
+                if !state.shown {
+                    return;
+                }
+                let text = if state.wide {
+                    "A label that is a lot wider than before"
+                } else {
+                    "Narrow"
+                };
+                state.rect = egui::Window::new("Anchored")
+                    .anchor(egui::Align2::RIGHT_TOP, Vec2::ZERO)
+                    .resizable(false)
+                    .measure_first(measure_first)
+                    .show(ctx, |ui| {
+                        ui.label(text);
+                    })
+                    .map(|response| response.response.rect);
+            },
+            State::default(),
+        );
+
+        harness.state_mut().shown = true;
+        harness.step();
+        let first = harness.state().rect.unwrap();
+        harness.run();
+        let settled = harness.state().rect.unwrap();
+
+        harness.state_mut().wide = true;
+        harness.step();
+        let grown = harness.state().rect.unwrap();
+        harness.run();
+        let grown_settled = harness.state().rect.unwrap();
+
+        (first, settled, grown, grown_settled)
+    };
+
+    let (first, settled, grown, grown_settled) = window_rects(true);
+    assert_eq!(first, settled);
+    assert_eq!(grown, grown_settled);
+    assert!(grown.width() > settled.width());
+    assert_eq!(grown.right(), 800.0, "stays anchored to the right edge");
+
+    let (first, settled, grown, grown_settled) = window_rects(false);
+    assert_ne!(
+        first, settled,
+        "without measuring first, the window starts out misplaced"
+    );
+    assert_ne!(grown, grown_settled);
+}
+
+#[test]
+fn window_snap_size_quantizes_resizing() {
+    let cell = Vec2::new(8.0, 16.0);
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(600.0, 600.0))
+        .build_state(
+            |ctx, content_size: &mut Vec2| {
+                egui::Window::new("Terminal")
+                    .default_pos(egui::pos2(50.0, 50.0))
+                    .default_size(Vec2::new(200.0, 96.0))
+                    .max_width(300.0)
+                    .snap_size(move |size| (size / cell).floor() * cell)
+                    .show(ctx, |ui| {
+                        *content_size = ui.allocate_space(ui.available_size()).1.size();
+                    });
+            },
+            Vec2::ZERO,
+        );
+    harness.run();
+
+    let rect = window_rect(&harness, egui::Id::new("Terminal"));
+    let corner = rect.right_bottom() - Vec2::splat(2.0);
+    drag_pointer(&mut harness, corner, corner + Vec2::new(21.0, 27.0));
+    assert_eq!(
+        harness.state().x,
+        216.0,
+        "200 + 21 rounded down to whole cells"
+    );
+    assert_eq!(
+        harness.state().y,
+        112.0,
+        "96 + 27 rounded down to whole cells"
+    );
+    assert_eq!(
+        window_rect(&harness, egui::Id::new("Terminal")).left_top(),
+        rect.left_top()
+    );
+
+    // The callback gets the size after clamping to the max width, and has the last word:
+    let rect = window_rect(&harness, egui::Id::new("Terminal"));
+    let right_edge = egui::pos2(rect.right(), rect.center().y);
+    drag_pointer(&mut harness, right_edge, right_edge + Vec2::new(200.0, 0.0));
+    assert_eq!(harness.state().x, 296.0, "300 rounded down to whole cells");
+}
+
+#[test]
+fn window_size_step_snaps_resizing() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(600.0, 600.0))
+        .build_state(
+            |ctx, content_size: &mut Vec2| {
+                egui::Window::new("Grid")
+                    .default_pos(egui::pos2(50.0, 50.0))
+                    .default_size(Vec2::new(200.0, 100.0))
+                    .size_step([50.0, 0.0])
+                    .max_width(320.0)
+                    .size_step_disable_modifiers(egui::Modifiers::ALT)
+                    .show(ctx, |ui| {
+                        *content_size = ui.allocate_space(ui.available_size()).1.size();
+                    });
+            },
+            Vec2::ZERO,
+        );
+    harness.run();
+
+    // Dragging the right edge by 37 points snaps the width to the next step:
+    let rect = window_rect(&harness, egui::Id::new("Grid"));
+    let right_edge = egui::pos2(rect.right(), rect.center().y);
+    drag_pointer(&mut harness, right_edge, right_edge + Vec2::new(37.0, 0.0));
+    assert_eq!(harness.state().x, 250.0);
+    assert_eq!(
+        window_rect(&harness, egui::Id::new("Grid")).left(),
+        rect.left(),
+        "left side stays put"
+    );
+
+    // Snapping down from the left edge keeps the right side in place:
+    let rect = window_rect(&harness, egui::Id::new("Grid"));
+    let left_edge = egui::pos2(rect.left(), rect.center().y);
+    drag_pointer(&mut harness, left_edge, left_edge + Vec2::new(30.0, 0.0));
+    assert_eq!(harness.state().x, 200.0);
+    assert_eq!(
+        window_rect(&harness, egui::Id::new("Grid")).right(),
+        rect.right(),
+        "right side stays put"
+    );
+
+    // The snapped width is clamped to the max width:
+    let rect = window_rect(&harness, egui::Id::new("Grid"));
+    let right_edge = egui::pos2(rect.right(), rect.center().y);
+    drag_pointer(&mut harness, right_edge, right_edge + Vec2::new(130.0, 0.0));
+    assert_eq!(harness.state().x, 320.0);
+
+    // Holding the modifier bypasses snapping:
+    harness.input_mut().modifiers = egui::Modifiers::ALT;
+    let rect = window_rect(&harness, egui::Id::new("Grid"));
+    let right_edge = egui::pos2(rect.right(), rect.center().y);
+    drag_pointer(&mut harness, right_edge, right_edge - Vec2::new(37.0, 0.0));
+    let width = harness.state().x;
+    assert!((width - 283.0).abs() <= 1.0, "unexpected width {width}");
+}
+
+#[test]
+fn window_resize_edge_thickness() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 400.0))
+        .build(|ctx| {
+            egui::Window::new("Window")
+                .default_pos(egui::pos2(100.0, 100.0))
+                .default_size(Vec2::splat(100.0))
+                .resize_edge_thickness(30.0)
+                .show(ctx, |ui| {
+                    ui.allocate_space(ui.available_size());
+                });
+        });
+    harness.run();
+
+    // Well outside the default grab radius, but within our thicker edge:
+    let rect = window_rect(&harness, egui::Id::new("Window"));
+    let near_edge = egui::pos2(rect.right() + 12.0, rect.center().y);
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(near_edge));
+    harness.run();
+    assert_eq!(
+        harness.output().platform_output.cursor_icon,
+        egui::CursorIcon::ResizeHorizontal
+    );
+
+    drag_pointer(&mut harness, near_edge, near_edge + Vec2::new(40.0, 0.0));
+    let resized = window_rect(&harness, egui::Id::new("Window"));
+    assert!(
+        resized.width() > rect.width() + 30.0,
+        "{rect:?} -> {resized:?}"
+    );
+}
+
+#[test]
+fn window_movable_anchor_follows_screen_resize() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 300.0))
+        .build(|ctx| {
+            egui::Window::new("Anchored")
+                .movable_anchor(egui::Align2::RIGHT_BOTTOM, [-10.0, -10.0])
+                .resizable(false)
+                .show(ctx, |ui| {
+                    ui.label("Stays in the corner");
+                });
+        });
+    harness.run();
+
+    let rect = window_rect(&harness, egui::Id::new("Anchored"));
+    assert_eq!(rect.right_bottom(), egui::pos2(390.0, 290.0));
+
+    // Moving the window changes its offset from the corner:
+    let title = egui::pos2(rect.center().x, rect.top() + 10.0);
+    drag_pointer(&mut harness, title, title - Vec2::new(50.0, 20.0));
+    let moved = window_rect(&harness, egui::Id::new("Anchored"));
+    assert_eq!(moved.right_bottom(), egui::pos2(340.0, 270.0));
+
+    harness.set_size(Vec2::new(600.0, 500.0));
+    harness.run();
+    assert_eq!(
+        window_rect(&harness, egui::Id::new("Anchored")).right_bottom(),
+        egui::pos2(540.0, 470.0),
+        "keeps its offset from the bottom-right corner"
+    );
+}
+
+#[test]
+fn window_collapsed_state_from_code() {
+    let mut harness = Harness::new_state(
+        |ctx, force_collapsed: &mut Option<bool>| {
+            egui::Window::new("Win")
+                .open_collapsed(force_collapsed.take())
+                .show(ctx, |ui| {
+                    ui.label("Body");
+                });
+        },
+        None,
+    );
+    harness.run();
+    let id = egui::Id::new("Win");
+    assert!(!egui::Window::is_collapsed(&harness.ctx, id));
+    assert!(harness.query_by_label("Body").is_some());
+
+    egui::Window::set_collapsed(&harness.ctx, id, true);
+    harness.run();
+    assert!(egui::Window::is_collapsed(&harness.ctx, id));
+    assert!(harness.query_by_label("Body").is_none());
+
+    *harness.state_mut() = Some(false);
+    harness.run();
+    assert!(!egui::Window::is_collapsed(&harness.ctx, id));
+    assert!(harness.query_by_label("Body").is_some());
+}
+
+#[test]
+fn window_scroll_body_follows_window_size() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 400.0))
+        .build_state(
+            |ctx, viewport: &mut egui::Rect| {
+                egui::Window::new("Scrolling")
+                    .default_pos(egui::pos2(50.0, 50.0))
+                    .default_size(Vec2::splat(150.0))
+                    .scroll(true)
+                    .show(ctx, |ui| {
+                        *viewport = ui.clip_rect();
+                        for i in 0..100 {
+                            ui.label(format!("Row {i} with enough text to wrap"));
+                        }
+                    });
+            },
+            egui::Rect::NOTHING,
+        );
+    harness.run();
+
+    let rect = window_rect(&harness, egui::Id::new("Scrolling"));
+    let viewport = *harness.state();
+    assert!(rect.height() < 250.0, "the content doesn't grow the window");
+    assert!(rect.contains_rect(viewport));
+    assert!(
+        rect.bottom() - viewport.bottom() < 10.0,
+        "the scroll area fills the window"
+    );
+
+    harness.run();
+    assert_eq!(
+        window_rect(&harness, egui::Id::new("Scrolling")),
+        rect,
+        "the size is stable"
+    );
+
+    let corner = rect.right_bottom() - Vec2::splat(2.0);
+    drag_pointer(&mut harness, corner, corner + Vec2::new(60.0, 80.0));
+    let resized = window_rect(&harness, egui::Id::new("Scrolling"));
+    let resized_viewport = *harness.state();
+    assert!(resized.height() > rect.height() + 50.0);
+    assert_eq!(
+        resized_viewport.size() - viewport.size(),
+        resized.size() - rect.size(),
+        "the scroll area resizes with the window"
+    );
+}
+
+#[test]
+fn context_windows_lists_open_windows_for_a_taskbar() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(500.0, 400.0))
+        .build(|ctx| {
+            egui::TopBottomPanel::bottom("taskbar").show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    for info in ctx.windows() {
+                        if ui.button(format!("Task: {}", info.title)).clicked() {
+                            ctx.move_to_top(info.layer_id);
+                            egui::Window::set_collapsed(ctx, info.id, false);
+                        }
+                    }
+                });
+            });
+            egui::Window::new("Editor")
+                .default_pos(egui::pos2(20.0, 20.0))
+                .show(ctx, |ui| ui.label("Editing"));
+            egui::Window::new("Console")
+                .default_pos(egui::pos2(200.0, 20.0))
+                .default_open(false)
+                .show(ctx, |ui| ui.label("Output"));
+            egui::Window::new("Closed")
+                .open(&mut false)
+                .show(ctx, |ui| ui.label("Hidden"));
+        });
+    harness.run();
+
+    let windows = harness.ctx.windows();
+    let titles: Vec<&str> = windows.iter().map(|w| w.title.as_str()).collect();
+    assert_eq!(titles, ["Editor", "Console"]);
+    assert_eq!(windows[0].id, egui::Id::new("Editor"));
+    assert!(!windows[0].collapsed);
+    assert!(windows[1].collapsed);
+    for info in &windows {
+        assert_eq!(
+            Some(info.rect),
+            harness.ctx.memory(|mem| mem.area_rect(info.id))
+        );
+    }
+
+    harness.get_by_label("Task: Console").click();
+    harness.run_steps(4);
+
+    let console = harness.ctx.windows()[1].clone();
+    assert!(!console.collapsed, "clicking the task restores the window");
+    assert_eq!(harness.ctx.top_layer_id(), Some(console.layer_id));
+}
+
+#[test]
+fn window_tear_off_fires_when_dragged_past_the_screen_edge() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 400.0))
+        .build_state(
+            |ctx, torn_off: &mut Vec<egui::Rect>| {
+                egui::Window::new("Tool")
+                    .default_pos(egui::pos2(50.0, 50.0))
+                    .on_tear_off(|rect| torn_off.push(rect))
+                    .show(ctx, |ui| ui.label("Tool contents"));
+            },
+            Vec::new(),
+        );
+    harness.run();
+    let id = egui::Id::new("Tool");
+    let start = window_rect(&harness, id);
+    let title_bar = start.left_top() + Vec2::new(20.0, 10.0);
+
+    // Without the modifier, the window just stops at the edge:
+    drag_pointer(&mut harness, title_bar, egui::pos2(-100.0, title_bar.y));
+    assert!(harness.state().is_empty());
+
+    // Move it back:
+    let title_bar = window_rect(&harness, id).left_top() + Vec2::new(20.0, 10.0);
+    drag_pointer(&mut harness, title_bar, title_bar + Vec2::new(100.0, 0.0));
+    let title_bar = window_rect(&harness, id).left_top() + Vec2::new(20.0, 10.0);
+
+    // Not far enough past the edge:
+    harness.input_mut().modifiers = egui::Modifiers::COMMAND;
+    drag_pointer(&mut harness, title_bar, egui::pos2(-10.0, title_bar.y));
+    assert!(harness.state().is_empty());
+
+    // Far enough: fires once, with the rect following the pointer.
+    let title_bar = window_rect(&harness, id).left_top() + Vec2::new(20.0, 10.0);
+    drag_pointer(&mut harness, title_bar, egui::pos2(-100.0, title_bar.y));
+    let torn_off = harness.state();
+    assert_eq!(torn_off.len(), 1);
+    assert_eq!(torn_off[0].size(), start.size());
+    assert_eq!(torn_off[0].left(), -120.0);
+}
+
+#[test]
+fn window_pin_button_keeps_window_on_top() {
+    let mut harness = Harness::builder().build_state(
+        |ctx, pinned: &mut bool| {
+            egui::Window::new("Palette")
+                .default_pos(egui::pos2(10.0, 10.0))
+                .pin_button(pinned)
+                .show(ctx, |ui| ui.label("Palette contents"));
+            egui::Window::new("Document")
+                .default_pos(egui::pos2(60.0, 60.0))
+                .show(ctx, |ui| ui.label("Document contents"));
+        },
+        false,
+    );
+    harness.run();
+
+    let overlap = window_rect(&harness, egui::Id::new("Palette"))
+        .intersect(window_rect(&harness, egui::Id::new("Document")))
+        .center();
+    let layer_at_overlap =
+        |harness: &Harness<'_, bool>| harness.ctx.layer_id_at(overlap).unwrap().id;
+    assert_eq!(layer_at_overlap(&harness), egui::Id::new("Document"));
+
+    harness.get_by_label("Keep window on top").simulate_click();
+    harness.run();
+    assert!(*harness.state());
+    assert_eq!(layer_at_overlap(&harness), egui::Id::new("Palette"));
+
+    // Clicking the other window doesn't bring it above the pinned one:
+    harness.get_by_label("Document contents").simulate_click();
+    harness.run();
+    assert_eq!(layer_at_overlap(&harness), egui::Id::new("Palette"));
+
+    harness.get_by_label("Keep window on top").simulate_click();
+    harness.run();
+    assert!(!*harness.state());
+    harness.get_by_label("Document contents").simulate_click();
+    harness.run();
+    assert_eq!(layer_at_overlap(&harness), egui::Id::new("Document"));
+}
+
+#[test]
+fn always_on_top_windows_stack_among_themselves() {
+    let mut harness = Harness::builder().build(|ctx| {
+        for (title, pos) in [("First", 10.0), ("Second", 60.0)] {
+            egui::Window::new(title)
+                .default_pos(egui::pos2(pos, pos))
+                .always_on_top(true)
+                .show(ctx, |ui| ui.label(format!("{title} contents")));
+        }
+        egui::Window::new("Normal")
+            .default_pos(egui::pos2(30.0, 30.0))
+            .show(ctx, |ui| ui.label("Normal contents"));
+    });
+    harness.run();
+
+    let overlap = window_rect(&harness, egui::Id::new("First"))
+        .intersect(window_rect(&harness, egui::Id::new("Second")))
+        .center();
+    assert_eq!(
+        harness.ctx.layer_id_at(overlap).unwrap().id,
+        egui::Id::new("Second")
+    );
+    harness.get_by_label("First").simulate_click();
+    harness.run();
+    assert_eq!(
+        harness.ctx.layer_id_at(overlap).unwrap().id,
+        egui::Id::new("First")
+    );
+}
+
+#[test]
+fn window_help_button_toggles_help_popover() {
+    let mut harness = Harness::new(|ctx| {
+        egui::Window::new("Settings")
+            .default_pos(egui::pos2(300.0, 20.0))
+            .help(|ui| {
+                ui.label("Changes are applied immediately");
+            })
+            .show(ctx, |ui| ui.label("Settings contents"));
+    });
+
+    assert!(harness
+        .query_by_label("Changes are applied immediately")
+        .is_none());
+
+    harness.get_by_label("Help").click();
+    harness.run();
+    let help_button = harness.get_by_label("Help").raw_bounds().unwrap();
+    let help_text = harness
+        .get_by_label("Changes are applied immediately")
+        .raw_bounds()
+        .unwrap();
+    assert!(
+        help_text.y0 >= help_button.y1,
+        "Help is shown below the button"
+    );
+    let screen = harness.ctx.screen_rect();
+    assert!(help_text.x0 >= screen.left() as f64 && help_text.x1 <= screen.right() as f64);
+
+    // Clicking the button again closes it:
+    harness.get_by_label("Help").click();
+    harness.run();
+    assert!(harness
+        .query_by_label("Changes are applied immediately")
+        .is_none());
+
+    // Clicking outside of it closes it too:
+    harness.get_by_label("Help").click();
+    harness.run();
+    assert!(harness
+        .query_by_label("Changes are applied immediately")
+        .is_some());
+    let outside = egui::pos2(10.0, 400.0);
+    drag_pointer(&mut harness, outside, outside);
+    assert!(harness
+        .query_by_label("Changes are applied immediately")
+        .is_none());
+}
+
+#[test]
+fn window_title_bar_side_puts_the_controls_on_that_edge() {
+    use egui::TitleBarSide;
+
+    for side in [
+        TitleBarSide::Top,
+        TitleBarSide::Bottom,
+        TitleBarSide::Left,
+        TitleBarSide::Right,
+    ] {
+        let mut harness = Harness::new_state(
+            |ctx, open: &mut bool| {
+                egui::Window::new("Tool")
+                    .default_pos(egui::pos2(50.0, 50.0))
+                    .title_bar_side(side)
+                    .open(open)
+                    .show(ctx, |ui| ui.label("Body"));
+            },
+            true,
+        );
+        harness.run();
+
+        let body = harness.get_by_label("Body").bounding_box().unwrap();
+        let close = harness.get_by_label("Close window").bounding_box().unwrap();
+        let on_that_edge = match side {
+            TitleBarSide::Top => close.y1 <= body.y0,
+            TitleBarSide::Bottom => close.y0 >= body.y1,
+            TitleBarSide::Left => close.x1 <= body.x0,
+            TitleBarSide::Right => close.x0 >= body.x1,
+        };
+        assert!(on_that_edge, "{side:?}: close {close:?}, body {body:?}");
+
+        harness.get_by_label("Close window").click();
+        harness.run();
+        assert!(!*harness.state(), "{side:?}");
+    }
+}
+
+#[test]
+fn window_resize_group_moves_the_shared_edge_of_both_windows() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 300.0))
+        .build(|ctx| {
+            // The default rect is of the contents, so leave room for the frame of the left window:
+            for (title, x) in [("Left", 0.0), ("Right", 164.0)] {
+                egui::Window::new(title)
+                    .resize_group("tiles")
+                    .default_rect(egui::Rect::from_min_size(
+                        egui::pos2(x, 20.0),
+                        Vec2::new(150.0, 200.0),
+                    ))
+                    .min_width(100.0)
+                    .show(ctx, |ui| {
+                        ui.allocate_space(ui.available_size());
+                    });
+            }
+        });
+    harness.run();
+    let (left, right) = (
+        window_rect(&harness, egui::Id::new("Left")),
+        window_rect(&harness, egui::Id::new("Right")),
+    );
+    assert_eq!(left.max.x, right.min.x, "flush to begin with");
+
+    let border = egui::pos2(left.max.x - 1.0, left.center().y);
+    drag_pointer(&mut harness, border, border + Vec2::new(40.0, 0.0));
+    let (new_left, new_right) = (
+        window_rect(&harness, egui::Id::new("Left")),
+        window_rect(&harness, egui::Id::new("Right")),
+    );
+    assert_eq!(new_left.min, left.min);
+    assert!(
+        (new_left.max.x - (left.max.x + 40.0)).abs() < 2.0,
+        "follows the pointer: {new_left:?}"
+    );
+    assert_eq!(new_right.min.x, new_left.max.x, "still flush");
+    assert_eq!(new_right.max, right.max, "the far edge stays put");
+
+    // The border stops where the right window reaches its minimum width:
+    let border = egui::pos2(new_left.max.x - 1.0, new_left.center().y);
+    drag_pointer(&mut harness, border, border + Vec2::new(200.0, 0.0));
+    let (new_left, new_right) = (
+        window_rect(&harness, egui::Id::new("Left")),
+        window_rect(&harness, egui::Id::new("Right")),
+    );
+    assert_eq!(new_right.min.x, new_left.max.x);
+    assert_eq!(new_right.max, right.max);
+    assert_eq!(
+        new_right.width(),
+        114.0,
+        "the minimum width, with the frame"
+    );
+}
+
+#[test]
+fn window_keep_on_screen_min_keeps_the_title_bar_reachable() {
+    let id = egui::Id::new("Off-screen");
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 300.0))
+        .build(move |ctx| {
+            egui::Window::new("Off-screen")
+                .id(id)
+                .constrain(false)
+                .keep_on_screen_min([60.0, 20.0])
+                .default_pos([50.0, 50.0])
+                .show(ctx, |ui| {
+                    ui.label("Hello");
+                });
+        });
+    harness.run();
+    let start = window_rect(&harness, id);
+    let grab = egui::pos2(start.left() + 10.0, start.top() + 8.0);
+
+    drag_pointer(&mut harness, grab, grab + Vec2::new(1000.0, 1000.0));
+    let rect = window_rect(&harness, id);
+    assert_eq!(rect.left_top(), egui::pos2(340.0, 280.0));
+
+    drag_pointer(
+        &mut harness,
+        rect.left_top() + Vec2::new(10.0, 8.0),
+        egui::pos2(-1000.0, -1000.0),
+    );
+    let rect = window_rect(&harness, id);
+    assert_eq!(rect.right(), 60.0, "60 points stay on screen horizontally");
+    assert_eq!(rect.top(), 0.0, "the title bar never goes above the screen");
+
+    drag_pointer(
+        &mut harness,
+        rect.right_top() + Vec2::new(-10.0, 8.0),
+        egui::pos2(390.0, 290.0),
+    );
+    harness.set_screen_size(Vec2::new(200.0, 150.0));
+    harness.run();
+    let rect = window_rect(&harness, id);
+    assert!(
+        rect.left() <= 140.0 && rect.top() <= 130.0,
+        "shrinking the screen nudges the window back: {rect:?}"
+    );
+}
+
+#[test]
+fn window_dense_only_tightens_the_spacing_of_its_contents() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(600.0, 300.0))
+        .build(|ctx| {
+            for (title, dense, x) in [("Normal", false, 0.0), ("Dense", true, 300.0)] {
+                egui::Window::new(title)
+                    .dense(dense)
+                    .default_pos([x, 0.0])
+                    .show(ctx, |ui| {
+                        let _ = ui.button(format!("{title} A"));
+                        let _ = ui.button(format!("{title} B"));
+                    });
+            }
+            egui::CentralPanel::default().show(ctx, |ui| {
+                assert_eq!(ui.spacing().item_spacing, egui::vec2(8.0, 3.0));
+            });
+        });
+    harness.run();
+
+    let gap = |harness: &Harness<'_>, title: &str| {
+        let a = harness
+            .get_by_label(&format!("{title} A"))
+            .raw_bounds()
+            .unwrap();
+        let b = harness
+            .get_by_label(&format!("{title} B"))
+            .raw_bounds()
+            .unwrap();
+        (b.y0 - a.y1, a.y1 - a.y0)
+    };
+    let (normal_gap, normal_height) = gap(&harness, "Normal");
+    let (dense_gap, dense_height) = gap(&harness, "Dense");
+    assert_eq!(normal_gap, 3.0);
+    assert_eq!(dense_gap, 1.5);
+    assert!(dense_height < normal_height);
+}
+
+#[test]
+fn window_state_round_trips_through_json_into_a_new_session() {
+    let id = egui::Id::new("Tool");
+    let app = |ctx: &egui::Context| {
+        egui::Window::new("Tool")
+            .default_pos([20.0, 20.0])
+            .default_size([150.0, 100.0])
+            .show(ctx, |ui| {
+                ui.label("Contents");
+                ui.allocate_space(ui.available_size());
+            });
+    };
+
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(600.0, 400.0))
+        .build(app);
+    harness.run();
+    let title = window_rect(&harness, id).min + Vec2::new(60.0, 10.0);
+    drag_pointer(&mut harness, title, title + Vec2::new(100.0, 50.0));
+    harness.run();
+    egui::Window::set_collapsed(&harness.ctx, id, true);
+    harness.run();
+
+    let saved = harness.ctx.window_state(id).unwrap();
+    assert_eq!(saved.pos, egui::pos2(120.0, 70.0));
+    assert!(saved.collapsed);
+    let json = serde_json::to_string(&saved).unwrap();
+    let loaded: egui::WindowState = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded, saved);
+
+    // The restored state wins over the defaults of the builder:
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(600.0, 400.0))
+        .build(app);
+    let restored = egui::WindowState {
+        size: Some(Vec2::new(250.0, 180.0)),
+        collapsed: false,
+        ..loaded
+    };
+    harness.ctx.set_window_state(id, restored);
+    harness.run();
+    assert_eq!(harness.ctx.window_state(id), Some(restored));
+    let rect = window_rect(&harness, id);
+    assert_eq!(rect.min, egui::pos2(120.0, 70.0));
+    assert!(rect.width() > 250.0, "{rect:?}");
+
+    harness.ctx.set_window_state(id, loaded);
+    harness.run();
+    assert_eq!(
+        harness.ctx.window_state(id).map(|s| s.collapsed),
+        Some(true)
+    );
+}
@@ -1753,6 +1753,93 @@ impl Tessellator {
         }
     }
 
+    /// Tessellate a filled rectangle that is blurred by `blur_width`, e.g. a [`crate::Shadow`].
+    ///
+    /// The blur is a number of rings around the rectangle, from `blur_width / 2` inside its edge
+    /// to `blur_width / 2` outside it, fading out smoothly.
+    /// Each ring follows the outline of the rectangle, with each corner radius grown (or shrunk) by the
+    /// distance of the ring from the edge. This way the blur follows the rounded corners,
+    /// and even a sharp corner gets the soft, round penumbra of a real shadow.
+    fn tessellate_blurred_rect(
+        &self,
+        rect: Rect,
+        corner_radius: CornerRadiusF32,
+        fill: Color32,
+        blur_width: f32,
+        out: &mut Mesh,
+    ) {
+        use std::f32::consts::TAU;
+
+        if fill == Color32::TRANSPARENT {
+            return;
+        }
+
+        let half_blur = blur_width / 2.0;
+        let corner_radius = corner_radius
+            .at_most(rect.size().min_elem() / 2.0)
+            .at_least(0.0);
+
+        // In the same order as `path::rounded_rectangle`: south-east, south-west, north-west, north-east.
+        let corners = [
+            (rect.right_bottom(), corner_radius.se, vec2(-1.0, -1.0)),
+            (rect.left_bottom(), corner_radius.sw, vec2(1.0, -1.0)),
+            (rect.left_top(), corner_radius.nw, vec2(1.0, 1.0)),
+            (rect.right_top(), corner_radius.ne, vec2(-1.0, 1.0)),
+        ];
+
+        // Every ring needs the same number of vertices, so pick the number of segments
+        // of each corner by its outermost radius:
+        let segments = corners.map(|(_, radius, _)| {
+            let radius_in_pixels = (radius + half_blur) * self.pixels_per_point;
+            (radius_in_pixels.sqrt().ceil() as usize).clamp(1, 16)
+        });
+        let ring_len: usize = segments.iter().map(|n| n + 1).sum();
+
+        // Enough rings that the piecewise linear falloff looks smooth:
+        let num_rings = ((blur_width * self.pixels_per_point / 4.0).ceil() as usize).clamp(2, 12);
+
+        let first_vertex = out.vertices.len() as u32;
+        out.reserve_vertices(1 + (num_rings + 1) * ring_len);
+        out.reserve_triangles(ring_len * (1 + 2 * num_rings));
+
+        // The opaque middle:
+        out.colored_vertex(rect.center(), fill);
+
+        for ring in 0..=num_rings {
+            let t = ring as f32 / num_rings as f32;
+            let distance = emath::lerp(-half_blur..=half_blur, t);
+            let opacity = 1.0 - t * t * (3.0 - 2.0 * t); // smoothstep
+            let color = fill.gamma_multiply(opacity);
+
+            for (quadrant, ((corner, radius, inwards), num_segments)) in
+                corners.into_iter().zip(segments).enumerate()
+            {
+                // The outline of `rect.expand(distance)`, with the corner radius grown by `distance`:
+                let radius = (radius + distance).at_least(0.0);
+                let center = corner + (radius - distance) * inwards;
+                for i in 0..=num_segments {
+                    let angle = (quadrant as f32 + i as f32 / num_segments as f32) * TAU / 4.0;
+                    out.colored_vertex(center + radius * Vec2::angled(angle), color);
+                }
+            }
+        }
+
+        let ring_start = |ring: usize| first_vertex + 1 + (ring * ring_len) as u32;
+        for i in 0..ring_len {
+            let j = (i + 1) % ring_len;
+            out.add_triangle(
+                first_vertex,
+                ring_start(0) + i as u32,
+                ring_start(0) + j as u32,
+            );
+            for ring in 0..num_rings {
+                let (inner, outer) = (ring_start(ring), ring_start(ring + 1));
+                out.add_triangle(inner + i as u32, outer + i as u32, outer + j as u32);
+                out.add_triangle(inner + i as u32, outer + j as u32, inner + j as u32);
+            }
+        }
+    }
+
     /// Tessellate a single [`Rect`] into a [`Mesh`].
     ///
     /// * `rect`: the rectangle to tessellate.
@@ -1874,6 +1961,15 @@ impl Tessellator {
             }
         }
 
+        if self.feathering < blur_width && stroke.is_empty() && brush.is_none() {
+            // The blur can't be wider than the smallest side of the rect:
+            let blur_width = blur_width.at_most(rect.size().min_elem()).at_least(0.0);
+            if self.feathering < blur_width {
+                self.tessellate_blurred_rect(rect, corner_radius, fill, blur_width, out);
+                return;
+            }
+        }
+
         let old_feathering = self.feathering;
 
         if self.feathering < blur_width {
@@ -2473,3 +2569,40 @@ fn path_bounding_box() {
         );
     }
 }
+
+#[test]
+fn blurred_rect_follows_rounded_corners() {
+    use crate::*;
+
+    let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 50.0));
+    let corner_radius = 10.0;
+    let blur_width = 16.0;
+
+    let shape = RectShape::filled(rect, corner_radius, Color32::BLACK).with_blur_width(blur_width);
+
+    let mut tessellator = Tessellator::new(1.0, Default::default(), [1024, 1024], vec![]);
+    let mut mesh = Mesh::default();
+    tessellator.tessellate_rect(&shape, &mut mesh);
+
+    assert_eq!(mesh.calc_bounds(), rect.expand(blur_width / 2.0));
+
+    // Signed distance to the rounded rectangle:
+    let distance = |p: Pos2| {
+        let q = (p - rect.center()).abs() - (rect.size() / 2.0 - Vec2::splat(corner_radius));
+        q.max(Vec2::ZERO).length() + q.max_elem().min(0.0) - corner_radius
+    };
+
+    let outer = mesh
+        .vertices
+        .iter()
+        .filter(|v| v.color == Color32::TRANSPARENT);
+    assert!(outer.clone().count() > 8);
+    for vertex in outer {
+        let d = distance(vertex.pos);
+        assert!(
+            (d - blur_width / 2.0).abs() < 0.01,
+            "{:?} is {d} from the rounded rect",
+            vertex.pos
+        );
+    }
+}